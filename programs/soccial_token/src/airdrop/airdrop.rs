@@ -31,6 +31,7 @@ use anchor_spl::token::{self, Transfer};
 use crate::airdrop::{context::ManageAirdrop, AirdropError};
 use crate::utils::error::ErrorCode;
 use crate::economy::MAX_AIRDROP_AMOUNT;
+use crate::vaults::{record_vault_flow, record_vault_outflow, LedgerCategory, LedgerFlow};
 
 /// ===========================================================================
 /// Function: distribute
@@ -44,6 +45,7 @@ use crate::economy::MAX_AIRDROP_AMOUNT;
 /// - Ensures the `airdrop_vault` has sufficient balance
 /// - Confirms the recipient's token account is owned by the user
 /// - Performs a secure CPI transfer with PDA signer authority
+/// - Posts an `Airdrops` outflow to the airdrop vault ledger
 /// - Emits an `AirdropEvent` with recipient, amount, timestamp, and reason
 ///
 /// ## Inputs:
@@ -56,6 +58,7 @@ use crate::economy::MAX_AIRDROP_AMOUNT;
 /// - `ExceedsPerAirdropLimit` if amount exceeds program limit
 /// - `VaultInsufficientBalance` if airdrop vault is underfunded
 /// - `InvalidRecipientAccount` if recipient ATA doesn't match wallet
+/// - `VaultError::EpochOutflowExceeded` if the vault's epoch outflow cap is reached
 /// ===========================================================================
 pub(crate) fn distribute(
     ctx: &mut Context<ManageAirdrop>, 
@@ -76,6 +79,14 @@ pub(crate) fn distribute(
         ErrorCode::Unauthorized
     );
 
+    // Enforce the vault's epoch outflow limits (may trip the circuit breaker)
+    record_vault_outflow(
+        &ctx.accounts.vault_limits,
+        &mut ctx.accounts.token_state,
+        &ctx.accounts.caller.key(),
+        amount,
+    )?;
+
     // --- Prepare vault signer seeds ---
     let seeds: &[&[u8]] = &[b"airdrop_vault", &[ctx.bumps.airdrop_vault]];
 
@@ -92,6 +103,7 @@ pub(crate) fn distribute(
     );
 
     token::transfer(cpi_ctx, amount)?;
+    record_vault_flow(&ctx.accounts.airdrop_ledger, LedgerFlow::Outflow, LedgerCategory::Airdrops, amount)?;
 
     let log_reason_str = reason.as_deref().unwrap_or("N/A");
     let log_reason = log_reason_str.to_string();
@@ -1,9 +1,9 @@
 // ======================================================================
 // Soccial Token – Airdrop Contexts
 //
-// This file defines the account context required to perform token
-// airdrop operations, including authority checks, vault validation,
-// and associated token account handling.
+// This file defines the account contexts required to perform token
+// airdrop operations and voucher redemptions, including authority checks,
+// vault validation, and associated token account handling.
 //
 // License: MIT License
 // Author: Paulo Rodrigues
@@ -13,7 +13,11 @@
 use anchor_lang::prelude::*;
 use anchor_spl::token::{Mint, Token, TokenAccount};
 
-use crate::{auth::user::UserAccessAccount, token::TokenState};
+use crate::{
+    airdrop::state::{VoucherBatch, VoucherBitmap, MAX_VOUCHERS_PER_BATCH},
+    auth::user::UserAccessAccount,
+    token::TokenState,
+};
 
 #[derive(Accounts)]
 pub struct ManageAirdrop<'info> {
@@ -38,6 +42,7 @@ pub struct ManageAirdrop<'info> {
 
     /// The TokenState account, holding global token configuration and airdrop supply.
     #[account(
+        mut,
         seeds = [b"token_state"],
         bump,
     )]
@@ -61,6 +66,22 @@ pub struct ManageAirdrop<'info> {
     )]
     pub airdrop_vault: AccountInfo<'info>,
 
+    /// CHECK: Outflow limits PDA of `airdrop_vault`. Enforced in logic once configured.
+    #[account(
+        mut,
+        seeds = [b"vault_limits", airdrop_vault.key().as_ref()],
+        bump
+    )]
+    pub vault_limits: AccountInfo<'info>,
+
+    /// CHECK: Ledger PDA of `airdrop_vault`. Posted to in logic once opened.
+    #[account(
+        mut,
+        seeds = [b"vault_ledger", airdrop_vault.key().as_ref()],
+        bump
+    )]
+    pub airdrop_ledger: AccountInfo<'info>,
+
     /// The Airdrop Vault's ATA holding the tokens to distribute.
     #[account(
         mut,
@@ -88,3 +109,203 @@ pub struct ManageAirdrop<'info> {
     pub token_program: Program<'info, Token>,
 }
 
+#[derive(Accounts)]
+#[instruction(args: Vec<String>)]
+pub struct CreateVoucherBatchContext<'info> {
+    // =========================================================================
+    // Caller & Access Control
+    // =========================================================================
+
+    /// The caller creating the batch (must hold `airdrop_tokens`; pays rent).
+    #[account(mut)]
+    pub caller: Signer<'info>,
+
+    /// User access control (optional). Used for permission checks.
+    #[account(
+        seeds = [b"user_access", caller.key().as_ref()],
+        bump,
+    )]
+    pub user_access: Option<Account<'info, UserAccessAccount>>,
+
+    // =========================================================================
+    // Voucher Batch
+    // =========================================================================
+
+    /// The new batch, keyed by `args[0]` (batch id).
+    #[account(
+        init,
+        payer = caller,
+        space = VoucherBatch::LEN,
+        seeds = [
+            b"voucher_batch".as_ref(),
+            &args.first().and_then(|id| id.parse::<u64>().ok()).unwrap_or_default().to_le_bytes(),
+        ],
+        bump
+    )]
+    pub voucher_batch: Account<'info, VoucherBatch>,
+
+    /// Spent-code bitmap, sized from `args[2]` (code count).
+    #[account(
+        init,
+        payer = caller,
+        space = VoucherBitmap::space_for(
+            args.get(2)
+                .and_then(|count| count.parse::<u32>().ok())
+                .unwrap_or_default()
+                .min(MAX_VOUCHERS_PER_BATCH)
+        ),
+        seeds = [b"voucher_bitmap", voucher_batch.key().as_ref()],
+        bump
+    )]
+    pub voucher_bitmap: Account<'info, VoucherBitmap>,
+
+    // =========================================================================
+    // Token State & Airdrop Vault
+    // =========================================================================
+
+    /// The TokenState account, holding global token configuration.
+    #[account(
+        seeds = [b"token_state"],
+        bump,
+    )]
+    pub token_state: Account<'info, TokenState>,
+
+    /// The Token Mint PDA.
+    #[account(
+        seeds = [b"token_mint"],
+        bump,
+    )]
+    pub mint: Account<'info, Mint>,
+
+    /// CHECK: The Airdrop Vault PDA. Must be manually verified in the handler.
+    #[account(
+        seeds = [b"airdrop_vault"],
+        bump,
+    )]
+    pub airdrop_vault: AccountInfo<'info>,
+
+    /// The Airdrop Vault's ATA funding the batch.
+    #[account(
+        associated_token::mint = mint,
+        associated_token::authority = airdrop_vault,
+    )]
+    pub airdrop_vault_token_account: Account<'info, TokenAccount>,
+
+    // =========================================================================
+    // Program Dependencies
+    // =========================================================================
+
+    /// System program required to create the batch accounts.
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+#[instruction(args: Vec<String>)]
+pub struct RedeemVoucherContext<'info> {
+    // =========================================================================
+    // Caller & Access Control
+    // =========================================================================
+
+    /// The user redeeming a code.
+    pub caller: Signer<'info>,
+
+    /// The voucher key seeded by the code; its signature proves the caller
+    /// holds the code.
+    pub voucher_key: Signer<'info>,
+
+    /// CHECK: The caller's `user_access` PDA. Always required (even when not
+    /// created yet), so a `Banned` flag cannot be skipped by omitting it.
+    #[account(
+        seeds = [b"user_access", caller.key().as_ref()],
+        bump,
+    )]
+    pub user_access: AccountInfo<'info>,
+
+    // =========================================================================
+    // Voucher Batch
+    // =========================================================================
+
+    /// The batch keyed by `args[0]` (batch id).
+    #[account(
+        mut,
+        seeds = [
+            b"voucher_batch".as_ref(),
+            &args.first().and_then(|id| id.parse::<u64>().ok()).unwrap_or_default().to_le_bytes(),
+        ],
+        bump = voucher_batch.bump
+    )]
+    pub voucher_batch: Account<'info, VoucherBatch>,
+
+    /// Spent-code bitmap of the batch.
+    #[account(
+        mut,
+        seeds = [b"voucher_bitmap", voucher_batch.key().as_ref()],
+        bump
+    )]
+    pub voucher_bitmap: Account<'info, VoucherBitmap>,
+
+    // =========================================================================
+    // Token State & Airdrop Vault
+    // =========================================================================
+
+    /// The TokenState account, holding global token configuration.
+    #[account(
+        mut,
+        seeds = [b"token_state"],
+        bump,
+    )]
+    pub token_state: Account<'info, TokenState>,
+
+    /// The Token Mint PDA.
+    #[account(
+        seeds = [b"token_mint"],
+        bump,
+    )]
+    pub mint: Account<'info, Mint>,
+
+    /// CHECK: The Airdrop Vault PDA. Must be manually verified in the handler.
+    #[account(
+        seeds = [b"airdrop_vault"],
+        bump,
+    )]
+    pub airdrop_vault: AccountInfo<'info>,
+
+    /// CHECK: Outflow limits PDA of `airdrop_vault`. Enforced in logic once configured.
+    #[account(
+        mut,
+        seeds = [b"vault_limits", airdrop_vault.key().as_ref()],
+        bump
+    )]
+    pub vault_limits: AccountInfo<'info>,
+
+    /// CHECK: Ledger PDA of `airdrop_vault`. Posted to in logic once opened.
+    #[account(
+        mut,
+        seeds = [b"vault_ledger", airdrop_vault.key().as_ref()],
+        bump
+    )]
+    pub airdrop_ledger: AccountInfo<'info>,
+
+    /// The Airdrop Vault's ATA paying the voucher.
+    #[account(
+        mut,
+        associated_token::mint = mint,
+        associated_token::authority = airdrop_vault,
+    )]
+    pub airdrop_vault_token_account: Account<'info, TokenAccount>,
+
+    /// The redeemer's associated token account.
+    #[account(
+        mut,
+        associated_token::mint = mint,
+        associated_token::authority = caller,
+    )]
+    pub redeemer_token_account: Account<'info, TokenAccount>,
+
+    // =========================================================================
+    // Program Dependencies
+    // =========================================================================
+
+    /// SPL Token Program.
+    pub token_program: Program<'info, Token>,
+}
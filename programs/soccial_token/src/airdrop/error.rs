@@ -3,7 +3,7 @@
 //
 // This module defines custom errors related to airdrop campaigns,
 // including supply limits, validation of recipient accounts,
-// and safety checks for vaults and token transfers.
+// safety checks for vaults and token transfers, and voucher redemption.
 //
 // License: MIT License
 // Author: Paulo Rodrigues
@@ -38,4 +38,32 @@ pub enum AirdropError {
     /// The airdrop vault does not have enough tokens to complete the operation.
     #[msg("Airdrop Vault does not have enough tokens.")]
     VaultInsufficientBalance,
+
+    /// The voucher batch parameters are invalid (code count, budget or expiry).
+    #[msg("Invalid voucher batch.")]
+    InvalidVoucherBatch,
+
+    /// A merkle root or proof node is not 32 bytes of hex.
+    #[msg("Invalid voucher hash.")]
+    InvalidVoucherHash,
+
+    /// The voucher does not match the batch (bad index, voucher key or proof).
+    #[msg("Invalid voucher code.")]
+    InvalidVoucher,
+
+    /// The code at this index was already redeemed.
+    #[msg("Voucher already redeemed.")]
+    VoucherAlreadyRedeemed,
+
+    /// The voucher batch has expired.
+    #[msg("Voucher batch has expired.")]
+    VoucherExpired,
+
+    /// Redeeming would exceed the batch budget.
+    #[msg("Voucher batch budget exhausted.")]
+    VoucherBudgetExhausted,
+
+    /// The redeemer is banned.
+    #[msg("Banned users cannot redeem vouchers.")]
+    RedeemerBanned,
 }
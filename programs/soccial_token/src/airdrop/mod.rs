@@ -1,7 +1,11 @@
 pub mod error;
 pub mod context;
 pub mod airdrop;
+pub mod state;
+pub mod voucher;
 
 pub use error::*;
 pub use context::*;
-pub use airdrop::*;
\ No newline at end of file
+pub use airdrop::*;
+pub use state::*;
+pub use voucher::*;
@@ -0,0 +1,177 @@
+// ===========================================================================
+// Voucher State – Soccial Token (SCTK)
+// ---------------------------------------------------------------------------
+//
+// This module defines gift-code batches paid from the `airdrop_vault`.
+// Codes never touch the chain in clear text: each code seeds an ed25519
+// voucher key, a batch only stores the merkle root of those keys, and a
+// bitmap marks which code indices were already spent.
+//
+// ---------------------------------------------------------------------------
+// ## Merkle Layout:
+// - `voucher_key = ed25519 keypair seeded with sha256(code)`
+// - `leaf = sha256(0x00 || index (u32 LE) || voucher_key.pubkey)`
+// - `node = sha256(0x01 || min(a, b) || max(a, b))` (sorted pairs, so proofs
+//   carry no left/right flags)
+//
+// ---------------------------------------------------------------------------
+// ## Components:
+// - `VoucherBatch`: Root, per-code amount, expiry and budget of a batch
+// - `VoucherBitmap`: One bit per code index, set once redeemed
+// - `voucher_key_seed()`: Seed of the voucher key derived from a code
+// - `voucher_leaf()` / `voucher_node()`: Merkle hashing helpers
+//
+// ---------------------------------------------------------------------------
+// Author: Paulo Rodrigues
+// Project: Soccial Token
+// Website: https://www.soccial.com/thetoken
+// License: MIT
+// ===========================================================================
+
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::hash::{hash, hashv};
+use crate::airdrop::error::AirdropError;
+
+/// Maximum number of codes in a single batch (bitmap of 8 KiB).
+pub const MAX_VOUCHERS_PER_BATCH: u32 = 65_536;
+
+/// Maximum merkle proof length (`log2(MAX_VOUCHERS_PER_BATCH)`).
+pub const MAX_VOUCHER_PROOF_LEN: usize = 16;
+
+/// ===========================================================================
+/// Account: VoucherBatch
+///
+/// A batch of gift codes redeemable for a fixed SCTK amount each, paid from
+/// the `airdrop_vault` until the budget is exhausted or the batch expires.
+///
+/// ## Fields:
+/// - `batch_id`: Identifier chosen by the creator (seeds the PDA)
+/// - `merkle_root`: Root of the voucher key leaves (see module header)
+/// - `code_count`: Number of codes (size of the bitmap)
+/// - `amount`: SCTK base units paid per redeemed code
+/// - `budget`: Maximum SCTK paid out by the whole batch
+/// - `redeemed_amount` / `redeemed_count`: Running totals
+/// - `expires_at`: Unix timestamp after which codes can no longer be redeemed
+/// - `created_by`: Caller that created the batch
+/// ===========================================================================
+#[account]
+pub struct VoucherBatch {
+    pub batch_id: u64,
+    pub merkle_root: [u8; 32],
+    pub code_count: u32,
+    pub amount: u64,
+    pub budget: u64,
+    pub redeemed_amount: u64,
+    pub redeemed_count: u32,
+    pub expires_at: i64,
+    pub created_by: Pubkey,
+    pub created_at: i64,
+    pub bump: u8,
+}
+
+impl VoucherBatch {
+    pub const LEN: usize =
+        8 +     // Anchor discriminator
+        8 +     // batch_id (u64)
+        32 +    // merkle_root ([u8; 32])
+        4 +     // code_count (u32)
+        8 +     // amount (u64)
+        8 +     // budget (u64)
+        8 +     // redeemed_amount (u64)
+        4 +     // redeemed_count (u32)
+        8 +     // expires_at (i64)
+        32 +    // created_by (Pubkey)
+        8 +     // created_at (i64)
+        1;      // bump (u8)
+
+    /// Verifies that `voucher_key` is the key committed at `index`.
+    ///
+    /// ## Errors:
+    /// - `InvalidVoucher` if the index is out of range or the proof fails
+    pub fn verify_key(&self, index: u32, voucher_key: &Pubkey, proof: &[[u8; 32]]) -> Result<()> {
+        require!(index < self.code_count, AirdropError::InvalidVoucher);
+        require!(proof.len() <= MAX_VOUCHER_PROOF_LEN, AirdropError::InvalidVoucher);
+
+        let root = proof
+            .iter()
+            .fold(voucher_leaf(index, voucher_key), |node, sibling| voucher_node(&node, sibling));
+        require!(root == self.merkle_root, AirdropError::InvalidVoucher);
+
+        Ok(())
+    }
+}
+
+/// ===========================================================================
+/// Account: VoucherBitmap
+///
+/// Spent-code bitmap of a `VoucherBatch`: bit `i` is set once code `i` has
+/// been redeemed.
+/// ===========================================================================
+#[account]
+pub struct VoucherBitmap {
+    pub batch: Pubkey,
+    pub bits: Vec<u8>,
+}
+
+impl VoucherBitmap {
+    /// Account size for a batch of `code_count` codes.
+    pub fn space_for(code_count: u32) -> usize {
+        8 +                                     // Anchor discriminator
+        32 +                                    // batch (Pubkey)
+        4 + (code_count as usize).div_ceil(8)   // bits (Vec<u8>)
+    }
+
+    /// Returns `true` if code `index` was already redeemed.
+    pub fn is_spent(&self, index: u32) -> bool {
+        self.bits
+            .get(index as usize / 8)
+            .is_some_and(|byte| byte & (1 << (index % 8)) != 0)
+    }
+
+    /// Marks code `index` as redeemed.
+    ///
+    /// ## Errors:
+    /// - `VoucherAlreadyRedeemed` if the bit is already set
+    /// - `InvalidVoucher` if `index` is outside the bitmap
+    pub fn mark_spent(&mut self, index: u32) -> Result<()> {
+        require!(!self.is_spent(index), AirdropError::VoucherAlreadyRedeemed);
+
+        let byte = self
+            .bits
+            .get_mut(index as usize / 8)
+            .ok_or(AirdropError::InvalidVoucher)?;
+        *byte |= 1 << (index % 8);
+
+        Ok(())
+    }
+}
+
+/// Seed of the ed25519 voucher key a code holder derives from `code`.
+pub fn voucher_key_seed(code: &str) -> [u8; 32] {
+    hash(code.as_bytes()).to_bytes()
+}
+
+/// Merkle leaf of the voucher key at `index`.
+pub fn voucher_leaf(index: u32, voucher_key: &Pubkey) -> [u8; 32] {
+    hashv(&[&[0x00], &index.to_le_bytes(), voucher_key.as_ref()]).to_bytes()
+}
+
+/// Merkle parent of two nodes (order-independent).
+pub fn voucher_node(a: &[u8; 32], b: &[u8; 32]) -> [u8; 32] {
+    let (low, high) = if a <= b { (a, b) } else { (b, a) };
+    hashv(&[&[0x01], low, high]).to_bytes()
+}
+
+/// Parses a 32-byte merkle node given as 64 hex characters.
+pub fn parse_voucher_hash(value: &str) -> Result<[u8; 32]> {
+    let value = value.trim();
+    require!(value.len() == 64 && value.is_ascii(), AirdropError::InvalidVoucherHash);
+
+    let mut hash = [0u8; 32];
+    for (i, byte) in hash.iter_mut().enumerate() {
+        *byte = u8::from_str_radix(&value[i * 2..i * 2 + 2], 16)
+            .map_err(|_| AirdropError::InvalidVoucherHash)?;
+    }
+
+    Ok(hash)
+}
@@ -0,0 +1,247 @@
+// ===========================================================================
+// Vouchers – Soccial Token (SCTK)
+// ---------------------------------------------------------------------------
+//
+// Gift codes handed out by marketing and redeemed for SCTK paid from the
+// `airdrop_vault`. A batch commits to its codes through a merkle root (see
+// `airdrop::state`); redeeming proves one code with a signature from the
+// voucher key it seeds, plus a merkle proof, and flips the matching bit in
+// the batch bitmap.
+//
+// ---------------------------------------------------------------------------
+// ## Rules:
+// - Per-code amount never exceeds `MAX_AIRDROP_AMOUNT`
+// - A batch never pays out more than its budget, nor after `expires_at`
+// - Users flagged `Banned` cannot redeem
+// - The code itself never goes on-chain; only the holder can sign with its
+//   voucher key, so a pending redemption cannot be replayed by someone else
+//
+// ---------------------------------------------------------------------------
+// ## Components:
+// - `create_voucher_batch()`: Registers a batch and its spent-code bitmap
+// - `redeem_voucher()`: Verifies a code and pays the redeemer
+//
+// ---------------------------------------------------------------------------
+// Author: Paulo Rodrigues
+// Project: Soccial Token
+// Website: https://www.soccial.com/thetoken
+// License: MIT
+// ===========================================================================
+
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, Transfer};
+
+use crate::{
+    airdrop::{
+        context::*,
+        error::AirdropError,
+        state::MAX_VOUCHERS_PER_BATCH,
+    },
+    auth::user::{load_user_access, ExtraFlag},
+    economy::MAX_AIRDROP_AMOUNT,
+    utils::system::check_core,
+    vaults::{record_vault_flow, record_vault_outflow, LedgerCategory, LedgerFlow},
+};
+
+#[event]
+pub struct VoucherBatchCreated {
+    pub batch: Pubkey,
+    pub batch_id: u64,
+    pub code_count: u32,
+    pub amount: u64,
+    pub budget: u64,
+    pub expires_at: i64,
+    pub created_by: Pubkey,
+}
+
+#[event]
+pub struct VoucherRedeemed {
+    pub batch: Pubkey,
+    pub batch_id: u64,
+    pub index: u32,
+    pub redeemer: Pubkey,
+    pub amount: u64,
+    pub redeemed_amount: u64,
+}
+
+/// ===========================================================================
+/// Registers a new voucher batch funded from the `airdrop_vault`.
+///
+/// ## Behavior:
+/// - Stores the merkle root, per-code amount, budget and expiry
+/// - Sizes the spent-code bitmap for `code_count` codes
+///
+/// ## Errors:
+/// - `AirdropError::InvalidAmount` if `amount` is zero
+/// - `AirdropError::ExceedsPerAirdropLimit` if `amount` exceeds `MAX_AIRDROP_AMOUNT`
+/// - `AirdropError::InvalidVoucherBatch` on a bad code count, budget or expiry
+/// - `AirdropError::VaultInsufficientBalance` if the vault cannot cover the budget
+/// ===========================================================================
+pub(crate) fn create_voucher_batch(
+    ctx: Context<CreateVoucherBatchContext>,
+    batch_id: u64,
+    merkle_root: [u8; 32],
+    code_count: u32,
+    amount: u64,
+    budget: u64,
+    expires_at: i64,
+) -> Result<()> {
+    let now = Clock::get()?.unix_timestamp;
+
+    require!(amount > 0, AirdropError::InvalidAmount);
+    require!(amount <= MAX_AIRDROP_AMOUNT, AirdropError::ExceedsPerAirdropLimit);
+    require!(
+        code_count > 0 && code_count <= MAX_VOUCHERS_PER_BATCH,
+        AirdropError::InvalidVoucherBatch
+    );
+    require!(
+        budget >= amount && budget <= amount.saturating_mul(code_count as u64),
+        AirdropError::InvalidVoucherBatch
+    );
+    require!(expires_at > now, AirdropError::InvalidVoucherBatch);
+    require!(
+        ctx.accounts.airdrop_vault_token_account.amount >= budget,
+        AirdropError::VaultInsufficientBalance
+    );
+
+    let caller = ctx.accounts.caller.key();
+    let batch_key = ctx.accounts.voucher_batch.key();
+
+    let batch = &mut ctx.accounts.voucher_batch;
+    batch.batch_id = batch_id;
+    batch.merkle_root = merkle_root;
+    batch.code_count = code_count;
+    batch.amount = amount;
+    batch.budget = budget;
+    batch.redeemed_amount = 0;
+    batch.redeemed_count = 0;
+    batch.expires_at = expires_at;
+    batch.created_by = caller;
+    batch.created_at = now;
+    batch.bump = ctx.bumps.voucher_batch;
+
+    let bitmap = &mut ctx.accounts.voucher_bitmap;
+    bitmap.batch = batch_key;
+    bitmap.bits = vec![0; (code_count as usize).div_ceil(8)];
+
+    msg!(
+        "🎟️ Voucher batch #{} created → {} codes × {} units (budget {}, expires at {})",
+        batch_id,
+        code_count,
+        amount,
+        budget,
+        expires_at,
+    );
+
+    emit!(VoucherBatchCreated {
+        batch: batch_key,
+        batch_id,
+        code_count,
+        amount,
+        budget,
+        expires_at,
+        created_by: caller,
+    });
+
+    Ok(())
+}
+
+/// ===========================================================================
+/// Redeems a gift code for SCTK paid from the `airdrop_vault`.
+///
+/// ## Behavior:
+/// - Rejects callers flagged `Banned`
+/// - Verifies the signing `voucher_key` at `index` against the batch merkle root
+/// - Marks the index as spent and transfers `amount` to the caller's ATA
+/// - Posts an `Airdrops` outflow to the airdrop vault ledger
+///
+/// ## Errors:
+/// - `AirdropError::RedeemerBanned` if the caller is banned
+/// - `AirdropError::VoucherExpired` if the batch has expired
+/// - `AirdropError::InvalidVoucher` if the voucher key or proof does not match
+/// - `AirdropError::VoucherAlreadyRedeemed` if the code was already used
+/// - `AirdropError::VoucherBudgetExhausted` if the budget is spent
+/// - `AirdropError::VaultInsufficientBalance` if the vault is underfunded
+/// - `VaultError::EpochOutflowExceeded` if the vault's epoch outflow cap is reached
+/// ===========================================================================
+pub(crate) fn redeem_voucher(
+    ctx: Context<RedeemVoucherContext>,
+    index: u32,
+    proof: Vec<[u8; 32]>,
+) -> Result<()> {
+    let caller = ctx.accounts.caller.key();
+    let voucher_key = ctx.accounts.voucher_key.key();
+
+    let user_access = load_user_access(&ctx.accounts.user_access)?;
+    check_core(&caller, user_access.as_ref(), &ctx.accounts.token_state)?;
+    require!(
+        !user_access.is_some_and(|access| access.has_flag(ExtraFlag::Banned)),
+        AirdropError::RedeemerBanned
+    );
+
+    let now = Clock::get()?.unix_timestamp;
+    let batch = &ctx.accounts.voucher_batch;
+    let amount = batch.amount;
+
+    require!(now <= batch.expires_at, AirdropError::VoucherExpired);
+    require!(amount <= MAX_AIRDROP_AMOUNT, AirdropError::ExceedsPerAirdropLimit);
+    batch.verify_key(index, &voucher_key, &proof)?;
+
+    let redeemed_amount = batch
+        .redeemed_amount
+        .checked_add(amount)
+        .ok_or(AirdropError::Overflow)?;
+    require!(redeemed_amount <= batch.budget, AirdropError::VoucherBudgetExhausted);
+    require!(
+        ctx.accounts.airdrop_vault_token_account.amount >= amount,
+        AirdropError::VaultInsufficientBalance
+    );
+
+    // Enforce the vault's epoch outflow limits (may trip the circuit breaker)
+    record_vault_outflow(
+        &ctx.accounts.vault_limits,
+        &mut ctx.accounts.token_state,
+        &ctx.accounts.caller.key(),
+        amount,
+    )?;
+
+    ctx.accounts.voucher_bitmap.mark_spent(index)?;
+
+    let batch = &mut ctx.accounts.voucher_batch;
+    batch.redeemed_amount = redeemed_amount;
+    batch.redeemed_count = batch.redeemed_count.checked_add(1).ok_or(AirdropError::Overflow)?;
+    let batch_id = batch.batch_id;
+
+    let seeds: &[&[u8]] = &[b"airdrop_vault", &[ctx.bumps.airdrop_vault]];
+    let binding = [seeds];
+    let cpi_ctx = CpiContext::new_with_signer(
+        ctx.accounts.token_program.to_account_info(),
+        Transfer {
+            from: ctx.accounts.airdrop_vault_token_account.to_account_info(),
+            to: ctx.accounts.redeemer_token_account.to_account_info(),
+            authority: ctx.accounts.airdrop_vault.to_account_info(),
+        },
+        &binding,
+    );
+    token::transfer(cpi_ctx, amount)?;
+    record_vault_flow(&ctx.accounts.airdrop_ledger, LedgerFlow::Outflow, LedgerCategory::Airdrops, amount)?;
+
+    msg!(
+        "🎁 Voucher #{}/{} redeemed by {} → {} units",
+        batch_id,
+        index,
+        caller,
+        amount,
+    );
+
+    emit!(VoucherRedeemed {
+        batch: ctx.accounts.voucher_batch.key(),
+        batch_id,
+        index,
+        redeemer: caller,
+        amount,
+        redeemed_amount,
+    });
+
+    Ok(())
+}
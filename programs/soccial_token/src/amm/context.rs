@@ -0,0 +1,519 @@
+// ======================================================================
+// Soccial Token – AMM Contexts
+//
+// Defines account contexts for the constant-product pool: seeding it from
+// the liquidity vault, adding and removing treasury-owned liquidity,
+// swapping against the reserves, and governed settings updates.
+//
+// License: MIT License
+// Author: Paulo Rodrigues
+// Project: Soccial Token
+// ======================================================================
+
+use anchor_lang::prelude::*;
+use anchor_spl::{
+    associated_token::AssociatedToken,
+    token::{Mint, Token, TokenAccount},
+};
+use crate::{
+    amm::{error::AmmError, state::AmmPool},
+    auth::user::UserAccessAccount,
+    governance::{GovernanceState, ProposalAccount},
+    oracle::state::PriceAccumulator,
+    token::state::TokenState,
+};
+
+#[derive(Accounts)]
+pub struct InitializeAmmPool<'info> {
+    // ─────────────────────────────────────────────────────────────
+    // Signer
+    // ─────────────────────────────────────────────────────────────
+
+    /// The operator seeding the pool; provides the quote side and pays rent.
+    #[account(mut)]
+    pub caller: Signer<'info>,
+
+    /// Operator's quote token account funding the quote reserve.
+    #[account(
+        mut,
+        token::mint = quote_mint,
+        token::authority = caller
+    )]
+    pub caller_quote_account: Account<'info, TokenAccount>,
+
+    // ─────────────────────────────────────────────────────────────
+    // Pool
+    // ─────────────────────────────────────────────────────────────
+
+    /// Global pool account.
+    #[account(
+        init,
+        payer = caller,
+        space = AmmPool::LEN,
+        seeds = [b"amm_pool"],
+        bump
+    )]
+    pub amm_pool: Account<'info, AmmPool>,
+
+    /// Quote mint paired with SCTK.
+    #[account(constraint = quote_mint.key() != token_mint.key() @ AmmError::InvalidQuoteMint)]
+    pub quote_mint: Account<'info, Mint>,
+
+    /// TWAP accumulator of the pool quote mint, created alongside the pool.
+    #[account(
+        init_if_needed,
+        payer = caller,
+        space = PriceAccumulator::LEN,
+        seeds = [b"price_accumulator", quote_mint.key().as_ref()],
+        bump
+    )]
+    pub price_accumulator: Account<'info, PriceAccumulator>,
+
+    /// Pool SCTK reserve, owned by the pool PDA.
+    #[account(
+        init,
+        payer = caller,
+        associated_token::mint = token_mint,
+        associated_token::authority = amm_pool
+    )]
+    pub pool_token_account: Account<'info, TokenAccount>,
+
+    /// Pool quote reserve, owned by the pool PDA.
+    #[account(
+        init,
+        payer = caller,
+        associated_token::mint = quote_mint,
+        associated_token::authority = amm_pool
+    )]
+    pub pool_quote_account: Account<'info, TokenAccount>,
+
+    // ------------------------------------------------------------------------
+    // Liquidity & Treasury Vaults
+    // ------------------------------------------------------------------------
+
+    /// CHECK: PDA authority for liquidity vault operations. Verified by seeds.
+    #[account(seeds = [b"liquidity_vault"], bump)]
+    pub liquidity_vault: AccountInfo<'info>,
+
+    /// Liquidity vault token account providing the SCTK side.
+    #[account(
+        mut,
+        associated_token::mint = token_mint,
+        associated_token::authority = liquidity_vault
+    )]
+    pub liquidity_vault_token_account: Account<'info, TokenAccount>,
+
+    /// CHECK: Outflow limits PDA of `liquidity_vault`. Enforced in logic once configured.
+    #[account(
+        mut,
+        seeds = [b"vault_limits", liquidity_vault.key().as_ref()],
+        bump
+    )]
+    pub vault_limits: AccountInfo<'info>,
+
+    /// CHECK: Ledger PDA of `liquidity_vault`. Posted to in logic once opened.
+    #[account(
+        mut,
+        seeds = [b"vault_ledger", liquidity_vault.key().as_ref()],
+        bump
+    )]
+    pub liquidity_ledger: AccountInfo<'info>,
+
+    /// CHECK: Treasury vault PDA recorded as the owner of all LP shares.
+    #[account(seeds = [b"treasury_vault"], bump)]
+    pub treasury_vault: AccountInfo<'info>,
+
+    // ─────────────────────────────────────────────────────────────
+    // Mint, Access Control & State
+    // ─────────────────────────────────────────────────────────────
+
+    /// Token mint (SCTK).
+    pub token_mint: Account<'info, Mint>,
+
+    /// Optional access control account for the caller.
+    #[account(
+        seeds = [b"user_access", caller.key().as_ref()],
+        bump
+    )]
+    pub user_access: Option<Account<'info, UserAccessAccount>>,
+
+    /// TokenState account for system-wide configuration.
+    #[account(mut)]
+    pub token_state: Account<'info, TokenState>,
+
+    // ─────────────────────────────────────────────────────────────
+    // Programs
+    // ─────────────────────────────────────────────────────────────
+
+    /// Token program required for token transfers.
+    pub token_program: Program<'info, Token>,
+
+    /// Associated Token Program used to create the pool reserves.
+    pub associated_token_program: Program<'info, AssociatedToken>,
+
+    /// System program required for account creation.
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct AddAmmLiquidity<'info> {
+    /// The operator adding liquidity; provides the quote side.
+    pub caller: Signer<'info>,
+
+    /// Operator's quote token account funding the quote reserve.
+    #[account(
+        mut,
+        token::mint = quote_mint,
+        token::authority = caller
+    )]
+    pub caller_quote_account: Account<'info, TokenAccount>,
+
+    /// Global pool account.
+    #[account(
+        mut,
+        seeds = [b"amm_pool"],
+        bump = amm_pool.bump
+    )]
+    pub amm_pool: Account<'info, AmmPool>,
+
+    /// Quote mint paired with SCTK.
+    #[account(address = amm_pool.quote_mint @ AmmError::InvalidQuoteMint)]
+    pub quote_mint: Account<'info, Mint>,
+
+    /// Pool SCTK reserve.
+    #[account(
+        mut,
+        associated_token::mint = token_mint,
+        associated_token::authority = amm_pool
+    )]
+    pub pool_token_account: Account<'info, TokenAccount>,
+
+    /// Pool quote reserve.
+    #[account(
+        mut,
+        associated_token::mint = quote_mint,
+        associated_token::authority = amm_pool
+    )]
+    pub pool_quote_account: Account<'info, TokenAccount>,
+
+    /// CHECK: PDA authority for liquidity vault operations. Verified by seeds.
+    #[account(seeds = [b"liquidity_vault"], bump)]
+    pub liquidity_vault: AccountInfo<'info>,
+
+    /// Liquidity vault token account providing the SCTK side.
+    #[account(
+        mut,
+        associated_token::mint = token_mint,
+        associated_token::authority = liquidity_vault
+    )]
+    pub liquidity_vault_token_account: Account<'info, TokenAccount>,
+
+    /// CHECK: Outflow limits PDA of `liquidity_vault`. Enforced in logic once configured.
+    #[account(
+        mut,
+        seeds = [b"vault_limits", liquidity_vault.key().as_ref()],
+        bump
+    )]
+    pub vault_limits: AccountInfo<'info>,
+
+    /// CHECK: Ledger PDA of `liquidity_vault`. Posted to in logic once opened.
+    #[account(
+        mut,
+        seeds = [b"vault_ledger", liquidity_vault.key().as_ref()],
+        bump
+    )]
+    pub liquidity_ledger: AccountInfo<'info>,
+
+    /// Token mint (SCTK).
+    pub token_mint: Account<'info, Mint>,
+
+    /// Optional access control account for the caller.
+    #[account(
+        seeds = [b"user_access", caller.key().as_ref()],
+        bump
+    )]
+    pub user_access: Option<Account<'info, UserAccessAccount>>,
+
+    /// TokenState account for system-wide configuration.
+    #[account(mut)]
+    pub token_state: Account<'info, TokenState>,
+
+    /// Token program required for token transfers.
+    pub token_program: Program<'info, Token>,
+}
+
+#[derive(Accounts)]
+pub struct RemoveAmmLiquidity<'info> {
+    /// The operator withdrawing treasury-owned liquidity; pays ATA rent if needed.
+    #[account(mut)]
+    pub caller: Signer<'info>,
+
+    /// Global pool account.
+    #[account(
+        mut,
+        seeds = [b"amm_pool"],
+        bump = amm_pool.bump
+    )]
+    pub amm_pool: Account<'info, AmmPool>,
+
+    /// Quote mint paired with SCTK.
+    #[account(address = amm_pool.quote_mint @ AmmError::InvalidQuoteMint)]
+    pub quote_mint: Account<'info, Mint>,
+
+    /// Pool SCTK reserve.
+    #[account(
+        mut,
+        associated_token::mint = token_mint,
+        associated_token::authority = amm_pool
+    )]
+    pub pool_token_account: Account<'info, TokenAccount>,
+
+    /// Pool quote reserve.
+    #[account(
+        mut,
+        associated_token::mint = quote_mint,
+        associated_token::authority = amm_pool
+    )]
+    pub pool_quote_account: Account<'info, TokenAccount>,
+
+    /// CHECK: PDA authority for liquidity vault operations. Verified by seeds.
+    #[account(seeds = [b"liquidity_vault"], bump)]
+    pub liquidity_vault: AccountInfo<'info>,
+
+    /// Liquidity vault token account receiving the SCTK side.
+    #[account(
+        mut,
+        associated_token::mint = token_mint,
+        associated_token::authority = liquidity_vault
+    )]
+    pub liquidity_vault_token_account: Account<'info, TokenAccount>,
+
+    /// CHECK: Ledger PDA of `liquidity_vault`. Posted to in logic once opened.
+    #[account(
+        mut,
+        seeds = [b"vault_ledger", liquidity_vault.key().as_ref()],
+        bump
+    )]
+    pub liquidity_ledger: AccountInfo<'info>,
+
+    /// CHECK: Treasury vault PDA owning the LP shares. Verified by seeds.
+    #[account(
+        seeds = [b"treasury_vault"],
+        bump,
+        address = amm_pool.lp_owner @ AmmError::InsufficientShares
+    )]
+    pub treasury_vault: AccountInfo<'info>,
+
+    /// Treasury quote account receiving the quote side.
+    #[account(
+        init_if_needed,
+        payer = caller,
+        associated_token::mint = quote_mint,
+        associated_token::authority = treasury_vault
+    )]
+    pub treasury_quote_account: Account<'info, TokenAccount>,
+
+    /// Token mint (SCTK).
+    pub token_mint: Account<'info, Mint>,
+
+    /// Optional access control account for the caller.
+    #[account(
+        seeds = [b"user_access", caller.key().as_ref()],
+        bump
+    )]
+    pub user_access: Option<Account<'info, UserAccessAccount>>,
+
+    /// TokenState account for system-wide configuration.
+    pub token_state: Account<'info, TokenState>,
+
+    /// Token program required for token transfers.
+    pub token_program: Program<'info, Token>,
+
+    /// Associated Token Program used to create the treasury quote account.
+    pub associated_token_program: Program<'info, AssociatedToken>,
+
+    /// System program required for account creation.
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct AmmSwapContext<'info> {
+    // ─────────────────────────────────────────────────────────────
+    // Signer & User Accounts
+    // ─────────────────────────────────────────────────────────────
+
+    /// The trader executing the swap.
+    pub caller: Signer<'info>,
+
+    /// Trader's SCTK account.
+    #[account(
+        mut,
+        associated_token::mint = token_mint,
+        associated_token::authority = caller
+    )]
+    pub user_token_account: Account<'info, TokenAccount>,
+
+    /// Trader's quote token account.
+    #[account(
+        mut,
+        token::mint = quote_mint,
+        token::authority = caller
+    )]
+    pub user_quote_account: Account<'info, TokenAccount>,
+
+    // ─────────────────────────────────────────────────────────────
+    // Pool
+    // ─────────────────────────────────────────────────────────────
+
+    /// Global pool account.
+    #[account(
+        mut,
+        seeds = [b"amm_pool"],
+        bump = amm_pool.bump
+    )]
+    pub amm_pool: Account<'info, AmmPool>,
+
+    /// TWAP accumulator of the pool quote mint, updated with the post-swap spot price.
+    #[account(
+        mut,
+        seeds = [b"price_accumulator", amm_pool.quote_mint.as_ref()],
+        bump
+    )]
+    pub price_accumulator: Account<'info, PriceAccumulator>,
+
+    /// Quote mint paired with SCTK.
+    #[account(address = amm_pool.quote_mint @ AmmError::InvalidQuoteMint)]
+    pub quote_mint: Account<'info, Mint>,
+
+    /// Pool SCTK reserve.
+    #[account(
+        mut,
+        associated_token::mint = token_mint,
+        associated_token::authority = amm_pool
+    )]
+    pub pool_token_account: Account<'info, TokenAccount>,
+
+    /// Pool quote reserve.
+    #[account(
+        mut,
+        associated_token::mint = quote_mint,
+        associated_token::authority = amm_pool
+    )]
+    pub pool_quote_account: Account<'info, TokenAccount>,
+
+    // ------------------------------------------------------------------------
+    // Fee Vaults
+    // ------------------------------------------------------------------------
+
+    /// CHECK: PDA authority for rewards logic. Verified by seeds.
+    #[account(seeds = [b"rewards_vault"], bump)]
+    pub rewards_vault: AccountInfo<'info>,
+
+    /// Rewards vault's token account.
+    #[account(
+        mut,
+        associated_token::mint = token_mint,
+        associated_token::authority = rewards_vault
+    )]
+    pub rewards_vault_token_account: Account<'info, TokenAccount>,
+
+    /// CHECK: Ledger PDA of `rewards_vault`. Posted to in logic once opened.
+    #[account(
+        mut,
+        seeds = [b"vault_ledger", rewards_vault.key().as_ref()],
+        bump
+    )]
+    pub rewards_ledger: AccountInfo<'info>,
+
+    /// CHECK: PDA authority for revenue logic. Verified by seeds.
+    #[account(seeds = [b"revenue_vault"], bump)]
+    pub revenue_vault: AccountInfo<'info>,
+
+    /// Revenue vault's token account.
+    #[account(
+        mut,
+        associated_token::mint = token_mint,
+        associated_token::authority = revenue_vault
+    )]
+    pub revenue_vault_token_account: Account<'info, TokenAccount>,
+
+    /// CHECK: Ledger PDA of `revenue_vault`. Posted to in logic once opened.
+    #[account(
+        mut,
+        seeds = [b"vault_ledger", revenue_vault.key().as_ref()],
+        bump
+    )]
+    pub revenue_ledger: AccountInfo<'info>,
+
+    /// CHECK: PDA authority for airdrop logic. Verified by seeds.
+    #[account(seeds = [b"airdrop_vault"], bump)]
+    pub airdrop_vault: AccountInfo<'info>,
+
+    /// Airdrop vault's token account.
+    #[account(
+        mut,
+        associated_token::mint = token_mint,
+        associated_token::authority = airdrop_vault
+    )]
+    pub airdrop_vault_token_account: Account<'info, TokenAccount>,
+
+    /// CHECK: Ledger PDA of `airdrop_vault`. Posted to in logic once opened.
+    #[account(
+        mut,
+        seeds = [b"vault_ledger", airdrop_vault.key().as_ref()],
+        bump
+    )]
+    pub airdrop_ledger: AccountInfo<'info>,
+
+    // ─────────────────────────────────────────────────────────────
+    // Mint, Access Control & State
+    // ─────────────────────────────────────────────────────────────
+
+    /// Token mint (SCTK).
+    pub token_mint: Account<'info, Mint>,
+
+    /// Optional access control account for the caller.
+    #[account(
+        seeds = [b"user_access", caller.key().as_ref()],
+        bump
+    )]
+    pub user_access: Option<Account<'info, UserAccessAccount>>,
+
+    /// TokenState account for system-wide configuration.
+    pub token_state: Account<'info, TokenState>,
+
+    /// Token program required for token transfers.
+    pub token_program: Program<'info, Token>,
+}
+
+#[derive(Accounts)]
+pub struct ManageAmmPool<'info> {
+    /// The caller proposing the settings change.
+    pub caller: Signer<'info>,
+
+    /// Global pool account.
+    #[account(
+        mut,
+        seeds = [b"amm_pool"],
+        bump = amm_pool.bump
+    )]
+    pub amm_pool: Account<'info, AmmPool>,
+
+    /// TokenState account for system-wide configuration.
+    #[account(seeds = [b"token_state"], bump)]
+    pub token_state: Account<'info, TokenState>,
+
+    /// Approved governance proposal authorizing the change.
+    #[account(mut)]
+    pub proposal: Account<'info, ProposalAccount>,
+
+    /// Global governance configuration.
+    pub governance_state: Account<'info, GovernanceState>,
+
+    /// Optional access control account for the caller.
+    #[account(
+        seeds = [b"user_access", caller.key().as_ref()],
+        bump
+    )]
+    pub user_access: Option<Account<'info, UserAccessAccount>>,
+}
@@ -0,0 +1,55 @@
+use anchor_lang::error_code;
+
+// ======================================================================
+// Soccial Token – AMM Error Definitions
+//
+// This module defines custom errors related to the constant-product pool,
+// including liquidity provisioning and swaps against the pool reserves.
+//
+// License: MIT License
+// Author: Paulo Rodrigues
+// Project: Soccial Token
+// ======================================================================
+
+#[error_code]
+pub enum AmmError {
+    /// The provided amount is invalid (e.g., zero).
+    #[msg("Invalid amount.")]
+    InvalidAmount,
+
+    /// The trader does not have enough balance to complete the swap.
+    #[msg("Insufficient funds.")]
+    InsufficientFunds,
+
+    /// Swaps against the pool are disabled.
+    #[msg("The AMM pool is currently disabled.")]
+    PoolDisabled,
+
+    /// The pool reserves cannot cover the requested operation.
+    #[msg("Insufficient pool liquidity.")]
+    InsufficientLiquidity,
+
+    /// The output of the swap is below the caller's `min_out` bound.
+    #[msg("Slippage limit exceeded.")]
+    SlippageExceeded,
+
+    /// The treasury does not own enough LP shares for the withdrawal.
+    #[msg("Insufficient LP shares.")]
+    InsufficientShares,
+
+    /// The quote mint is invalid (e.g., the SCTK mint itself).
+    #[msg("Invalid quote mint.")]
+    InvalidQuoteMint,
+
+    /// The requested swap fee is above the allowed threshold.
+    #[msg("The specified fee exceeds the maximum allowed limit.")]
+    FeeTooHigh,
+
+    /// Arithmetic overflow occurred during a calculation.
+    #[msg("Overflow occurred during calculation.")]
+    Overflow,
+
+    /// Arithmetic underflow occurred during a calculation.
+    #[msg("Underflow occurred during calculation.")]
+    Underflow,
+}
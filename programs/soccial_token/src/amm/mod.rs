@@ -0,0 +1,11 @@
+pub mod error;
+pub mod context;
+pub mod state;
+pub mod pool;
+pub mod swap;
+
+pub use error::*;
+pub use context::*;
+pub use state::*;
+pub use pool::*;
+pub use swap::*;
@@ -0,0 +1,313 @@
+// ===========================================================================
+// AMM Pool Liquidity – Soccial Token (SCTK)
+// ---------------------------------------------------------------------------
+//
+// This module seeds the constant-product pool from the liquidity vault and
+// manages the treasury-owned liquidity afterwards.
+//
+// ---------------------------------------------------------------------------
+// ## Flow:
+// - `initialize_pool()`: SCTK from the liquidity vault + quote from the operator
+// - `add_liquidity()`: Proportional top-up, shares credited to the treasury
+// - `remove_liquidity()`: Burns treasury shares; SCTK returns to the liquidity
+//   vault and quote goes to the treasury vault
+//
+// ---------------------------------------------------------------------------
+// Author: Paulo Rodrigues
+// Project: Soccial Token
+// Website: https://www.soccial.com/thetoken
+// License: MIT
+// ===========================================================================
+
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, Transfer};
+
+use crate::{
+    amm::{context::*, error::AmmError, state::AmmPool},
+    economy::fee::MAX_FEE_BPS,
+    utils::math::format_sctk,
+    vaults::{record_vault_flow, record_vault_outflow, LedgerCategory, LedgerFlow},
+};
+
+#[event]
+pub struct AmmPoolInitialized {
+    pub quote_mint: Pubkey,
+    pub lp_owner: Pubkey,
+    pub sctk_amount: u64,
+    pub quote_amount: u64,
+    pub shares: u64,
+    pub swap_fee_bps: u16,
+}
+
+#[event]
+pub struct AmmLiquidityAdded {
+    pub sctk_amount: u64,
+    pub quote_amount: u64,
+    pub shares: u64,
+    pub lp_supply: u64,
+}
+
+#[event]
+pub struct AmmLiquidityRemoved {
+    pub sctk_amount: u64,
+    pub quote_amount: u64,
+    pub shares: u64,
+    pub lp_supply: u64,
+}
+
+/// ===========================================================================
+/// Seeds the pool with SCTK from the liquidity vault and quote tokens from
+/// the operator, minting the initial LP shares to the treasury.
+///
+/// ## Behavior:
+/// - Initial shares = `sqrt(sctk_amount * quote_amount)`
+/// - Swaps are enabled immediately with `swap_fee_bps`
+/// - Posts the SCTK side as a `Market` outflow to the liquidity vault ledger
+/// - Counts the SCTK side against the liquidity vault's outflow limits
+///
+/// ## Errors:
+/// - `AmmError::InvalidAmount` on zero amounts
+/// - `AmmError::FeeTooHigh` if `swap_fee_bps` exceeds `MAX_FEE_BPS`
+/// - `AmmError::InsufficientLiquidity` if the liquidity vault is short
+/// - `VaultError::EpochOutflowExceeded` if the vault's epoch outflow cap is reached
+/// ===========================================================================
+pub(crate) fn initialize_pool(
+    ctx: Context<InitializeAmmPool>,
+    sctk_amount: u64,
+    quote_amount: u64,
+    swap_fee_bps: u16,
+) -> Result<()> {
+    require!(sctk_amount > 0 && quote_amount > 0, AmmError::InvalidAmount);
+    require!(swap_fee_bps <= MAX_FEE_BPS, AmmError::FeeTooHigh);
+    require!(
+        ctx.accounts.liquidity_vault_token_account.amount >= sctk_amount,
+        AmmError::InsufficientLiquidity
+    );
+
+    let shares = AmmPool::initial_shares(sctk_amount, quote_amount)?;
+
+    // Enforce the vault's epoch outflow limits (may trip the circuit breaker)
+    record_vault_outflow(
+        &ctx.accounts.vault_limits,
+        &mut ctx.accounts.token_state,
+        &ctx.accounts.caller.key(),
+        sctk_amount,
+    )?;
+
+    // SCTK side from the liquidity vault
+    let liquidity_seeds: &[&[u8]] = &[b"liquidity_vault", &[ctx.bumps.liquidity_vault]];
+    let signer_seeds = &[liquidity_seeds];
+    let transfer_ctx = CpiContext::new_with_signer(
+        ctx.accounts.token_program.to_account_info(),
+        Transfer {
+            from: ctx.accounts.liquidity_vault_token_account.to_account_info(),
+            to: ctx.accounts.pool_token_account.to_account_info(),
+            authority: ctx.accounts.liquidity_vault.to_account_info(),
+        },
+        signer_seeds,
+    );
+    token::transfer(transfer_ctx, sctk_amount)?;
+    record_vault_flow(&ctx.accounts.liquidity_ledger, LedgerFlow::Outflow, LedgerCategory::Market, sctk_amount)?;
+
+    // Quote side from the operator
+    let transfer_ctx = CpiContext::new(
+        ctx.accounts.token_program.to_account_info(),
+        Transfer {
+            from: ctx.accounts.caller_quote_account.to_account_info(),
+            to: ctx.accounts.pool_quote_account.to_account_info(),
+            authority: ctx.accounts.caller.to_account_info(),
+        },
+    );
+    token::transfer(transfer_ctx, quote_amount)?;
+
+    let now = Clock::get()?.unix_timestamp;
+    let pool = &mut ctx.accounts.amm_pool;
+    pool.quote_mint = ctx.accounts.quote_mint.key();
+    pool.lp_owner = ctx.accounts.treasury_vault.key();
+    pool.sctk_reserve = sctk_amount;
+    pool.quote_reserve = quote_amount;
+    pool.lp_supply = shares;
+    pool.swap_fee_bps = swap_fee_bps;
+    pool.enabled = true;
+    pool.bump = ctx.bumps.amm_pool;
+    pool.created_at = now;
+    pool.updated_at = now;
+
+    msg!(
+        "🌊 AMM pool seeded with {} SCTK ({} units) and {} quote units | 🧾 {} LP shares to treasury | Fee: {} BPS",
+        format_sctk(sctk_amount),
+        sctk_amount,
+        quote_amount,
+        shares,
+        swap_fee_bps,
+    );
+
+    emit!(AmmPoolInitialized {
+        quote_mint: pool.quote_mint,
+        lp_owner: pool.lp_owner,
+        sctk_amount,
+        quote_amount,
+        shares,
+        swap_fee_bps,
+    });
+
+    Ok(())
+}
+
+/// ===========================================================================
+/// Adds liquidity at the current pool ratio. SCTK comes from the liquidity
+/// vault, quote from the operator, and the new shares go to the treasury.
+///
+/// ## Behavior:
+/// - Quote required is derived from `sctk_amount` (rounded up)
+/// - Fails if it exceeds `max_quote`
+/// - Posts the SCTK side as a `Market` outflow to the liquidity vault ledger
+/// - Counts the SCTK side against the liquidity vault's outflow limits
+///
+/// ## Errors:
+/// - `AmmError::SlippageExceeded` if the quote required exceeds `max_quote`
+/// - `AmmError::InsufficientLiquidity` if the liquidity vault is short
+/// - `VaultError::EpochOutflowExceeded` if the vault's epoch outflow cap is reached
+/// ===========================================================================
+pub(crate) fn add_liquidity(
+    ctx: Context<AddAmmLiquidity>,
+    sctk_amount: u64,
+    max_quote: u64,
+) -> Result<()> {
+    require!(sctk_amount > 0, AmmError::InvalidAmount);
+    require!(
+        ctx.accounts.liquidity_vault_token_account.amount >= sctk_amount,
+        AmmError::InsufficientLiquidity
+    );
+
+    let (quote_amount, shares) = ctx.accounts.amm_pool.deposit_for(sctk_amount)?;
+    require!(quote_amount <= max_quote, AmmError::SlippageExceeded);
+
+    // Enforce the vault's epoch outflow limits (may trip the circuit breaker)
+    record_vault_outflow(
+        &ctx.accounts.vault_limits,
+        &mut ctx.accounts.token_state,
+        &ctx.accounts.caller.key(),
+        sctk_amount,
+    )?;
+
+    let liquidity_seeds: &[&[u8]] = &[b"liquidity_vault", &[ctx.bumps.liquidity_vault]];
+    let signer_seeds = &[liquidity_seeds];
+    let transfer_ctx = CpiContext::new_with_signer(
+        ctx.accounts.token_program.to_account_info(),
+        Transfer {
+            from: ctx.accounts.liquidity_vault_token_account.to_account_info(),
+            to: ctx.accounts.pool_token_account.to_account_info(),
+            authority: ctx.accounts.liquidity_vault.to_account_info(),
+        },
+        signer_seeds,
+    );
+    token::transfer(transfer_ctx, sctk_amount)?;
+    record_vault_flow(&ctx.accounts.liquidity_ledger, LedgerFlow::Outflow, LedgerCategory::Market, sctk_amount)?;
+
+    let transfer_ctx = CpiContext::new(
+        ctx.accounts.token_program.to_account_info(),
+        Transfer {
+            from: ctx.accounts.caller_quote_account.to_account_info(),
+            to: ctx.accounts.pool_quote_account.to_account_info(),
+            authority: ctx.accounts.caller.to_account_info(),
+        },
+    );
+    token::transfer(transfer_ctx, quote_amount)?;
+
+    let pool = &mut ctx.accounts.amm_pool;
+    pool.sctk_reserve = pool.sctk_reserve.checked_add(sctk_amount).ok_or(AmmError::Overflow)?;
+    pool.quote_reserve = pool.quote_reserve.checked_add(quote_amount).ok_or(AmmError::Overflow)?;
+    pool.lp_supply = pool.lp_supply.checked_add(shares).ok_or(AmmError::Overflow)?;
+    pool.updated_at = Clock::get()?.unix_timestamp;
+
+    msg!(
+        "➕ Added {} SCTK ({} units) and {} quote units to AMM pool | 🧾 +{} LP shares (total {})",
+        format_sctk(sctk_amount),
+        sctk_amount,
+        quote_amount,
+        shares,
+        pool.lp_supply,
+    );
+
+    emit!(AmmLiquidityAdded {
+        sctk_amount,
+        quote_amount,
+        shares,
+        lp_supply: pool.lp_supply,
+    });
+
+    Ok(())
+}
+
+/// ===========================================================================
+/// Burns treasury LP shares and withdraws the matching reserves.
+///
+/// ## Behavior:
+/// - SCTK returns to the liquidity vault
+/// - Quote is sent to the treasury vault's quote account
+/// - Posts the SCTK side as a `Market` inflow to the liquidity vault ledger
+///
+/// ## Errors:
+/// - `AmmError::InsufficientShares` if `shares` exceeds the LP supply
+/// ===========================================================================
+pub(crate) fn remove_liquidity(
+    ctx: Context<RemoveAmmLiquidity>,
+    shares: u64,
+) -> Result<()> {
+    let (sctk_amount, quote_amount) = ctx.accounts.amm_pool.withdrawal_for(shares)?;
+
+    let pool_seeds: &[&[u8]] = &[b"amm_pool", &[ctx.accounts.amm_pool.bump]];
+    let signer_seeds = &[pool_seeds];
+
+    if sctk_amount > 0 {
+        let transfer_ctx = CpiContext::new_with_signer(
+            ctx.accounts.token_program.to_account_info(),
+            Transfer {
+                from: ctx.accounts.pool_token_account.to_account_info(),
+                to: ctx.accounts.liquidity_vault_token_account.to_account_info(),
+                authority: ctx.accounts.amm_pool.to_account_info(),
+            },
+            signer_seeds,
+        );
+        token::transfer(transfer_ctx, sctk_amount)?;
+        record_vault_flow(&ctx.accounts.liquidity_ledger, LedgerFlow::Inflow, LedgerCategory::Market, sctk_amount)?;
+    }
+
+    if quote_amount > 0 {
+        let transfer_ctx = CpiContext::new_with_signer(
+            ctx.accounts.token_program.to_account_info(),
+            Transfer {
+                from: ctx.accounts.pool_quote_account.to_account_info(),
+                to: ctx.accounts.treasury_quote_account.to_account_info(),
+                authority: ctx.accounts.amm_pool.to_account_info(),
+            },
+            signer_seeds,
+        );
+        token::transfer(transfer_ctx, quote_amount)?;
+    }
+
+    let pool = &mut ctx.accounts.amm_pool;
+    pool.sctk_reserve = pool.sctk_reserve.checked_sub(sctk_amount).ok_or(AmmError::Underflow)?;
+    pool.quote_reserve = pool.quote_reserve.checked_sub(quote_amount).ok_or(AmmError::Underflow)?;
+    pool.lp_supply = pool.lp_supply.checked_sub(shares).ok_or(AmmError::Underflow)?;
+    pool.updated_at = Clock::get()?.unix_timestamp;
+
+    msg!(
+        "➖ Removed {} SCTK ({} units) and {} quote units from AMM pool | 🧾 -{} LP shares (total {})",
+        format_sctk(sctk_amount),
+        sctk_amount,
+        quote_amount,
+        shares,
+        pool.lp_supply,
+    );
+
+    emit!(AmmLiquidityRemoved {
+        sctk_amount,
+        quote_amount,
+        shares,
+        lp_supply: pool.lp_supply,
+    });
+
+    Ok(())
+}
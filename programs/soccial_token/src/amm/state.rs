@@ -0,0 +1,252 @@
+// ===========================================================================
+// AMM State – Soccial Token (SCTK)
+// ---------------------------------------------------------------------------
+//
+// This module defines the on-chain constant-product pool (x * y = k) that
+// pairs SCTK seeded from the liquidity vault with a quote SPL mint, giving
+// on-chain price discovery without depending on an external DEX.
+//
+// ---------------------------------------------------------------------------
+// ## Components:
+// - `AmmPool`: Pool reserves, LP share supply and swap settings
+// - `SwapSide`: Direction of a swap (buy or sell SCTK)
+//
+// ---------------------------------------------------------------------------
+// ## LP Shares:
+// - Shares are pure accounting on the pool account (no LP mint)
+// - All shares belong to the treasury vault (`lp_owner`)
+// - Initial shares = sqrt(sctk * quote); later deposits are proportional
+//
+// ---------------------------------------------------------------------------
+// Author: Paulo Rodrigues
+// Project: Soccial Token
+// Website: https://www.soccial.com/thetoken
+// License: MIT
+// ===========================================================================
+
+use anchor_lang::prelude::*;
+use core::str::FromStr;
+use crate::{amm::error::AmmError, economy::{fee::MAX_FEE_BPS, TOKEN_DECIMAL}};
+
+#[event]
+pub struct AmmPoolUpdated {
+    pub swap_fee_bps: u16,
+    pub enabled: bool,
+    pub updated_at: i64,
+}
+
+/// Direction of a swap against the pool.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug)]
+pub enum SwapSide {
+    /// Quote in, SCTK out.
+    Buy,
+    /// SCTK in, quote out.
+    Sell,
+}
+
+impl FromStr for SwapSide {
+    type Err = ();
+
+    fn from_str(value: &str) -> core::result::Result<Self, Self::Err> {
+        match value.trim().to_ascii_lowercase().as_str() {
+            "buy" => Ok(SwapSide::Buy),
+            "sell" => Ok(SwapSide::Sell),
+            _ => Err(()),
+        }
+    }
+}
+
+/// ===========================================================================
+/// Account: AmmPool
+///
+/// Global constant-product pool. Reserves are tracked on the account rather
+/// than read from token balances, so direct donations cannot move the price.
+///
+/// ## Fields:
+/// - `quote_mint`: SPL mint paired with SCTK
+/// - `lp_owner`: Treasury vault PDA owning every LP share
+/// - `sctk_reserve` / `quote_reserve`: Tracked pool reserves
+/// - `lp_supply`: Total LP shares outstanding
+/// - `swap_fee_bps`: Fee taken from the SCTK side of every swap
+/// - `enabled`: Master switch for swaps
+/// - `bump`: PDA bump for the `amm_pool` seed
+/// - `created_at` / `updated_at`: Unix timestamps
+/// ===========================================================================
+#[account]
+pub struct AmmPool {
+    pub quote_mint: Pubkey,
+    pub lp_owner: Pubkey,
+    pub sctk_reserve: u64,
+    pub quote_reserve: u64,
+    pub lp_supply: u64,
+    pub swap_fee_bps: u16,
+    pub enabled: bool,
+    pub bump: u8,
+    pub created_at: i64,
+    pub updated_at: i64,
+}
+
+impl AmmPool {
+    pub const LEN: usize =
+        8 +     // Anchor discriminator
+        32 +    // quote_mint (Pubkey)
+        32 +    // lp_owner (Pubkey)
+        8 +     // sctk_reserve (u64)
+        8 +     // quote_reserve (u64)
+        8 +     // lp_supply (u64)
+        2 +     // swap_fee_bps (u16)
+        1 +     // enabled (bool)
+        1 +     // bump (u8)
+        8 +     // created_at (i64)
+        8;      // updated_at (i64)
+
+    /// Returns the shares minted when seeding an empty pool: `sqrt(sctk * quote)`.
+    ///
+    /// ## Errors:
+    /// - `InvalidAmount` if the result is zero
+    /// - `Overflow` if the result does not fit in a `u64`
+    pub fn initial_shares(sctk_amount: u64, quote_amount: u64) -> Result<u64> {
+        let shares = integer_sqrt(sctk_amount as u128 * quote_amount as u128);
+        require!(shares > 0, AmmError::InvalidAmount);
+
+        u64::try_from(shares).map_err(|_| AmmError::Overflow.into())
+    }
+
+    /// Computes the proportional deposit for `sctk_amount` SCTK units.
+    ///
+    /// ## Returns:
+    /// - `(quote_required, shares)` – quote rounded up, shares rounded down
+    ///
+    /// ## Errors:
+    /// - `InsufficientLiquidity` if the pool is empty
+    /// - `InvalidAmount` if no shares would be minted
+    pub fn deposit_for(&self, sctk_amount: u64) -> Result<(u64, u64)> {
+        require!(self.sctk_reserve > 0 && self.lp_supply > 0, AmmError::InsufficientLiquidity);
+
+        let sctk = sctk_amount as u128;
+        let quote_required = (sctk * self.quote_reserve as u128).div_ceil(self.sctk_reserve as u128);
+        let shares = sctk * self.lp_supply as u128 / self.sctk_reserve as u128;
+        require!(shares > 0, AmmError::InvalidAmount);
+
+        Ok((
+            u64::try_from(quote_required).map_err(|_| AmmError::Overflow)?,
+            u64::try_from(shares).map_err(|_| AmmError::Overflow)?,
+        ))
+    }
+
+    /// Computes the reserves released when burning `shares` LP shares.
+    ///
+    /// ## Returns:
+    /// - `(sctk_amount, quote_amount)` – both rounded down
+    ///
+    /// ## Errors:
+    /// - `InsufficientShares` if `shares` exceeds the supply
+    pub fn withdrawal_for(&self, shares: u64) -> Result<(u64, u64)> {
+        require!(shares > 0, AmmError::InvalidAmount);
+        require!(shares <= self.lp_supply, AmmError::InsufficientShares);
+
+        let sctk = shares as u128 * self.sctk_reserve as u128 / self.lp_supply as u128;
+        let quote = shares as u128 * self.quote_reserve as u128 / self.lp_supply as u128;
+
+        Ok((sctk as u64, quote as u64))
+    }
+
+    /// Constant-product output for `amount_in`: `out = y * dx / (x + dx)`.
+    ///
+    /// Rounded down so the invariant `k` never decreases.
+    ///
+    /// ## Errors:
+    /// - `InsufficientLiquidity` if a reserve is empty or the output is zero
+    pub fn output_for(amount_in: u64, reserve_in: u64, reserve_out: u64) -> Result<u64> {
+        require!(reserve_in > 0 && reserve_out > 0, AmmError::InsufficientLiquidity);
+
+        let numerator = reserve_out as u128 * amount_in as u128;
+        let denominator = reserve_in as u128 + amount_in as u128;
+        let out = (numerator / denominator) as u64;
+        require!(out > 0, AmmError::InsufficientLiquidity);
+
+        Ok(out)
+    }
+
+    /// Spot price in quote base units per 1 SCTK for the given reserves.
+    ///
+    /// Returns 0 if the SCTK reserve is empty.
+    pub fn spot_price(sctk_reserve: u64, quote_reserve: u64) -> u64 {
+        if sctk_reserve == 0 {
+            return 0;
+        }
+
+        let one_sctk = 10u128.pow(TOKEN_DECIMAL as u32);
+        (quote_reserve as u128 * one_sctk / sctk_reserve as u128).min(u64::MAX as u128) as u64
+    }
+
+    /// Dynamically updates pool settings via string-based key=value pairs.
+    ///
+    /// ## Supported Keys:
+    /// - `"swap_fee_bps"` → fee applied to the SCTK side (≤ `MAX_FEE_BPS`)
+    /// - `"enabled"` → `true` / `false`
+    ///
+    /// ## Errors:
+    /// - `NotEnoughArguments` if input is empty
+    /// - `InvalidArgument` if any key is unknown or parsing fails
+    /// - `FeeTooHigh` if the fee exceeds `MAX_FEE_BPS`
+    pub(crate) fn apply_updates(&mut self, args: Vec<String>) -> Result<()> {
+        require!(!args.is_empty(), crate::ErrorCode::NotEnoughArguments);
+
+        let mut log: Vec<String> = vec![];
+
+        for arg in args {
+            let parts: Vec<&str> = arg.split('=').collect();
+            require!(parts.len() == 2, crate::ErrorCode::InvalidArgument);
+            let key = parts[0].trim();
+            let value = parts[1].trim();
+
+            match key {
+                "swap_fee_bps" => {
+                    let val = value.parse::<u16>().map_err(|_| crate::ErrorCode::InvalidArgument)?;
+                    require!(val <= MAX_FEE_BPS, AmmError::FeeTooHigh);
+                    self.swap_fee_bps = val;
+                    log.push(format!("swap_fee_bps: {}", val));
+                }
+                "enabled" => {
+                    let val = value.parse::<bool>().map_err(|_| crate::ErrorCode::InvalidArgument)?;
+                    self.enabled = val;
+                    log.push(format!("enabled: {}", val));
+                }
+                _ => return Err(crate::ErrorCode::InvalidArgument.into()),
+            }
+        }
+
+        let now = Clock::get()?.unix_timestamp;
+        self.updated_at = now;
+
+        msg!("⚙️ Updated AMM pool → {}", log.join(" | "));
+
+        emit!(AmmPoolUpdated {
+            swap_fee_bps: self.swap_fee_bps,
+            enabled: self.enabled,
+            updated_at: now,
+        });
+
+        Ok(())
+    }
+}
+
+/// Floor of the square root of `value`, by Newton's method.
+///
+/// Kept local instead of `u128::isqrt`, which is newer than the Rust
+/// toolchain shipped with the Solana platform tools.
+fn integer_sqrt(value: u128) -> u128 {
+    if value < 2 {
+        return value;
+    }
+
+    let mut x = value;
+    let mut y = x / 2 + (x & 1);
+    while y < x {
+        x = y;
+        y = (x + value / x) / 2;
+    }
+
+    x
+}
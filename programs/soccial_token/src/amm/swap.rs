@@ -0,0 +1,213 @@
+// ===========================================================================
+// AMM Swap – Soccial Token (SCTK)
+// ---------------------------------------------------------------------------
+//
+// This module implements exact-input swaps against the constant-product
+// pool, in both directions, bounded by a caller-provided `min_out`.
+//
+// ---------------------------------------------------------------------------
+// ## Fee Logic:
+// - The swap fee is always taken on the SCTK side
+// - Buy: deducted from the SCTK leaving the pool
+// - Sell: deducted from the SCTK entering the pool
+// - The fee is routed to vaults via `distribute_fees` (`FeeDistribution::split_fee`)
+//
+// ---------------------------------------------------------------------------
+// Author: Paulo Rodrigues
+// Project: Soccial Token
+// Website: https://www.soccial.com/thetoken
+// License: MIT
+// ===========================================================================
+
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, Transfer};
+
+use crate::{
+    amm::{context::*, error::AmmError, state::{AmmPool, SwapSide}},
+    market::{apply_fee_bps, distribute_fees, FeeDistributionContext},
+    utils::math::format_sctk,
+};
+
+#[event]
+pub struct AmmSwapExecuted {
+    pub trader: Pubkey,
+    pub side: SwapSide,
+    pub amount_in: u64,
+    pub amount_out: u64,
+    pub fee_charged: u64,
+    pub to_rewards: u64,
+    pub to_airdrop: u64,
+    pub to_revenue: u64,
+    pub sctk_reserve: u64,
+    pub quote_reserve: u64,
+}
+
+/// ===========================================================================
+/// Swaps an exact input amount against the pool.
+///
+/// ## Behavior:
+/// - `Buy`: `amount_in` quote → SCTK, fee deducted from the SCTK output
+/// - `Sell`: `amount_in` SCTK → quote, fee deducted from the SCTK input
+/// - Tracked reserves follow `x * y = k` (outputs round down)
+/// - The post-swap spot price is recorded in the pool quote mint's `PriceAccumulator`
+///
+/// ## Errors:
+/// - `AmmError::PoolDisabled` if swaps are disabled
+/// - `AmmError::InsufficientFunds` if the trader lacks SCTK on a sell
+/// - `AmmError::SlippageExceeded` if the output is below `min_out`
+/// - `AmmError::InsufficientLiquidity` if the output would be zero
+/// ===========================================================================
+pub(crate) fn swap(
+    ctx: Context<AmmSwapContext>,
+    side: SwapSide,
+    amount_in: u64,
+    min_out: u64,
+) -> Result<()> {
+    require!(amount_in > 0, AmmError::InvalidAmount);
+    require!(ctx.accounts.amm_pool.enabled, AmmError::PoolDisabled);
+
+    let pool = &ctx.accounts.amm_pool;
+    let fee_bps = pool.swap_fee_bps;
+    let pool_seeds: &[&[u8]] = &[b"amm_pool", &[pool.bump]];
+    let signer_seeds = &[pool_seeds];
+
+    let fee_ctx = FeeDistributionContext {
+        token_state: ctx.accounts.token_state.clone(),
+        token_program: ctx.accounts.token_program.to_account_info(),
+        source_token_account: ctx.accounts.user_token_account.to_account_info(),
+        rewards_vault_token_account: ctx.accounts.rewards_vault_token_account.to_account_info(),
+        rewards_ledger: ctx.accounts.rewards_ledger.to_account_info(),
+        airdrop_vault_token_account: ctx.accounts.airdrop_vault_token_account.to_account_info(),
+        airdrop_ledger: ctx.accounts.airdrop_ledger.to_account_info(),
+        revenue_vault_token_account: ctx.accounts.revenue_vault_token_account.to_account_info(),
+        revenue_ledger: ctx.accounts.revenue_ledger.to_account_info(),
+        authority: ctx.accounts.caller.to_account_info(),
+    };
+
+    let (amount_out, fee_amount, fee_split, sctk_reserve, quote_reserve) = match side {
+        SwapSide::Buy => {
+            let gross_out = AmmPool::output_for(amount_in, pool.quote_reserve, pool.sctk_reserve)?;
+            let (net_out, fee_amount) = apply_fee_bps(gross_out, fee_bps)?;
+            require!(net_out >= min_out, AmmError::SlippageExceeded);
+
+            // Quote in from the trader
+            let transfer_ctx = CpiContext::new(
+                ctx.accounts.token_program.to_account_info(),
+                Transfer {
+                    from: ctx.accounts.user_quote_account.to_account_info(),
+                    to: ctx.accounts.pool_quote_account.to_account_info(),
+                    authority: ctx.accounts.caller.to_account_info(),
+                },
+            );
+            token::transfer(transfer_ctx, amount_in)?;
+
+            // Net SCTK out to the trader
+            let transfer_ctx = CpiContext::new_with_signer(
+                ctx.accounts.token_program.to_account_info(),
+                Transfer {
+                    from: ctx.accounts.pool_token_account.to_account_info(),
+                    to: ctx.accounts.user_token_account.to_account_info(),
+                    authority: pool.to_account_info(),
+                },
+                signer_seeds,
+            );
+            token::transfer(transfer_ctx, net_out)?;
+
+            // Fee leaves the pool reserve, signed by the pool PDA
+            let fee_split = distribute_fees(
+                &FeeDistributionContext {
+                    source_token_account: ctx.accounts.pool_token_account.to_account_info(),
+                    authority: pool.to_account_info(),
+                    ..fee_ctx
+                },
+                fee_amount,
+                Some(signer_seeds),
+            )?;
+
+            let sctk_reserve = pool.sctk_reserve.checked_sub(gross_out).ok_or(AmmError::InsufficientLiquidity)?;
+            let quote_reserve = pool.quote_reserve.checked_add(amount_in).ok_or(AmmError::Overflow)?;
+
+            (net_out, fee_amount, fee_split, sctk_reserve, quote_reserve)
+        }
+        SwapSide::Sell => {
+            require!(
+                ctx.accounts.user_token_account.amount >= amount_in,
+                AmmError::InsufficientFunds
+            );
+
+            let (net_in, fee_amount) = apply_fee_bps(amount_in, fee_bps)?;
+            let quote_out = AmmPool::output_for(net_in, pool.sctk_reserve, pool.quote_reserve)?;
+            require!(quote_out >= min_out, AmmError::SlippageExceeded);
+
+            // Net SCTK in from the trader
+            let transfer_ctx = CpiContext::new(
+                ctx.accounts.token_program.to_account_info(),
+                Transfer {
+                    from: ctx.accounts.user_token_account.to_account_info(),
+                    to: ctx.accounts.pool_token_account.to_account_info(),
+                    authority: ctx.accounts.caller.to_account_info(),
+                },
+            );
+            token::transfer(transfer_ctx, net_in)?;
+
+            // Fee from the trader
+            let fee_split = distribute_fees(&fee_ctx, fee_amount, None)?;
+
+            // Quote out to the trader
+            let transfer_ctx = CpiContext::new_with_signer(
+                ctx.accounts.token_program.to_account_info(),
+                Transfer {
+                    from: ctx.accounts.pool_quote_account.to_account_info(),
+                    to: ctx.accounts.user_quote_account.to_account_info(),
+                    authority: pool.to_account_info(),
+                },
+                signer_seeds,
+            );
+            token::transfer(transfer_ctx, quote_out)?;
+
+            let sctk_reserve = pool.sctk_reserve.checked_add(net_in).ok_or(AmmError::Overflow)?;
+            let quote_reserve = pool.quote_reserve.checked_sub(quote_out).ok_or(AmmError::InsufficientLiquidity)?;
+
+            (quote_out, fee_amount, fee_split, sctk_reserve, quote_reserve)
+        }
+    };
+
+    let (to_rewards, to_airdrop, to_revenue) = fee_split;
+
+    let pool = &mut ctx.accounts.amm_pool;
+    pool.sctk_reserve = sctk_reserve;
+    pool.quote_reserve = quote_reserve;
+
+    let now = Clock::get()?.unix_timestamp;
+    ctx.accounts
+        .price_accumulator
+        .record(AmmPool::spot_price(sctk_reserve, quote_reserve), now)?;
+
+    msg!(
+        "🔄 AMM {:?} by {}: {} in → {} out | 📈 Fee: {} SCTK ({} units → {} to revenue, {} to rewards, {} to airdrop)",
+        side,
+        ctx.accounts.caller.key(),
+        amount_in,
+        amount_out,
+        format_sctk(fee_amount),
+        fee_amount,
+        to_revenue,
+        to_rewards,
+        to_airdrop,
+    );
+
+    emit!(AmmSwapExecuted {
+        trader: ctx.accounts.caller.key(),
+        side,
+        amount_in,
+        amount_out,
+        fee_charged: fee_amount,
+        to_rewards,
+        to_airdrop,
+        to_revenue,
+        sctk_reserve,
+        quote_reserve,
+    });
+
+    Ok(())
+}
@@ -73,6 +73,7 @@ impl PermissionMap {
             "deposit_vaults"           => Some(7),
             "transfer_between_vaults"  => Some(8),
             "manage_vaults"            => Some(9),
+            "guard_vaults"             => Some(25),
 
             // ─────────────────────
             // Token Economy
@@ -107,6 +108,16 @@ impl PermissionMap {
             "transfer_tokens"         => Some(21),
             "deposit_tokens"          => Some(22),
 
+            // ─────────────────────
+            // Payments
+            // ─────────────────────
+            "manage_merchants"        => Some(23),
+
+            // ─────────────────────
+            // Oracle
+            // ─────────────────────
+            "set_price"               => Some(24),
+
             _ => None,
         }
     }
@@ -20,6 +20,7 @@
 // - `ExtraFlag`: Enum representing user status (e.g., banned, early adopter)
 // - `UserAccessAccount`: Anchor account storing access control data
 // - `add_flag`, `assign_permission`, etc.: Utility functions to mutate state safely
+// - `load_user_access()`: Optional read of a `user_access` PDA that may not exist
 //
 // Author: Paulo Rodrigues  
 // Project: Soccial Token  
@@ -344,3 +345,26 @@ impl UserAccessAccount {
     }
 
 }
+
+/// ===========================================================================
+/// Reads a `user_access` PDA passed as a raw account, if it was created.
+///
+/// ## Behavior:
+/// - Returns `None` for an empty (never initialized) account
+/// - Otherwise requires program ownership and deserializes it
+///
+/// ## Errors:
+/// - `ErrorCode::Unauthorized` if the account is not a valid `UserAccessAccount`
+/// ===========================================================================
+pub(crate) fn load_user_access(info: &AccountInfo) -> Result<Option<UserAccessAccount>> {
+    if info.data_is_empty() {
+        return Ok(None);
+    }
+
+    require_keys_eq!(*info.owner, crate::ID, crate::ErrorCode::Unauthorized);
+
+    let data = info.try_borrow_data()?;
+    UserAccessAccount::try_deserialize(&mut &data[..])
+        .map(Some)
+        .map_err(|_| crate::ErrorCode::Unauthorized.into())
+}
@@ -26,4 +26,8 @@ pub enum EconomicsErrorCode {
     /// An arithmetic overflow occurred during internal fee calculations.
     #[msg("Arithmetic overflow occurred during fee distribution.")]
     Overflow,
+
+    /// The provided fee discount exceeds 100% (10,000 BPS).
+    #[msg("Invalid fee discount. Must not exceed 100% (10,000 BPS).")]
+    InvalidDiscountValue,
 }
@@ -7,6 +7,9 @@
 // - Dynamically updating fee values (rewards / airdrop)
 // - Splitting total fee amounts into vault portions
 // - Calculating the residual "revenue" fee
+// - Storing the per-operation fee schedule (`FeeSchedule`)
+// - Storing flag-based fee discounts (`FeeDiscounts`)
+// - Storing the referrer share of referee fees (`ReferralSettings`)
 //
 // ---------------------------------------------------------------------------
 // Fee Structure:
@@ -14,6 +17,9 @@
 // - `rewards_fee_bps`: % of fees allocated to the Rewards Vault
 // - `airdrop_fee_bps`: % of fees allocated to the Airdrop Vault
 // - Remainder goes to the Revenue Vault (`revenue_fee_bps`)
+// - `FeeSchedule` defines the fee charged per operation (buy, withdrawal, P2P)
+// - `FeeDiscounts` reduces that fee for flagged users (VIP, Staff, ...)
+// - `ReferralSettings` defines the share of referee fees owed to the referrer
 //
 // ---------------------------------------------------------------------------
 // Key Features:
@@ -23,7 +29,10 @@
 //
 // ---------------------------------------------------------------------------
 // Storage:
-// - Struct size: `LEN = 4 bytes`
+// - `FeeDistribution`: `LEN = 4 bytes`
+// - `FeeSchedule`: `LEN = 6 bytes`
+// - `FeeDiscounts`: `LEN = 12 bytes`
+// - `ReferralSettings`: `LEN = 2 bytes`
 //
 // ---------------------------------------------------------------------------
 // Author: Paulo Rodrigues  
@@ -34,8 +43,12 @@
 
 use anchor_lang::prelude::*;
 use crate::{
+    auth::user::{ExtraFlag, UserAccessAccount},
     economics::error::EconomicsErrorCode,
-    economy::fee::{FEE_BPS_BASE, MAX_AIRDROP_FEE_BPS, MAX_REWARDS_FEE_BPS, MIN_FEE_BPS},
+    economy::fee::{
+        FEE_BPS_BASE, MAX_AIRDROP_FEE_BPS, MAX_FEE_BPS, MAX_REFERRAL_SHARE_BPS, MAX_REWARDS_FEE_BPS,
+        MIN_FEE_BPS,
+    },
 };
 
 #[event]
@@ -50,6 +63,29 @@ pub struct AirdropFeeUpdated {
     pub percent: f64,
 }
 
+#[event]
+pub struct FeeScheduleUpdated {
+    pub buy_fee_bps: u16,
+    pub withdrawal_fee_bps: u16,
+    pub transfer_fee_bps: u16,
+}
+
+#[event]
+pub struct FeeDiscountsUpdated {
+    pub early_adopter1_bps: u16,
+    pub early_adopter2_bps: u16,
+    pub whitelist_bps: u16,
+    pub vip_bps: u16,
+    pub beta_tester_bps: u16,
+    pub staff_bps: u16,
+}
+
+#[event]
+pub struct ReferralSettingsUpdated {
+    pub share_bps: u16,
+    pub percent: f64,
+}
+
 
 /// 📊 Handles the economy operations of the Soccial Token (SCTK).
 ///
@@ -171,3 +207,253 @@ impl FeeDistribution {
     /// Constant space used by this struct in bytes.
     pub const LEN: usize = 2 + 2; // u16 + u16
 }
+
+/// Market operations that are charged according to the `FeeSchedule`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum FeeOperation {
+    /// Primary sale from the liquidity vault (`buy_tokens`).
+    Buy,
+    /// Withdrawal from the off-chain reserve into an SPL wallet (`deposit_tokens`).
+    OffchainWithdrawal,
+    /// Peer-to-peer transfer between users (`transfer_tokens`).
+    Transfer,
+}
+
+/// 🧾 Fee charged per market operation, in **basis points (BPS)**.
+///
+/// Stored in `TokenState` and editable only through an approved
+/// `AdjustTaxRate` proposal, so fees are protocol parameters instead of
+/// per-call choices by the API.
+#[derive(Clone, Copy, Debug, AnchorSerialize, AnchorDeserialize)]
+pub struct FeeSchedule {
+    pub buy_fee_bps: u16,
+    pub withdrawal_fee_bps: u16,
+    pub transfer_fee_bps: u16,
+}
+
+impl FeeSchedule {
+    /// Returns the configured fee (in BPS) for the given operation.
+    pub fn fee_bps_for(&self, operation: FeeOperation) -> u16 {
+        match operation {
+            FeeOperation::Buy => self.buy_fee_bps,
+            FeeOperation::OffchainWithdrawal => self.withdrawal_fee_bps,
+            FeeOperation::Transfer => self.transfer_fee_bps,
+        }
+    }
+
+    /// Updates the schedule via string-based key=value pairs.
+    ///
+    /// ## Supported Keys:
+    /// - `"buy"` → fee for `buy_tokens`
+    /// - `"withdrawal"` → fee for `deposit_tokens` (off-chain reserve withdrawal)
+    /// - `"transfer"` → fee for `transfer_tokens`
+    ///
+    /// ## Errors:
+    /// - `NotEnoughArguments` if input is empty
+    /// - `InvalidArgument` if any key is unknown or parsing fails
+    /// - `InvalidFeeValue` if any fee exceeds `MAX_FEE_BPS`
+    pub(crate) fn apply_updates(&mut self, args: Vec<String>) -> Result<()> {
+        require!(!args.is_empty(), crate::ErrorCode::NotEnoughArguments);
+
+        let mut log: Vec<String> = vec![];
+
+        for arg in args {
+            let parts: Vec<&str> = arg.split('=').collect();
+            require!(parts.len() == 2, crate::ErrorCode::InvalidArgument);
+            let key = parts[0].trim();
+            let val = parts[1].trim().parse::<u16>().map_err(|_| crate::ErrorCode::InvalidArgument)?;
+            require!(
+                (MIN_FEE_BPS..=MAX_FEE_BPS).contains(&val),
+                EconomicsErrorCode::InvalidFeeValue
+            );
+
+            match key {
+                "buy" => self.buy_fee_bps = val,
+                "withdrawal" => self.withdrawal_fee_bps = val,
+                "transfer" => self.transfer_fee_bps = val,
+                _ => return Err(crate::ErrorCode::InvalidArgument.into()),
+            }
+            log.push(format!("{}: {} BPS", key, val));
+        }
+
+        msg!("✅ Fee schedule updated → {}", log.join(" | "));
+
+        emit!(FeeScheduleUpdated {
+            buy_fee_bps: self.buy_fee_bps,
+            withdrawal_fee_bps: self.withdrawal_fee_bps,
+            transfer_fee_bps: self.transfer_fee_bps,
+        });
+
+        Ok(())
+    }
+
+    /// Constant space used by this struct in bytes.
+    pub const LEN: usize = 2 + 2 + 2; // u16 + u16 + u16
+}
+
+/// 🏷️ Fee discounts granted by `ExtraFlag`, in **basis points of the base fee**.
+///
+/// A discount of `5_000` halves the scheduled fee, `10_000` exempts the user.
+/// When several flags are set, the largest discount wins. Stored in
+/// `TokenState` and editable only through an approved `AdjustTaxRate` proposal.
+#[derive(Clone, Copy, Debug, Default, AnchorSerialize, AnchorDeserialize)]
+pub struct FeeDiscounts {
+    pub early_adopter1_bps: u16,
+    pub early_adopter2_bps: u16,
+    pub whitelist_bps: u16,
+    pub vip_bps: u16,
+    pub beta_tester_bps: u16,
+    pub staff_bps: u16,
+}
+
+impl FeeDiscounts {
+    /// Returns the discount (in BPS) configured for a flag.
+    /// `Banned` and `Suspended` never carry a discount.
+    pub fn discount_bps_for(&self, flag: ExtraFlag) -> u16 {
+        match flag {
+            ExtraFlag::EarlyAdopter1 => self.early_adopter1_bps,
+            ExtraFlag::EarlyAdopter2 => self.early_adopter2_bps,
+            ExtraFlag::Whitelist => self.whitelist_bps,
+            ExtraFlag::Vip => self.vip_bps,
+            ExtraFlag::BetaTester => self.beta_tester_bps,
+            ExtraFlag::Staff => self.staff_bps,
+            ExtraFlag::Banned | ExtraFlag::Suspended => 0,
+        }
+    }
+
+    /// Returns the best discount (in BPS) among the flags set on `user_access`.
+    /// Callers without a `UserAccessAccount` get no discount.
+    pub fn discount_bps_for_user(&self, user_access: Option<&UserAccessAccount>) -> u16 {
+        let Some(access) = user_access else {
+            return 0;
+        };
+
+        [
+            ExtraFlag::EarlyAdopter1,
+            ExtraFlag::EarlyAdopter2,
+            ExtraFlag::Whitelist,
+            ExtraFlag::Vip,
+            ExtraFlag::BetaTester,
+            ExtraFlag::Staff,
+        ]
+        .into_iter()
+        .filter(|flag| access.has_flag(*flag))
+        .map(|flag| self.discount_bps_for(flag))
+        .max()
+        .unwrap_or(0)
+    }
+
+    /// Updates the discount table via string-based flag=value pairs.
+    ///
+    /// ## Supported Keys:
+    /// - Any `ExtraFlag` name except `banned` / `suspended`
+    ///   (e.g. `"vip"`, `"staff"`, `"early1"`, `"whitelist"`)
+    ///
+    /// ## Errors:
+    /// - `NotEnoughArguments` if input is empty
+    /// - `InvalidArgument` if parsing fails or the flag cannot carry a discount
+    /// - `UnknownFlagName` if the flag does not exist
+    /// - `InvalidDiscountValue` if any discount exceeds `FEE_BPS_BASE`
+    pub(crate) fn apply_updates(&mut self, args: Vec<String>) -> Result<()> {
+        require!(!args.is_empty(), crate::ErrorCode::NotEnoughArguments);
+
+        let mut log: Vec<String> = vec![];
+
+        for arg in args {
+            let parts: Vec<&str> = arg.split('=').collect();
+            require!(parts.len() == 2, crate::ErrorCode::InvalidArgument);
+            let flag = ExtraFlag::from_str(parts[0].trim())?;
+            let val = parts[1].trim().parse::<u16>().map_err(|_| crate::ErrorCode::InvalidArgument)?;
+            require!(val <= FEE_BPS_BASE, EconomicsErrorCode::InvalidDiscountValue);
+
+            match flag {
+                ExtraFlag::EarlyAdopter1 => self.early_adopter1_bps = val,
+                ExtraFlag::EarlyAdopter2 => self.early_adopter2_bps = val,
+                ExtraFlag::Whitelist => self.whitelist_bps = val,
+                ExtraFlag::Vip => self.vip_bps = val,
+                ExtraFlag::BetaTester => self.beta_tester_bps = val,
+                ExtraFlag::Staff => self.staff_bps = val,
+                ExtraFlag::Banned | ExtraFlag::Suspended => {
+                    return Err(crate::ErrorCode::InvalidArgument.into())
+                }
+            }
+            log.push(format!("{:?}: {} BPS", flag, val));
+        }
+
+        msg!("✅ Fee discounts updated → {}", log.join(" | "));
+
+        emit!(FeeDiscountsUpdated {
+            early_adopter1_bps: self.early_adopter1_bps,
+            early_adopter2_bps: self.early_adopter2_bps,
+            whitelist_bps: self.whitelist_bps,
+            vip_bps: self.vip_bps,
+            beta_tester_bps: self.beta_tester_bps,
+            staff_bps: self.staff_bps,
+        });
+
+        Ok(())
+    }
+
+    /// Constant space used by this struct in bytes.
+    pub const LEN: usize = 2 * 6; // 6 × u16
+}
+
+/// 🤝 Share of the fees generated by a referee that is owed to its referrer,
+/// in **basis points of the collected fee**.
+///
+/// The fee itself is still distributed to the vaults as usual; the share is
+/// only credited as a claimable balance and later paid from the Revenue Vault.
+/// Stored in `TokenState` and editable only through an approved
+/// `AdjustTaxRate` proposal.
+#[derive(Clone, Copy, Debug, AnchorSerialize, AnchorDeserialize)]
+pub struct ReferralSettings {
+    pub share_bps: u16,
+}
+
+impl ReferralSettings {
+    /// Returns the referrer share of `fee`, rounded down.
+    pub fn share_of(&self, fee: u64) -> u64 {
+        (fee as u128 * self.share_bps as u128 / FEE_BPS_BASE as u128) as u64
+    }
+
+    /// Updates the settings via string-based key=value pairs.
+    ///
+    /// ## Supported Keys:
+    /// - `"share"` → referrer share of referee fees (in BPS)
+    ///
+    /// ## Errors:
+    /// - `NotEnoughArguments` if input is empty
+    /// - `InvalidArgument` if any key is unknown or parsing fails
+    /// - `InvalidFeeValue` if the share exceeds `MAX_REFERRAL_SHARE_BPS`
+    pub(crate) fn apply_updates(&mut self, args: Vec<String>) -> Result<()> {
+        require!(!args.is_empty(), crate::ErrorCode::NotEnoughArguments);
+
+        let mut log: Vec<String> = vec![];
+
+        for arg in args {
+            let parts: Vec<&str> = arg.split('=').collect();
+            require!(parts.len() == 2, crate::ErrorCode::InvalidArgument);
+            let key = parts[0].trim();
+            let val = parts[1].trim().parse::<u16>().map_err(|_| crate::ErrorCode::InvalidArgument)?;
+            require!(val <= MAX_REFERRAL_SHARE_BPS, EconomicsErrorCode::InvalidFeeValue);
+
+            match key {
+                "share" => self.share_bps = val,
+                _ => return Err(crate::ErrorCode::InvalidArgument.into()),
+            }
+            log.push(format!("{}: {} BPS", key, val));
+        }
+
+        msg!("✅ Referral settings updated → {}", log.join(" | "));
+
+        emit!(ReferralSettingsUpdated {
+            share_bps: self.share_bps,
+            percent: self.share_bps as f64 / 100.0,
+        });
+
+        Ok(())
+    }
+
+    /// Constant space used by this struct in bytes.
+    pub const LEN: usize = 2; // u16
+}
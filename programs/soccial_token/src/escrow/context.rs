@@ -0,0 +1,481 @@
+// ======================================================================
+// Soccial Token – Escrow Contexts
+//
+// Defines account contexts for escrowed peer-to-peer trades: opening an
+// escrow and settling it (release, refund or arbitration), and for
+// milestone-based collaboration escrows.
+//
+// Contexts include PDA validations, token account checks, optional user access,
+// and global token state references.
+//
+// License: MIT License
+// Author: Paulo Rodrigues
+// Project: Soccial Token
+// ======================================================================
+
+use anchor_lang::prelude::*;
+use anchor_spl::{
+    associated_token::AssociatedToken,
+    token::{Mint, Token, TokenAccount},
+};
+use crate::{
+    auth::user::UserAccessAccount,
+    escrow::state::{Escrow, EscrowState, MilestoneEscrow},
+    token::state::TokenState,
+};
+
+#[derive(Accounts)]
+pub struct OpenEscrowContext<'info> {
+    /// The maker funding the escrow (pays for the accounts).
+    #[account(mut)]
+    pub caller: Signer<'info>,
+
+    /// Token account of the maker, source of the escrowed funds.
+    #[account(
+        mut,
+        associated_token::mint = token_mint,
+        associated_token::authority = caller
+    )]
+    pub maker_token_account: Account<'info, TokenAccount>,
+
+    // ─────────────────────────────────────────────────────────────
+    // Escrow
+    // ─────────────────────────────────────────────────────────────
+
+    /// Global escrow counter, created on first use.
+    #[account(
+        init_if_needed,
+        payer = caller,
+        space = EscrowState::LEN,
+        seeds = [b"escrow_state"],
+        bump
+    )]
+    pub escrow_state: Account<'info, EscrowState>,
+
+    /// Escrow PDA, seeded by the next escrow ID.
+    #[account(
+        init,
+        payer = caller,
+        space = Escrow::LEN,
+        seeds = [b"escrow", escrow_state.last_id.to_le_bytes().as_ref()],
+        bump
+    )]
+    pub escrow: Account<'info, Escrow>,
+
+    /// Escrow SCTK holdings, owned by the escrow PDA.
+    #[account(
+        init,
+        payer = caller,
+        associated_token::mint = token_mint,
+        associated_token::authority = escrow
+    )]
+    pub escrow_token_account: Account<'info, TokenAccount>,
+
+    // ─────────────────────────────────────────────────────────────
+    // Mint, Access Control & State
+    // ─────────────────────────────────────────────────────────────
+
+    /// Token mint (SCTK).
+    pub token_mint: Account<'info, Mint>,
+
+    /// Optional access control account for the caller.
+    #[account(
+        seeds = [b"user_access", caller.key().as_ref()],
+        bump
+    )]
+    pub user_access: Option<Account<'info, UserAccessAccount>>,
+
+    /// TokenState account for system-wide configuration.
+    pub token_state: Account<'info, TokenState>,
+
+    // ─────────────────────────────────────────────────────────────
+    // Programs
+    // ─────────────────────────────────────────────────────────────
+
+    /// Token program required for token transfers.
+    pub token_program: Program<'info, Token>,
+
+    /// Associated Token Program used to create the escrow holdings.
+    pub associated_token_program: Program<'info, AssociatedToken>,
+
+    /// System program required for account creation.
+    pub system_program: Program<'info, System>,
+}
+
+/// Shared by `release_escrow`, `refund_escrow` and `arbitrate_escrow`.
+#[derive(Accounts)]
+pub struct SettleEscrowContext<'info> {
+    /// The maker, taker or arbiter settling the escrow.
+    pub caller: Signer<'info>,
+
+    // ─────────────────────────────────────────────────────────────
+    // Escrow & Parties
+    // ─────────────────────────────────────────────────────────────
+
+    /// Escrow being settled.
+    #[account(
+        mut,
+        seeds = [b"escrow", escrow.id.to_le_bytes().as_ref()],
+        bump = escrow.bump
+    )]
+    pub escrow: Account<'info, Escrow>,
+
+    /// Escrow SCTK holdings; closed on settlement.
+    #[account(
+        mut,
+        associated_token::mint = token_mint,
+        associated_token::authority = escrow
+    )]
+    pub escrow_token_account: Account<'info, TokenAccount>,
+
+    /// CHECK: Maker wallet receiving the holdings' rent on settlement.
+    #[account(mut, address = escrow.maker)]
+    pub maker: AccountInfo<'info>,
+
+    /// Token account of the maker, destination of refunds.
+    #[account(
+        mut,
+        associated_token::mint = token_mint,
+        associated_token::authority = escrow.maker
+    )]
+    pub maker_token_account: Account<'info, TokenAccount>,
+
+    /// Token account of the taker, destination of releases.
+    #[account(
+        mut,
+        associated_token::mint = token_mint,
+        associated_token::authority = escrow.taker
+    )]
+    pub taker_token_account: Account<'info, TokenAccount>,
+
+    // ------------------------------------------------------------------------
+    // Rewards Vault
+    // ------------------------------------------------------------------------
+
+    /// CHECK: PDA authority for rewards logic. Verified in handler.
+    #[account(seeds = [b"rewards_vault"], bump)]
+    pub rewards_vault: AccountInfo<'info>,
+
+    /// Token account holding tokens designated for rewards.
+    #[account(
+        mut,
+        associated_token::mint = token_mint,
+        associated_token::authority = rewards_vault
+    )]
+    pub rewards_vault_token_account: Account<'info, TokenAccount>,
+
+    /// CHECK: Ledger PDA of `rewards_vault`. Posted to in logic once opened.
+    #[account(
+        mut,
+        seeds = [b"vault_ledger", rewards_vault.key().as_ref()],
+        bump
+    )]
+    pub rewards_ledger: AccountInfo<'info>,
+
+    // ------------------------------------------------------------------------
+    // Revenue Vault
+    // ------------------------------------------------------------------------
+
+    /// CHECK: PDA authority for revenue logic. Verified in handler.
+    #[account(seeds = [b"revenue_vault"], bump)]
+    pub revenue_vault: AccountInfo<'info>,
+
+    /// Token account holding tokens allocated for revenue collection.
+    #[account(
+        mut,
+        associated_token::mint = token_mint,
+        associated_token::authority = revenue_vault
+    )]
+    pub revenue_vault_token_account: Account<'info, TokenAccount>,
+
+    /// CHECK: Ledger PDA of `revenue_vault`. Posted to in logic once opened.
+    #[account(
+        mut,
+        seeds = [b"vault_ledger", revenue_vault.key().as_ref()],
+        bump
+    )]
+    pub revenue_ledger: AccountInfo<'info>,
+
+    // ------------------------------------------------------------------------
+    // Airdrop Vault
+    // ------------------------------------------------------------------------
+
+    /// CHECK: PDA authority for airdrop logic. Verified in handler.
+    #[account(seeds = [b"airdrop_vault"], bump)]
+    pub airdrop_vault: AccountInfo<'info>,
+
+    /// Token account holding tokens reserved for airdrops.
+    #[account(
+        mut,
+        associated_token::mint = token_mint,
+        associated_token::authority = airdrop_vault
+    )]
+    pub airdrop_vault_token_account: Account<'info, TokenAccount>,
+
+    /// CHECK: Ledger PDA of `airdrop_vault`. Posted to in logic once opened.
+    #[account(
+        mut,
+        seeds = [b"vault_ledger", airdrop_vault.key().as_ref()],
+        bump
+    )]
+    pub airdrop_ledger: AccountInfo<'info>,
+
+    // ─────────────────────────────────────────────────────────────
+    // Mint, Access Control & State
+    // ─────────────────────────────────────────────────────────────
+
+    /// Token mint (SCTK).
+    pub token_mint: Account<'info, Mint>,
+
+    /// Optional access control account for the caller.
+    #[account(
+        seeds = [b"user_access", caller.key().as_ref()],
+        bump
+    )]
+    pub user_access: Option<Account<'info, UserAccessAccount>>,
+
+    /// Optional access control account of the maker (fee discounts).
+    #[account(
+        seeds = [b"user_access", escrow.maker.as_ref()],
+        bump
+    )]
+    pub maker_access: Option<Account<'info, UserAccessAccount>>,
+
+    /// TokenState account for system-wide configuration.
+    pub token_state: Account<'info, TokenState>,
+
+    // ─────────────────────────────────────────────────────────────
+    // Programs
+    // ─────────────────────────────────────────────────────────────
+
+    /// Token program required for token transfers.
+    pub token_program: Program<'info, Token>,
+}
+
+#[derive(Accounts)]
+pub struct OpenMilestoneEscrowContext<'info> {
+    /// The funder depositing the collaboration total (pays for the accounts).
+    #[account(mut)]
+    pub caller: Signer<'info>,
+
+    /// Token account of the funder, source of the deposit.
+    #[account(
+        mut,
+        associated_token::mint = token_mint,
+        associated_token::authority = caller
+    )]
+    pub funder_token_account: Account<'info, TokenAccount>,
+
+    // ─────────────────────────────────────────────────────────────
+    // Escrow
+    // ─────────────────────────────────────────────────────────────
+
+    /// Global escrow counter, created on first use.
+    #[account(
+        init_if_needed,
+        payer = caller,
+        space = EscrowState::LEN,
+        seeds = [b"escrow_state"],
+        bump
+    )]
+    pub escrow_state: Account<'info, EscrowState>,
+
+    /// Milestone escrow PDA, seeded by the next escrow ID.
+    #[account(
+        init,
+        payer = caller,
+        space = MilestoneEscrow::LEN,
+        seeds = [b"milestone_escrow", escrow_state.last_id.to_le_bytes().as_ref()],
+        bump
+    )]
+    pub milestone_escrow: Account<'info, MilestoneEscrow>,
+
+    /// Escrow SCTK holdings, owned by the milestone escrow PDA.
+    #[account(
+        init,
+        payer = caller,
+        associated_token::mint = token_mint,
+        associated_token::authority = milestone_escrow
+    )]
+    pub escrow_token_account: Account<'info, TokenAccount>,
+
+    // ─────────────────────────────────────────────────────────────
+    // Mint, Access Control & State
+    // ─────────────────────────────────────────────────────────────
+
+    /// Token mint (SCTK).
+    pub token_mint: Account<'info, Mint>,
+
+    /// Optional access control account for the caller.
+    #[account(
+        seeds = [b"user_access", caller.key().as_ref()],
+        bump
+    )]
+    pub user_access: Option<Account<'info, UserAccessAccount>>,
+
+    /// TokenState account for system-wide configuration.
+    pub token_state: Account<'info, TokenState>,
+
+    // ─────────────────────────────────────────────────────────────
+    // Programs
+    // ─────────────────────────────────────────────────────────────
+
+    /// Token program required for token transfers.
+    pub token_program: Program<'info, Token>,
+
+    /// Associated Token Program used to create the escrow holdings.
+    pub associated_token_program: Program<'info, AssociatedToken>,
+
+    /// System program required for account creation.
+    pub system_program: Program<'info, System>,
+}
+
+/// Shared by `release_milestone` and `refund_milestone_escrow`.
+#[derive(Accounts)]
+pub struct SettleMilestoneContext<'info> {
+    /// The funder, or an arbiter holding `manage_user`.
+    pub caller: Signer<'info>,
+
+    // ─────────────────────────────────────────────────────────────
+    // Escrow & Parties
+    // ─────────────────────────────────────────────────────────────
+
+    /// Milestone escrow being settled.
+    #[account(
+        mut,
+        seeds = [b"milestone_escrow", milestone_escrow.id.to_le_bytes().as_ref()],
+        bump = milestone_escrow.bump
+    )]
+    pub milestone_escrow: Account<'info, MilestoneEscrow>,
+
+    /// Escrow SCTK holdings; closed once fully settled.
+    #[account(
+        mut,
+        associated_token::mint = token_mint,
+        associated_token::authority = milestone_escrow
+    )]
+    pub escrow_token_account: Account<'info, TokenAccount>,
+
+    /// CHECK: Funder wallet receiving the holdings' rent once closed.
+    #[account(mut, address = milestone_escrow.funder)]
+    pub funder: AccountInfo<'info>,
+
+    /// Token account of the funder, destination of refunds.
+    #[account(
+        mut,
+        associated_token::mint = token_mint,
+        associated_token::authority = milestone_escrow.funder
+    )]
+    pub funder_token_account: Account<'info, TokenAccount>,
+
+    /// Token account of the recipient, destination of milestone payouts.
+    #[account(
+        mut,
+        associated_token::mint = token_mint,
+        associated_token::authority = milestone_escrow.recipient
+    )]
+    pub recipient_token_account: Account<'info, TokenAccount>,
+
+    // ------------------------------------------------------------------------
+    // Rewards Vault
+    // ------------------------------------------------------------------------
+
+    /// CHECK: PDA authority for rewards logic. Verified in handler.
+    #[account(seeds = [b"rewards_vault"], bump)]
+    pub rewards_vault: AccountInfo<'info>,
+
+    /// Token account holding tokens designated for rewards.
+    #[account(
+        mut,
+        associated_token::mint = token_mint,
+        associated_token::authority = rewards_vault
+    )]
+    pub rewards_vault_token_account: Account<'info, TokenAccount>,
+
+    /// CHECK: Ledger PDA of `rewards_vault`. Posted to in logic once opened.
+    #[account(
+        mut,
+        seeds = [b"vault_ledger", rewards_vault.key().as_ref()],
+        bump
+    )]
+    pub rewards_ledger: AccountInfo<'info>,
+
+    // ------------------------------------------------------------------------
+    // Revenue Vault
+    // ------------------------------------------------------------------------
+
+    /// CHECK: PDA authority for revenue logic. Verified in handler.
+    #[account(seeds = [b"revenue_vault"], bump)]
+    pub revenue_vault: AccountInfo<'info>,
+
+    /// Token account holding tokens allocated for revenue collection.
+    #[account(
+        mut,
+        associated_token::mint = token_mint,
+        associated_token::authority = revenue_vault
+    )]
+    pub revenue_vault_token_account: Account<'info, TokenAccount>,
+
+    /// CHECK: Ledger PDA of `revenue_vault`. Posted to in logic once opened.
+    #[account(
+        mut,
+        seeds = [b"vault_ledger", revenue_vault.key().as_ref()],
+        bump
+    )]
+    pub revenue_ledger: AccountInfo<'info>,
+
+    // ------------------------------------------------------------------------
+    // Airdrop Vault
+    // ------------------------------------------------------------------------
+
+    /// CHECK: PDA authority for airdrop logic. Verified in handler.
+    #[account(seeds = [b"airdrop_vault"], bump)]
+    pub airdrop_vault: AccountInfo<'info>,
+
+    /// Token account holding tokens reserved for airdrops.
+    #[account(
+        mut,
+        associated_token::mint = token_mint,
+        associated_token::authority = airdrop_vault
+    )]
+    pub airdrop_vault_token_account: Account<'info, TokenAccount>,
+
+    /// CHECK: Ledger PDA of `airdrop_vault`. Posted to in logic once opened.
+    #[account(
+        mut,
+        seeds = [b"vault_ledger", airdrop_vault.key().as_ref()],
+        bump
+    )]
+    pub airdrop_ledger: AccountInfo<'info>,
+
+    // ─────────────────────────────────────────────────────────────
+    // Mint, Access Control & State
+    // ─────────────────────────────────────────────────────────────
+
+    /// Token mint (SCTK).
+    pub token_mint: Account<'info, Mint>,
+
+    /// Optional access control account for the caller.
+    #[account(
+        seeds = [b"user_access", caller.key().as_ref()],
+        bump
+    )]
+    pub user_access: Option<Account<'info, UserAccessAccount>>,
+
+    /// Optional access control account of the funder (fee discounts).
+    #[account(
+        seeds = [b"user_access", milestone_escrow.funder.as_ref()],
+        bump
+    )]
+    pub funder_access: Option<Account<'info, UserAccessAccount>>,
+
+    /// TokenState account for system-wide configuration.
+    pub token_state: Account<'info, TokenState>,
+
+    // ─────────────────────────────────────────────────────────────
+    // Programs
+    // ─────────────────────────────────────────────────────────────
+
+    /// Token program required for token transfers.
+    pub token_program: Program<'info, Token>,
+}
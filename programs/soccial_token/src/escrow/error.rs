@@ -0,0 +1,64 @@
+use anchor_lang::error_code;
+
+// ======================================================================
+// Soccial Token – Escrow Error Definitions
+//
+// This module defines custom errors for escrowed peer-to-peer trades,
+// such as opening, releasing, refunding and arbitrating an escrow, and
+// milestone-based collaboration escrows.
+//
+// License: MIT License
+// Author: Paulo Rodrigues
+// Project: Soccial Token
+// ======================================================================
+
+#[error_code]
+pub enum EscrowError {
+    /// The provided amount is invalid (e.g., zero).
+    #[msg("Invalid amount.")]
+    InvalidAmount,
+
+    /// The maker does not have enough balance to fund the escrow.
+    #[msg("Insufficient funds.")]
+    InsufficientFunds,
+
+    /// The deadline is not in the future.
+    #[msg("Invalid escrow deadline.")]
+    InvalidDeadline,
+
+    /// The item reference hash is not 32 bytes of hex.
+    #[msg("Invalid item reference hash.")]
+    InvalidItemHash,
+
+    /// The taker or arbiter is invalid (e.g., the maker itself).
+    #[msg("Invalid escrow party.")]
+    InvalidParty,
+
+    /// The escrow is no longer open.
+    #[msg("Escrow is not open.")]
+    EscrowNotOpen,
+
+    /// The caller is not allowed to perform this action on the escrow.
+    #[msg("Caller is not allowed to settle this escrow.")]
+    NotEscrowParty,
+
+    /// The maker can only refund after the deadline.
+    #[msg("Escrow deadline has not passed.")]
+    DeadlineNotReached,
+
+    /// The milestone list is empty, too long or has a zero amount.
+    #[msg("Invalid milestones.")]
+    InvalidMilestones,
+
+    /// The milestone index does not exist.
+    #[msg("Invalid milestone index.")]
+    InvalidMilestoneIndex,
+
+    /// The milestone was already released.
+    #[msg("Milestone already released.")]
+    MilestoneAlreadyReleased,
+
+    /// Overflow occurred during calculation.
+    #[msg("Overflow occurred during calculation.")]
+    Overflow,
+}
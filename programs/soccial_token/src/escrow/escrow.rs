@@ -0,0 +1,382 @@
+// ===========================================================================
+// Escrow – Soccial Token (SCTK)
+// ---------------------------------------------------------------------------
+//
+// This module implements escrowed peer-to-peer trades (e.g. buying and
+// selling Collaboration Cards). The maker's SCTK is held in an account owned
+// by the escrow PDA until the trade is settled, so disputes can be resolved
+// without the backend custodying funds.
+//
+// ---------------------------------------------------------------------------
+// ## Settlement Rules:
+// - Release (taker is paid, market transfer fee applies): by the maker
+// - Refund (maker gets the full amount back): by the taker at any time, or
+//   by the maker once the deadline has passed
+// - Arbitration (release or refund): by the arbiter, if one was set
+// - Any surplus left in the holdings (e.g. unsolicited deposits) goes back to
+//   the maker, then the holdings account is closed and its rent returned
+//
+// ---------------------------------------------------------------------------
+// ## Components:
+// - `open_escrow()`: Funds a new escrow
+// - `release_escrow()` / `refund_escrow()` / `arbitrate_escrow()`: Settlement
+//
+// ---------------------------------------------------------------------------
+// Author: Paulo Rodrigues
+// Project: Soccial Token
+// Website: https://www.soccial.com/thetoken
+// License: MIT
+// ===========================================================================
+
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, CloseAccount, Transfer};
+
+use crate::{
+    economics::state::FeeOperation,
+    escrow::{
+        context::*,
+        error::EscrowError,
+        state::{EscrowDecision, EscrowStatus},
+    },
+    market::{calculate_fee, distribute_fees, FeeDistributionContext},
+    utils::math::format_sctk,
+};
+
+#[event]
+pub struct EscrowOpened {
+    pub escrow: Pubkey,
+    pub id: u64,
+    pub maker: Pubkey,
+    pub taker: Pubkey,
+    pub amount: u64,
+    pub item_hash: [u8; 32],
+    pub deadline: i64,
+    pub arbiter: Pubkey,
+}
+
+#[event]
+pub struct EscrowReleased {
+    pub escrow: Pubkey,
+    pub id: u64,
+    pub taker: Pubkey,
+    pub amount: u64,
+    pub net_received: u64,
+    pub fee_charged: u64,
+    pub to_rewards: u64,
+    pub to_airdrop: u64,
+    pub to_revenue: u64,
+    pub settled_by: Pubkey,
+}
+
+#[event]
+pub struct EscrowRefunded {
+    pub escrow: Pubkey,
+    pub id: u64,
+    pub maker: Pubkey,
+    pub amount: u64,
+    pub settled_by: Pubkey,
+}
+
+/// ===========================================================================
+/// Opens an escrow, moving `amount` SCTK from the maker into the escrow
+/// holdings account.
+///
+/// ## Errors:
+/// - `EscrowError::InvalidAmount` on zero amount
+/// - `EscrowError::InvalidParty` if the taker or arbiter is the maker
+/// - `EscrowError::InvalidDeadline` if the deadline is not in the future
+/// - `EscrowError::InsufficientFunds` if the maker lacks SCTK
+/// ===========================================================================
+pub(crate) fn open_escrow(
+    ctx: Context<OpenEscrowContext>,
+    taker: Pubkey,
+    amount: u64,
+    item_hash: [u8; 32],
+    deadline: i64,
+    arbiter: Pubkey,
+) -> Result<()> {
+    let maker = ctx.accounts.caller.key();
+    let now = Clock::get()?.unix_timestamp;
+
+    require!(amount > 0, EscrowError::InvalidAmount);
+    require!(taker != maker && taker != Pubkey::default(), EscrowError::InvalidParty);
+    require!(arbiter != maker && arbiter != taker, EscrowError::InvalidParty);
+    require!(deadline > now, EscrowError::InvalidDeadline);
+    require!(
+        ctx.accounts.maker_token_account.amount >= amount,
+        EscrowError::InsufficientFunds
+    );
+
+    token::transfer(
+        CpiContext::new(
+            ctx.accounts.token_program.to_account_info(),
+            Transfer {
+                from: ctx.accounts.maker_token_account.to_account_info(),
+                to: ctx.accounts.escrow_token_account.to_account_info(),
+                authority: ctx.accounts.caller.to_account_info(),
+            },
+        ),
+        amount,
+    )?;
+
+    let escrow_state = &mut ctx.accounts.escrow_state;
+    let id = escrow_state.last_id;
+    escrow_state.last_id = id.checked_add(1).ok_or(EscrowError::Overflow)?;
+
+    let escrow = &mut ctx.accounts.escrow;
+    escrow.id = id;
+    escrow.maker = maker;
+    escrow.taker = taker;
+    escrow.amount = amount;
+    escrow.item_hash = item_hash;
+    escrow.deadline = deadline;
+    escrow.arbiter = arbiter;
+    escrow.status = EscrowStatus::Open;
+    escrow.created_at = now;
+    escrow.settled_at = 0;
+    escrow.bump = ctx.bumps.escrow;
+
+    msg!(
+        "🔒 Escrow #{} opened: {} SCTK ({} units) for {} | ⏳ Deadline: {}",
+        id,
+        format_sctk(amount),
+        amount,
+        taker,
+        deadline,
+    );
+
+    emit!(EscrowOpened {
+        escrow: escrow.key(),
+        id,
+        maker,
+        taker,
+        amount,
+        item_hash,
+        deadline,
+        arbiter,
+    });
+
+    Ok(())
+}
+
+/// ===========================================================================
+/// Releases the escrow to the taker; the market transfer fee is deducted.
+///
+/// ## Errors:
+/// - `EscrowError::EscrowNotOpen` if already settled
+/// - `EscrowError::NotEscrowParty` if the caller is not the maker
+/// ===========================================================================
+pub(crate) fn release_escrow(ctx: Context<SettleEscrowContext>) -> Result<()> {
+    require!(ctx.accounts.escrow.status == EscrowStatus::Open, EscrowError::EscrowNotOpen);
+    require_keys_eq!(ctx.accounts.caller.key(), ctx.accounts.escrow.maker, EscrowError::NotEscrowParty);
+
+    settle_release(ctx.accounts)
+}
+
+/// ===========================================================================
+/// Refunds the full escrow amount to the maker.
+///
+/// ## Behavior:
+/// - The taker may refund at any time (e.g. cannot deliver the item)
+/// - The maker may refund only once the deadline has passed
+///
+/// ## Errors:
+/// - `EscrowError::EscrowNotOpen` if already settled
+/// - `EscrowError::DeadlineNotReached` if the maker refunds too early
+/// - `EscrowError::NotEscrowParty` for any other caller
+/// ===========================================================================
+pub(crate) fn refund_escrow(ctx: Context<SettleEscrowContext>) -> Result<()> {
+    let escrow = &ctx.accounts.escrow;
+    let caller = ctx.accounts.caller.key();
+
+    require!(escrow.status == EscrowStatus::Open, EscrowError::EscrowNotOpen);
+
+    if caller == escrow.maker {
+        let now = Clock::get()?.unix_timestamp;
+        require!(now > escrow.deadline, EscrowError::DeadlineNotReached);
+    } else {
+        require_keys_eq!(caller, escrow.taker, EscrowError::NotEscrowParty);
+    }
+
+    settle_refund(ctx.accounts)
+}
+
+/// ===========================================================================
+/// Settles a disputed escrow according to the arbiter's decision.
+///
+/// ## Errors:
+/// - `EscrowError::EscrowNotOpen` if already settled
+/// - `EscrowError::NotEscrowParty` if no arbiter was set or the caller is not
+///   the arbiter
+/// ===========================================================================
+pub(crate) fn arbitrate_escrow(
+    ctx: Context<SettleEscrowContext>,
+    decision: EscrowDecision,
+) -> Result<()> {
+    let escrow = &ctx.accounts.escrow;
+
+    require!(escrow.status == EscrowStatus::Open, EscrowError::EscrowNotOpen);
+    require!(escrow.has_arbiter(), EscrowError::NotEscrowParty);
+    require_keys_eq!(ctx.accounts.caller.key(), escrow.arbiter, EscrowError::NotEscrowParty);
+
+    msg!("⚖️ Escrow #{} arbitrated: {:?}", escrow.id, decision);
+
+    match decision {
+        EscrowDecision::Release => settle_release(ctx.accounts),
+        EscrowDecision::Refund => settle_refund(ctx.accounts),
+    }
+}
+
+/// Pays the escrow to the taker with the market fee split, then closes the
+/// holdings account.
+fn settle_release(accounts: &mut SettleEscrowContext) -> Result<()> {
+    let amount = accounts.escrow.amount;
+    let id_bytes = accounts.escrow.id.to_le_bytes();
+    let signer_seeds: &[&[u8]] = &[b"escrow", id_bytes.as_ref(), &[accounts.escrow.bump]];
+    let signer_seeds_nested = &[signer_seeds];
+
+    let (net_amount, fee_amount, _) = calculate_fee(
+        &accounts.token_state,
+        accounts.maker_access.as_deref(),
+        FeeOperation::Transfer,
+        amount,
+    )?;
+
+    token::transfer(
+        CpiContext::new_with_signer(
+            accounts.token_program.to_account_info(),
+            Transfer {
+                from: accounts.escrow_token_account.to_account_info(),
+                to: accounts.taker_token_account.to_account_info(),
+                authority: accounts.escrow.to_account_info(),
+            },
+            signer_seeds_nested,
+        ),
+        net_amount,
+    )?;
+
+    let (to_rewards, to_airdrop, to_revenue) = distribute_fees(
+        &FeeDistributionContext {
+            token_state: accounts.token_state.clone(),
+            token_program: accounts.token_program.to_account_info(),
+            source_token_account: accounts.escrow_token_account.to_account_info(),
+            rewards_vault_token_account: accounts.rewards_vault_token_account.to_account_info(),
+            rewards_ledger: accounts.rewards_ledger.to_account_info(),
+            airdrop_vault_token_account: accounts.airdrop_vault_token_account.to_account_info(),
+            airdrop_ledger: accounts.airdrop_ledger.to_account_info(),
+            revenue_vault_token_account: accounts.revenue_vault_token_account.to_account_info(),
+            revenue_ledger: accounts.revenue_ledger.to_account_info(),
+            authority: accounts.escrow.to_account_info(),
+        },
+        fee_amount,
+        Some(signer_seeds_nested),
+    )?;
+
+    close_holdings(accounts, signer_seeds_nested)?;
+
+    let escrow = &mut accounts.escrow;
+    escrow.status = EscrowStatus::Released;
+    escrow.settled_at = Clock::get()?.unix_timestamp;
+
+    msg!(
+        "🔓 Escrow #{} released: {} SCTK ({} units) to {} | 📈 Fee: {} SCTK ({} units)",
+        escrow.id,
+        format_sctk(net_amount),
+        net_amount,
+        escrow.taker,
+        format_sctk(fee_amount),
+        fee_amount,
+    );
+
+    emit!(EscrowReleased {
+        escrow: escrow.key(),
+        id: escrow.id,
+        taker: escrow.taker,
+        amount,
+        net_received: net_amount,
+        fee_charged: fee_amount,
+        to_rewards,
+        to_airdrop,
+        to_revenue,
+        settled_by: accounts.caller.key(),
+    });
+
+    Ok(())
+}
+
+/// Returns the whole holdings balance to the maker, then closes the holdings
+/// account.
+fn settle_refund(accounts: &mut SettleEscrowContext) -> Result<()> {
+    let amount = accounts.escrow_token_account.amount;
+    let id_bytes = accounts.escrow.id.to_le_bytes();
+    let signer_seeds: &[&[u8]] = &[b"escrow", id_bytes.as_ref(), &[accounts.escrow.bump]];
+    let signer_seeds_nested = &[signer_seeds];
+
+    token::transfer(
+        CpiContext::new_with_signer(
+            accounts.token_program.to_account_info(),
+            Transfer {
+                from: accounts.escrow_token_account.to_account_info(),
+                to: accounts.maker_token_account.to_account_info(),
+                authority: accounts.escrow.to_account_info(),
+            },
+            signer_seeds_nested,
+        ),
+        amount,
+    )?;
+
+    close_holdings(accounts, signer_seeds_nested)?;
+
+    let escrow = &mut accounts.escrow;
+    escrow.status = EscrowStatus::Refunded;
+    escrow.settled_at = Clock::get()?.unix_timestamp;
+
+    msg!(
+        "↩️ Escrow #{} refunded: {} SCTK ({} units) to {}",
+        escrow.id,
+        format_sctk(amount),
+        amount,
+        escrow.maker,
+    );
+
+    emit!(EscrowRefunded {
+        escrow: escrow.key(),
+        id: escrow.id,
+        maker: escrow.maker,
+        amount,
+        settled_by: accounts.caller.key(),
+    });
+
+    Ok(())
+}
+
+/// Sweeps any surplus (e.g. unsolicited deposits) to the maker, then closes
+/// the holdings account, returning its rent to the maker.
+fn close_holdings(accounts: &mut SettleEscrowContext, signer_seeds: &[&[&[u8]]]) -> Result<()> {
+    accounts.escrow_token_account.reload()?;
+    let surplus = accounts.escrow_token_account.amount;
+    if surplus > 0 {
+        token::transfer(
+            CpiContext::new_with_signer(
+                accounts.token_program.to_account_info(),
+                Transfer {
+                    from: accounts.escrow_token_account.to_account_info(),
+                    to: accounts.maker_token_account.to_account_info(),
+                    authority: accounts.escrow.to_account_info(),
+                },
+                signer_seeds,
+            ),
+            surplus,
+        )?;
+    }
+
+    token::close_account(CpiContext::new_with_signer(
+        accounts.token_program.to_account_info(),
+        CloseAccount {
+            account: accounts.escrow_token_account.to_account_info(),
+            destination: accounts.maker.to_account_info(),
+            authority: accounts.escrow.to_account_info(),
+        },
+        signer_seeds,
+    ))
+}
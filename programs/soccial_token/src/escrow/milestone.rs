@@ -0,0 +1,352 @@
+// ===========================================================================
+// Milestone Escrow – Soccial Token (SCTK)
+// ---------------------------------------------------------------------------
+//
+// This module implements staged payouts for collaborations between creators.
+// The funder deposits the total up front into an account owned by the
+// milestone escrow PDA, with a fixed list of milestone amounts.
+//
+// ---------------------------------------------------------------------------
+// ## Settlement Rules:
+// - Each milestone is released individually by the funder, or by an arbiter
+//   holding `manage_user`; the market transfer fee applies per milestone
+// - After the deadline, the unreleased remainder can be refunded to the funder
+// - The holdings account is closed once the escrow is fully settled; any
+//   surplus left in it (e.g. unsolicited deposits) goes back to the funder
+//
+// ---------------------------------------------------------------------------
+// ## Components:
+// - `open_milestone_escrow()`: Deposits the total and fixes the milestones
+// - `release_milestone()`: Pays out one milestone to the recipient
+// - `refund_milestone_escrow()`: Returns the remainder after the deadline
+//
+// ---------------------------------------------------------------------------
+// Author: Paulo Rodrigues
+// Project: Soccial Token
+// Website: https://www.soccial.com/thetoken
+// License: MIT
+// ===========================================================================
+
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, CloseAccount, Transfer};
+
+use crate::{
+    economics::state::FeeOperation,
+    escrow::{
+        context::*,
+        error::EscrowError,
+        state::{EscrowStatus, MAX_MILESTONES},
+    },
+    market::{calculate_fee, distribute_fees, FeeDistributionContext},
+    utils::math::format_sctk,
+};
+
+#[event]
+pub struct MilestoneEscrowOpened {
+    pub escrow: Pubkey,
+    pub id: u64,
+    pub funder: Pubkey,
+    pub recipient: Pubkey,
+    pub total_amount: u64,
+    pub milestones: Vec<u64>,
+    pub deadline: i64,
+}
+
+#[event]
+pub struct MilestoneReleased {
+    pub escrow: Pubkey,
+    pub id: u64,
+    pub index: u8,
+    pub recipient: Pubkey,
+    pub amount: u64,
+    pub net_received: u64,
+    pub fee_charged: u64,
+    pub to_rewards: u64,
+    pub to_airdrop: u64,
+    pub to_revenue: u64,
+    pub released_amount: u64,
+    pub released_by: Pubkey,
+}
+
+#[event]
+pub struct MilestoneEscrowRefunded {
+    pub escrow: Pubkey,
+    pub id: u64,
+    pub funder: Pubkey,
+    pub refunded_amount: u64,
+    pub released_amount: u64,
+}
+
+/// ===========================================================================
+/// Opens a milestone escrow, depositing the sum of all milestones.
+///
+/// ## Errors:
+/// - `EscrowError::InvalidMilestones` if the list is empty, longer than
+///   `MAX_MILESTONES` or contains a zero amount
+/// - `EscrowError::InvalidParty` if the recipient is the funder
+/// - `EscrowError::InvalidDeadline` if the deadline is not in the future
+/// - `EscrowError::InsufficientFunds` if the funder lacks SCTK
+/// ===========================================================================
+pub(crate) fn open_milestone_escrow(
+    ctx: Context<OpenMilestoneEscrowContext>,
+    recipient: Pubkey,
+    deadline: i64,
+    milestones: Vec<u64>,
+) -> Result<()> {
+    let funder = ctx.accounts.caller.key();
+    let now = Clock::get()?.unix_timestamp;
+
+    require!(
+        !milestones.is_empty() && milestones.len() <= MAX_MILESTONES && milestones.iter().all(|m| *m > 0),
+        EscrowError::InvalidMilestones
+    );
+    require!(recipient != funder && recipient != Pubkey::default(), EscrowError::InvalidParty);
+    require!(deadline > now, EscrowError::InvalidDeadline);
+
+    let total_amount = milestones
+        .iter()
+        .try_fold(0u64, |total, m| total.checked_add(*m))
+        .ok_or(EscrowError::Overflow)?;
+
+    require!(
+        ctx.accounts.funder_token_account.amount >= total_amount,
+        EscrowError::InsufficientFunds
+    );
+
+    token::transfer(
+        CpiContext::new(
+            ctx.accounts.token_program.to_account_info(),
+            Transfer {
+                from: ctx.accounts.funder_token_account.to_account_info(),
+                to: ctx.accounts.escrow_token_account.to_account_info(),
+                authority: ctx.accounts.caller.to_account_info(),
+            },
+        ),
+        total_amount,
+    )?;
+
+    let escrow_state = &mut ctx.accounts.escrow_state;
+    let id = escrow_state.last_id;
+    escrow_state.last_id = id.checked_add(1).ok_or(EscrowError::Overflow)?;
+
+    let escrow = &mut ctx.accounts.milestone_escrow;
+    escrow.id = id;
+    escrow.funder = funder;
+    escrow.recipient = recipient;
+    escrow.total_amount = total_amount;
+    escrow.milestones = milestones.clone();
+    escrow.released_mask = 0;
+    escrow.released_amount = 0;
+    escrow.deadline = deadline;
+    escrow.status = EscrowStatus::Open;
+    escrow.created_at = now;
+    escrow.bump = ctx.bumps.milestone_escrow;
+
+    msg!(
+        "🧱 Milestone escrow #{} opened: {} SCTK ({} units) in {} milestones for {}",
+        id,
+        format_sctk(total_amount),
+        total_amount,
+        milestones.len(),
+        recipient,
+    );
+
+    emit!(MilestoneEscrowOpened {
+        escrow: escrow.key(),
+        id,
+        funder,
+        recipient,
+        total_amount,
+        milestones,
+        deadline,
+    });
+
+    Ok(())
+}
+
+/// ===========================================================================
+/// Releases milestone `index` to the recipient; the transfer fee is deducted.
+///
+/// ## Behavior:
+/// - Milestones can be released in any order, each at most once
+/// - Releasing the last milestone closes the holdings account
+///
+/// ## Errors:
+/// - `EscrowError::EscrowNotOpen` if already fully settled
+/// - `EscrowError::InvalidMilestoneIndex` if `index` does not exist
+/// - `EscrowError::MilestoneAlreadyReleased` if already paid out
+/// ===========================================================================
+pub(crate) fn release_milestone(ctx: Context<SettleMilestoneContext>, index: u8) -> Result<()> {
+    let escrow = &ctx.accounts.milestone_escrow;
+    let i = index as usize;
+
+    require!(escrow.status == EscrowStatus::Open, EscrowError::EscrowNotOpen);
+    require!(i < escrow.milestones.len(), EscrowError::InvalidMilestoneIndex);
+    require!(!escrow.is_released(i), EscrowError::MilestoneAlreadyReleased);
+
+    let amount = escrow.milestones[i];
+    let id_bytes = escrow.id.to_le_bytes();
+    let signer_seeds: &[&[u8]] = &[b"milestone_escrow", id_bytes.as_ref(), &[escrow.bump]];
+    let signer_seeds_nested = &[signer_seeds];
+
+    let (net_amount, fee_amount, _) = calculate_fee(
+        &ctx.accounts.token_state,
+        ctx.accounts.funder_access.as_deref(),
+        FeeOperation::Transfer,
+        amount,
+    )?;
+
+    token::transfer(
+        CpiContext::new_with_signer(
+            ctx.accounts.token_program.to_account_info(),
+            Transfer {
+                from: ctx.accounts.escrow_token_account.to_account_info(),
+                to: ctx.accounts.recipient_token_account.to_account_info(),
+                authority: ctx.accounts.milestone_escrow.to_account_info(),
+            },
+            signer_seeds_nested,
+        ),
+        net_amount,
+    )?;
+
+    let (to_rewards, to_airdrop, to_revenue) = distribute_fees(
+        &FeeDistributionContext {
+            token_state: ctx.accounts.token_state.clone(),
+            token_program: ctx.accounts.token_program.to_account_info(),
+            source_token_account: ctx.accounts.escrow_token_account.to_account_info(),
+            rewards_vault_token_account: ctx.accounts.rewards_vault_token_account.to_account_info(),
+            rewards_ledger: ctx.accounts.rewards_ledger.to_account_info(),
+            airdrop_vault_token_account: ctx.accounts.airdrop_vault_token_account.to_account_info(),
+            airdrop_ledger: ctx.accounts.airdrop_ledger.to_account_info(),
+            revenue_vault_token_account: ctx.accounts.revenue_vault_token_account.to_account_info(),
+            revenue_ledger: ctx.accounts.revenue_ledger.to_account_info(),
+            authority: ctx.accounts.milestone_escrow.to_account_info(),
+        },
+        fee_amount,
+        Some(signer_seeds_nested),
+    )?;
+
+    let escrow = &mut ctx.accounts.milestone_escrow;
+    escrow.released_mask |= 1 << i;
+    escrow.released_amount = escrow.released_amount.checked_add(amount).ok_or(EscrowError::Overflow)?;
+
+    msg!(
+        "🧱 Milestone {} of escrow #{} released: {} SCTK ({} units) | 📈 Fee: {} SCTK ({} units)",
+        index,
+        escrow.id,
+        format_sctk(net_amount),
+        net_amount,
+        format_sctk(fee_amount),
+        fee_amount,
+    );
+
+    emit!(MilestoneReleased {
+        escrow: escrow.key(),
+        id: escrow.id,
+        index,
+        recipient: escrow.recipient,
+        amount,
+        net_received: net_amount,
+        fee_charged: fee_amount,
+        to_rewards,
+        to_airdrop,
+        to_revenue,
+        released_amount: escrow.released_amount,
+        released_by: ctx.accounts.caller.key(),
+    });
+
+    if escrow.is_complete() {
+        escrow.status = EscrowStatus::Released;
+        close_holdings(ctx.accounts, signer_seeds_nested)?;
+    }
+
+    Ok(())
+}
+
+/// ===========================================================================
+/// Refunds the unreleased remainder to the funder once the deadline passed.
+///
+/// ## Errors:
+/// - `EscrowError::EscrowNotOpen` if already fully settled
+/// - `EscrowError::DeadlineNotReached` before the deadline
+/// ===========================================================================
+pub(crate) fn refund_milestone_escrow(ctx: Context<SettleMilestoneContext>) -> Result<()> {
+    let escrow = &ctx.accounts.milestone_escrow;
+    let now = Clock::get()?.unix_timestamp;
+
+    require!(escrow.status == EscrowStatus::Open, EscrowError::EscrowNotOpen);
+    require!(now > escrow.deadline, EscrowError::DeadlineNotReached);
+
+    // The whole holdings balance goes back, including any unsolicited deposits
+    let remainder = ctx.accounts.escrow_token_account.amount;
+    let id_bytes = escrow.id.to_le_bytes();
+    let signer_seeds: &[&[u8]] = &[b"milestone_escrow", id_bytes.as_ref(), &[escrow.bump]];
+    let signer_seeds_nested = &[signer_seeds];
+
+    token::transfer(
+        CpiContext::new_with_signer(
+            ctx.accounts.token_program.to_account_info(),
+            Transfer {
+                from: ctx.accounts.escrow_token_account.to_account_info(),
+                to: ctx.accounts.funder_token_account.to_account_info(),
+                authority: ctx.accounts.milestone_escrow.to_account_info(),
+            },
+            signer_seeds_nested,
+        ),
+        remainder,
+    )?;
+
+    close_holdings(ctx.accounts, signer_seeds_nested)?;
+
+    let escrow = &mut ctx.accounts.milestone_escrow;
+    escrow.status = EscrowStatus::Refunded;
+
+    msg!(
+        "↩️ Milestone escrow #{} refunded: {} SCTK ({} units) to {}",
+        escrow.id,
+        format_sctk(remainder),
+        remainder,
+        escrow.funder,
+    );
+
+    emit!(MilestoneEscrowRefunded {
+        escrow: escrow.key(),
+        id: escrow.id,
+        funder: escrow.funder,
+        refunded_amount: remainder,
+        released_amount: escrow.released_amount,
+    });
+
+    Ok(())
+}
+
+/// Sweeps any surplus (e.g. unsolicited deposits) to the funder, then closes
+/// the holdings account, returning its rent to the funder.
+fn close_holdings(accounts: &mut SettleMilestoneContext, signer_seeds: &[&[&[u8]]]) -> Result<()> {
+    accounts.escrow_token_account.reload()?;
+    let surplus = accounts.escrow_token_account.amount;
+    if surplus > 0 {
+        token::transfer(
+            CpiContext::new_with_signer(
+                accounts.token_program.to_account_info(),
+                Transfer {
+                    from: accounts.escrow_token_account.to_account_info(),
+                    to: accounts.funder_token_account.to_account_info(),
+                    authority: accounts.milestone_escrow.to_account_info(),
+                },
+                signer_seeds,
+            ),
+            surplus,
+        )?;
+    }
+
+    token::close_account(CpiContext::new_with_signer(
+        accounts.token_program.to_account_info(),
+        CloseAccount {
+            account: accounts.escrow_token_account.to_account_info(),
+            destination: accounts.funder.to_account_info(),
+            authority: accounts.milestone_escrow.to_account_info(),
+        },
+        signer_seeds,
+    ))
+}
@@ -0,0 +1,11 @@
+pub mod error;
+pub mod context;
+pub mod state;
+pub mod escrow;
+pub mod milestone;
+
+pub use error::*;
+pub use context::*;
+pub use state::*;
+pub use escrow::*;
+pub use milestone::*;
@@ -0,0 +1,205 @@
+// ===========================================================================
+// Escrow State – Soccial Token (SCTK)
+// ---------------------------------------------------------------------------
+//
+// This module defines the on-chain escrow used for peer-to-peer trades such
+// as Collaboration Cards, so funds are held by the program instead of the
+// backend until the trade is settled.
+//
+// ---------------------------------------------------------------------------
+// ## Components:
+// - `EscrowState`: Global counter used to derive escrow PDAs
+// - `Escrow`: A single trade between a maker (payer) and a taker (seller)
+// - `EscrowStatus`: Lifecycle of an escrow (open → released / refunded)
+// - `EscrowDecision`: Arbiter outcome for a disputed escrow
+// - `MilestoneEscrow`: Collaboration funds paid out in fixed stages
+//
+// ---------------------------------------------------------------------------
+// Author: Paulo Rodrigues
+// Project: Soccial Token
+// Website: https://www.soccial.com/thetoken
+// License: MIT
+// ===========================================================================
+
+use anchor_lang::prelude::*;
+use std::str::FromStr;
+use crate::escrow::error::EscrowError;
+
+/// ===========================================================================
+/// Account: EscrowState
+///
+/// Global escrow counter; `last_id` seeds the next escrow or milestone
+/// escrow PDA.
+/// ===========================================================================
+#[account]
+pub struct EscrowState {
+    pub last_id: u64,
+}
+
+impl EscrowState {
+    pub const LEN: usize =
+        8 +                         // Anchor discriminator
+        8;                          // last_id (u64)
+}
+
+/// Lifecycle of an `Escrow`.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug)]
+pub enum EscrowStatus {
+    /// Funds are held by the program.
+    Open,
+    /// Funds were paid to the taker.
+    Released,
+    /// Funds were returned to the maker.
+    Refunded,
+}
+
+/// Arbiter outcome for a disputed escrow.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug)]
+pub enum EscrowDecision {
+    /// Pay the taker (market fee applies).
+    Release,
+    /// Return the full amount to the maker.
+    Refund,
+}
+
+impl FromStr for EscrowDecision {
+    type Err = ();
+
+    fn from_str(value: &str) -> core::result::Result<Self, Self::Err> {
+        match value.trim().to_ascii_lowercase().as_str() {
+            "release" => Ok(EscrowDecision::Release),
+            "refund" => Ok(EscrowDecision::Refund),
+            _ => Err(()),
+        }
+    }
+}
+
+/// ===========================================================================
+/// Account: Escrow
+///
+/// SCTK held for a single trade, in an ATA owned by this PDA.
+///
+/// ## Fields:
+/// - `id`: Sequential escrow ID (PDA seed)
+/// - `maker`: Wallet funding the escrow
+/// - `taker`: Wallet paid on release
+/// - `amount`: SCTK units held
+/// - `item_hash`: Hash of the traded item reference (e.g. a Collaboration Card)
+/// - `deadline`: Unix timestamp after which the maker may refund
+/// - `arbiter`: Optional dispute arbiter (default = none)
+/// - `status`: Open, released or refunded
+/// - `created_at` / `settled_at`: Unix timestamps
+/// - `bump`: PDA bump seed
+/// ===========================================================================
+#[account]
+pub struct Escrow {
+    pub id: u64,
+    pub maker: Pubkey,
+    pub taker: Pubkey,
+    pub amount: u64,
+    pub item_hash: [u8; 32],
+    pub deadline: i64,
+    pub arbiter: Pubkey,
+    pub status: EscrowStatus,
+    pub created_at: i64,
+    pub settled_at: i64,
+    pub bump: u8,
+}
+
+impl Escrow {
+    pub const LEN: usize =
+        8 +                         // Anchor discriminator
+        8 +                         // id (u64)
+        32 +                        // maker (Pubkey)
+        32 +                        // taker (Pubkey)
+        8 +                         // amount (u64)
+        32 +                        // item_hash ([u8; 32])
+        8 +                         // deadline (i64)
+        32 +                        // arbiter (Pubkey)
+        1 +                         // status (enum)
+        8 +                         // created_at (i64)
+        8 +                         // settled_at (i64)
+        1;                          // bump (u8)
+
+    /// Returns `true` if a dispute arbiter was set.
+    pub fn has_arbiter(&self) -> bool {
+        self.arbiter != Pubkey::default()
+    }
+}
+
+/// Maximum number of milestones in a `MilestoneEscrow`.
+pub const MAX_MILESTONES: usize = 10;
+
+/// ===========================================================================
+/// Account: MilestoneEscrow
+///
+/// Collaboration funds deposited up front and paid out milestone by
+/// milestone, held in an ATA owned by this PDA.
+///
+/// ## Fields:
+/// - `id`: Sequential escrow ID (PDA seed, shared with `Escrow`)
+/// - `funder`: Wallet funding the collaboration
+/// - `recipient`: Wallet paid on each milestone
+/// - `total_amount`: Sum of all milestones
+/// - `milestones`: Fixed list of milestone amounts
+/// - `released_mask`: Bit `i` set once milestone `i` was released
+/// - `released_amount`: Sum of released milestones
+/// - `deadline`: Unix timestamp after which the remainder may be refunded
+/// - `status`: Open, released (all milestones) or refunded (remainder)
+/// - `created_at`: Unix timestamp
+/// - `bump`: PDA bump seed
+/// ===========================================================================
+#[account]
+pub struct MilestoneEscrow {
+    pub id: u64,
+    pub funder: Pubkey,
+    pub recipient: Pubkey,
+    pub total_amount: u64,
+    pub milestones: Vec<u64>,
+    pub released_mask: u16,
+    pub released_amount: u64,
+    pub deadline: i64,
+    pub status: EscrowStatus,
+    pub created_at: i64,
+    pub bump: u8,
+}
+
+impl MilestoneEscrow {
+    pub const LEN: usize =
+        8 +                         // Anchor discriminator
+        8 +                         // id (u64)
+        32 +                        // funder (Pubkey)
+        32 +                        // recipient (Pubkey)
+        8 +                         // total_amount (u64)
+        4 + 8 * MAX_MILESTONES +    // milestones (Vec<u64>)
+        2 +                         // released_mask (u16)
+        8 +                         // released_amount (u64)
+        8 +                         // deadline (i64)
+        1 +                         // status (enum)
+        8 +                         // created_at (i64)
+        1;                          // bump (u8)
+
+    /// Returns `true` if milestone `index` was already released.
+    pub fn is_released(&self, index: usize) -> bool {
+        self.released_mask & (1 << index) != 0
+    }
+
+    /// Returns `true` once every milestone was released.
+    pub fn is_complete(&self) -> bool {
+        self.released_mask.count_ones() as usize == self.milestones.len()
+    }
+}
+
+/// Parses a 32-byte hash given as 64 hex characters.
+pub fn parse_item_hash(value: &str) -> Result<[u8; 32]> {
+    let value = value.trim();
+    require!(value.len() == 64 && value.is_ascii(), EscrowError::InvalidItemHash);
+
+    let mut hash = [0u8; 32];
+    for (i, byte) in hash.iter_mut().enumerate() {
+        *byte = u8::from_str_radix(&value[i * 2..i * 2 + 2], 16)
+            .map_err(|_| EscrowError::InvalidItemHash)?;
+    }
+
+    Ok(hash)
+}
@@ -89,6 +89,9 @@ pub enum ProposalTypeBit {
 
     // --- Misc ---
     Custom,
+
+    // --- Market & Pricing ---
+    UpdatePricing,
 }
 
 impl ProposalTypeBit {
@@ -123,6 +126,9 @@ impl ProposalTypeBit {
 
         // Misc
         ("Custom", Self::Custom),
+
+        // Market & Pricing
+        ("UpdatePricing", Self::UpdatePricing),
     ];
 
     pub(crate) fn from_str(name: &str) -> Option<Self> {
@@ -84,6 +84,14 @@ pub struct InitializeToken<'info> {
     )]
     pub staking_state: AccountInfo<'info>,
 
+    /// CHECK: Primary-sale bonding curve PDA.
+    #[account(
+        mut,
+        seeds = [b"sale_state"],
+        bump,
+    )]
+    pub sale_state: AccountInfo<'info>,
+
     // =========================================================================
     // Initial Token Allocation
     // =========================================================================
@@ -28,6 +28,7 @@
 // - GovernanceState – Stores proposal config and counters.
 // - StakingState – Contains available staking plans and stake counters.
 // - VestingState – Tracks global vesting ID and total schedules.
+// - SaleState – Primary-sale bonding curve, created unset.
 // - Vaults – Accounts for liquidity, staking, rewards, treasury, insurance, etc.
 //
 // ---------------------------------------------------------------------------
@@ -51,7 +52,8 @@ use anchor_lang::solana_program::{
 };
 use anchor_spl::token::{self, Mint};
 
-use crate::economics::state::FeeDistribution;
+use crate::economics::state::{FeeDiscounts, FeeDistribution, FeeSchedule, ReferralSettings};
+use crate::market::state::{CurveType, SaleState, WithdrawalSettings, DEFAULT_ADDRESS_COOLDOWN_SECS};
 use crate::governance::GovernanceState;
 use crate::initialize::InitializeErrorCode;
 use crate::staking::StakingPlan;
@@ -141,9 +143,6 @@ mod vaults {
     /// Initial supply allocated for Airdrop campaigns
     pub(crate) const INITIAL_AIRDROP_SUPPLY: u64 = TOTAL_SUPPLY * 5 / 100;
 
-    /// Initial supply allocated for Buyback
-    pub(crate) const INITIAL_BUYBACK_SUPPLY: u64 = TOTAL_SUPPLY * 0 / 100;
-
     /// Initial supply allocated for liquidity operations (Presale),
     pub(crate) const INITIAL_LIQUIDITY_SUPPLY: u64 = TOTAL_SUPPLY * 20 / 100;
 
@@ -168,7 +167,6 @@ mod vaults {
         - INITIAL_OFFCHAIN_RESERVE_SUPPLY
         - INITIAL_REWARDS_SUPPLY
         - INITIAL_AIRDROP_SUPPLY
-        - INITIAL_BUYBACK_SUPPLY
         - INITIAL_LIQUIDITY_SUPPLY
         - INITIAL_VESTING_SUPPLY
         - INITIAL_INSURANCE_SUPPLY
@@ -489,6 +487,52 @@ pub(crate) fn initialize_token(ctx: &mut Context<InitializeToken>) -> Result<()>
         }
     }
 
+    // -----------------------------------------
+    // Sale State Initialization
+    // -----------------------------------------
+
+    if ctx.accounts.sale_state.to_account_info().data_is_empty() {
+        let rent = Rent::get()?.minimum_balance(SaleState::LEN);
+
+        let sale_bump = ctx.bumps.sale_state;
+        let sale_seeds: &[&[u8]] = &[b"sale_state", &[sale_bump]];
+
+        invoke_signed(
+            &system_instruction::create_account(
+                &caller,
+                &ctx.accounts.sale_state.key(),
+                rent,
+                SaleState::LEN as u64,
+                ctx.program_id,
+            ),
+            &[
+                ctx.accounts.caller.to_account_info(),
+                ctx.accounts.sale_state.to_account_info(),
+                ctx.accounts.system_program.to_account_info(),
+            ],
+            &[sale_seeds],
+        )?;
+
+        // Curve left unset (base_price = 0) until governance configures it
+        let state = SaleState {
+            curve_type: CurveType::Linear,
+            base_price: 0,
+            slope: 0,
+            growth_bps: 0,
+            step_size: 0,
+            tokens_sold: 0,
+            updated_at: 0,
+        };
+
+        let mut data = ctx.accounts.sale_state.try_borrow_mut_data()?;
+        state.try_serialize(&mut *data)?;
+
+        #[cfg(feature = "devlogs")]
+        {
+            msg!("🗳️ SaleState initialized with an unset curve");
+        }
+    }
+
     // ─────────────────────────────────────────────────────────────
     // Step 2: Create Mint Authority PDA
     // ─────────────────────────────────────────────────────────────
@@ -614,7 +658,19 @@ pub(crate) fn initialize_token(ctx: &mut Context<InitializeToken>) -> Result<()>
         fee: FeeDistribution {
             rewards_fee_bps: fee::DEFAULT_REWARDS_FEE_BPS,
             airdrop_fee_bps: fee::DEFAULT_AIRDROP_FEE_BPS
-        }
+        },
+        fee_schedule: FeeSchedule {
+            buy_fee_bps: fee::DEFAULT_BUY_FEE_BPS,
+            withdrawal_fee_bps: fee::DEFAULT_WITHDRAWAL_FEE_BPS,
+            transfer_fee_bps: fee::DEFAULT_TRANSFER_FEE_BPS,
+        },
+        fee_discounts: FeeDiscounts::default(),
+        referral: ReferralSettings {
+            share_bps: fee::DEFAULT_REFERRAL_SHARE_BPS,
+        },
+        withdrawal: WithdrawalSettings {
+            address_cooldown_secs: DEFAULT_ADDRESS_COOLDOWN_SECS,
+        },
     };
     
     let account_info = ctx.accounts.token_state.to_account_info();
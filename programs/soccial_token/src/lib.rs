@@ -40,19 +40,29 @@ pub mod auth;
 pub mod initialize;
 pub mod token;
 pub mod market;
+pub mod amm;
 pub mod vesting;
 pub mod airdrop;
 pub mod governance;
 pub mod vaults;
 pub mod staking;
 pub mod economics;
+pub mod payments;
+pub mod escrow;
+pub mod referral;
+pub mod oracle;
 pub use utils::system;
 use crate::airdrop::context::*;
+use crate::amm::context::*;
 use crate::auth::context::*;
+use crate::escrow::context::*;
 use crate::governance::context::*;
 use crate::initialize::context::*;
 use crate::staking::context::*;
 use crate::market::context::*;
+use crate::oracle::context::*;
+use crate::payments::context::*;
+use crate::referral::context::*;
 use crate::token::context::*;
 use crate::vaults::context::*;
 use crate::vesting::context::*;
@@ -131,6 +141,21 @@ pub mod economy {
 
         /// Maximum allowed airdrop fee: 5% (500 BPS).
         pub const MAX_AIRDROP_FEE_BPS: u16 = 500;
+
+        /// Default fee charged on primary sales (`buy_tokens`): 3% (300 BPS).
+        pub const DEFAULT_BUY_FEE_BPS: u16 = 300;
+
+        /// Default fee charged on off-chain reserve withdrawals: 1% (100 BPS).
+        pub const DEFAULT_WITHDRAWAL_FEE_BPS: u16 = 100;
+
+        /// Default fee charged on peer-to-peer transfers: 0.2% (20 BPS).
+        pub const DEFAULT_TRANSFER_FEE_BPS: u16 = 20;
+
+        /// Default share of referee fees credited to the referrer: 10% (1_000 BPS).
+        pub const DEFAULT_REFERRAL_SHARE_BPS: u16 = 1_000;
+
+        /// Maximum share of referee fees credited to the referrer: 50% (5_000 BPS).
+        pub const MAX_REFERRAL_SHARE_BPS: u16 = 5_000;
     }
 
 }
@@ -153,7 +178,9 @@ pub mod soccial_token {
     ///
     /// # Args
     /// * `args[0]` – Amount to buy (u64)
-    /// * `args[1]` – Fee in BPS (u16)
+    /// * `args[1]` – Maximum accepted bonding-curve price (u64)
+    ///
+    /// The buy fee comes from the governed `FeeSchedule`.
     ///
     /// # Permissions
     /// * Requires `buy_tokens`
@@ -165,18 +192,60 @@ pub mod soccial_token {
         
         require_args!(args, 2)?;
         let amount = parse_arg!(args, 0, u64)?;
-        let fee_bps = parse_arg!(args, 1, u16)?;
+        let max_price = parse_arg!(args, 1, u64)?;
         let caller = ctx.accounts.caller.key();
         
         secure!(ctx, &caller, "buy_tokens", true);
 
-        market::buy_tokens(ctx, amount, fee_bps)
+        market::buy_tokens(ctx, amount, max_price)
+    }
+
+    /// Buys tokens from the liquidity vault at a price signed by the API authority.
+    ///
+    /// The instruction must be preceded by a native ed25519 instruction
+    /// verifying the quote signed by `core.api_authority`. The buyer pays the
+    /// executed curve price, capped by the quoted price, in the `PriceConfig`
+    /// quote currency.
+    ///
+    /// # Arguments
+    /// * `args[0]` - Quote nonce (u64), single-use per buyer.
+    /// * `args[1]` - Amount of tokens to buy (in base units).
+    /// * `args[2]` - Quoted price per 1 SCTK (u64).
+    /// * `args[3]` - Quote expiry unix timestamp (i64).
+    ///
+    /// # Permissions
+    /// * No permission required; the buyer signs and submits the quote
+    pub fn buy_tokens_with_quote(
+        ctx: Context<BuyTokensWithQuoteContext>,
+        args: Vec<String>,
+    ) -> Result<()> {
+        require_args!(args, 4)?;
+        let caller = ctx.accounts.caller.key();
+        let quote = market::PriceQuote {
+            nonce: parse_arg!(args, 0, u64)?,
+            amount: parse_arg!(args, 1, u64)?,
+            price: parse_arg!(args, 2, u64)?,
+            expires_at: parse_arg!(args, 3, i64)?,
+            buyer: caller,
+        };
+
+        check!(ctx, &caller)?;
+
+        market::buy_tokens_with_quote(ctx, quote)
     }
 
     /// Mints tokens from the Soccial Wallet (off-chain) into the user’s SPL wallet.
     ///
     /// # Arguments
     /// * `args[0]` - The amount of tokens to deposit (in base units).
+    /// * `args[1]` - Backend request ID (idempotency key, max 64 bytes).
+    ///
+    /// The withdrawal fee comes from the governed `FeeSchedule`.
+    /// A `WithdrawalReceipt` PDA is created per request ID (seeded by its
+    /// hash), so a retried submission with the same ID fails instead of
+    /// paying twice.
+    /// The destination must be an active entry of the user's
+    /// `WithdrawalAddressBook` (registered by the user, past the cooldown).
     ///
     /// # Permissions
     /// * Requires `mint_tokens` permission (internal operation by Soccial backend).
@@ -188,129 +257,1124 @@ pub mod soccial_token {
         ctx: Context<DepositTokensContext>, 
         args: Vec<String>
     ) -> Result<()> {
-        require_args!(args, 2)?;
+        require_args!(args, 2)?;
+
+        let caller = ctx.accounts.caller.key();
+        
+        secure!(ctx, &caller, "deposit_tokens", true);
+
+        let amount = parse_arg!(args, 0, u64)?;
+        let request_id = parse_arg!(args, 1, String)?;
+
+        market::deposit_tokens(ctx, amount, request_id)
+    }
+
+    /// Deposits tokens from the off-chain Soccial Wallet into the user’s SPL account.
+    ///
+    /// # Args
+    /// * `args[0]` – Amount to deposit (u64)
+    ///
+    /// The transfer fee comes from the governed `FeeSchedule`.
+    ///
+    /// # Permissions
+    /// * Requires `mint_tokens`
+   pub fn transfer_tokens(
+        ctx: Context<TransferTokensContext>, 
+        args: Vec<String>,
+    ) -> Result<()> {
+        require_args!(args, 1)?;
+        
+        let caller = ctx.accounts.caller.key();
+        
+        secure!(ctx, &caller, "transfer_tokens", true);
+
+        let amount = parse_arg!(args, 0, u64)?;
+
+        market::transfer_tokens(ctx, amount)
+    }
+
+    /// Transfers tokens from one sender to many recipients in one instruction.
+    ///
+    /// # Args
+    /// * `args[0]` – Fee mode: `per_leg` (deducted from each leg) or
+    ///   `aggregate` (charged once on the total, paid by the sender)
+    /// * `args[1..]` – Amount per recipient (u64)
+    ///
+    /// Recipient token accounts are passed as `remaining_accounts`,
+    /// in the same order as the amounts.
+    ///
+    /// # Permissions
+    /// * Requires `transfer_tokens`
+    pub fn batch_transfer_tokens<'info>(
+        ctx: Context<'_, '_, 'info, 'info, BatchTransferTokensContext<'info>>,
+        args: Vec<String>,
+    ) -> Result<()> {
+        require_args!(args, 2)?;
+
+        let caller = ctx.accounts.caller.key();
+
+        secure!(ctx, &caller, "transfer_tokens", true);
+
+        let fee_mode = parse_arg!(args, 0, market::BatchFeeMode)?;
+        let amounts = (1..args.len())
+            .map(|i| parse_arg!(args, i, u64))
+            .collect::<Result<Vec<u64>>>()?;
+
+        market::batch_transfer_tokens(ctx, fee_mode, amounts)
+    }
+
+    /// Moves SCTK from the caller's SPL wallet into the off-chain Soccial Wallet.
+    ///
+    /// # Args
+    /// * `args[0]` – Amount to credit (u64)
+    /// * `args[1]` – Soccial user reference (max 64 bytes)
+    ///
+    /// A `CreditReceipt` PDA sequenced per holder is created and `OffchainWalletCredited`
+    /// is emitted for the backend to consume exactly once.
+    ///
+    /// # Permissions
+    /// * No permission required; the holder signs
+    pub fn credit_offchain_wallet(
+        ctx: Context<CreditOffchainWalletContext>,
+        args: Vec<String>,
+    ) -> Result<()> {
+        require_args!(args, 2)?;
+        let amount = parse_arg!(args, 0, u64)?;
+        let user_reference = parse_arg!(args, 1, String)?;
+
+        let caller = ctx.accounts.caller.key();
+
+        check!(ctx, &caller)?;
+
+        market::credit_offchain_wallet(ctx, amount, user_reference)
+    }
+
+    /// Registers an SCTK token account as a `deposit_tokens` destination in
+    /// the caller's withdrawal address book. Usable after the address cooldown.
+    ///
+    /// # Args
+    /// * None (the token account is passed as `destination_token_account`)
+    ///
+    /// # Permissions
+    /// * No permission required; the book owner signs
+    pub fn add_withdrawal_address(
+        ctx: Context<AddWithdrawalAddressContext>,
+    ) -> Result<()> {
+        let caller = ctx.accounts.caller.key();
+
+        check!(ctx, &caller)?;
+
+        market::add_withdrawal_address(ctx)
+    }
+
+    /// Removes a destination from the caller's withdrawal address book.
+    ///
+    /// # Args
+    /// * `args[0]` – Token account to remove (Pubkey)
+    ///
+    /// # Permissions
+    /// * No permission required; the book owner signs
+    pub fn remove_withdrawal_address(
+        ctx: Context<RemoveWithdrawalAddressContext>,
+        args: Vec<String>,
+    ) -> Result<()> {
+        require_args!(args, 1)?;
+        let token_account = parse_arg!(args, 0, Pubkey)?;
+
+        let caller = ctx.accounts.caller.key();
+
+        check!(ctx, &caller)?;
+
+        market::remove_withdrawal_address(ctx, token_account)
+    }
+
+    /// Buys tokens from the liquidity vault, paid on-chain by the buyer in SOL
+    /// or in the quote SPL mint configured in `PriceConfig`.
+    ///
+    /// # Args
+    /// * `args[0]` – Amount to buy (u64)
+    /// * `args[1]` – Maximum price per 1 SCTK the buyer accepts (u64)
+    ///
+    /// # Permissions
+    /// * No permission required; the buyer signs and pays
+    pub fn purchase_tokens(
+        ctx: Context<PurchaseTokensContext>,
+        args: Vec<String>,
+    ) -> Result<()> {
+        require_args!(args, 2)?;
+        let amount = parse_arg!(args, 0, u64)?;
+        let max_price = parse_arg!(args, 1, u64)?;
+
+        let caller = ctx.accounts.caller.key();
+
+        check!(ctx, &caller)?;
+
+        market::purchase_tokens(ctx, amount, max_price)
+    }
+
+    /// Sells tokens back to the protocol for the quote currency at the
+    /// governed sell price, within the per-epoch buyback cap.
+    ///
+    /// # Args
+    /// * `args[0]` – Amount to sell (u64)
+    ///
+    /// # Permissions
+    /// * No permission required; the seller signs
+    pub fn sell_tokens(
+        ctx: Context<SellTokensContext>,
+        args: Vec<String>,
+    ) -> Result<()> {
+        require_args!(args, 1)?;
+        let amount = parse_arg!(args, 0, u64)?;
+
+        let caller = ctx.accounts.caller.key();
+
+        check!(ctx, &caller)?;
+
+        market::sell_tokens(ctx, amount)
+    }
+
+    /// Updates the price configuration used by priced purchases and buybacks.
+    ///
+    /// # Example
+    /// * ["quote_mint=SOL", "buy_price=1000000", "enabled=true"]
+    /// * ["sell_price=800000", "buyback_epoch_duration=86400", "buyback_epoch_cap=1000000000000"]
+    ///
+    /// # Permissions
+    /// * Requires `manage_economy`
+    /// * Requires Governance Community Approval
+    pub fn update_price_config(
+        ctx: Context<ManagePriceConfig>,
+        args: Vec<String>,
+    ) -> Result<()> {
+        let caller = ctx.accounts.caller.key();
+        secure!(ctx, &caller, "manage_economy", true);
+
+        governance::require_approved_proposal(
+            &mut ctx.accounts.proposal,
+            &ctx.accounts.governance_state,
+            governance::ProposalTypeBit::UpdatePricing,
+        )?;
+
+        let sale_state = &ctx.accounts.sale_state;
+        ctx.accounts.price_config.apply_updates(args, sale_state)?;
+
+        governance::mark_proposal_as_used(&mut ctx.accounts.proposal)?;
+
+        Ok(())
+    }
+
+    /// Updates the bonding curve used to price primary sales in `buy_tokens`.
+    ///
+    /// # Example
+    /// * ["curve_type=linear", "base_price=10000", "slope=1"]
+    /// * ["curve_type=exponential", "base_price=10000", "growth_bps=50", "step_size=1000000000000000"]
+    ///
+    /// # Permissions
+    /// * Requires `manage_economy`
+    /// * Requires Governance Community Approval
+    pub fn update_sale_curve(
+        ctx: Context<ManageSaleState>,
+        args: Vec<String>,
+    ) -> Result<()> {
+        let caller = ctx.accounts.caller.key();
+        secure!(ctx, &caller, "manage_economy", true);
+
+        governance::require_approved_proposal(
+            &mut ctx.accounts.proposal,
+            &ctx.accounts.governance_state,
+            governance::ProposalTypeBit::UpdatePricing,
+        )?;
+
+        let price_config = &ctx.accounts.price_config;
+        ctx.accounts.sale_state.apply_updates(args, price_config)?;
+
+        governance::mark_proposal_as_used(&mut ctx.accounts.proposal)?;
+
+        Ok(())
+    }
+
+    // ========================================================
+    // Payments
+    // ========================================================
+
+    /// Creates an invoice (payment request) for an external order ID.
+    ///
+    /// # Args
+    /// * `args[0]` – External order ID (max 32 bytes)
+    /// * `args[1]` – Amount due (u64)
+    /// * `args[2]` – Expiry unix timestamp (i64, `0` = never expires)
+    ///
+    /// # Permissions
+    /// * No permission required; the merchant signs and pays for the invoice
+    pub fn create_invoice(
+        ctx: Context<CreateInvoiceContext>,
+        args: Vec<String>,
+    ) -> Result<()> {
+        require_args!(args, 3)?;
+        let order_id = parse_arg!(args, 0, String)?;
+        let amount = parse_arg!(args, 1, u64)?;
+        let expires_at = parse_arg!(args, 2, i64)?;
+
+        let caller = ctx.accounts.caller.key();
+
+        check!(ctx, &caller)?;
+
+        payments::create_invoice(ctx, order_id, amount, expires_at)
+    }
+
+    /// Pays an open invoice; the transfer fee from the `FeeSchedule` is
+    /// deducted and split across the vaults.
+    ///
+    /// # Args
+    /// * `args[0]` – Amount accepted by the payer (u64, must match the invoice)
+    ///
+    /// # Permissions
+    /// * No permission required; the payer signs
+    pub fn pay_invoice(
+        ctx: Context<PayInvoiceContext>,
+        args: Vec<String>,
+    ) -> Result<()> {
+        require_args!(args, 1)?;
+        let amount = parse_arg!(args, 0, u64)?;
+
+        let caller = ctx.accounts.caller.key();
+
+        check!(ctx, &caller)?;
+
+        payments::pay_invoice(ctx, amount)
+    }
+
+    /// Cancels an open invoice.
+    ///
+    /// # Permissions
+    /// * Only the invoice merchant
+    pub fn cancel_invoice(ctx: Context<CancelInvoiceContext>) -> Result<()> {
+        let caller = ctx.accounts.caller.key();
+
+        check!(ctx, &caller)?;
+
+        payments::cancel_invoice(ctx)
+    }
+
+    /// Registers a third-party merchant, or updates an existing one.
+    ///
+    /// The passed `settlement_token_account` becomes the merchant's
+    /// settlement account.
+    ///
+    /// # Example
+    /// * [] – registers with defaults (transfer fee tier, no referrer)
+    /// * ["fee_bps=150", "referrer=<token_account>", "referral_share_bps=2000"]
+    /// * ["active=false"]
+    ///
+    /// # Permissions
+    /// * Requires `manage_merchants`
+    pub fn manage_merchant(
+        ctx: Context<ManageMerchantContext>,
+        args: Vec<String>,
+    ) -> Result<()> {
+        let caller = ctx.accounts.caller.key();
+
+        secure!(ctx, &caller, "manage_merchants", true);
+
+        payments::manage_merchant(ctx, args)
+    }
+
+    /// Pays a registered merchant; the merchant fee tier is deducted, the
+    /// referrer share is paid out and the rest follows the standard split.
+    ///
+    /// # Args
+    /// * `args[0]` – Amount to pay (u64)
+    ///
+    /// # Permissions
+    /// * No permission required; the payer signs
+    pub fn merchant_payment(
+        ctx: Context<MerchantPaymentContext>,
+        args: Vec<String>,
+    ) -> Result<()> {
+        require_args!(args, 1)?;
+        let amount = parse_arg!(args, 0, u64)?;
+
+        let caller = ctx.accounts.caller.key();
+
+        check!(ctx, &caller)?;
+
+        payments::merchant_payment(ctx, amount)
+    }
+
+    /// Subscribes the caller to recurring payments to a merchant and approves
+    /// the program delegate on the caller's ATA.
+    ///
+    /// # Args
+    /// * `args[0]` – Amount charged per period (u64)
+    /// * `args[1]` – Period in seconds (i64)
+    /// * `args[2]` – Number of periods approved for the delegate (u64)
+    ///
+    /// # Permissions
+    /// * No permission required; the payer signs
+    pub fn create_subscription(
+        ctx: Context<CreateSubscriptionContext>,
+        args: Vec<String>,
+    ) -> Result<()> {
+        require_args!(args, 3)?;
+        let amount = parse_arg!(args, 0, u64)?;
+        let period = parse_arg!(args, 1, i64)?;
+        let approved_periods = parse_arg!(args, 2, u64)?;
+
+        let caller = ctx.accounts.caller.key();
+
+        check!(ctx, &caller)?;
+
+        payments::create_subscription(ctx, amount, period, approved_periods)
+    }
+
+    /// Charges one due period of a subscription.
+    ///
+    /// # Permissions
+    /// * Permissionless crank; anyone may call it once a period is due
+    pub fn charge_subscription(ctx: Context<ChargeSubscriptionContext>) -> Result<()> {
+        let caller = ctx.accounts.caller.key();
+
+        system::check_core(&caller, None, &ctx.accounts.token_state)?;
+
+        payments::charge_subscription(ctx)
+    }
+
+    /// Cancels a subscription and closes its account. When the payer cancels,
+    /// the delegate allowance is lowered by what the subscription had left.
+    ///
+    /// # Permissions
+    /// * Only the subscription payer or merchant
+    pub fn cancel_subscription(ctx: Context<CancelSubscriptionContext>) -> Result<()> {
+        let caller = ctx.accounts.caller.key();
+
+        check!(ctx, &caller)?;
+
+        payments::cancel_subscription(ctx)
+    }
+
+    // ========================================================
+    // Escrow
+    // ========================================================
+
+    /// Opens an escrow for a peer-to-peer trade, holding the caller's SCTK
+    /// until it is released, refunded or arbitrated.
+    ///
+    /// # Args
+    /// * `args[0]` – Taker wallet (Pubkey)
+    /// * `args[1]` – Amount to escrow (u64)
+    /// * `args[2]` – Item reference hash (64 hex characters)
+    /// * `args[3]` – Deadline unix timestamp (i64)
+    /// * `args[4]` – Optional arbiter wallet (Pubkey, or `none`)
+    ///
+    /// # Permissions
+    /// * No permission required; the maker signs and funds the escrow
+    pub fn open_escrow(
+        ctx: Context<OpenEscrowContext>,
+        args: Vec<String>,
+    ) -> Result<()> {
+        require_args!(args, 4)?;
+        let taker = parse_arg!(args, 0, Pubkey)?;
+        let amount = parse_arg!(args, 1, u64)?;
+        let item_hash = escrow::parse_item_hash(&args[2])?;
+        let deadline = parse_arg!(args, 3, i64)?;
+        let arbiter = match args.get(4).map(|value| value.trim()) {
+            None | Some("none") => Pubkey::default(),
+            Some(_) => parse_arg!(args, 4, Pubkey)?,
+        };
+
+        let caller = ctx.accounts.caller.key();
+
+        check!(ctx, &caller)?;
+
+        escrow::open_escrow(ctx, taker, amount, item_hash, deadline, arbiter)
+    }
+
+    /// Releases an escrow to the taker; the transfer fee is deducted.
+    ///
+    /// # Permissions
+    /// * Only the escrow maker
+    pub fn release_escrow(ctx: Context<SettleEscrowContext>) -> Result<()> {
+        let caller = ctx.accounts.caller.key();
+
+        check!(ctx, &caller)?;
+
+        escrow::release_escrow(ctx)
+    }
+
+    /// Refunds an escrow to the maker.
+    ///
+    /// # Permissions
+    /// * The taker at any time, or the maker after the deadline
+    pub fn refund_escrow(ctx: Context<SettleEscrowContext>) -> Result<()> {
+        let caller = ctx.accounts.caller.key();
+
+        check!(ctx, &caller)?;
+
+        escrow::refund_escrow(ctx)
+    }
+
+    /// Settles a disputed escrow.
+    ///
+    /// # Args
+    /// * `args[0]` – Decision: `release` or `refund`
+    ///
+    /// # Permissions
+    /// * Only the arbiter set on the escrow
+    pub fn arbitrate_escrow(
+        ctx: Context<SettleEscrowContext>,
+        args: Vec<String>,
+    ) -> Result<()> {
+        require_args!(args, 1)?;
+        let decision = parse_arg!(args, 0, escrow::EscrowDecision)?;
+
+        let caller = ctx.accounts.caller.key();
+
+        check!(ctx, &caller)?;
+
+        escrow::arbitrate_escrow(ctx, decision)
+    }
+
+    /// Opens a milestone escrow, depositing the sum of all milestones.
+    ///
+    /// # Args
+    /// * `args[0]` – Recipient wallet (Pubkey)
+    /// * `args[1]` – Deadline unix timestamp for refunds (i64)
+    /// * `args[2..]` – Milestone amounts (u64, at most 10)
+    ///
+    /// # Permissions
+    /// * No permission required; the funder signs and deposits
+    pub fn open_milestone_escrow(
+        ctx: Context<OpenMilestoneEscrowContext>,
+        args: Vec<String>,
+    ) -> Result<()> {
+        require_args!(args, 3)?;
+        let recipient = parse_arg!(args, 0, Pubkey)?;
+        let deadline = parse_arg!(args, 1, i64)?;
+        let milestones = (2..args.len())
+            .map(|i| parse_arg!(args, i, u64))
+            .collect::<Result<Vec<u64>>>()?;
+
+        let caller = ctx.accounts.caller.key();
+
+        check!(ctx, &caller)?;
+
+        escrow::open_milestone_escrow(ctx, recipient, deadline, milestones)
+    }
+
+    /// Releases one milestone to the recipient; the transfer fee is deducted.
+    ///
+    /// # Args
+    /// * `args[0]` – Milestone index (u8)
+    ///
+    /// # Permissions
+    /// * The funder, or an arbiter with `manage_user`
+    pub fn release_milestone(
+        ctx: Context<SettleMilestoneContext>,
+        args: Vec<String>,
+    ) -> Result<()> {
+        require_args!(args, 1)?;
+        let index = parse_arg!(args, 0, u8)?;
+
+        let caller = ctx.accounts.caller.key();
+        let target = ctx.accounts.milestone_escrow.funder;
+
+        secure_user_or_permission!(ctx, &caller, &target, "manage_user");
+
+        escrow::release_milestone(ctx, index)
+    }
+
+    /// Refunds the unreleased remainder to the funder after the deadline.
+    ///
+    /// # Permissions
+    /// * The funder, or an arbiter with `manage_user`
+    pub fn refund_milestone_escrow(ctx: Context<SettleMilestoneContext>) -> Result<()> {
+        let caller = ctx.accounts.caller.key();
+        let target = ctx.accounts.milestone_escrow.funder;
+
+        secure_user_or_permission!(ctx, &caller, &target, "manage_user");
+
+        escrow::refund_milestone_escrow(ctx)
+    }
+
+    // ========================================================
+    // Referral
+    // ========================================================
+
+    /// Binds the caller to a referrer. Each user can be referred only once.
+    ///
+    /// Afterwards, a governed share of the fees the caller pays on
+    /// `buy_tokens` and `transfer_tokens` is credited to the referrer.
+    ///
+    /// # Permissions
+    /// * No permission required; the referee signs for itself
+    pub fn register_referral(ctx: Context<RegisterReferralContext>) -> Result<()> {
+        let caller = ctx.accounts.caller.key();
+
+        check!(ctx, &caller)?;
+
+        referral::register_referral(ctx)
+    }
+
+    /// Pays the caller's credited referral share from the Revenue Vault.
+    ///
+    /// # Permissions
+    /// * Only the referrer
+    pub fn claim_referral_rewards(ctx: Context<ClaimReferralRewardsContext>) -> Result<()> {
+        let caller = ctx.accounts.caller.key();
+
+        check!(ctx, &caller)?;
+
+        referral::claim_referral_rewards(ctx)
+    }
+
+    // ========================================================
+    // Oracle
+    // ========================================================
+
+    /// Pushes a new SCTK price into the program-owned `ManualPriceFeed`.
+    ///
+    /// # Args
+    /// * `args[0]` – Price in quote base units per 1 SCTK (u64)
+    /// * `args[1]` – Confidence interval, same units (u64)
+    ///
+    /// # Permissions
+    /// * Requires `set_price` (or API authority)
+    pub fn set_price(
+        ctx: Context<SetPriceContext>,
+        args: Vec<String>,
+    ) -> Result<()> {
+        require_args!(args, 2)?;
+        let price = parse_arg!(args, 0, u64)?;
+        let confidence = parse_arg!(args, 1, u64)?;
+
+        let caller = ctx.accounts.caller.key();
+
+        secure!(ctx, &caller, "set_price", true);
+
+        oracle::set_price(ctx, price, confidence)
+    }
+
+    /// Returns the validated reading of a price feed as return data.
+    ///
+    /// # Args
+    /// * `args[0]` – Optional maximum age in seconds (i64, default 300)
+    /// * `args[1]` – Optional maximum confidence in BPS of the price (u16, default 200)
+    ///
+    /// # Permissions
+    /// * No permission required
+    pub fn get_price(
+        ctx: Context<ReadPriceFeedContext>,
+        args: Vec<String>,
+    ) -> Result<oracle::PriceReading> {
+        let max_age = match args.first() {
+            Some(_) => parse_arg!(args, 0, i64)?,
+            None => oracle::DEFAULT_MAX_PRICE_AGE_SECS,
+        };
+        let max_confidence_bps = match args.get(1) {
+            Some(_) => parse_arg!(args, 1, u16)?,
+            None => oracle::DEFAULT_MAX_CONFIDENCE_BPS,
+        };
+
+        let caller = ctx.accounts.caller.key();
+        system::check_core(&caller, None, &ctx.accounts.token_state)?;
+
+        oracle::get_price(ctx, max_age, max_confidence_bps)
+    }
+
+    /// Returns the SCTK time-weighted average price as return data.
+    ///
+    /// The TWAP is read from the accumulator of the `quote_mint` account
+    /// passed in (system program ID for SOL) and is expressed in that mint.
+    ///
+    /// # Args
+    /// * `args[0]` – Window in seconds, ending now (i64)
+    ///
+    /// # Permissions
+    /// * No permission required
+    pub fn get_twap(
+        ctx: Context<ReadTwapContext>,
+        args: Vec<String>,
+    ) -> Result<oracle::TwapReading> {
+        require_args!(args, 1)?;
+        let window = parse_arg!(args, 0, i64)?;
+
+        let caller = ctx.accounts.caller.key();
+        system::check_core(&caller, None, &ctx.accounts.token_state)?;
+
+        oracle::get_twap(ctx, window)
+    }
+
+    // ========================================================
+    // AMM
+    // ========================================================
+
+    /// Seeds the constant-product pool with SCTK from the liquidity vault and
+    /// quote tokens from the caller. LP shares are owned by the treasury.
+    ///
+    /// # Args
+    /// * `args[0]` – SCTK amount from the liquidity vault (u64)
+    /// * `args[1]` – Quote amount from the caller (u64)
+    /// * `args[2]` – Swap fee in BPS (u16)
+    ///
+    /// # Permissions
+    /// * Requires `manage_vaults`
+    pub fn initialize_amm_pool(
+        ctx: Context<InitializeAmmPool>,
+        args: Vec<String>,
+    ) -> Result<()> {
+        require_args!(args, 3)?;
+        let sctk_amount = parse_arg!(args, 0, u64)?;
+        let quote_amount = parse_arg!(args, 1, u64)?;
+        let swap_fee_bps = parse_arg!(args, 2, u16)?;
+
+        let caller = ctx.accounts.caller.key();
+        secure!(ctx, &caller, "manage_vaults");
+
+        amm::initialize_pool(ctx, sctk_amount, quote_amount, swap_fee_bps)
+    }
+
+    /// Adds treasury-owned liquidity to the pool at the current ratio.
+    ///
+    /// # Args
+    /// * `args[0]` – SCTK amount from the liquidity vault (u64)
+    /// * `args[1]` – Max quote amount from the caller (u64)
+    ///
+    /// # Permissions
+    /// * Requires `manage_vaults`
+    pub fn add_amm_liquidity(
+        ctx: Context<AddAmmLiquidity>,
+        args: Vec<String>,
+    ) -> Result<()> {
+        require_args!(args, 2)?;
+        let sctk_amount = parse_arg!(args, 0, u64)?;
+        let max_quote = parse_arg!(args, 1, u64)?;
+
+        let caller = ctx.accounts.caller.key();
+        secure!(ctx, &caller, "manage_vaults");
+
+        amm::add_liquidity(ctx, sctk_amount, max_quote)
+    }
+
+    /// Burns treasury LP shares, returning SCTK to the liquidity vault and
+    /// quote tokens to the treasury vault.
+    ///
+    /// # Args
+    /// * `args[0]` – LP shares to burn (u64)
+    ///
+    /// # Permissions
+    /// * Requires `manage_vaults`
+    pub fn remove_amm_liquidity(
+        ctx: Context<RemoveAmmLiquidity>,
+        args: Vec<String>,
+    ) -> Result<()> {
+        require_args!(args, 1)?;
+        let shares = parse_arg!(args, 0, u64)?;
+
+        let caller = ctx.accounts.caller.key();
+        secure!(ctx, &caller, "manage_vaults");
+
+        amm::remove_liquidity(ctx, shares)
+    }
+
+    /// Swaps an exact input amount against the pool.
+    ///
+    /// # Args
+    /// * `args[0]` – Side: `buy` (quote → SCTK) or `sell` (SCTK → quote)
+    /// * `args[1]` – Amount in (u64)
+    /// * `args[2]` – Minimum amount out (u64)
+    ///
+    /// # Permissions
+    /// * No permission required; the trader signs
+    pub fn amm_swap(
+        ctx: Context<AmmSwapContext>,
+        args: Vec<String>,
+    ) -> Result<()> {
+        require_args!(args, 3)?;
+        let side = parse_arg!(args, 0, amm::SwapSide)?;
+        let amount_in = parse_arg!(args, 1, u64)?;
+        let min_out = parse_arg!(args, 2, u64)?;
+
+        let caller = ctx.accounts.caller.key();
+
+        check!(ctx, &caller)?;
+
+        amm::swap(ctx, side, amount_in, min_out)
+    }
+
+    /// Updates the pool swap fee or enables/disables swaps.
+    ///
+    /// # Example
+    /// * ["swap_fee_bps=30", "enabled=false"]
+    ///
+    /// # Permissions
+    /// * Requires `manage_economy`
+    /// * Requires Governance Community Approval
+    pub fn update_amm_pool(
+        ctx: Context<ManageAmmPool>,
+        args: Vec<String>,
+    ) -> Result<()> {
+        let caller = ctx.accounts.caller.key();
+        secure!(ctx, &caller, "manage_economy", true);
+
+        governance::require_approved_proposal(
+            &mut ctx.accounts.proposal,
+            &ctx.accounts.governance_state,
+            governance::ProposalTypeBit::UpdatePricing,
+        )?;
+
+        ctx.accounts.amm_pool.apply_updates(args)?;
+
+        governance::mark_proposal_as_used(&mut ctx.accounts.proposal)?;
+
+        Ok(())
+    }
+
+    // ========================================================
+    // Vaults
+    // ========================================================
+
+    /// Deposits tokens into a system vault.
+    ///
+    /// # Args
+    /// * `args[0]` – Amount to deposit (u64)
+    ///
+    /// # Permissions
+    /// * Requires `deposit_vaults`
+    pub fn vault_deposit<'a, 'b, 'c, 'info>(
+        ctx: Context<VaultDepositContext>,
+        args: Vec<String>,
+    ) -> Result<()> {
+        require_args!(args, 1)?;
+        let amount = parse_arg!(args, 0, u64)?;
+        let reason = args.get(1).cloned(); // Optional reason
+        let caller = ctx.accounts.caller.key();
+
+        secure!(ctx, &caller, "deposit_vaults");
+
+        crate::vaults::deposit(ctx, amount, reason)
+    }
+
+    /// Withdraws tokens from a system vault.
+    ///
+    /// # Args
+    /// * `args[0]` – Amount to withdraw (u64)
+    ///
+    /// # Permissions
+    /// * Requires `manage_vaults`
+    pub fn vault_withdraw<'a, 'b, 'c, 'info>(
+        ctx: Context<VaultWithdrawContext>,
+        args: Vec<String>,
+    ) -> Result<()> {
+        require_args!(args, 1)?;
+        let amount = parse_arg!(args, 0, u64)?;
+        let reason = args.get(1).cloned(); // Optional reason
+
+        let caller = ctx.accounts.caller.key();
+
+        secure!(ctx, &caller, "manage_vaults");
+
+        crate::vaults::withdraw(ctx, amount, reason)
+    }
+
+    /// Transfers tokens from one vault to another.
+    ///
+    /// # Args
+    /// * `args[0]` – Amount (u64)
+    /// * `args[1]` – Optional reason
+    ///
+    /// # Permissions
+    /// * Requires `manage_vaults`
+    pub fn transfer_between_vaults(
+        ctx: Context<VaultTransferContext>,
+        args: Vec<String>,
+    ) -> Result<()> {
+        require_args!(args, 1)?;
+        let amount = parse_arg!(args, 0, u64)?;
+        let reason = args.get(1).cloned(); // Optional reason
+
+        let caller = ctx.accounts.caller.key();
+        secure!(ctx, &caller, "manage_vaults");
+
+        vaults::transfer_between_vaults(ctx, amount, reason)
+    }
+
+    /// Transfers tokens from contract fallback ATA to a vault.
+    ///
+    /// # Args
+    /// * `args[0]` – Amount (u64)
+    ///
+    /// # Permissions
+    /// * Requires `transfer_between_vaults`
+    pub fn move_from_contract_to_vault(
+        ctx: Context<ContractToVaultContext>,
+        args: Vec<String>,
+    ) -> Result<()> {
+        require_args!(args, 1)?;
+        let amount = parse_arg!(args, 0, u64)?;
+
+        let caller = ctx.accounts.caller.key();
+        secure!(ctx, &caller, "transfer_between_vaults");
+
+        vaults::move_from_contract_to_vault(ctx, amount)
+    }
+
+    /// Queues a withdrawal from a timelocked vault (treasury, insurance,
+    /// liquidity, revenue), executable once the vault's delay has elapsed.
+    ///
+    /// # Args
+    /// * `args[0]` – Withdrawal id (u64)
+    /// * `args[1]` – Amount to withdraw (u64)
+    /// * `args[2]` – Optional reason
+    ///
+    /// # Permissions
+    /// * Requires `manage_vaults`
+    pub fn queue_vault_withdrawal(
+        ctx: Context<QueueVaultWithdrawalContext>,
+        args: Vec<String>,
+    ) -> Result<()> {
+        require_args!(args, 2)?;
+        let id = parse_arg!(args, 0, u64)?;
+        let amount = parse_arg!(args, 1, u64)?;
+        let reason = args.get(2).cloned(); // Optional reason
+
+        let caller = ctx.accounts.caller.key();
+
+        secure!(ctx, &caller, "manage_vaults");
+
+        vaults::queue_vault_withdrawal(ctx, id, amount, reason)
+    }
+
+    /// Executes a queued vault withdrawal after its delay.
+    ///
+    /// # Args
+    /// * `args[0]` – Withdrawal id (u64)
+    ///
+    /// # Permissions
+    /// * Requires `manage_vaults`
+    pub fn execute_vault_withdrawal(
+        ctx: Context<ExecuteVaultWithdrawalContext>,
+        args: Vec<String>,
+    ) -> Result<()> {
+        require_args!(args, 1)?;
+
+        let caller = ctx.accounts.caller.key();
+
+        secure!(ctx, &caller, "manage_vaults");
+
+        vaults::execute_vault_withdrawal(ctx)
+    }
+
+    /// Cancels a queued vault withdrawal.
+    ///
+    /// # Args
+    /// * `args[0]` – Withdrawal id (u64)
+    ///
+    /// # Permissions
+    /// * Requires admin or `guard_vaults`
+    pub fn cancel_vault_withdrawal(
+        ctx: Context<CancelVaultWithdrawalContext>,
+        args: Vec<String>,
+    ) -> Result<()> {
+        require_args!(args, 1)?;
 
         let caller = ctx.accounts.caller.key();
-        
-        secure!(ctx, &caller, "deposit_tokens", true);
 
-        let amount = parse_arg!(args, 0, u64)?;
-        let fee_bps = parse_arg!(args, 1, u16)?;
+        secure!(ctx, &caller, "guard_vaults");
 
-        market::deposit_tokens(ctx, amount, fee_bps)
+        vaults::cancel_vault_withdrawal(ctx)
     }
 
-    /// Deposits tokens from the off-chain Soccial Wallet into the user’s SPL account.
+    /// Replaces the approval council guarding treasury and insurance outflows.
     ///
     /// # Args
-    /// * `args[0]` – Amount to deposit (u64)
-    /// * `args[1]` – Fee in BPS (u16)
+    /// * `args[0]` – Approval threshold (u8)
+    /// * `args[1..]` – Council member wallets (Pubkey)
     ///
     /// # Permissions
-    /// * Requires `mint_tokens`
-   pub fn transfer_tokens(
-        ctx: Context<TransferTokensContext>, 
+    /// * Requires `manage_contract`
+    /// * Requires Governance Community Approval (`UpdateGovernance`)
+    pub fn update_approval_council(
+        ctx: Context<UpdateApprovalCouncilContext>,
         args: Vec<String>,
     ) -> Result<()> {
         require_args!(args, 2)?;
-        
+        let threshold = parse_arg!(args, 0, u8)?;
+        let members = (1..args.len())
+            .map(|i| parse_arg!(args, i, Pubkey))
+            .collect::<Result<Vec<_>>>()?;
+
         let caller = ctx.accounts.caller.key();
-        
-        secure!(ctx, &caller, "transfer_tokens", true);
+        secure!(ctx, &caller, "manage_contract");
 
-        let amount = parse_arg!(args, 0, u64)?;
-        let fee_bps = parse_arg!(args, 1, u16)?;
+        governance::require_approved_proposal(
+            &mut ctx.accounts.proposal,
+            &ctx.accounts.governance_state,
+            governance::ProposalTypeBit::UpdateGovernance,
+        )?;
+
+        governance::mark_proposal_as_used(&mut ctx.accounts.proposal)?;
 
-        market::transfer_tokens(ctx, amount, fee_bps)
+        vaults::update_approval_council(ctx, threshold, members)
     }
 
-    // ========================================================
-    // Vaults
-    // ========================================================
+    /// Proposes a treasury or insurance outflow to the approval council.
+    ///
+    /// # Args
+    /// * `args[0]` – Operation id (u64)
+    /// * `args[1]` – Kind: `withdraw` or `transfer`
+    /// * `args[2]` – Amount (u64)
+    /// * `args[3]` – Optional reason
+    ///
+    /// # Permissions
+    /// * Caller must be a council member
+    pub fn propose_council_operation(
+        ctx: Context<ProposeCouncilOperationContext>,
+        args: Vec<String>,
+    ) -> Result<()> {
+        require_args!(args, 3)?;
+        let id = parse_arg!(args, 0, u64)?;
+        let kind = args[1]
+            .parse::<vaults::CouncilOperationKind>()
+            .map_err(|_| vaults::VaultError::InvalidOperationKind)?;
+        let amount = parse_arg!(args, 2, u64)?;
+        let reason = args.get(3).cloned(); // Optional reason
 
-    /// Deposits tokens into a system vault.
+        let caller = ctx.accounts.caller.key();
+        check!(ctx, &caller)?;
+
+        vaults::propose_council_operation(ctx, id, kind, amount, reason)
+    }
+
+    /// Approves a pending council operation.
     ///
     /// # Args
-    /// * `args[0]` – Amount to deposit (u64)
+    /// * `args[0]` – Operation id (u64)
     ///
     /// # Permissions
-    /// * Requires `deposit_vaults`
-    pub fn vault_deposit<'a, 'b, 'c, 'info>(
-        ctx: Context<VaultDepositContext>,
+    /// * Caller must be a council member
+    pub fn approve_council_operation(
+        ctx: Context<ApproveCouncilOperationContext>,
         args: Vec<String>,
     ) -> Result<()> {
         require_args!(args, 1)?;
-        let amount = parse_arg!(args, 0, u64)?;
-        let reason = args.get(1).cloned(); // Optional reason
-        let caller = ctx.accounts.caller.key();
 
-        secure!(ctx, &caller, "deposit_vaults");
+        let caller = ctx.accounts.caller.key();
+        check!(ctx, &caller)?;
 
-        crate::vaults::deposit(ctx, amount, reason)
+        vaults::approve_council_operation(ctx)
     }
 
-    /// Withdraws tokens from a system vault.
+    /// Executes a council operation that reached the approval threshold.
     ///
     /// # Args
-    /// * `args[0]` – Amount to withdraw (u64)
+    /// * `args[0]` – Operation id (u64)
     ///
     /// # Permissions
-    /// * Requires `manage_vaults`
-    pub fn vault_withdraw<'a, 'b, 'c, 'info>(
-        ctx: Context<VaultWithdrawContext>,
+    /// * Caller must be a council member
+    pub fn execute_council_operation(
+        ctx: Context<ExecuteCouncilOperationContext>,
         args: Vec<String>,
     ) -> Result<()> {
         require_args!(args, 1)?;
-        let amount = parse_arg!(args, 0, u64)?;
-        let reason = args.get(1).cloned(); // Optional reason
 
         let caller = ctx.accounts.caller.key();
+        check!(ctx, &caller)?;
 
-        secure!(ctx, &caller, "manage_vaults");
-
-        crate::vaults::withdraw(ctx, amount, reason)
+        vaults::execute_council_operation(ctx)
     }
 
-    /// Transfers tokens from one vault to another.
+    /// Sets the per-epoch outflow cap and circuit breaker of a vault.
     ///
     /// # Args
-    /// * `args[0]` – Amount (u64)
-    /// * `args[1]` – Optional reason
+    /// * `args[0]` – Epoch duration in seconds (i64)
+    /// * `args[1]` – Maximum outflow per epoch (u64)
+    /// * `args[2]` – Circuit-breaker threshold per epoch (u64, `0` disables it)
     ///
     /// # Permissions
-    /// * Requires `manage_vaults`
-    pub fn transfer_between_vaults(
-        ctx: Context<VaultTransferContext>,
+    /// * Requires `manage_contract`
+    /// * Requires Governance Community Approval (`UpdateGovernance`)
+    pub fn set_vault_limits(
+        ctx: Context<SetVaultLimitsContext>,
         args: Vec<String>,
     ) -> Result<()> {
-        require_args!(args, 1)?;
-        let amount = parse_arg!(args, 0, u64)?;
-        let reason = args.get(1).cloned(); // Optional reason
+        require_args!(args, 3)?;
+        let epoch_duration = parse_arg!(args, 0, i64)?;
+        let max_outflow = parse_arg!(args, 1, u64)?;
+        let pause_threshold = parse_arg!(args, 2, u64)?;
+
+        let caller = ctx.accounts.caller.key();
+        secure!(ctx, &caller, "manage_contract");
+
+        governance::require_approved_proposal(
+            &mut ctx.accounts.proposal,
+            &ctx.accounts.governance_state,
+            governance::ProposalTypeBit::UpdateGovernance,
+        )?;
 
+        governance::mark_proposal_as_used(&mut ctx.accounts.proposal)?;
+
+        vaults::set_vault_limits(ctx, epoch_duration, max_outflow, pause_threshold)
+    }
+
+    /// Opens the ledger of a vault, starting from its current balance.
+    ///
+    /// # Permissions
+    /// * Requires `manage_vaults`
+    pub fn open_vault_ledger(ctx: Context<OpenVaultLedgerContext>) -> Result<()> {
         let caller = ctx.accounts.caller.key();
+
         secure!(ctx, &caller, "manage_vaults");
 
-        vaults::transfer_between_vaults(ctx, amount, reason)
+        vaults::open_vault_ledger(ctx)
     }
 
-    /// Transfers tokens from contract fallback ATA to a vault.
+    /// Returns the cumulative in/out ledger of a vault as return data.
+    ///
+    /// # Permissions
+    /// * No permission required
+    pub fn get_vault_ledger(
+        ctx: Context<ReadVaultLedgerContext>,
+    ) -> Result<vaults::VaultLedgerSnapshot> {
+        let caller = ctx.accounts.caller.key();
+        system::check_core(&caller, None, &ctx.accounts.token_state)?;
+
+        vaults::get_vault_ledger(ctx)
+    }
+
+    /// Sets the rule applied to transfers between two vaults.
     ///
     /// # Args
-    /// * `args[0]` – Amount (u64)
+    /// * `args[0]` – Source vault seed (e.g. `"treasury_vault"`)
+    /// * `args[1]` – Destination vault seed
+    /// * `args[2]` – `Allowed`, `Forbidden`, or the proposal type a transfer requires
     ///
     /// # Permissions
-    /// * Requires `transfer_between_vaults`
-    pub fn move_from_contract_to_vault(
-        ctx: Context<ContractToVaultContext>,
+    /// * Requires `manage_contract`
+    /// * Requires Governance Community Approval (`UpdateGovernance`)
+    pub fn set_vault_transfer_rule(
+        ctx: Context<SetVaultTransferRuleContext>,
         args: Vec<String>,
     ) -> Result<()> {
-        require_args!(args, 1)?;
-        let amount = parse_arg!(args, 0, u64)?;
+        require_args!(args, 3)?;
+        let source = vaults::vault_type_from_seed(args[0].as_bytes())
+            .ok_or(vaults::VaultError::UnknownVaultType)?;
+        let destination = vaults::vault_type_from_seed(args[1].as_bytes())
+            .ok_or(vaults::VaultError::UnknownVaultType)?;
+        let rule = vaults::parse_transfer_rule(&args[2])?;
 
         let caller = ctx.accounts.caller.key();
-        secure!(ctx, &caller, "transfer_between_vaults");
+        secure!(ctx, &caller, "manage_contract");
 
-        vaults::move_from_contract_to_vault(ctx, amount)
+        governance::require_approved_proposal(
+            &mut ctx.accounts.proposal,
+            &ctx.accounts.governance_state,
+            governance::ProposalTypeBit::UpdateGovernance,
+        )?;
+
+        governance::mark_proposal_as_used(&mut ctx.accounts.proposal)?;
+
+        vaults::set_vault_transfer_rule(ctx, source, destination, rule)
     }
 
     //////////////////////////////////////////////////////////////////////////////////////////
@@ -870,10 +1934,189 @@ pub mod soccial_token {
         airdrop::distribute(&mut ctx, amount, reason)
     }
 
+    /// Creates a batch of gift codes redeemable from the airdrop vault.
+    ///
+    /// # Args
+    /// * `args[0]` – Batch id (u64)
+    /// * `args[1]` – Merkle root of the code leaves (64 hex characters)
+    /// * `args[2]` – Number of codes (u32)
+    /// * `args[3]` – Amount paid per code (u64)
+    /// * `args[4]` – Total budget of the batch (u64)
+    /// * `args[5]` – Expiry unix timestamp (i64)
+    ///
+    /// # Permissions
+    /// * Requires `airdrop_tokens`
+    pub fn create_voucher_batch(
+        ctx: Context<CreateVoucherBatchContext>,
+        args: Vec<String>,
+    ) -> Result<()> {
+        require_args!(args, 6)?;
+        let batch_id = parse_arg!(args, 0, u64)?;
+        let merkle_root = airdrop::parse_voucher_hash(&args[1])?;
+        let code_count = parse_arg!(args, 2, u32)?;
+        let amount = parse_arg!(args, 3, u64)?;
+        let budget = parse_arg!(args, 4, u64)?;
+        let expires_at = parse_arg!(args, 5, i64)?;
+
+        let caller = ctx.accounts.caller.key();
+
+        secure!(ctx, &caller, "airdrop_tokens", true);
+
+        airdrop::create_voucher_batch(ctx, batch_id, merkle_root, code_count, amount, budget, expires_at)
+    }
+
+    /// Redeems a gift code for SCTK sent to the caller's token account.
+    /// The `voucher_key` seeded by the code must co-sign.
+    ///
+    /// # Args
+    /// * `args[0]` – Batch id (u64)
+    /// * `args[1]` – Code index in the batch (u32)
+    /// * `args[2..]` – Merkle proof nodes (64 hex characters each)
+    ///
+    /// # Permissions
+    /// * No permission required (`Banned` users are rejected)
+    pub fn redeem_voucher(
+        ctx: Context<RedeemVoucherContext>,
+        args: Vec<String>,
+    ) -> Result<()> {
+        require_args!(args, 2)?;
+        let index = parse_arg!(args, 1, u32)?;
+        let proof = args[2..]
+            .iter()
+            .map(|node| airdrop::parse_voucher_hash(node))
+            .collect::<Result<Vec<_>>>()?;
+
+        airdrop::redeem_voucher(ctx, index, proof)
+    }
+
     //////////////////////////////////////////////////////////////////////////////////////////
     /// Manage Economy
     //////////////////////////////////////////////////////////////////////////////////////////
     
+    /// Updates the per-operation fee schedule (in BPS).
+    ///
+    /// # Args
+    /// * Key-value pairs, e.g.:
+    /// * ["buy=250", "withdrawal=100", "transfer=10"]
+    ///
+    /// # Permissions
+    /// * Requires `manage_economy`
+    /// * Requires Governance Community Approval
+    pub fn update_fee_schedule(
+        ctx: Context<ManageContractGovernance>,
+        args: Vec<String>,
+    ) -> Result<()> {
+        require_args!(args, 1)?;
+
+        let caller = ctx.accounts.caller.key();
+        secure!(ctx, &caller, "manage_economy", true);
+
+        governance::require_approved_proposal(
+            &mut ctx.accounts.proposal,
+            &ctx.accounts.governance_state,
+            governance::ProposalTypeBit::AdjustTaxRate,
+        )?;
+
+        ctx.accounts.token_state.fee_schedule.apply_updates(args)?;
+
+        governance::mark_proposal_as_used(&mut ctx.accounts.proposal)?;
+
+        Ok(())
+    }
+
+    /// Updates the flag-based fee discounts (in BPS of the base fee).
+    ///
+    /// # Args
+    /// * Key-value pairs, e.g.:
+    /// * ["vip=5000", "staff=10000"]
+    ///
+    /// # Permissions
+    /// * Requires `manage_economy`
+    /// * Requires Governance Community Approval
+    pub fn update_fee_discounts(
+        ctx: Context<ManageContractGovernance>,
+        args: Vec<String>,
+    ) -> Result<()> {
+        require_args!(args, 1)?;
+
+        let caller = ctx.accounts.caller.key();
+        secure!(ctx, &caller, "manage_economy", true);
+
+        governance::require_approved_proposal(
+            &mut ctx.accounts.proposal,
+            &ctx.accounts.governance_state,
+            governance::ProposalTypeBit::AdjustTaxRate,
+        )?;
+
+        ctx.accounts.token_state.fee_discounts.apply_updates(args)?;
+
+        governance::mark_proposal_as_used(&mut ctx.accounts.proposal)?;
+
+        Ok(())
+    }
+
+    /// Updates the referrer share of referee fees (in BPS of the fee).
+    ///
+    /// # Args
+    /// * Key-value pairs, e.g.:
+    /// * ["share=1000"]
+    ///
+    /// # Permissions
+    /// * Requires `manage_economy`
+    /// * Requires Governance Community Approval
+    pub fn update_referral_settings(
+        ctx: Context<ManageContractGovernance>,
+        args: Vec<String>,
+    ) -> Result<()> {
+        require_args!(args, 1)?;
+
+        let caller = ctx.accounts.caller.key();
+        secure!(ctx, &caller, "manage_economy", true);
+
+        governance::require_approved_proposal(
+            &mut ctx.accounts.proposal,
+            &ctx.accounts.governance_state,
+            governance::ProposalTypeBit::AdjustTaxRate,
+        )?;
+
+        ctx.accounts.token_state.referral.apply_updates(args)?;
+
+        governance::mark_proposal_as_used(&mut ctx.accounts.proposal)?;
+
+        Ok(())
+    }
+
+    /// Updates the withdrawal safeguards (address book cooldown, in seconds).
+    ///
+    /// # Args
+    /// * Key-value pairs, e.g.:
+    /// * ["address_cooldown=86400"]
+    ///
+    /// # Permissions
+    /// * Requires `manage_contract`
+    /// * Requires Governance Community Approval (`UpdateGovernance`)
+    pub fn update_withdrawal_settings(
+        ctx: Context<ManageContractGovernance>,
+        args: Vec<String>,
+    ) -> Result<()> {
+        require_args!(args, 1)?;
+
+        let caller = ctx.accounts.caller.key();
+        secure!(ctx, &caller, "manage_contract", true);
+
+        governance::require_approved_proposal(
+            &mut ctx.accounts.proposal,
+            &ctx.accounts.governance_state,
+            governance::ProposalTypeBit::UpdateGovernance,
+        )?;
+
+        ctx.accounts.token_state.withdrawal.apply_updates(args)?;
+
+        governance::mark_proposal_as_used(&mut ctx.accounts.proposal)?;
+
+        Ok(())
+    }
+
     /// Updates the rewards fee (in BPS).
     ///
     /// # Args
@@ -1119,6 +2362,33 @@ pub mod soccial_token {
         Ok(())
     }
 
+    /// Grows `TokenState` to the current layout after an upgrade that
+    /// appended new settings, filling them with their defaults.
+    ///
+    /// # Permissions
+    /// * Requires contract ownership
+    /// * Requires Governance Community Approval
+    pub fn migrate_token_state(
+        ctx: Context<MigrateTokenStateContext>,
+    ) -> Result<()> {
+        let caller = ctx.accounts.caller.key();
+
+        let owner = crate::token::legacy_owner(&ctx.accounts.token_state)?;
+        require_keys_eq!(caller, owner, ErrorCode::Unauthorized);
+
+        governance::require_approved_proposal(
+            &mut ctx.accounts.proposal,
+            &ctx.accounts.governance_state,
+            governance::ProposalTypeBit::UpdateGovernance,
+        )?;
+
+        crate::token::migrate_token_state(&ctx)?;
+
+        governance::mark_proposal_as_used(&mut ctx.accounts.proposal)?;
+
+        Ok(())
+    }
+
     /// Creates or updates the token metadata URI on-chain.
     ///
     /// # Arguments
@@ -0,0 +1,104 @@
+// ===========================================================================
+// Withdrawal Address Book – Soccial Token (SCTK)
+// ---------------------------------------------------------------------------
+//
+// `deposit_tokens` is submitted by the API authority. To limit the blast
+// radius of a compromised backend key, withdrawals may only be paid into
+// token accounts the user registered themselves, and only once the governed
+// cooldown (`WithdrawalSettings::address_cooldown_secs`) has elapsed.
+//
+// ---------------------------------------------------------------------------
+// ## Rules:
+// - Only the book owner can add or remove entries (signer-seeded PDA)
+// - New entries activate after the cooldown; removals apply immediately
+// - `deposit_tokens` refuses destinations missing from the book or not active
+//
+// ---------------------------------------------------------------------------
+// ## Components:
+// - `add_withdrawal_address()`: Registers a destination token account
+// - `remove_withdrawal_address()`: Drops a destination
+//
+// ---------------------------------------------------------------------------
+// Author: Paulo Rodrigues
+// Project: Soccial Token
+// Website: https://www.soccial.com/thetoken
+// License: MIT
+// ===========================================================================
+
+use anchor_lang::prelude::*;
+
+use crate::market::context::*;
+
+#[event]
+pub struct WithdrawalAddressAdded {
+    pub owner: Pubkey,
+    pub token_account: Pubkey,
+    pub active_at: i64,
+}
+
+#[event]
+pub struct WithdrawalAddressRemoved {
+    pub owner: Pubkey,
+    pub token_account: Pubkey,
+}
+
+/// ===========================================================================
+/// Registers a destination in the caller's withdrawal address book.
+///
+/// ## Behavior:
+/// - Creates the book on first use
+/// - The entry becomes usable after the governed address cooldown
+///
+/// ## Errors:
+/// - `MarketError::AddressAlreadyRegistered` if the account is already listed
+/// - `MarketError::AddressBookFull` if the book has no free slot
+/// ===========================================================================
+pub(crate) fn add_withdrawal_address(ctx: Context<AddWithdrawalAddressContext>) -> Result<()> {
+    let owner = ctx.accounts.caller.key();
+    let token_account = ctx.accounts.destination_token_account.key();
+    let cooldown = ctx.accounts.token_state.withdrawal.address_cooldown_secs;
+    let active_at = Clock::get()?.unix_timestamp.saturating_add(cooldown);
+
+    let book = &mut ctx.accounts.withdrawal_address_book;
+    book.owner = owner;
+    book.bump = ctx.bumps.withdrawal_address_book;
+    book.add(token_account, active_at)?;
+
+    msg!(
+        "📒 Withdrawal address {} registered by {} (active at {})",
+        token_account,
+        owner,
+        active_at,
+    );
+
+    emit!(WithdrawalAddressAdded {
+        owner,
+        token_account,
+        active_at,
+    });
+
+    Ok(())
+}
+
+/// ===========================================================================
+/// Removes a destination from the caller's withdrawal address book.
+///
+/// ## Errors:
+/// - `MarketError::AddressNotRegistered` if the account is not listed
+/// ===========================================================================
+pub(crate) fn remove_withdrawal_address(
+    ctx: Context<RemoveWithdrawalAddressContext>,
+    token_account: Pubkey,
+) -> Result<()> {
+    let owner = ctx.accounts.caller.key();
+    ctx.accounts.withdrawal_address_book.remove(&token_account)?;
+
+    msg!("📒 Withdrawal address {} removed by {}", token_account, owner);
+
+    emit!(WithdrawalAddressRemoved {
+        owner,
+        token_account,
+    });
+
+    Ok(())
+}
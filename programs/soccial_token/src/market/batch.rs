@@ -0,0 +1,222 @@
+// ===========================================================================
+// Batch Transfers – Soccial Token (SCTK)
+// ---------------------------------------------------------------------------
+//
+// This module implements multi-recipient transfers for mass creator payouts
+// and collaboration splits, so many recipients are paid in one transaction.
+//
+// ---------------------------------------------------------------------------
+// ## Layout:
+// - Recipient token accounts are passed as `remaining_accounts`
+// - Amounts are passed as arguments, in the same order as the recipients
+//
+// ---------------------------------------------------------------------------
+// ## Fee Logic:
+// - `BatchFeeMode::PerLeg`: scheduled fee deducted from each leg
+// - `BatchFeeMode::Aggregate`: fee computed once on the total and paid by
+//   the sender on top; recipients receive the full amounts
+// - Either way, fees are routed with a single `distribute_fees` call
+//
+// ---------------------------------------------------------------------------
+// Author: Paulo Rodrigues
+// Project: Soccial Token
+// Website: https://www.soccial.com/thetoken
+// License: MIT
+// ===========================================================================
+
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, TokenAccount, Transfer};
+
+use crate::{
+    auth::user::load_user_access,
+    economics::state::FeeOperation,
+    market::{
+        calculate_fee, context::*, distribute_fees, error::MarketError,
+        state::{BatchFeeMode, MAX_BATCH_TRANSFER_LEGS}, FeeDistributionContext,
+    },
+    referral::credit_referral_fee,
+    utils::math::format_sctk,
+};
+
+#[event]
+pub struct BatchTransferLeg {
+    pub sender: Pubkey,
+    pub recipient: Pubkey,
+    pub index: u8,
+    pub amount: u64,
+    pub net_received: u64,
+    pub fee_charged: u64,
+}
+
+#[event]
+pub struct BatchTransferCompleted {
+    pub sender: Pubkey,
+    pub fee_mode: BatchFeeMode,
+    pub legs: u8,
+    pub total_amount: u64,
+    pub total_received: u64,
+    pub fee_charged: u64,
+    pub to_rewards: u64,
+    pub to_airdrop: u64,
+    pub to_revenue: u64,
+    pub discount_bps: u16,
+}
+
+/// ===========================================================================
+/// Transfers SCTK from one sender to many recipients in a single instruction.
+///
+/// ## Behavior:
+/// - Validates each recipient as an SCTK token account
+/// - Applies the scheduled transfer fee per leg or on the aggregate total
+/// - Routes all fees with one `distribute_fees` call
+/// - Credits the sender's referrer, if any, from the total fee
+/// - Emits one `BatchTransferLeg` per recipient and a `BatchTransferCompleted`
+///
+/// ## Errors:
+/// - `MarketError::InvalidBatch` if recipients and amounts mismatch, or the
+///   batch is empty or above `MAX_BATCH_TRANSFER_LEGS`
+/// - `MarketError::InvalidAmount` if any amount is zero
+/// - `MarketError::InvalidRecipientAccount` if a recipient is not an SCTK account
+/// - `MarketError::InsufficientFunds` if the sender cannot cover amounts and fees
+/// ===========================================================================
+pub(crate) fn batch_transfer_tokens<'info>(
+    ctx: Context<'_, '_, 'info, 'info, BatchTransferTokensContext<'info>>,
+    fee_mode: BatchFeeMode,
+    amounts: Vec<u64>,
+) -> Result<()> {
+    let recipients = ctx.remaining_accounts;
+    require!(
+        !amounts.is_empty()
+            && amounts.len() <= MAX_BATCH_TRANSFER_LEGS
+            && amounts.len() == recipients.len(),
+        MarketError::InvalidBatch
+    );
+    require!(amounts.iter().all(|amount| *amount > 0), MarketError::InvalidAmount);
+
+    let total_amount = amounts
+        .iter()
+        .try_fold(0u64, |acc, amount| acc.checked_add(*amount))
+        .ok_or(MarketError::Overflow)?;
+
+    let token_state = &ctx.accounts.token_state;
+    let fee_payer_access = load_user_access(&ctx.accounts.fee_payer_access)?;
+    let user_access = fee_payer_access.as_ref();
+
+    // (net, fee) per leg, plus the aggregate fee and discount
+    let (legs, fee_amount, discount_bps) = match fee_mode {
+        BatchFeeMode::PerLeg => {
+            let mut legs = Vec::with_capacity(amounts.len());
+            let mut fee_total = 0u64;
+            let mut discount_bps = 0u16;
+            for amount in &amounts {
+                let (net, fee, discount) =
+                    calculate_fee(token_state, user_access, FeeOperation::Transfer, *amount)?;
+                fee_total = fee_total.checked_add(fee).ok_or(MarketError::Overflow)?;
+                discount_bps = discount;
+                legs.push((net, fee));
+            }
+            (legs, fee_total, discount_bps)
+        }
+        BatchFeeMode::Aggregate => {
+            let (_, fee, discount_bps) =
+                calculate_fee(token_state, user_access, FeeOperation::Transfer, total_amount)?;
+            let legs = amounts.iter().map(|amount| (*amount, 0)).collect();
+            (legs, fee, discount_bps)
+        }
+    };
+
+    let total_debit = match fee_mode {
+        BatchFeeMode::PerLeg => total_amount,
+        BatchFeeMode::Aggregate => total_amount.checked_add(fee_amount).ok_or(MarketError::Overflow)?,
+    };
+    require!(
+        ctx.accounts.sender_token_account.amount >= total_debit,
+        MarketError::InsufficientFunds
+    );
+
+    let mint = ctx.accounts.token_mint.key();
+    let sender = ctx.accounts.sender.key();
+    let mut total_received = 0u64;
+
+    for (index, (recipient_info, (net, fee))) in recipients.iter().zip(legs).enumerate() {
+        let recipient = Account::<TokenAccount>::try_from(recipient_info)
+            .map_err(|_| MarketError::InvalidRecipientAccount)?;
+        require!(recipient.mint == mint, MarketError::InvalidRecipientAccount);
+
+        let transfer_ctx = CpiContext::new(
+            ctx.accounts.token_program.to_account_info(),
+            Transfer {
+                from: ctx.accounts.sender_token_account.to_account_info(),
+                to: recipient_info.clone(),
+                authority: ctx.accounts.sender.to_account_info(),
+            },
+        );
+        token::transfer(transfer_ctx, net)?;
+
+        total_received = total_received.checked_add(net).ok_or(MarketError::Overflow)?;
+
+        emit!(BatchTransferLeg {
+            sender,
+            recipient: recipient.owner,
+            index: index as u8,
+            amount: amounts[index],
+            net_received: net,
+            fee_charged: fee,
+        });
+    }
+
+    let (to_rewards, to_airdrop, to_revenue) = distribute_fees(
+        &FeeDistributionContext {
+            token_state: ctx.accounts.token_state.clone(),
+            token_program: ctx.accounts.token_program.to_account_info(),
+            source_token_account: ctx.accounts.sender_token_account.to_account_info(),
+            rewards_vault_token_account: ctx.accounts.rewards_vault_token_account.to_account_info(),
+            rewards_ledger: ctx.accounts.rewards_ledger.to_account_info(),
+            airdrop_vault_token_account: ctx.accounts.airdrop_vault_token_account.to_account_info(),
+            airdrop_ledger: ctx.accounts.airdrop_ledger.to_account_info(),
+            revenue_vault_token_account: ctx.accounts.revenue_vault_token_account.to_account_info(),
+            revenue_ledger: ctx.accounts.revenue_ledger.to_account_info(),
+            authority: ctx.accounts.sender.to_account_info(),
+        },
+        fee_amount,
+        None,
+    )?;
+
+    // Credit the sender's referrer, if any
+    credit_referral_fee(
+        &ctx.accounts.token_state,
+        &ctx.accounts.referral,
+        &ctx.accounts.referrer_account,
+        fee_amount,
+        to_revenue,
+    )?;
+
+    msg!(
+        "📦 Batch of {} transfers ({:?}): {} SCTK ({} units) sent | 📈 Fee: {} SCTK ({} units → {} to revenue, {} to rewards, {} to airdrop) | 🏷️ Discount: {} BPS",
+        amounts.len(),
+        fee_mode,
+        format_sctk(total_received),
+        total_received,
+        format_sctk(fee_amount),
+        fee_amount,
+        to_revenue,
+        to_rewards,
+        to_airdrop,
+        discount_bps,
+    );
+
+    emit!(BatchTransferCompleted {
+        sender,
+        fee_mode,
+        legs: amounts.len() as u8,
+        total_amount,
+        total_received,
+        fee_charged: fee_amount,
+        to_rewards,
+        to_airdrop,
+        to_revenue,
+        discount_bps,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,223 @@
+// ===========================================================================
+// Buyback – Soccial Token (SCTK)
+// ---------------------------------------------------------------------------
+//
+// This module implements the on-chain exit path for holders: SCTK is sold
+// back to the protocol and paid out in the quote currency from the
+// program-owned quote treasury, at the governed `PriceConfig.sell_price`.
+//
+// ---------------------------------------------------------------------------
+// ## Flow:
+// 1. Sale is checked against the per-epoch buyback cap
+// 2. Net SCTK returns to the liquidity vault, fee is split via `distribute_fees`
+// 3. Quote treasury pays the seller for the net amount (SOL or SPL)
+//
+// ---------------------------------------------------------------------------
+// ## Safety:
+// - Payouts round down in favor of the protocol
+// - SOL payouts never drain the treasury below its rent-exempt minimum
+//
+// ---------------------------------------------------------------------------
+// Author: Paulo Rodrigues
+// Project: Soccial Token
+// Website: https://www.soccial.com/thetoken
+// License: MIT
+// ===========================================================================
+
+use anchor_lang::{prelude::*, system_program};
+use anchor_spl::{associated_token::get_associated_token_address, token::{self, Transfer}};
+
+use crate::{
+    market::{apply_fee_bps, context::*, distribute_fees, error::MarketError, state::PriceConfig},
+    utils::math::format_sctk,
+    vaults::{record_vault_flow, LedgerCategory, LedgerFlow},
+};
+
+#[event]
+pub struct TokensSold {
+    pub seller: Pubkey,
+    pub amount: u64,
+    pub net_sold: u64,
+    pub fee_charged: u64,
+    pub to_rewards: u64,
+    pub to_airdrop: u64,
+    pub to_revenue: u64,
+    pub quote_mint: Pubkey,
+    pub quote_amount: u64,
+    pub price: u64,
+    pub epoch_sold: u64,
+}
+
+/// ===========================================================================
+/// Sells tokens back to the protocol in exchange for the quote currency.
+///
+/// ## Behavior:
+/// - Requires `PriceConfig.enabled` and a non-zero `sell_price`
+/// - Registers the sale against the current buyback epoch cap
+/// - Records `sell_price` in the quote mint's `PriceAccumulator`
+/// - Moves net SCTK to the liquidity vault and routes the fee to vaults
+/// - Pays the seller from the quote treasury for the net amount
+/// - Posts the net amount as a `Market` inflow to the liquidity vault ledger
+///
+/// ## Errors:
+/// - `MarketError::PricingDisabled` if buyback is disabled or unset
+/// - `MarketError::BuybackCapExceeded` if the epoch cap would be exceeded
+/// - `MarketError::InsufficientFunds` if the seller or treasury lacks funds
+/// - `MarketError::InvalidQuoteAccount` on missing or mismatched quote accounts
+/// ===========================================================================
+pub(crate) fn sell_tokens(
+    ctx: Context<SellTokensContext>,
+    amount: u64,
+) -> Result<()> {
+    require!(amount > 0, MarketError::InvalidAmount);
+    require!(
+        ctx.accounts.seller_token_account.amount >= amount,
+        MarketError::InsufficientFunds
+    );
+
+    let now = Clock::get()?.unix_timestamp;
+    let price_config = &mut ctx.accounts.price_config;
+    require!(
+        price_config.enabled && price_config.sell_price > 0,
+        MarketError::PricingDisabled
+    );
+
+    price_config.consume_buyback(amount, now)?;
+    let epoch_sold = price_config.buyback_epoch_sold;
+    let price = price_config.sell_price;
+
+    let (net_amount, fee_amount) = apply_fee_bps(amount, price_config.sell_fee_bps)?;
+    let quote_amount = PriceConfig::payout_for(net_amount, price)?;
+    require!(quote_amount > 0, MarketError::InvalidAmount);
+
+    ctx.accounts.price_accumulator.record(price, now)?;
+
+    // Return net tokens to the liquidity vault
+    let transfer_ctx = CpiContext::new(
+        ctx.accounts.token_program.to_account_info(),
+        Transfer {
+            from: ctx.accounts.seller_token_account.to_account_info(),
+            to: ctx.accounts.liquidity_vault_token_account.to_account_info(),
+            authority: ctx.accounts.caller.to_account_info(),
+        },
+    );
+    token::transfer(transfer_ctx, net_amount)?;
+
+    // Distribute fee to vaults
+    let (to_rewards, to_airdrop, to_revenue) = distribute_fees(
+        &FeeDistributionContext {
+            token_state: ctx.accounts.token_state.clone(),
+            token_program: ctx.accounts.token_program.to_account_info(),
+            source_token_account: ctx.accounts.seller_token_account.to_account_info(),
+            rewards_vault_token_account: ctx.accounts.rewards_vault_token_account.to_account_info(),
+            rewards_ledger: ctx.accounts.rewards_ledger.to_account_info(),
+            airdrop_vault_token_account: ctx.accounts.airdrop_vault_token_account.to_account_info(),
+            airdrop_ledger: ctx.accounts.airdrop_ledger.to_account_info(),
+            revenue_vault_token_account: ctx.accounts.revenue_vault_token_account.to_account_info(),
+            revenue_ledger: ctx.accounts.revenue_ledger.to_account_info(),
+            authority: ctx.accounts.caller.to_account_info(),
+        },
+        fee_amount,
+        None,
+    )?;
+    record_vault_flow(&ctx.accounts.liquidity_ledger, LedgerFlow::Inflow, LedgerCategory::Market, net_amount)?;
+
+    // Pay the seller from the quote treasury
+    pay_quote_payout(&ctx, quote_amount)?;
+
+    msg!(
+        "🔁 Seller {} sold {} SCTK ({} units) back for {} quote units | 📈 Fee: {} SCTK ({} units → {} to revenue, {} to rewards, {} to airdrop)",
+        ctx.accounts.caller.key(),
+        format_sctk(net_amount),
+        net_amount,
+        quote_amount,
+        format_sctk(fee_amount),
+        fee_amount,
+        to_revenue,
+        to_rewards,
+        to_airdrop,
+    );
+
+    emit!(TokensSold {
+        seller: ctx.accounts.caller.key(),
+        amount,
+        net_sold: net_amount,
+        fee_charged: fee_amount,
+        to_rewards,
+        to_airdrop,
+        to_revenue,
+        quote_mint: ctx.accounts.price_config.quote_mint,
+        quote_amount,
+        price,
+        epoch_sold,
+    });
+
+    Ok(())
+}
+
+/// ===========================================================================
+/// Pays the seller from the quote treasury, signing as the `quote_treasury` PDA.
+///
+/// ## Behavior:
+/// - Native SOL: system transfer, keeping the treasury rent-exempt
+/// - SPL mint: token transfer from the treasury ATA to the seller's quote account
+///
+/// ## Errors:
+/// - `MarketError::InsufficientFunds` if the treasury cannot cover the payout
+/// - `MarketError::InvalidQuoteAccount` on missing or mismatched accounts
+/// ===========================================================================
+fn pay_quote_payout(
+    ctx: &Context<SellTokensContext>,
+    quote_amount: u64,
+) -> Result<()> {
+    let price_config = &ctx.accounts.price_config;
+    let signer_seeds: &[&[u8]] = &[b"quote_treasury", &[ctx.bumps.quote_treasury]];
+    let signer_seeds_nested = &[signer_seeds];
+
+    if price_config.is_native() {
+        let reserve = Rent::get()?.minimum_balance(0);
+        let available = ctx.accounts.quote_treasury.lamports().saturating_sub(reserve);
+        require!(available >= quote_amount, MarketError::InsufficientFunds);
+
+        let cpi_ctx = CpiContext::new_with_signer(
+            ctx.accounts.system_program.to_account_info(),
+            system_program::Transfer {
+                from: ctx.accounts.quote_treasury.to_account_info(),
+                to: ctx.accounts.caller.to_account_info(),
+            },
+            signer_seeds_nested,
+        );
+        return system_program::transfer(cpi_ctx, quote_amount);
+    }
+
+    let seller_quote = ctx
+        .accounts
+        .seller_quote_account
+        .as_ref()
+        .ok_or(MarketError::InvalidQuoteAccount)?;
+    let treasury_quote = ctx
+        .accounts
+        .quote_treasury_token_account
+        .as_ref()
+        .ok_or(MarketError::InvalidQuoteAccount)?;
+
+    require_keys_eq!(seller_quote.mint, price_config.quote_mint, MarketError::InvalidQuoteAccount);
+    require_keys_eq!(seller_quote.owner, ctx.accounts.caller.key(), MarketError::InvalidQuoteAccount);
+    require_keys_eq!(
+        treasury_quote.key(),
+        get_associated_token_address(&ctx.accounts.quote_treasury.key(), &price_config.quote_mint),
+        MarketError::InvalidQuoteAccount
+    );
+    require!(treasury_quote.amount >= quote_amount, MarketError::InsufficientFunds);
+
+    let cpi_ctx = CpiContext::new_with_signer(
+        ctx.accounts.token_program.to_account_info(),
+        Transfer {
+            from: treasury_quote.to_account_info(),
+            to: seller_quote.to_account_info(),
+            authority: ctx.accounts.quote_treasury.to_account_info(),
+        },
+        signer_seeds_nested,
+    );
+    token::transfer(cpi_ctx, quote_amount)
+}
@@ -14,7 +14,16 @@
 
 use anchor_lang::prelude::*;
 use anchor_spl::token::{Mint, Token, TokenAccount};
-use crate::{auth::user::UserAccessAccount, token::state::TokenState};
+use crate::{
+    auth::user::UserAccessAccount,
+    governance::{GovernanceState, ProposalAccount},
+    market::state::{
+        CreditLedger, CreditReceipt, PriceConfig, QuoteNonce, SaleState, WithdrawalAddressBook,
+        WithdrawalReceipt, request_id_seed,
+    },
+    oracle::state::PriceAccumulator,
+    token::state::TokenState,
+};
 
 #[derive(Accounts)]
 pub struct BuyTokensContext<'info> {
@@ -36,6 +45,18 @@ pub struct BuyTokensContext<'info> {
     )]
     pub buyer_token_account: Account<'info, TokenAccount>,
 
+    // ------------------------------------------------------------------------
+    // Bonding Curve
+    // ------------------------------------------------------------------------
+
+    /// Sale state holding the bonding curve and tokens sold so far.
+    #[account(
+        mut,
+        seeds = [b"sale_state"],
+        bump
+    )]
+    pub sale_state: Account<'info, SaleState>,
+
    // ------------------------------------------------------------------------
     // Liquidity Vault
     // ------------------------------------------------------------------------
@@ -47,6 +68,22 @@ pub struct BuyTokensContext<'info> {
     )]
     pub liquidity_vault: AccountInfo<'info>,
 
+    /// CHECK: Outflow limits PDA of `liquidity_vault`. Enforced in logic once configured.
+    #[account(
+        mut,
+        seeds = [b"vault_limits", liquidity_vault.key().as_ref()],
+        bump
+    )]
+    pub vault_limits: AccountInfo<'info>,
+
+    /// CHECK: Ledger PDA of `liquidity_vault`. Posted to in logic once opened.
+    #[account(
+        mut,
+        seeds = [b"vault_ledger", liquidity_vault.key().as_ref()],
+        bump
+    )]
+    pub liquidity_ledger: AccountInfo<'info>,
+
     /// Token account holding tokens available for purchase.
     #[account(
         mut,
@@ -74,6 +111,14 @@ pub struct BuyTokensContext<'info> {
     )]
     pub rewards_vault_token_account: Account<'info, TokenAccount>,
 
+    /// CHECK: Ledger PDA of `rewards_vault`. Posted to in logic once opened.
+    #[account(
+        mut,
+        seeds = [b"vault_ledger", rewards_vault.key().as_ref()],
+        bump
+    )]
+    pub rewards_ledger: AccountInfo<'info>,
+
     // ------------------------------------------------------------------------
     // Revenue Vault
     // ------------------------------------------------------------------------
@@ -93,6 +138,14 @@ pub struct BuyTokensContext<'info> {
     )]
     pub revenue_vault_token_account: Account<'info, TokenAccount>,
 
+    /// CHECK: Ledger PDA of `revenue_vault`. Posted to in logic once opened.
+    #[account(
+        mut,
+        seeds = [b"vault_ledger", revenue_vault.key().as_ref()],
+        bump
+    )]
+    pub revenue_ledger: AccountInfo<'info>,
+
     // ------------------------------------------------------------------------
     // Airdrop Vault
     // ------------------------------------------------------------------------
@@ -112,6 +165,14 @@ pub struct BuyTokensContext<'info> {
     )]
     pub airdrop_vault_token_account: Account<'info, TokenAccount>,
 
+    /// CHECK: Ledger PDA of `airdrop_vault`. Posted to in logic once opened.
+    #[account(
+        mut,
+        seeds = [b"vault_ledger", airdrop_vault.key().as_ref()],
+        bump
+    )]
+    pub airdrop_ledger: AccountInfo<'info>,
+
 
     // ─────────────────────────────────────────────────────────────
     // Mint
@@ -120,6 +181,25 @@ pub struct BuyTokensContext<'info> {
     /// Token mint (SCTK).
     pub token_mint: Account<'info, Mint>,
 
+    // ─────────────────────────────────────────────────────────────
+    // Referral
+    // ─────────────────────────────────────────────────────────────
+
+    /// CHECK: Referral PDA of the buyer, always required. Empty when the buyer
+    /// has no referrer; validated and credited in logic otherwise.
+    #[account(
+        mut,
+        seeds = [b"referral", buyer_token_account.owner.as_ref()],
+        bump
+    )]
+    pub referral: AccountInfo<'info>,
+
+    /// CHECK: `ReferrerAccount` of the referral's referrer, validated in logic.
+    /// Ignored when `referral` is empty; clients then pass the buyer's own
+    /// `referrer` PDA.
+    #[account(mut)]
+    pub referrer_account: AccountInfo<'info>,
+
     // ─────────────────────────────────────────────────────────────
     // Access Control
     // ─────────────────────────────────────────────────────────────
@@ -131,11 +211,20 @@ pub struct BuyTokensContext<'info> {
     )]
     pub user_access: Option<Account<'info, UserAccessAccount>>,
 
+    /// CHECK: `user_access` PDA of the buyer, whose flags set the fee discount.
+    /// Always required; empty when the user never had flags assigned.
+    #[account(
+        seeds = [b"user_access", buyer_token_account.owner.as_ref()],
+        bump
+    )]
+    pub fee_payer_access: AccountInfo<'info>,
+
     // ─────────────────────────────────────────────────────────────
     // State & Programs
     // ─────────────────────────────────────────────────────────────
 
     /// TokenState account for system-wide configuration.
+    #[account(mut)]
     pub token_state: Account<'info, TokenState>,
 
     /// Token program required for token transfers.
@@ -143,133 +232,120 @@ pub struct BuyTokensContext<'info> {
 }
 
 #[derive(Accounts)]
-pub struct DepositTokensContext<'info> {
-    // ------------------------------------------------------------------------
-    // Caller and Access Control
-    // ------------------------------------------------------------------------
+#[instruction(args: Vec<String>)]
+pub struct BuyTokensWithQuoteContext<'info> {
+    // ─────────────────────────────────────────────────────────────
+    // Signer
+    // ─────────────────────────────────────────────────────────────
 
-    /// The caller initiating the deposit (must be authorized).
+    /// The buyer redeeming the quote (pays for the nonce account).
     #[account(mut)]
     pub caller: Signer<'info>,
 
-    /// Optional user access control (validated if present).
+    // ─────────────────────────────────────────────────────────────
+    // Buyer Token Account
+    // ─────────────────────────────────────────────────────────────
+    /// Token account owned by the buyer, receives purchased tokens.
     #[account(
-        seeds = [b"user_access", caller.key().as_ref()],
-        bump
+        mut,
+        associated_token::mint = token_mint,
+        associated_token::authority = caller
     )]
-    pub user_access: Option<Account<'info, UserAccessAccount>>,
-
-    /// Global token configuration and state.
-    pub token_state: Account<'info, TokenState>,
-
-    // ------------------------------------------------------------------------
-    // Token Mint
-    // ------------------------------------------------------------------------
-
-    /// Mint of the SCTK token.
-    pub token_mint: Account<'info, Mint>,
+    pub buyer_token_account: Account<'info, TokenAccount>,
 
     // ------------------------------------------------------------------------
-    // Offchain Reserve Vault (source of tokens)
+    // Bonding Curve
     // ------------------------------------------------------------------------
 
-    /// CHECK: PDA authority for offchain reserve operations. Verified in handler.
-    #[account(seeds = [b"offchain_reserve_vault"], bump)]
-    pub offchain_reserve_vault: AccountInfo<'info>,
-
-    /// Offchain reserve vault's token account.
+    /// Sale state holding the bonding curve and tokens sold so far.
     #[account(
         mut,
-        associated_token::mint = token_mint,
-        associated_token::authority = offchain_reserve_vault
+        seeds = [b"sale_state"],
+        bump
     )]
-    pub offchain_reserve_vault_token_account: Account<'info, TokenAccount>,
+    pub sale_state: Account<'info, SaleState>,
 
     // ------------------------------------------------------------------------
-    // Destination Account
+    // Pricing & Quote Treasury
     // ------------------------------------------------------------------------
 
-    /// CHECK: Authority expected to match recipient's token account. Used for validation only.
-    pub destination_authority: AccountInfo<'info>,
+    /// Governance-managed price configuration (quote currency).
+    #[account(seeds = [b"price_config"], bump)]
+    pub price_config: Account<'info, PriceConfig>,
 
-    /// Token account receiving the tokens.
+    /// TWAP accumulator of the pricing currency, updated with this trade.
     #[account(
-        mut,
-        associated_token::mint = token_mint,
-        associated_token::authority = destination_authority
+        init_if_needed,
+        payer = caller,
+        space = PriceAccumulator::LEN,
+        seeds = [b"price_accumulator", price_config.quote_mint.as_ref()],
+        bump
     )]
-    pub destination_token_account: Account<'info, TokenAccount>,
+    pub price_accumulator: Account<'info, PriceAccumulator>,
 
-    // ------------------------------------------------------------------------
-    // Rewards Vault
-    // ------------------------------------------------------------------------
-
-    /// CHECK: PDA authority for rewards logic. Verified in handler.
-    #[account(seeds = [b"rewards_vault"], bump)]
-    pub rewards_vault: AccountInfo<'info>,
-
-    /// Rewards vault's token account.
+    /// CHECK: PDA holding SOL proceeds and owning the quote treasury ATA.
     #[account(
         mut,
-        associated_token::mint = token_mint,
-        associated_token::authority = rewards_vault
+        seeds = [b"quote_treasury"],
+        bump
     )]
-    pub rewards_vault_token_account: Account<'info, TokenAccount>,
+    pub quote_treasury: AccountInfo<'info>,
+
+    /// Buyer's quote token account (required when paying with an SPL mint).
+    #[account(mut)]
+    pub buyer_quote_account: Option<Account<'info, TokenAccount>>,
+
+    /// Quote treasury ATA receiving SPL proceeds (required when paying with an SPL mint).
+    #[account(mut)]
+    pub quote_treasury_token_account: Option<Account<'info, TokenAccount>>,
 
     // ------------------------------------------------------------------------
-    // Revenue Vault
+    // Liquidity Vault
     // ------------------------------------------------------------------------
 
-    /// CHECK: PDA authority for revenue logic. Verified in handler.
-    #[account(seeds = [b"revenue_vault"], bump)]
-    pub revenue_vault: AccountInfo<'info>,
+    /// CHECK: PDA authority for liquidity vault operations. Verified in handler.
+    #[account(
+        seeds = [b"liquidity_vault"],
+        bump
+    )]
+    pub liquidity_vault: AccountInfo<'info>,
 
-    /// Revenue vault's token account.
+    /// CHECK: Outflow limits PDA of `liquidity_vault`. Enforced in logic once configured.
     #[account(
         mut,
-        associated_token::mint = token_mint,
-        associated_token::authority = revenue_vault
+        seeds = [b"vault_limits", liquidity_vault.key().as_ref()],
+        bump
     )]
-    pub revenue_vault_token_account: Account<'info, TokenAccount>,
-
-    // ------------------------------------------------------------------------
-    // Airdrop Vault
-    // ------------------------------------------------------------------------
+    pub vault_limits: AccountInfo<'info>,
 
-    /// CHECK: PDA authority for airdrop logic. Verified in handler.
-    #[account(seeds = [b"airdrop_vault"], bump)]
-    pub airdrop_vault: AccountInfo<'info>,
+    /// CHECK: Ledger PDA of `liquidity_vault`. Posted to in logic once opened.
+    #[account(
+        mut,
+        seeds = [b"vault_ledger", liquidity_vault.key().as_ref()],
+        bump
+    )]
+    pub liquidity_ledger: AccountInfo<'info>,
 
-    /// Airdrop vault's token account.
+    /// Token account holding tokens available for purchase.
     #[account(
         mut,
         associated_token::mint = token_mint,
-        associated_token::authority = airdrop_vault
+        associated_token::authority = liquidity_vault
     )]
-    pub airdrop_vault_token_account: Account<'info, TokenAccount>,
-
-    // ------------------------------------------------------------------------
-    // SPL Token Program
-    // ------------------------------------------------------------------------
-
-    /// SPL Token Program.
-    pub token_program: Program<'info, Token>,
-}
-
-#[derive(Accounts)]
-pub struct TransferTokensContext<'info> {
-    /// The caller initiating the transfer (must be authorized).
-    pub caller: Signer<'info>,
-
+    pub liquidity_vault_token_account: Account<'info, TokenAccount>,
+    
     // ------------------------------------------------------------------------
     // Rewards Vault
     // ------------------------------------------------------------------------
 
-    /// CHECK: PDA authority for rewards logic. Verified in handler.
-    #[account(seeds = [b"rewards_vault"], bump)]
+    /// CHECK: PDA authority for rewards distribution logic. Verified in handler.
+    #[account(
+        seeds = [b"rewards_vault"],
+        bump
+    )]
     pub rewards_vault: AccountInfo<'info>,
 
-    /// Token account holding tokens designated for rewards.
+    /// Token account holding tokens used for rewards distribution.
     #[account(
         mut,
         associated_token::mint = token_mint,
@@ -277,15 +353,26 @@ pub struct TransferTokensContext<'info> {
     )]
     pub rewards_vault_token_account: Account<'info, TokenAccount>,
 
+    /// CHECK: Ledger PDA of `rewards_vault`. Posted to in logic once opened.
+    #[account(
+        mut,
+        seeds = [b"vault_ledger", rewards_vault.key().as_ref()],
+        bump
+    )]
+    pub rewards_ledger: AccountInfo<'info>,
+
     // ------------------------------------------------------------------------
     // Revenue Vault
     // ------------------------------------------------------------------------
 
-    /// CHECK: PDA authority for revenue logic. Verified in handler.
-    #[account(seeds = [b"revenue_vault"], bump)]
+    /// CHECK: PDA authority for revenue collection logic. Verified in handler.
+    #[account(
+        seeds = [b"revenue_vault"],
+        bump
+    )]
     pub revenue_vault: AccountInfo<'info>,
 
-    /// Token account holding tokens allocated for revenue collection.
+    /// Token account accumulating revenue from token sales or fees.
     #[account(
         mut,
         associated_token::mint = token_mint,
@@ -293,12 +380,23 @@ pub struct TransferTokensContext<'info> {
     )]
     pub revenue_vault_token_account: Account<'info, TokenAccount>,
 
+    /// CHECK: Ledger PDA of `revenue_vault`. Posted to in logic once opened.
+    #[account(
+        mut,
+        seeds = [b"vault_ledger", revenue_vault.key().as_ref()],
+        bump
+    )]
+    pub revenue_ledger: AccountInfo<'info>,
+
     // ------------------------------------------------------------------------
     // Airdrop Vault
     // ------------------------------------------------------------------------
 
     /// CHECK: PDA authority for airdrop logic. Verified in handler.
-    #[account(seeds = [b"airdrop_vault"], bump)]
+    #[account(
+        seeds = [b"airdrop_vault"],
+        bump
+    )]
     pub airdrop_vault: AccountInfo<'info>,
 
     /// Token account holding tokens reserved for airdrops.
@@ -309,29 +407,44 @@ pub struct TransferTokensContext<'info> {
     )]
     pub airdrop_vault_token_account: Account<'info, TokenAccount>,
 
-    // ------------------------------------------------------------------------
-    // Sender & Recipient
-    // ------------------------------------------------------------------------
-
-    /// Token account of the sender (must match mint and authority).
+    /// CHECK: Ledger PDA of `airdrop_vault`. Posted to in logic once opened.
     #[account(
         mut,
-        associated_token::mint = token_mint,
-        associated_token::authority = sender
+        seeds = [b"vault_ledger", airdrop_vault.key().as_ref()],
+        bump
     )]
-    pub sender_token_account: Account<'info, TokenAccount>,
+    pub airdrop_ledger: AccountInfo<'info>,
 
-    /// CHECK: The actual sender (must sign the transaction).
-    #[account(mut, signer)]
-    pub sender: AccountInfo<'info>,
 
-    /// Token account receiving the transferred tokens.
+    // ─────────────────────────────────────────────────────────────
+    // Mint
+    // ─────────────────────────────────────────────────────────────
+
+    /// Token mint (SCTK).
+    pub token_mint: Account<'info, Mint>,
+
+    // ─────────────────────────────────────────────────────────────
+    // Referral
+    // ─────────────────────────────────────────────────────────────
+
+    /// CHECK: Referral PDA of the buyer, always required. Empty when the buyer
+    /// has no referrer; validated and credited in logic otherwise.
+    #[account(
+        mut,
+        seeds = [b"referral", caller.key().as_ref()],
+        bump
+    )]
+    pub referral: AccountInfo<'info>,
+
+    /// CHECK: `ReferrerAccount` of the referral's referrer, validated in logic.
+    /// Ignored when `referral` is empty; clients then pass the buyer's own
+    /// `referrer` PDA.
     #[account(mut)]
-    pub recipient_token_account: Account<'info, TokenAccount>,
+    pub referrer_account: AccountInfo<'info>,
 
-    // ------------------------------------------------------------------------
-    // Misc
-    // ------------------------------------------------------------------------
+    // ─────────────────────────────────────────────────────────────
+    // Access Control
+    // ─────────────────────────────────────────────────────────────
 
     /// Optional access control account for the caller.
     #[account(
@@ -340,36 +453,1223 @@ pub struct TransferTokensContext<'info> {
     )]
     pub user_access: Option<Account<'info, UserAccessAccount>>,
 
-    /// SPL Token mint (SCTK).
-    pub token_mint: Account<'info, Mint>,
+    // ─────────────────────────────────────────────────────────────
+    // State & Programs
+    // ─────────────────────────────────────────────────────────────
 
-    /// Global token configuration.
+    /// TokenState account for system-wide configuration.
+    #[account(mut)]
     pub token_state: Account<'info, TokenState>,
 
-    /// SPL Token program.
+    /// Token program required for token transfers.
     pub token_program: Program<'info, Token>,
-}
-
 
-#[derive(Accounts)]
-pub struct FeeDistributionContext<'info> {
-    pub token_state: Account<'info, TokenState>,
+    // ─────────────────────────────────────────────────────────────
+    // Signed Quote
+    // ─────────────────────────────────────────────────────────────
 
-    /// CHECK: Token program passed as generic account, validated at runtime.
-    pub token_program: AccountInfo<'info>,
+    /// Nonce record seeded by the buyer and quote nonce (`args[0]`).
+    /// Creation fails if the quote was already redeemed.
+    #[account(
+        init,
+        payer = caller,
+        space = QuoteNonce::LEN,
+        seeds = [
+            b"quote_nonce",
+            caller.key().as_ref(),
+            &args.first().and_then(|nonce| nonce.parse::<u64>().ok()).unwrap_or_default().to_le_bytes(),
+        ],
+        bump
+    )]
+    pub quote_nonce: Account<'info, QuoteNonce>,
 
-    /// CHECK: Passed as generic token account reference. Validation occurs during CPI.
-    pub source_token_account: AccountInfo<'info>,
+    /// CHECK: Instructions sysvar, used to read the ed25519 verification.
+    #[account(address = anchor_lang::solana_program::sysvar::instructions::ID)]
+    pub instructions_sysvar: AccountInfo<'info>,
 
-    /// CHECK: Rewards vault ATA used in token transfer logic; validated in instruction.
-    pub rewards_vault_token_account: AccountInfo<'info>,
+    /// System program required to create the nonce record.
+    pub system_program: Program<'info, System>,
+}
 
-    /// CHECK: Airdrop vault ATA used in token transfer logic; validated in instruction.
+#[derive(Accounts)]
+#[instruction(args: Vec<String>)]
+pub struct DepositTokensContext<'info> {
+    // ------------------------------------------------------------------------
+    // Caller and Access Control
+    // ------------------------------------------------------------------------
+
+    /// The caller initiating the deposit (must be authorized).
+    #[account(mut)]
+    pub caller: Signer<'info>,
+
+    /// Optional user access control (validated if present).
+    #[account(
+        seeds = [b"user_access", caller.key().as_ref()],
+        bump
+    )]
+    pub user_access: Option<Account<'info, UserAccessAccount>>,
+
+    /// CHECK: `user_access` PDA of the user being paid, whose flags set the fee discount.
+    /// Always required; empty when the user never had flags assigned.
+    #[account(
+        seeds = [b"user_access", destination_authority.key().as_ref()],
+        bump
+    )]
+    pub fee_payer_access: AccountInfo<'info>,
+
+    /// Global token configuration and state.
+    #[account(mut)]
+    pub token_state: Account<'info, TokenState>,
+
+    // ------------------------------------------------------------------------
+    // Token Mint
+    // ------------------------------------------------------------------------
+
+    /// Mint of the SCTK token.
+    pub token_mint: Account<'info, Mint>,
+
+    // ------------------------------------------------------------------------
+    // Offchain Reserve Vault (source of tokens)
+    // ------------------------------------------------------------------------
+
+    /// CHECK: PDA authority for offchain reserve operations. Verified in handler.
+    #[account(seeds = [b"offchain_reserve_vault"], bump)]
+    pub offchain_reserve_vault: AccountInfo<'info>,
+
+    /// CHECK: Outflow limits PDA of `offchain_reserve_vault`. Enforced in logic once configured.
+    #[account(
+        mut,
+        seeds = [b"vault_limits", offchain_reserve_vault.key().as_ref()],
+        bump
+    )]
+    pub vault_limits: AccountInfo<'info>,
+
+    /// CHECK: Ledger PDA of `offchain_reserve_vault`. Posted to in logic once opened.
+    #[account(
+        mut,
+        seeds = [b"vault_ledger", offchain_reserve_vault.key().as_ref()],
+        bump
+    )]
+    pub offchain_reserve_ledger: AccountInfo<'info>,
+
+    /// Offchain reserve vault's token account.
+    #[account(
+        mut,
+        associated_token::mint = token_mint,
+        associated_token::authority = offchain_reserve_vault
+    )]
+    pub offchain_reserve_vault_token_account: Account<'info, TokenAccount>,
+
+    // ------------------------------------------------------------------------
+    // Destination Account
+    // ------------------------------------------------------------------------
+
+    /// CHECK: Authority expected to match recipient's token account. Used for validation only.
+    pub destination_authority: AccountInfo<'info>,
+
+    /// Token account receiving the tokens.
+    #[account(
+        mut,
+        associated_token::mint = token_mint,
+        associated_token::authority = destination_authority
+    )]
+    pub destination_token_account: Account<'info, TokenAccount>,
+
+    /// Address book of the user being paid; must list the destination.
+    #[account(
+        seeds = [b"withdrawal_address_book", destination_authority.key().as_ref()],
+        bump = withdrawal_address_book.bump
+    )]
+    pub withdrawal_address_book: Option<Account<'info, WithdrawalAddressBook>>,
+
+    // ------------------------------------------------------------------------
+    // Rewards Vault
+    // ------------------------------------------------------------------------
+
+    /// CHECK: PDA authority for rewards logic. Verified in handler.
+    #[account(seeds = [b"rewards_vault"], bump)]
+    pub rewards_vault: AccountInfo<'info>,
+
+    /// Rewards vault's token account.
+    #[account(
+        mut,
+        associated_token::mint = token_mint,
+        associated_token::authority = rewards_vault
+    )]
+    pub rewards_vault_token_account: Account<'info, TokenAccount>,
+
+    /// CHECK: Ledger PDA of `rewards_vault`. Posted to in logic once opened.
+    #[account(
+        mut,
+        seeds = [b"vault_ledger", rewards_vault.key().as_ref()],
+        bump
+    )]
+    pub rewards_ledger: AccountInfo<'info>,
+
+    // ------------------------------------------------------------------------
+    // Revenue Vault
+    // ------------------------------------------------------------------------
+
+    /// CHECK: PDA authority for revenue logic. Verified in handler.
+    #[account(seeds = [b"revenue_vault"], bump)]
+    pub revenue_vault: AccountInfo<'info>,
+
+    /// Revenue vault's token account.
+    #[account(
+        mut,
+        associated_token::mint = token_mint,
+        associated_token::authority = revenue_vault
+    )]
+    pub revenue_vault_token_account: Account<'info, TokenAccount>,
+
+    /// CHECK: Ledger PDA of `revenue_vault`. Posted to in logic once opened.
+    #[account(
+        mut,
+        seeds = [b"vault_ledger", revenue_vault.key().as_ref()],
+        bump
+    )]
+    pub revenue_ledger: AccountInfo<'info>,
+
+    // ------------------------------------------------------------------------
+    // Airdrop Vault
+    // ------------------------------------------------------------------------
+
+    /// CHECK: PDA authority for airdrop logic. Verified in handler.
+    #[account(seeds = [b"airdrop_vault"], bump)]
+    pub airdrop_vault: AccountInfo<'info>,
+
+    /// Airdrop vault's token account.
+    #[account(
+        mut,
+        associated_token::mint = token_mint,
+        associated_token::authority = airdrop_vault
+    )]
+    pub airdrop_vault_token_account: Account<'info, TokenAccount>,
+
+    /// CHECK: Ledger PDA of `airdrop_vault`. Posted to in logic once opened.
+    #[account(
+        mut,
+        seeds = [b"vault_ledger", airdrop_vault.key().as_ref()],
+        bump
+    )]
+    pub airdrop_ledger: AccountInfo<'info>,
+
+    // ------------------------------------------------------------------------
+    // Idempotency Receipt
+    // ------------------------------------------------------------------------
+
+    /// Receipt seeded by the backend request ID (`args[1]`).
+    /// Creation fails if the same request was already processed.
+    #[account(
+        init,
+        payer = caller,
+        space = WithdrawalReceipt::LEN,
+        seeds = [b"withdrawal_receipt".as_ref(), request_id_seed(args.get(1).map(String::as_str).unwrap_or_default()).as_ref()],
+        bump
+    )]
+    pub withdrawal_receipt: Account<'info, WithdrawalReceipt>,
+
+    // ------------------------------------------------------------------------
+    // Programs
+    // ------------------------------------------------------------------------
+
+    /// SPL Token Program.
+    pub token_program: Program<'info, Token>,
+
+    /// System program required to create the receipt.
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct TransferTokensContext<'info> {
+    /// The caller initiating the transfer (must be authorized).
+    pub caller: Signer<'info>,
+
+    // ------------------------------------------------------------------------
+    // Rewards Vault
+    // ------------------------------------------------------------------------
+
+    /// CHECK: PDA authority for rewards logic. Verified in handler.
+    #[account(seeds = [b"rewards_vault"], bump)]
+    pub rewards_vault: AccountInfo<'info>,
+
+    /// Token account holding tokens designated for rewards.
+    #[account(
+        mut,
+        associated_token::mint = token_mint,
+        associated_token::authority = rewards_vault
+    )]
+    pub rewards_vault_token_account: Account<'info, TokenAccount>,
+
+    /// CHECK: Ledger PDA of `rewards_vault`. Posted to in logic once opened.
+    #[account(
+        mut,
+        seeds = [b"vault_ledger", rewards_vault.key().as_ref()],
+        bump
+    )]
+    pub rewards_ledger: AccountInfo<'info>,
+
+    // ------------------------------------------------------------------------
+    // Revenue Vault
+    // ------------------------------------------------------------------------
+
+    /// CHECK: PDA authority for revenue logic. Verified in handler.
+    #[account(seeds = [b"revenue_vault"], bump)]
+    pub revenue_vault: AccountInfo<'info>,
+
+    /// Token account holding tokens allocated for revenue collection.
+    #[account(
+        mut,
+        associated_token::mint = token_mint,
+        associated_token::authority = revenue_vault
+    )]
+    pub revenue_vault_token_account: Account<'info, TokenAccount>,
+
+    /// CHECK: Ledger PDA of `revenue_vault`. Posted to in logic once opened.
+    #[account(
+        mut,
+        seeds = [b"vault_ledger", revenue_vault.key().as_ref()],
+        bump
+    )]
+    pub revenue_ledger: AccountInfo<'info>,
+
+    // ------------------------------------------------------------------------
+    // Airdrop Vault
+    // ------------------------------------------------------------------------
+
+    /// CHECK: PDA authority for airdrop logic. Verified in handler.
+    #[account(seeds = [b"airdrop_vault"], bump)]
+    pub airdrop_vault: AccountInfo<'info>,
+
+    /// Token account holding tokens reserved for airdrops.
+    #[account(
+        mut,
+        associated_token::mint = token_mint,
+        associated_token::authority = airdrop_vault
+    )]
+    pub airdrop_vault_token_account: Account<'info, TokenAccount>,
+
+    /// CHECK: Ledger PDA of `airdrop_vault`. Posted to in logic once opened.
+    #[account(
+        mut,
+        seeds = [b"vault_ledger", airdrop_vault.key().as_ref()],
+        bump
+    )]
+    pub airdrop_ledger: AccountInfo<'info>,
+
+    // ------------------------------------------------------------------------
+    // Sender & Recipient
+    // ------------------------------------------------------------------------
+
+    /// Token account of the sender (must match mint and authority).
+    #[account(
+        mut,
+        associated_token::mint = token_mint,
+        associated_token::authority = sender
+    )]
+    pub sender_token_account: Account<'info, TokenAccount>,
+
+    /// CHECK: The actual sender (must sign the transaction).
+    #[account(mut, signer)]
+    pub sender: AccountInfo<'info>,
+
+    /// Token account receiving the transferred tokens.
+    #[account(mut)]
+    pub recipient_token_account: Account<'info, TokenAccount>,
+
+    // ------------------------------------------------------------------------
+    // Referral
+    // ------------------------------------------------------------------------
+
+    /// CHECK: Referral PDA of the sender, always required. Empty when the sender
+    /// has no referrer; validated and credited in logic otherwise.
+    #[account(
+        mut,
+        seeds = [b"referral", sender.key().as_ref()],
+        bump
+    )]
+    pub referral: AccountInfo<'info>,
+
+    /// CHECK: `ReferrerAccount` of the referral's referrer, validated in logic.
+    /// Ignored when `referral` is empty; clients then pass the sender's own
+    /// `referrer` PDA.
+    #[account(mut)]
+    pub referrer_account: AccountInfo<'info>,
+
+    // ------------------------------------------------------------------------
+    // Misc
+    // ------------------------------------------------------------------------
+
+    /// Optional access control account for the caller.
+    #[account(
+        seeds = [b"user_access", caller.key().as_ref()],
+        bump
+    )]
+    pub user_access: Option<Account<'info, UserAccessAccount>>,
+
+    /// CHECK: `user_access` PDA of the sender, whose flags set the fee discount.
+    /// Always required; empty when the user never had flags assigned.
+    #[account(
+        seeds = [b"user_access", sender.key().as_ref()],
+        bump
+    )]
+    pub fee_payer_access: AccountInfo<'info>,
+
+    /// SPL Token mint (SCTK).
+    pub token_mint: Account<'info, Mint>,
+
+    /// Global token configuration.
+    pub token_state: Account<'info, TokenState>,
+
+    /// SPL Token program.
+    pub token_program: Program<'info, Token>,
+}
+
+
+#[derive(Accounts)]
+pub struct FeeDistributionContext<'info> {
+    pub token_state: Account<'info, TokenState>,
+
+    /// CHECK: Token program passed as generic account, validated at runtime.
+    pub token_program: AccountInfo<'info>,
+
+    /// CHECK: Passed as generic token account reference. Validation occurs during CPI.
+    pub source_token_account: AccountInfo<'info>,
+
+    /// CHECK: Rewards vault ATA used in token transfer logic; validated in instruction.
+    pub rewards_vault_token_account: AccountInfo<'info>,
+
+    /// CHECK: Rewards vault ledger; posted to in `distribute_fees` once opened.
+    pub rewards_ledger: AccountInfo<'info>,
+
+    /// CHECK: Airdrop vault ATA used in token transfer logic; validated in instruction.
     pub airdrop_vault_token_account: AccountInfo<'info>,
 
-    /// CHECK: Revenue vault ATA used in token transfer logic; validated in instruction.
-    pub revenue_vault_token_account: AccountInfo<'info>,
+    /// CHECK: Airdrop vault ledger; posted to in `distribute_fees` once opened.
+    pub airdrop_ledger: AccountInfo<'info>,
+
+    /// CHECK: Revenue vault ATA used in token transfer logic; validated in instruction.
+    pub revenue_vault_token_account: AccountInfo<'info>,
+
+    /// CHECK: Revenue vault ledger; posted to in `distribute_fees` once opened.
+    pub revenue_ledger: AccountInfo<'info>,
+
+    /// CHECK: Authority passed as signer and checked at runtime.
+    pub authority: AccountInfo<'info>,
+}
+
+#[derive(Accounts)]
+pub struct QuotePaymentContext<'info> {
+    pub price_config: Account<'info, PriceConfig>,
+
+    /// CHECK: Buyer paying the quote amount; signer checked at runtime.
+    pub buyer: AccountInfo<'info>,
+
+    /// CHECK: Quote treasury PDA; validated in instruction.
+    pub quote_treasury: AccountInfo<'info>,
+
+    pub buyer_quote_account: Option<Account<'info, TokenAccount>>,
+
+    pub quote_treasury_token_account: Option<Account<'info, TokenAccount>>,
+
+    /// CHECK: System program passed as generic account, validated at runtime.
+    pub system_program: AccountInfo<'info>,
+
+    /// CHECK: Token program passed as generic account, validated at runtime.
+    pub token_program: AccountInfo<'info>,
+}
+
+#[derive(Accounts)]
+pub struct PurchaseTokensContext<'info> {
+    // ─────────────────────────────────────────────────────────────
+    // Signer
+    // ─────────────────────────────────────────────────────────────
+
+    /// The buyer paying for the purchase in the quote currency.
+    #[account(mut)]
+    pub caller: Signer<'info>,
+
+    /// Token account owned by the buyer, receives purchased tokens.
+    #[account(
+        mut,
+        associated_token::mint = token_mint,
+        associated_token::authority = caller
+    )]
+    pub buyer_token_account: Account<'info, TokenAccount>,
+
+    // ------------------------------------------------------------------------
+    // Pricing & Quote Treasury
+    // ------------------------------------------------------------------------
+
+    /// Governance-managed price configuration.
+    #[account(seeds = [b"price_config"], bump)]
+    pub price_config: Account<'info, PriceConfig>,
+
+    /// Sale state holding the bonding curve and tokens sold so far.
+    #[account(
+        mut,
+        seeds = [b"sale_state"],
+        bump
+    )]
+    pub sale_state: Account<'info, SaleState>,
+
+    /// TWAP accumulator of the pricing currency, updated with this trade.
+    #[account(
+        init_if_needed,
+        payer = caller,
+        space = PriceAccumulator::LEN,
+        seeds = [b"price_accumulator", price_config.quote_mint.as_ref()],
+        bump
+    )]
+    pub price_accumulator: Account<'info, PriceAccumulator>,
+
+    /// CHECK: PDA holding SOL proceeds and owning the quote treasury ATA.
+    #[account(
+        mut,
+        seeds = [b"quote_treasury"],
+        bump
+    )]
+    pub quote_treasury: AccountInfo<'info>,
+
+    /// Buyer's quote token account (required when paying with an SPL mint).
+    #[account(mut)]
+    pub buyer_quote_account: Option<Account<'info, TokenAccount>>,
+
+    /// Quote treasury ATA receiving SPL proceeds (required when paying with an SPL mint).
+    #[account(mut)]
+    pub quote_treasury_token_account: Option<Account<'info, TokenAccount>>,
+
+    // ------------------------------------------------------------------------
+    // Liquidity Vault
+    // ------------------------------------------------------------------------
+
+    /// CHECK: PDA authority for liquidity vault operations. Verified in handler.
+    #[account(seeds = [b"liquidity_vault"], bump)]
+    pub liquidity_vault: AccountInfo<'info>,
+
+    /// CHECK: Outflow limits PDA of `liquidity_vault`. Enforced in logic once configured.
+    #[account(
+        mut,
+        seeds = [b"vault_limits", liquidity_vault.key().as_ref()],
+        bump
+    )]
+    pub vault_limits: AccountInfo<'info>,
+
+    /// CHECK: Ledger PDA of `liquidity_vault`. Posted to in logic once opened.
+    #[account(
+        mut,
+        seeds = [b"vault_ledger", liquidity_vault.key().as_ref()],
+        bump
+    )]
+    pub liquidity_ledger: AccountInfo<'info>,
+
+    /// Token account holding tokens available for purchase.
+    #[account(
+        mut,
+        associated_token::mint = token_mint,
+        associated_token::authority = liquidity_vault
+    )]
+    pub liquidity_vault_token_account: Account<'info, TokenAccount>,
+
+    // ------------------------------------------------------------------------
+    // Fee Vaults
+    // ------------------------------------------------------------------------
+
+    /// CHECK: PDA authority for rewards logic. Verified in handler.
+    #[account(seeds = [b"rewards_vault"], bump)]
+    pub rewards_vault: AccountInfo<'info>,
+
+    /// Rewards vault's token account.
+    #[account(
+        mut,
+        associated_token::mint = token_mint,
+        associated_token::authority = rewards_vault
+    )]
+    pub rewards_vault_token_account: Account<'info, TokenAccount>,
+
+    /// CHECK: Ledger PDA of `rewards_vault`. Posted to in logic once opened.
+    #[account(
+        mut,
+        seeds = [b"vault_ledger", rewards_vault.key().as_ref()],
+        bump
+    )]
+    pub rewards_ledger: AccountInfo<'info>,
+
+    /// CHECK: PDA authority for revenue logic. Verified in handler.
+    #[account(seeds = [b"revenue_vault"], bump)]
+    pub revenue_vault: AccountInfo<'info>,
+
+    /// Revenue vault's token account.
+    #[account(
+        mut,
+        associated_token::mint = token_mint,
+        associated_token::authority = revenue_vault
+    )]
+    pub revenue_vault_token_account: Account<'info, TokenAccount>,
+
+    /// CHECK: Ledger PDA of `revenue_vault`. Posted to in logic once opened.
+    #[account(
+        mut,
+        seeds = [b"vault_ledger", revenue_vault.key().as_ref()],
+        bump
+    )]
+    pub revenue_ledger: AccountInfo<'info>,
+
+    /// CHECK: PDA authority for airdrop logic. Verified in handler.
+    #[account(seeds = [b"airdrop_vault"], bump)]
+    pub airdrop_vault: AccountInfo<'info>,
+
+    /// Airdrop vault's token account.
+    #[account(
+        mut,
+        associated_token::mint = token_mint,
+        associated_token::authority = airdrop_vault
+    )]
+    pub airdrop_vault_token_account: Account<'info, TokenAccount>,
+
+    /// CHECK: Ledger PDA of `airdrop_vault`. Posted to in logic once opened.
+    #[account(
+        mut,
+        seeds = [b"vault_ledger", airdrop_vault.key().as_ref()],
+        bump
+    )]
+    pub airdrop_ledger: AccountInfo<'info>,
+
+    // ─────────────────────────────────────────────────────────────
+    // Referral
+    // ─────────────────────────────────────────────────────────────
+
+    /// CHECK: Referral PDA of the buyer, always required. Empty when the buyer
+    /// has no referrer; validated and credited in logic otherwise.
+    #[account(
+        mut,
+        seeds = [b"referral", caller.key().as_ref()],
+        bump
+    )]
+    pub referral: AccountInfo<'info>,
+
+    /// CHECK: `ReferrerAccount` of the referral's referrer, validated in logic.
+    /// Ignored when `referral` is empty; clients then pass the buyer's own
+    /// `referrer` PDA.
+    #[account(mut)]
+    pub referrer_account: AccountInfo<'info>,
+
+    // ─────────────────────────────────────────────────────────────
+    // Mint, Access Control & State
+    // ─────────────────────────────────────────────────────────────
+
+    /// Token mint (SCTK).
+    pub token_mint: Account<'info, Mint>,
+
+    /// Optional access control account for the caller.
+    #[account(
+        seeds = [b"user_access", caller.key().as_ref()],
+        bump
+    )]
+    pub user_access: Option<Account<'info, UserAccessAccount>>,
+
+    /// TokenState account for system-wide configuration.
+    #[account(mut)]
+    pub token_state: Account<'info, TokenState>,
+
+    // ─────────────────────────────────────────────────────────────
+    // Programs
+    // ─────────────────────────────────────────────────────────────
+
+    /// Token program required for token transfers.
+    pub token_program: Program<'info, Token>,
+
+    /// System program used for SOL payments.
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct SellTokensContext<'info> {
+    // ─────────────────────────────────────────────────────────────
+    // Signer
+    // ─────────────────────────────────────────────────────────────
+
+    /// The holder selling SCTK back to the protocol.
+    #[account(mut)]
+    pub caller: Signer<'info>,
+
+    /// Token account owned by the seller, source of the sold tokens.
+    #[account(
+        mut,
+        associated_token::mint = token_mint,
+        associated_token::authority = caller
+    )]
+    pub seller_token_account: Account<'info, TokenAccount>,
+
+    // ------------------------------------------------------------------------
+    // Pricing & Quote Treasury
+    // ------------------------------------------------------------------------
+
+    /// Governance-managed price configuration (tracks the buyback epoch).
+    #[account(mut, seeds = [b"price_config"], bump)]
+    pub price_config: Account<'info, PriceConfig>,
+
+    /// TWAP accumulator of the pricing currency, updated with this trade.
+    #[account(
+        init_if_needed,
+        payer = caller,
+        space = PriceAccumulator::LEN,
+        seeds = [b"price_accumulator", price_config.quote_mint.as_ref()],
+        bump
+    )]
+    pub price_accumulator: Account<'info, PriceAccumulator>,
+
+    /// CHECK: PDA holding SOL proceeds and owning the quote treasury ATA.
+    #[account(
+        mut,
+        seeds = [b"quote_treasury"],
+        bump
+    )]
+    pub quote_treasury: AccountInfo<'info>,
+
+    /// Seller's quote token account (required when paid in an SPL mint).
+    #[account(mut)]
+    pub seller_quote_account: Option<Account<'info, TokenAccount>>,
+
+    /// Quote treasury ATA funding the payout (required when paid in an SPL mint).
+    #[account(mut)]
+    pub quote_treasury_token_account: Option<Account<'info, TokenAccount>>,
+
+    // ------------------------------------------------------------------------
+    // Liquidity Vault
+    // ------------------------------------------------------------------------
+
+    /// CHECK: PDA authority for liquidity vault operations. Verified in handler.
+    #[account(seeds = [b"liquidity_vault"], bump)]
+    pub liquidity_vault: AccountInfo<'info>,
+
+    /// CHECK: Ledger PDA of `liquidity_vault`. Posted to in logic once opened.
+    #[account(
+        mut,
+        seeds = [b"vault_ledger", liquidity_vault.key().as_ref()],
+        bump
+    )]
+    pub liquidity_ledger: AccountInfo<'info>,
+
+    /// Token account receiving the tokens bought back.
+    #[account(
+        mut,
+        associated_token::mint = token_mint,
+        associated_token::authority = liquidity_vault
+    )]
+    pub liquidity_vault_token_account: Account<'info, TokenAccount>,
+
+    // ------------------------------------------------------------------------
+    // Fee Vaults
+    // ------------------------------------------------------------------------
+
+    /// CHECK: PDA authority for rewards logic. Verified in handler.
+    #[account(seeds = [b"rewards_vault"], bump)]
+    pub rewards_vault: AccountInfo<'info>,
+
+    /// Rewards vault's token account.
+    #[account(
+        mut,
+        associated_token::mint = token_mint,
+        associated_token::authority = rewards_vault
+    )]
+    pub rewards_vault_token_account: Account<'info, TokenAccount>,
+
+    /// CHECK: Ledger PDA of `rewards_vault`. Posted to in logic once opened.
+    #[account(
+        mut,
+        seeds = [b"vault_ledger", rewards_vault.key().as_ref()],
+        bump
+    )]
+    pub rewards_ledger: AccountInfo<'info>,
+
+    /// CHECK: PDA authority for revenue logic. Verified in handler.
+    #[account(seeds = [b"revenue_vault"], bump)]
+    pub revenue_vault: AccountInfo<'info>,
+
+    /// Revenue vault's token account.
+    #[account(
+        mut,
+        associated_token::mint = token_mint,
+        associated_token::authority = revenue_vault
+    )]
+    pub revenue_vault_token_account: Account<'info, TokenAccount>,
+
+    /// CHECK: Ledger PDA of `revenue_vault`. Posted to in logic once opened.
+    #[account(
+        mut,
+        seeds = [b"vault_ledger", revenue_vault.key().as_ref()],
+        bump
+    )]
+    pub revenue_ledger: AccountInfo<'info>,
+
+    /// CHECK: PDA authority for airdrop logic. Verified in handler.
+    #[account(seeds = [b"airdrop_vault"], bump)]
+    pub airdrop_vault: AccountInfo<'info>,
+
+    /// Airdrop vault's token account.
+    #[account(
+        mut,
+        associated_token::mint = token_mint,
+        associated_token::authority = airdrop_vault
+    )]
+    pub airdrop_vault_token_account: Account<'info, TokenAccount>,
+
+    /// CHECK: Ledger PDA of `airdrop_vault`. Posted to in logic once opened.
+    #[account(
+        mut,
+        seeds = [b"vault_ledger", airdrop_vault.key().as_ref()],
+        bump
+    )]
+    pub airdrop_ledger: AccountInfo<'info>,
+
+    // ─────────────────────────────────────────────────────────────
+    // Mint, Access Control & State
+    // ─────────────────────────────────────────────────────────────
+
+    /// Token mint (SCTK).
+    pub token_mint: Account<'info, Mint>,
+
+    /// Optional access control account for the caller.
+    #[account(
+        seeds = [b"user_access", caller.key().as_ref()],
+        bump
+    )]
+    pub user_access: Option<Account<'info, UserAccessAccount>>,
+
+    /// TokenState account for system-wide configuration.
+    pub token_state: Account<'info, TokenState>,
+
+    // ─────────────────────────────────────────────────────────────
+    // Programs
+    // ─────────────────────────────────────────────────────────────
+
+    /// Token program required for token transfers.
+    pub token_program: Program<'info, Token>,
+
+    /// System program used for SOL payouts.
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct ManagePriceConfig<'info> {
+    // ─────────────────────────────────────────────────────────────
+    // Signer
+    // ─────────────────────────────────────────────────────────────
+
+    /// The caller updating the price configuration via governance.
+    #[account(mut)]
+    pub caller: Signer<'info>,
+
+    // ─────────────────────────────────────────────────────────────
+    // State
+    // ─────────────────────────────────────────────────────────────
+
+    /// Price configuration, created on first update.
+    #[account(
+        init_if_needed,
+        payer = caller,
+        space = PriceConfig::LEN,
+        seeds = [b"price_config"],
+        bump
+    )]
+    pub price_config: Account<'info, PriceConfig>,
+
+    /// Sale state, checked so the sell price stays below the curve.
+    #[account(
+        init_if_needed,
+        payer = caller,
+        space = SaleState::LEN,
+        seeds = [b"sale_state"],
+        bump
+    )]
+    pub sale_state: Account<'info, SaleState>,
+
+    /// TokenState account for system-wide configuration.
+    #[account(seeds = [b"token_state"], bump)]
+    pub token_state: Account<'info, TokenState>,
+
+    /// Governance proposal approval needed for the instruction.
+    #[account(mut)]
+    pub proposal: Account<'info, ProposalAccount>,
+
+    /// Governance state account (quorum config, etc).
+    pub governance_state: Account<'info, GovernanceState>,
+
+    // ─────────────────────────────────────────────────────────────
+    // Access Control
+    // ─────────────────────────────────────────────────────────────
+
+    /// Optional access control account for the caller.
+    #[account(
+        seeds = [b"user_access", caller.key().as_ref()],
+        bump
+    )]
+    pub user_access: Option<Account<'info, UserAccessAccount>>,
+
+    // ─────────────────────────────────────────────────────────────
+    // Programs
+    // ─────────────────────────────────────────────────────────────
+
+    /// System program for account creation.
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct ManageSaleState<'info> {
+    // ─────────────────────────────────────────────────────────────
+    // Signer
+    // ─────────────────────────────────────────────────────────────
+
+    /// The caller updating the bonding curve via governance.
+    #[account(mut)]
+    pub caller: Signer<'info>,
 
-    /// CHECK: Authority passed as signer and checked at runtime.
-    pub authority: AccountInfo<'info>,
+    // ─────────────────────────────────────────────────────────────
+    // State
+    // ─────────────────────────────────────────────────────────────
+
+    /// Sale state, created by `initialize_token` (or here for older deployments).
+    #[account(
+        init_if_needed,
+        payer = caller,
+        space = SaleState::LEN,
+        seeds = [b"sale_state"],
+        bump
+    )]
+    pub sale_state: Account<'info, SaleState>,
+
+    /// Price configuration, checked so the curve stays above the sell price.
+    #[account(
+        init_if_needed,
+        payer = caller,
+        space = PriceConfig::LEN,
+        seeds = [b"price_config"],
+        bump
+    )]
+    pub price_config: Account<'info, PriceConfig>,
+
+    /// TokenState account for system-wide configuration.
+    #[account(seeds = [b"token_state"], bump)]
+    pub token_state: Account<'info, TokenState>,
+
+    /// Governance proposal approval needed for the instruction.
+    #[account(mut)]
+    pub proposal: Account<'info, ProposalAccount>,
+
+    /// Governance state account (quorum config, etc).
+    pub governance_state: Account<'info, GovernanceState>,
+
+    // ─────────────────────────────────────────────────────────────
+    // Access Control
+    // ─────────────────────────────────────────────────────────────
+
+    /// Optional access control account for the caller.
+    #[account(
+        seeds = [b"user_access", caller.key().as_ref()],
+        bump
+    )]
+    pub user_access: Option<Account<'info, UserAccessAccount>>,
+
+    // ─────────────────────────────────────────────────────────────
+    // Programs
+    // ─────────────────────────────────────────────────────────────
+
+    /// System program for account creation.
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct CreditOffchainWalletContext<'info> {
+    // ─────────────────────────────────────────────────────────────
+    // Signer
+    // ─────────────────────────────────────────────────────────────
+
+    /// The holder moving SCTK into their Soccial Wallet.
+    #[account(mut)]
+    pub caller: Signer<'info>,
+
+    /// Token account owned by the holder, source of the credited tokens.
+    #[account(
+        mut,
+        associated_token::mint = token_mint,
+        associated_token::authority = caller
+    )]
+    pub source_token_account: Account<'info, TokenAccount>,
+
+    // ------------------------------------------------------------------------
+    // Offchain Reserve Vault (destination of tokens)
+    // ------------------------------------------------------------------------
+
+    /// CHECK: PDA authority for offchain reserve operations.
+    #[account(seeds = [b"offchain_reserve_vault"], bump)]
+    pub offchain_reserve_vault: AccountInfo<'info>,
+
+    /// CHECK: Ledger PDA of `offchain_reserve_vault`. Posted to in logic once opened.
+    #[account(
+        mut,
+        seeds = [b"vault_ledger", offchain_reserve_vault.key().as_ref()],
+        bump
+    )]
+    pub offchain_reserve_ledger: AccountInfo<'info>,
+
+    /// Offchain reserve vault's token account.
+    #[account(
+        mut,
+        associated_token::mint = token_mint,
+        associated_token::authority = offchain_reserve_vault
+    )]
+    pub offchain_reserve_vault_token_account: Account<'info, TokenAccount>,
+
+    // ------------------------------------------------------------------------
+    // Credit Ledger & Receipt
+    // ------------------------------------------------------------------------
+
+    /// Caller's credit sequence, created on first use.
+    #[account(
+        init_if_needed,
+        payer = caller,
+        space = CreditLedger::LEN,
+        seeds = [b"credit_ledger", caller.key().as_ref()],
+        bump
+    )]
+    pub credit_ledger: Account<'info, CreditLedger>,
+
+    /// Receipt for this credit, seeded by the caller and their next sequence number.
+    #[account(
+        init,
+        payer = caller,
+        space = CreditReceipt::LEN,
+        seeds = [
+            b"credit_receipt",
+            caller.key().as_ref(),
+            credit_ledger.last_sequence.to_le_bytes().as_ref()
+        ],
+        bump
+    )]
+    pub credit_receipt: Account<'info, CreditReceipt>,
+
+    // ─────────────────────────────────────────────────────────────
+    // Mint, Access Control & State
+    // ─────────────────────────────────────────────────────────────
+
+    /// Token mint (SCTK).
+    pub token_mint: Account<'info, Mint>,
+
+    /// Optional access control account for the caller.
+    #[account(
+        seeds = [b"user_access", caller.key().as_ref()],
+        bump
+    )]
+    pub user_access: Option<Account<'info, UserAccessAccount>>,
+
+    /// TokenState account for system-wide configuration.
+    pub token_state: Account<'info, TokenState>,
+
+    // ─────────────────────────────────────────────────────────────
+    // Programs
+    // ─────────────────────────────────────────────────────────────
+
+    /// Token program required for token transfers.
+    pub token_program: Program<'info, Token>,
+
+    /// System program required to create the ledger and receipt.
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct BatchTransferTokensContext<'info> {
+    /// The caller initiating the batch (must be authorized).
+    pub caller: Signer<'info>,
+
+    // ------------------------------------------------------------------------
+    // Sender
+    // ------------------------------------------------------------------------
+
+    /// Token account of the sender (must match mint and authority).
+    #[account(
+        mut,
+        associated_token::mint = token_mint,
+        associated_token::authority = sender
+    )]
+    pub sender_token_account: Account<'info, TokenAccount>,
+
+    /// CHECK: The actual sender (must sign the transaction).
+    #[account(mut, signer)]
+    pub sender: AccountInfo<'info>,
+
+    // ------------------------------------------------------------------------
+    // Fee Vaults
+    // ------------------------------------------------------------------------
+
+    /// CHECK: PDA authority for rewards logic. Verified in handler.
+    #[account(seeds = [b"rewards_vault"], bump)]
+    pub rewards_vault: AccountInfo<'info>,
+
+    /// Rewards vault's token account.
+    #[account(
+        mut,
+        associated_token::mint = token_mint,
+        associated_token::authority = rewards_vault
+    )]
+    pub rewards_vault_token_account: Account<'info, TokenAccount>,
+
+    /// CHECK: Ledger PDA of `rewards_vault`. Posted to in logic once opened.
+    #[account(
+        mut,
+        seeds = [b"vault_ledger", rewards_vault.key().as_ref()],
+        bump
+    )]
+    pub rewards_ledger: AccountInfo<'info>,
+
+    /// CHECK: PDA authority for revenue logic. Verified in handler.
+    #[account(seeds = [b"revenue_vault"], bump)]
+    pub revenue_vault: AccountInfo<'info>,
+
+    /// Revenue vault's token account.
+    #[account(
+        mut,
+        associated_token::mint = token_mint,
+        associated_token::authority = revenue_vault
+    )]
+    pub revenue_vault_token_account: Account<'info, TokenAccount>,
+
+    /// CHECK: Ledger PDA of `revenue_vault`. Posted to in logic once opened.
+    #[account(
+        mut,
+        seeds = [b"vault_ledger", revenue_vault.key().as_ref()],
+        bump
+    )]
+    pub revenue_ledger: AccountInfo<'info>,
+
+    /// CHECK: PDA authority for airdrop logic. Verified in handler.
+    #[account(seeds = [b"airdrop_vault"], bump)]
+    pub airdrop_vault: AccountInfo<'info>,
+
+    /// Airdrop vault's token account.
+    #[account(
+        mut,
+        associated_token::mint = token_mint,
+        associated_token::authority = airdrop_vault
+    )]
+    pub airdrop_vault_token_account: Account<'info, TokenAccount>,
+
+    /// CHECK: Ledger PDA of `airdrop_vault`. Posted to in logic once opened.
+    #[account(
+        mut,
+        seeds = [b"vault_ledger", airdrop_vault.key().as_ref()],
+        bump
+    )]
+    pub airdrop_ledger: AccountInfo<'info>,
+
+    // ------------------------------------------------------------------------
+    // Referral
+    // ------------------------------------------------------------------------
+
+    /// CHECK: Referral PDA of the sender, always required. Empty when the sender
+    /// has no referrer; validated and credited in logic otherwise.
+    #[account(
+        mut,
+        seeds = [b"referral", sender.key().as_ref()],
+        bump
+    )]
+    pub referral: AccountInfo<'info>,
+
+    /// CHECK: `ReferrerAccount` of the referral's referrer, validated in logic.
+    /// Ignored when `referral` is empty; clients then pass the sender's own
+    /// `referrer` PDA.
+    #[account(mut)]
+    pub referrer_account: AccountInfo<'info>,
+
+    // ------------------------------------------------------------------------
+    // Misc
+    // ------------------------------------------------------------------------
+
+    /// Optional access control account for the caller.
+    #[account(
+        seeds = [b"user_access", caller.key().as_ref()],
+        bump
+    )]
+    pub user_access: Option<Account<'info, UserAccessAccount>>,
+
+    /// CHECK: `user_access` PDA of the sender, whose flags set the fee discount.
+    /// Always required; empty when the user never had flags assigned.
+    #[account(
+        seeds = [b"user_access", sender.key().as_ref()],
+        bump
+    )]
+    pub fee_payer_access: AccountInfo<'info>,
+
+    /// SPL Token mint (SCTK).
+    pub token_mint: Account<'info, Mint>,
+
+    /// Global token configuration.
+    pub token_state: Account<'info, TokenState>,
+
+    /// SPL Token program.
+    pub token_program: Program<'info, Token>,
+    // Recipient token accounts are passed as `remaining_accounts`,
+    // in the same order as the amounts.
+}
+
+#[derive(Accounts)]
+pub struct AddWithdrawalAddressContext<'info> {
+    /// The user registering a destination (pays for the book on first use).
+    #[account(mut)]
+    pub caller: Signer<'info>,
+
+    /// The caller's withdrawal address book, created on first use.
+    #[account(
+        init_if_needed,
+        payer = caller,
+        space = WithdrawalAddressBook::LEN,
+        seeds = [b"withdrawal_address_book", caller.key().as_ref()],
+        bump
+    )]
+    pub withdrawal_address_book: Account<'info, WithdrawalAddressBook>,
+
+    /// SCTK token account being registered.
+    #[account(token::mint = token_mint)]
+    pub destination_token_account: Account<'info, TokenAccount>,
+
+    /// Mint of the SCTK token.
+    #[account(seeds = [b"token_mint"], bump)]
+    pub token_mint: Account<'info, Mint>,
+
+    /// Optional access control account for the caller.
+    #[account(
+        seeds = [b"user_access", caller.key().as_ref()],
+        bump
+    )]
+    pub user_access: Option<Account<'info, UserAccessAccount>>,
+
+    /// TokenState account for system-wide configuration.
+    pub token_state: Account<'info, TokenState>,
+
+    /// System program required to create the address book.
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct RemoveWithdrawalAddressContext<'info> {
+    /// The user removing a destination.
+    pub caller: Signer<'info>,
+
+    /// The caller's withdrawal address book.
+    #[account(
+        mut,
+        seeds = [b"withdrawal_address_book", caller.key().as_ref()],
+        bump = withdrawal_address_book.bump
+    )]
+    pub withdrawal_address_book: Account<'info, WithdrawalAddressBook>,
+
+    /// Optional access control account for the caller.
+    #[account(
+        seeds = [b"user_access", caller.key().as_ref()],
+        bump
+    )]
+    pub user_access: Option<Account<'info, UserAccessAccount>>,
+
+    /// TokenState account for system-wide configuration.
+    pub token_state: Account<'info, TokenState>,
 }
@@ -0,0 +1,117 @@
+// ===========================================================================
+// Offchain Wallet Credit – Soccial Token (SCTK)
+// ---------------------------------------------------------------------------
+//
+// This module implements the reverse of `deposit_tokens`: a holder moves SCTK
+// from their SPL wallet into `offchain_reserve_vault` to be credited to their
+// Soccial Wallet balance.
+//
+// ---------------------------------------------------------------------------
+// ## Flow:
+// 1. Tokens move from the holder's ATA into the off-chain reserve vault
+// 2. A `CreditReceipt` is created with the holder's next `CreditLedger` sequence
+// 3. `OffchainWalletCredited` is emitted for the backend to consume once
+//
+// ---------------------------------------------------------------------------
+// ## Reconciliation:
+// - Sequence numbers are gap-free per holder, so the backend can detect missed events
+// - The receipt PDA is derived from the holder and sequence and can be re-read at any time
+// - Holders never share a sequence, so concurrent credits do not collide
+//
+// ---------------------------------------------------------------------------
+// Author: Paulo Rodrigues
+// Project: Soccial Token
+// Website: https://www.soccial.com/thetoken
+// License: MIT
+// ===========================================================================
+
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, Transfer};
+
+use crate::{
+    market::{context::*, error::MarketError, state::MAX_USER_REFERENCE_LEN},
+    utils::math::format_sctk,
+    vaults::{record_vault_flow, LedgerCategory, LedgerFlow},
+};
+
+#[event]
+pub struct OffchainWalletCredited {
+    pub sequence: u64,
+    pub user_reference: String,
+    pub owner: Pubkey,
+    pub amount: u64,
+    pub created_at: i64,
+}
+
+/// ===========================================================================
+/// Moves SCTK from the caller's SPL wallet into the off-chain reserve and
+/// records a sequenced `CreditReceipt` for the Soccial Wallet backend.
+///
+/// ## Behavior:
+/// - No fee is charged; the full amount is credited off-chain
+/// - Consumes the caller's `CreditLedger.last_sequence` and advances it by one
+/// - Posts a `Market` inflow to the off-chain reserve vault ledger
+///
+/// ## Errors:
+/// - `MarketError::InvalidAmount` on zero amount
+/// - `MarketError::InvalidUserReference` if the reference is empty or too long
+/// - `MarketError::InsufficientFunds` if the caller lacks SCTK
+/// ===========================================================================
+pub(crate) fn credit_offchain_wallet(
+    ctx: Context<CreditOffchainWalletContext>,
+    amount: u64,
+    user_reference: String,
+) -> Result<()> {
+    require!(amount > 0, MarketError::InvalidAmount);
+    require!(
+        !user_reference.is_empty() && user_reference.len() <= MAX_USER_REFERENCE_LEN,
+        MarketError::InvalidUserReference
+    );
+    require!(
+        ctx.accounts.source_token_account.amount >= amount,
+        MarketError::InsufficientFunds
+    );
+
+    let transfer_ctx = CpiContext::new(
+        ctx.accounts.token_program.to_account_info(),
+        Transfer {
+            from: ctx.accounts.source_token_account.to_account_info(),
+            to: ctx.accounts.offchain_reserve_vault_token_account.to_account_info(),
+            authority: ctx.accounts.caller.to_account_info(),
+        },
+    );
+    token::transfer(transfer_ctx, amount)?;
+    record_vault_flow(&ctx.accounts.offchain_reserve_ledger, LedgerFlow::Inflow, LedgerCategory::Market, amount)?;
+
+    let now = Clock::get()?.unix_timestamp;
+    let ledger = &mut ctx.accounts.credit_ledger;
+    let sequence = ledger.last_sequence;
+    ledger.last_sequence = sequence.checked_add(1).ok_or(MarketError::Overflow)?;
+    ledger.total_credited = ledger.total_credited.checked_add(amount).ok_or(MarketError::Overflow)?;
+
+    let receipt = &mut ctx.accounts.credit_receipt;
+    receipt.sequence = sequence;
+    receipt.user_reference = user_reference.clone();
+    receipt.owner = ctx.accounts.caller.key();
+    receipt.amount = amount;
+    receipt.created_at = now;
+    receipt.bump = ctx.bumps.credit_receipt;
+
+    msg!(
+        "🏦 Credited {} SCTK ({} units) to Soccial Wallet {} | 🧾 Sequence #{}",
+        format_sctk(amount),
+        amount,
+        user_reference,
+        sequence,
+    );
+
+    emit!(OffchainWalletCredited {
+        sequence,
+        user_reference,
+        owner: ctx.accounts.caller.key(),
+        amount,
+        created_at: now,
+    });
+
+    Ok(())
+}
@@ -32,4 +32,73 @@ pub enum MarketError {
     /// Arithmetic underflow occurred during a calculation (e.g., subtraction below zero).
     #[msg("Underflow occurred during calculation.")]
     Underflow,
+
+    /// Priced purchases are disabled or the price has not been configured.
+    #[msg("Priced purchases are currently disabled.")]
+    PricingDisabled,
+
+    /// The quote currency accounts do not match the configured quote mint.
+    #[msg("Invalid quote currency account.")]
+    InvalidQuoteAccount,
+
+    /// The sale would exceed the buyback cap of the current epoch.
+    #[msg("Buyback cap for the current epoch exceeded.")]
+    BuybackCapExceeded,
+
+    /// The bonding-curve price is above the caller's maximum accepted price.
+    #[msg("Sale price exceeds the maximum accepted price.")]
+    PriceAboveMaximum,
+
+    /// The backend request ID is empty or longer than `MAX_REQUEST_ID_LEN`.
+    #[msg("Invalid request ID.")]
+    InvalidRequestId,
+
+    /// The Soccial user reference is empty or longer than `MAX_USER_REFERENCE_LEN`.
+    #[msg("Invalid user reference.")]
+    InvalidUserReference,
+
+    /// The number of recipient accounts does not match the number of amounts,
+    /// or the batch is empty or above `MAX_BATCH_TRANSFER_LEGS`.
+    #[msg("Invalid batch: recipients and amounts must match and stay within the batch limit.")]
+    InvalidBatch,
+
+    /// A recipient account is not a token account for the SCTK mint.
+    #[msg("Invalid recipient token account.")]
+    InvalidRecipientAccount,
+
+    /// The signed quote has expired.
+    #[msg("Price quote has expired.")]
+    QuoteExpired,
+
+    /// No ed25519 verification of the quote precedes the purchase instruction.
+    #[msg("Missing signed price quote.")]
+    MissingQuoteSignature,
+
+    /// The quote was not signed by the API authority or does not match the purchase.
+    #[msg("Invalid price quote signature.")]
+    InvalidQuoteSignature,
+
+    /// The destination is not in the owner's withdrawal address book.
+    #[msg("Destination is not in the withdrawal address book.")]
+    AddressNotRegistered,
+
+    /// The destination was registered too recently to be used.
+    #[msg("Destination is still in its activation cooldown.")]
+    AddressInCooldown,
+
+    /// The destination is already in the withdrawal address book.
+    #[msg("Destination already registered.")]
+    AddressAlreadyRegistered,
+
+    /// The withdrawal address book has no free slot.
+    #[msg("Withdrawal address book is full.")]
+    AddressBookFull,
+
+    /// The address cooldown is outside the allowed range.
+    #[msg("Invalid address cooldown.")]
+    InvalidCooldown,
+
+    /// The buyback price is above the sale price.
+    #[msg("Sell price cannot exceed buy price.")]
+    InvalidPriceSpread,
 }
@@ -13,17 +13,20 @@
 //
 // ---------------------------------------------------------------------------
 // ## Fee Logic:
-// - All transfers may include a fee in BPS (basis points)
+// - Fees in BPS (basis points) come from the governed `FeeSchedule`
+// - Flagged callers (VIP, Staff, ...) get the governed `FeeDiscounts` reduction
 // - Fees are split between rewards, airdrop, and revenue vaults
 // - Vault distribution uses `TokenState.fee` config
+// - Referred buyers/senders credit a governed share to their referrer
 //
 // ---------------------------------------------------------------------------
 // ## Components:
 // - `buy_tokens()`: Mints tokens into user wallet after fiat purchase
-// - `deposit_tokens()`: Transfers tokens from offchain vault with optional fee
+// - `deposit_tokens()`: Transfers tokens from offchain vault, once per request ID
 // - `transfer_tokens()`: Allows direct user-to-user SCTK transfers with fee
 // - `distribute_fees()`: Handles vault routing of fees
-// - `calculate_fee()`: Computes net and fee portions using overflow-safe math
+// - `calculate_fee()`: Computes net and fee portions from the fee schedule
+// - `apply_fee_bps()`: Overflow-safe fee math for an explicit BPS rate
 //
 // ---------------------------------------------------------------------------
 // Author: Paulo Rodrigues  
@@ -36,7 +39,7 @@ use anchor_lang::prelude::*;
 use anchor_spl::token::{self, Transfer};
 
 use crate::{
-    economy::fee::{FEE_BPS_BASE, MAX_FEE_BPS}, market::{context::*, error::MarketError}, utils::math::format_sctk, vaults::{error::VaultError, resolve_vault_seeds, VaultAction}
+    auth::user::{load_user_access, UserAccessAccount}, economics::state::FeeOperation, economy::fee::{FEE_BPS_BASE, MAX_FEE_BPS}, market::{context::*, error::MarketError, state::MAX_REQUEST_ID_LEN}, referral::credit_referral_fee, token::state::TokenState, utils::math::format_sctk, vaults::{error::VaultError, record_vault_flow, record_vault_outflow, resolve_vault_seeds, LedgerCategory, LedgerFlow, VaultAction}
 };
 
 #[event]
@@ -48,10 +51,13 @@ pub struct TokensPurchased {
     pub to_rewards: u64,
     pub to_airdrop: u64,
     pub to_revenue: u64,
+    pub price: u64,
+    pub discount_bps: u16,
 }
 
 #[event]
 pub struct TokensWithdrawn {
+    pub request_id: String,
     pub recipient: Pubkey,
     pub amount: u64,
     pub net_received: u64,
@@ -59,6 +65,7 @@ pub struct TokensWithdrawn {
     pub to_rewards: u64,
     pub to_airdrop: u64,
     pub to_revenue: u64,
+    pub discount_bps: u16,
 }
 
 #[event]
@@ -71,15 +78,17 @@ pub struct TokensTransferred {
     pub to_rewards: u64,
     pub to_airdrop: u64,
     pub to_revenue: u64,
+    pub discount_bps: u16,
 }
 
 
 /// ===========================================================================
 /// Purchases tokens from the liquidity vault and transfers them to the buyer,
-/// deducting a fee (in BPS) and distributing it across vaults.
+/// deducting the scheduled buy fee and distributing it across vaults.
 ///
 /// ## Source:
 /// - Liquidity Vault (PDA)
+/// - Net (`Market`) and fee (`Fees`) outflows are posted to its ledger
 ///
 /// ## Accounts:
 /// - buyer_token_account: Destination for net amount
@@ -87,27 +96,53 @@ pub struct TokensTransferred {
 /// - rewards/airdrop/revenue vaults: Fee receivers
 ///
 /// ## Fee Behavior:
-/// - Calculated using `calculate_fee`
+/// - Calculated using `calculate_fee` with `FeeOperation::Buy`
 /// - Distributed using `distribute_fees`
 ///
+/// ## Pricing:
+/// - Realized price comes from the `SaleState` bonding curve
+/// - Purchase is rejected if it exceeds the caller's `max_price`
+/// - The gross amount is recorded as sold, moving the curve forward
+/// - Payment is settled off-chain, so the price is not recorded in any TWAP
+///
 /// ## Errors:
 /// - `VaultError::InsufficientVaultBalance` if vault has insufficient funds
-/// - `MarketError::FeeTooHigh` if fee exceeds limit
+/// - `MarketError::FeeTooHigh` if the scheduled fee exceeds limit
+/// - `MarketError::PriceAboveMaximum` if the curve price exceeds `max_price`
+/// - `VaultError::EpochOutflowExceeded` if the vault's epoch outflow cap is reached
 /// ===========================================================================
 pub(crate) fn buy_tokens(
     ctx: Context<BuyTokensContext>, 
     amount: u64, 
-    fee_bps: u16,
+    max_price: u64,
 ) -> Result<()> {
     require!(amount > 0, VaultError::InvalidVaultAmount);
-    require!(fee_bps <= MAX_FEE_BPS, MarketError::FeeTooHigh);
     require!(
         ctx.accounts.liquidity_vault_token_account.amount >= amount,
         VaultError::InsufficientVaultBalance
     );
 
-    // Calculate net and fee portions
-    let (net_amount, fee_amount) = calculate_fee(amount, fee_bps)?;
+    // Price the sale on the bonding curve
+    let price = ctx.accounts.sale_state.price_for(amount)?;
+    require!(price <= max_price, MarketError::PriceAboveMaximum);
+    ctx.accounts.sale_state.record_sale(amount)?;
+
+    // Enforce the vault's epoch outflow limits (may trip the circuit breaker)
+    record_vault_outflow(
+        &ctx.accounts.vault_limits,
+        &mut ctx.accounts.token_state,
+        &ctx.accounts.caller.key(),
+        amount,
+    )?;
+
+    // Calculate net and fee portions from the fee schedule
+    let fee_payer_access = load_user_access(&ctx.accounts.fee_payer_access)?;
+    let (net_amount, fee_amount, discount_bps) = calculate_fee(
+        &ctx.accounts.token_state,
+        fee_payer_access.as_ref(),
+        FeeOperation::Buy,
+        amount,
+    )?;
 
     // Prepare signer seeds for PDA authority
     let signer_seeds: &[&[u8]] = &[b"liquidity_vault", &[ctx.bumps.liquidity_vault]];
@@ -132,25 +167,41 @@ pub(crate) fn buy_tokens(
             token_program: ctx.accounts.token_program.to_account_info(),
             source_token_account: ctx.accounts.liquidity_vault_token_account.to_account_info(),
             rewards_vault_token_account: ctx.accounts.rewards_vault_token_account.to_account_info(),
+            rewards_ledger: ctx.accounts.rewards_ledger.to_account_info(),
             airdrop_vault_token_account: ctx.accounts.airdrop_vault_token_account.to_account_info(),
+            airdrop_ledger: ctx.accounts.airdrop_ledger.to_account_info(),
             revenue_vault_token_account: ctx.accounts.revenue_vault_token_account.to_account_info(),
+            revenue_ledger: ctx.accounts.revenue_ledger.to_account_info(),
             authority: ctx.accounts.liquidity_vault.to_account_info(),
         },
         fee_amount,
         Some(signer_seeds_nested),
     )?;
+    record_vault_flow(&ctx.accounts.liquidity_ledger, LedgerFlow::Outflow, LedgerCategory::Market, net_amount)?;
+    record_vault_flow(&ctx.accounts.liquidity_ledger, LedgerFlow::Outflow, LedgerCategory::Fees, fee_amount)?;
+
+    // Credit the buyer's referrer, if any
+    credit_referral_fee(
+        &ctx.accounts.token_state,
+        &ctx.accounts.referral,
+        &ctx.accounts.referrer_account,
+        fee_amount,
+        to_revenue,
+    )?;
 
     // Log transaction
     msg!(
-        "🛒 User purchased {} SCTK ({} units) from liquidity vault → {} | 📈 Fee: {} SCTK ({} units → {} to revenue, {} to rewards, {} to airdrop)",
+        "🛒 User purchased {} SCTK ({} units) from liquidity vault → {} at price {} | 📈 Fee: {} SCTK ({} units → {} to revenue, {} to rewards, {} to airdrop) | 🏷️ Discount: {} BPS",
         format_sctk(net_amount),
         net_amount,
         ctx.accounts.buyer_token_account.key(),
+        price,
         format_sctk(fee_amount),
         fee_amount,
         to_revenue,
         to_rewards,
         to_airdrop,
+        discount_bps,
     );
 
     emit!(TokensPurchased {
@@ -161,6 +212,8 @@ pub(crate) fn buy_tokens(
         to_rewards,
         to_airdrop,
         to_revenue,
+        price,
+        discount_bps,
     });
 
 
@@ -170,39 +223,70 @@ pub(crate) fn buy_tokens(
 
 /// ===========================================================================
 /// Withdraws tokens from the offchain reserve vault into a user wallet,
-/// with optional fee logic and vault-based fee distribution.
+/// charging the scheduled withdrawal fee and distributing it across vaults.
 ///
 /// ## Source:
 /// - Offchain Reserve Vault (PDA)
 ///
 /// ## Behavior:
 /// - Checks vault balance
-/// - Applies fee via `calculate_fee`
+/// - Requires the destination to be active in the `WithdrawalAddressBook`
+/// - Applies fee via `calculate_fee` with `FeeOperation::OffchainWithdrawal`
 /// - Sends net to user, routes fee to vaults
+/// - Records a `WithdrawalReceipt` keyed by the backend `request_id`
+/// - Posts the net (`Market`) and fee (`Fees`) outflows to the reserve vault ledger
 ///
 /// ## Use Case:
 /// - Fiat ramp bridge to SPL token via custodial backend
 ///
 /// ## Errors:
 /// - `VaultError::InsufficientVaultBalance`
+/// - `MarketError::InvalidRequestId` if the request ID is empty or too long
+/// - `MarketError::AddressNotRegistered` if the destination is not in the book
+/// - `MarketError::AddressInCooldown` if the destination is not active yet
 /// - `MarketError::*` for fee or amount issues
+/// - Account creation fails if the request ID was already processed
+/// - `VaultError::EpochOutflowExceeded` if the vault's epoch outflow cap is reached
 /// ===========================================================================
 pub(crate) fn deposit_tokens(
     ctx: Context<DepositTokensContext>,
     amount: u64,
-    fee_bps: u16,
+    request_id: String,
 ) -> Result<()> {
     require!(amount > 0, MarketError::InvalidAmount);
-    require!(fee_bps <= MAX_FEE_BPS, MarketError::FeeTooHigh);
-
-   
+    require!(
+        !request_id.is_empty() && request_id.len() <= MAX_REQUEST_ID_LEN,
+        MarketError::InvalidRequestId
+    );
 
     require!(
         ctx.accounts.offchain_reserve_vault_token_account.amount >= amount,
         VaultError::InsufficientVaultBalance
     );
 
-    let (net_amount, fee_amount) = calculate_fee(amount, fee_bps)?;
+    // Only pay into destinations the user registered and that are past cooldown
+    let address_book = ctx
+        .accounts
+        .withdrawal_address_book
+        .as_ref()
+        .ok_or(MarketError::AddressNotRegistered)?;
+    address_book.require_active(&ctx.accounts.destination_token_account.key(), Clock::get()?.unix_timestamp)?;
+
+    // Enforce the vault's epoch outflow limits (may trip the circuit breaker)
+    record_vault_outflow(
+        &ctx.accounts.vault_limits,
+        &mut ctx.accounts.token_state,
+        &ctx.accounts.caller.key(),
+        amount,
+    )?;
+
+    let fee_payer_access = load_user_access(&ctx.accounts.fee_payer_access)?;
+    let (net_amount, fee_amount, discount_bps) = calculate_fee(
+        &ctx.accounts.token_state,
+        fee_payer_access.as_ref(),
+        FeeOperation::OffchainWithdrawal,
+        amount,
+    )?;
 
     let (seed, bump) =
         resolve_vault_seeds(&ctx.accounts.offchain_reserve_vault, VaultAction::Operation)?;
@@ -230,13 +314,18 @@ pub(crate) fn deposit_tokens(
             token_program: ctx.accounts.token_program.to_account_info(),
             source_token_account: ctx.accounts.offchain_reserve_vault_token_account.to_account_info(),
             rewards_vault_token_account: ctx.accounts.rewards_vault_token_account.to_account_info(),
+            rewards_ledger: ctx.accounts.rewards_ledger.to_account_info(),
             airdrop_vault_token_account: ctx.accounts.airdrop_vault_token_account.to_account_info(),
+            airdrop_ledger: ctx.accounts.airdrop_ledger.to_account_info(),
             revenue_vault_token_account: ctx.accounts.revenue_vault_token_account.to_account_info(),
+            revenue_ledger: ctx.accounts.revenue_ledger.to_account_info(),
             authority: ctx.accounts.offchain_reserve_vault.to_account_info(),
         },
         fee_amount,
         Some(signer_seeds_nested),
     )?;
+    record_vault_flow(&ctx.accounts.offchain_reserve_ledger, LedgerFlow::Outflow, LedgerCategory::Market, net_amount)?;
+    record_vault_flow(&ctx.accounts.offchain_reserve_ledger, LedgerFlow::Outflow, LedgerCategory::Fees, fee_amount)?;
 
    msg!(
         "💸 User withdrew {} SCTK ({} units) from offchain_reserve_vault to SPL wallet: {} | 📈 Fee: {} SCTK ({} units → {} to revenue, {} to rewards, {} to airdrop)",
@@ -250,7 +339,18 @@ pub(crate) fn deposit_tokens(
         to_airdrop,
     );
 
+    // Record the receipt so the same request cannot be replayed
+    let receipt = &mut ctx.accounts.withdrawal_receipt;
+    receipt.request_id = request_id.clone();
+    receipt.recipient = ctx.accounts.destination_token_account.owner;
+    receipt.destination = ctx.accounts.destination_token_account.key();
+    receipt.amount = amount;
+    receipt.fee_charged = fee_amount;
+    receipt.created_at = Clock::get()?.unix_timestamp;
+    receipt.bump = ctx.bumps.withdrawal_receipt;
+
     emit!(TokensWithdrawn {
+        request_id,
         recipient: ctx.accounts.destination_token_account.owner,
         amount,
         net_received: net_amount,
@@ -258,6 +358,7 @@ pub(crate) fn deposit_tokens(
         to_rewards,
         to_airdrop,
         to_revenue,
+        discount_bps,
     });
 
 
@@ -271,7 +372,7 @@ pub(crate) fn deposit_tokens(
 ///
 /// ## Use Cases:
 /// - Peer-to-peer tips and microtransactions
-/// - Custom fee can be set by dApp layer
+/// - Fee comes from the scheduled P2P transfer rate
 ///
 /// ## Fee Distribution:
 /// - Sender pays fee
@@ -281,17 +382,21 @@ pub(crate) fn deposit_tokens(
 /// - Uses user's authority as signer
 ///
 /// ## Errors:
-/// - Invalid transfer amount or excessive fee
+/// - Invalid transfer amount or excessive scheduled fee
 /// ===========================================================================
 pub(crate) fn transfer_tokens(
     ctx: Context<TransferTokensContext>,
     amount: u64,
-    fee_bps: u16,
 ) -> Result<()> {
     require!(amount > 0, MarketError::InvalidAmount);
-    require!(fee_bps <= MAX_FEE_BPS, MarketError::FeeTooHigh);
 
-    let (net_amount, fee_amount) = calculate_fee(amount, fee_bps)?;
+    let fee_payer_access = load_user_access(&ctx.accounts.fee_payer_access)?;
+    let (net_amount, fee_amount, discount_bps) = calculate_fee(
+        &ctx.accounts.token_state,
+        fee_payer_access.as_ref(),
+        FeeOperation::Transfer,
+        amount,
+    )?;
 
     let cpi_ctx_net = CpiContext::new(
         ctx.accounts.token_program.to_account_info(),
@@ -310,16 +415,27 @@ pub(crate) fn transfer_tokens(
             token_program: ctx.accounts.token_program.to_account_info(),
             source_token_account: ctx.accounts.sender_token_account.to_account_info(),
             rewards_vault_token_account: ctx.accounts.rewards_vault_token_account.to_account_info(),
+            rewards_ledger: ctx.accounts.rewards_ledger.to_account_info(),
             airdrop_vault_token_account: ctx.accounts.airdrop_vault_token_account.to_account_info(),
+            airdrop_ledger: ctx.accounts.airdrop_ledger.to_account_info(),
             revenue_vault_token_account: ctx.accounts.revenue_vault_token_account.to_account_info(),
+            revenue_ledger: ctx.accounts.revenue_ledger.to_account_info(),
             authority: ctx.accounts.sender.to_account_info(),
         },
         fee_amount,
         None,
     )?;
 
+    credit_referral_fee(
+        &ctx.accounts.token_state,
+        &ctx.accounts.referral,
+        &ctx.accounts.referrer_account,
+        fee_amount,
+        to_revenue,
+    )?;
+
     msg!(
-        "💸 User transferred {} SCTK ({} units) to {} | 📈 Fee: {} SCTK ({} units → {} to revenue, {} to rewards, {} to airdrop)",
+        "💸 User transferred {} SCTK ({} units) to {} | 📈 Fee: {} SCTK ({} units → {} to revenue, {} to rewards, {} to airdrop) | 🏷️ Discount: {} BPS",
         format_sctk(net_amount),
         net_amount,
         ctx.accounts.recipient_token_account.key(),
@@ -328,6 +444,7 @@ pub(crate) fn transfer_tokens(
         to_revenue,
         to_rewards,
         to_airdrop,
+        discount_bps,
     );
 
     emit!(TokensTransferred {
@@ -339,6 +456,7 @@ pub(crate) fn transfer_tokens(
         to_rewards,
         to_airdrop,
         to_revenue,
+        discount_bps,
     });
 
 
@@ -363,6 +481,7 @@ pub(crate) fn transfer_tokens(
 /// ## Behavior:
 /// - Early return if fee == 0
 /// - Transfers executed only for non-zero shares
+/// - Each share is posted as a `Fees` inflow to its vault ledger
 ///
 /// ## Errors:
 /// - Any transfer failure results in early exit
@@ -399,6 +518,7 @@ pub(crate) fn distribute_fees<'info>(
             ),
         };
         token::transfer(transfer_ctx, to_rewards)?;
+        record_vault_flow(&ctx.rewards_ledger, LedgerFlow::Inflow, LedgerCategory::Fees, to_rewards)?;
     }
 
     if to_airdrop > 0 {
@@ -422,6 +542,7 @@ pub(crate) fn distribute_fees<'info>(
             ),
         };
         token::transfer(transfer_ctx, to_airdrop)?;
+        record_vault_flow(&ctx.airdrop_ledger, LedgerFlow::Inflow, LedgerCategory::Fees, to_airdrop)?;
     }
 
     if to_revenue > 0 {
@@ -445,15 +566,52 @@ pub(crate) fn distribute_fees<'info>(
             ),
         };
         token::transfer(transfer_ctx, to_revenue)?;
+        record_vault_flow(&ctx.revenue_ledger, LedgerFlow::Inflow, LedgerCategory::Fees, to_revenue)?;
     }
 
     Ok((to_rewards, to_airdrop, to_revenue))
 }
 
 
+/// ===========================================================================
+/// Calculates the fee and net amount for a market operation, reading the
+/// fee rate from the governed `FeeSchedule` in `TokenState` and reducing it
+/// by the caller's best `FeeDiscounts` entry.
+///
+/// ## Inputs:
+/// - `token_state`: Global state holding the fee schedule and discounts
+/// - `user_access`: Fee payer's `UserAccessAccount`, if any (source of flags)
+/// - `operation`: Operation being charged (buy, withdrawal, transfer)
+/// - `amount`: Total token amount (in base units)
+///
+/// ## Returns:
+/// - (net_amount, fee_amount, discount_bps)
+///
+/// ## Errors:
+/// - Same as `apply_fee_bps`
+/// ===========================================================================
+pub(crate) fn calculate_fee(
+    token_state: &TokenState,
+    user_access: Option<&UserAccessAccount>,
+    operation: FeeOperation,
+    amount: u64,
+) -> Result<(u64, u64, u16)> {
+    let base_bps = token_state.fee_schedule.fee_bps_for(operation) as u64;
+    let discount_bps = token_state.fee_discounts.discount_bps_for_user(user_access);
+
+    let fee_bps = base_bps
+        .checked_mul((FEE_BPS_BASE - discount_bps) as u64)
+        .ok_or(MarketError::Overflow)?
+        / FEE_BPS_BASE as u64;
+
+    let (net, fee) = apply_fee_bps(amount, fee_bps as u16)?;
+    Ok((net, fee, discount_bps))
+}
+
 /// ===========================================================================
 /// Calculates the fee amount and net amount from a total using BPS (basis points),
-/// applying overflow-safe arithmetic throughout.
+/// applying overflow-safe arithmetic throughout. Used directly by operations
+/// that carry their own governed rate (priced purchases, buybacks, AMM swaps).
 ///
 /// ## Inputs:
 /// - `amount`: Total token amount (in base units)
@@ -468,12 +626,12 @@ pub(crate) fn distribute_fees<'info>(
 ///
 /// ## Example:
 
-/// let (net, fee) = calculate_fee(1_000_000, 300)?; // 3% fee
+/// let (net, fee) = apply_fee_bps(1_000_000, 300)?; // 3% fee
 /// assert_eq!(net, 970_000);
 /// assert_eq!(fee, 30_000);
 
 /// ===========================================================================
-pub(crate) fn calculate_fee(
+pub(crate) fn apply_fee_bps(
     amount: u64, 
     fee_bps: u16
 ) -> Result<(u64, u64)> {
@@ -1,7 +1,21 @@
 pub mod error;
 pub mod context;
 pub mod market;
+pub mod state;
+pub mod purchase;
+pub mod buyback;
+pub mod credit;
+pub mod batch;
+pub mod quote;
+pub mod address_book;
 
 pub use error::*;
 pub use context::*;
-pub use market::*;
\ No newline at end of file
+pub use market::*;
+pub use state::*;
+pub use purchase::*;
+pub use buyback::*;
+pub use credit::*;
+pub use batch::*;
+pub use quote::*;
+pub use address_book::*;
@@ -0,0 +1,270 @@
+// ===========================================================================
+// Priced Purchases – Soccial Token (SCTK)
+// ---------------------------------------------------------------------------
+//
+// This module implements the trust-minimized purchase flow, where the buyer
+// signs the transaction and pays for SCTK on-chain in SOL or in the quote
+// SPL mint configured in `PriceConfig`.
+//
+// ---------------------------------------------------------------------------
+// ## Flow:
+// 1. Quote amount is computed from the `SaleState` bonding curve, or from
+//    `PriceConfig.buy_price` while no curve is configured
+// 2. Buyer pays the quote amount into the program-owned quote treasury
+// 3. Liquidity vault releases the SCTK, net of the scheduled buy fee
+// 4. Fee is split via `distribute_fees` (rewards / airdrop / revenue)
+//
+// ---------------------------------------------------------------------------
+// ## Quote Treasury:
+// - PDA `quote_treasury` holds SOL proceeds directly (system-owned)
+// - For SPL quote mints, proceeds land in the ATA owned by `quote_treasury`
+//
+// ---------------------------------------------------------------------------
+// Author: Paulo Rodrigues
+// Project: Soccial Token
+// Website: https://www.soccial.com/thetoken
+// License: MIT
+// ===========================================================================
+
+use anchor_lang::{prelude::*, system_program};
+use anchor_spl::{associated_token::get_associated_token_address, token::{self, Transfer}};
+
+use crate::{
+    economics::state::FeeOperation,
+    market::{calculate_fee, context::*, distribute_fees, error::MarketError, state::PriceConfig, TokensPurchased},
+    referral::credit_referral_fee,
+    utils::math::format_sctk,
+    vaults::{error::VaultError, record_vault_flow, record_vault_outflow, LedgerCategory, LedgerFlow},
+};
+
+#[event]
+pub struct QuotePaymentReceived {
+    pub buyer: Pubkey,
+    pub quote_mint: Pubkey,
+    pub quote_amount: u64,
+    pub price: u64,
+    pub sctk_amount: u64,
+}
+
+/// ===========================================================================
+/// Purchases tokens from the liquidity vault, charging the buyer on-chain in
+/// the configured quote currency.
+///
+/// ## Behavior:
+/// - Requires `PriceConfig.enabled` and a non-zero price
+/// - Prices the purchase on the `SaleState` curve (flat `buy_price` while unset)
+/// - Purchase is rejected if the price exceeds the caller's `max_price`
+/// - Transfers SOL (system transfer) or SPL quote tokens into the quote treasury
+/// - Records the sale on the curve and the price in the quote mint's `PriceAccumulator`
+/// - Charges the scheduled buy fee via `calculate_fee` with `FeeOperation::Buy`
+/// - The buyer's `user_access` flags (e.g. VIP, Staff) discount the fee
+/// - Sends the net SCTK amount to the buyer and routes the fee to vaults
+/// - Posts the net (`Market`) and fee (`Fees`) outflows to the liquidity vault ledger
+/// - Credits the buyer's referrer, if any, from the buy fee
+///
+/// ## Errors:
+/// - `MarketError::PricingDisabled` if pricing is disabled or unset
+/// - `MarketError::PriceAboveMaximum` if the price exceeds `max_price`
+/// - `MarketError::FeeTooHigh` if the scheduled fee exceeds limit
+/// - `MarketError::InvalidQuoteAccount` if quote accounts are missing or mismatched
+/// - `VaultError::InsufficientVaultBalance` if liquidity is insufficient
+/// - `VaultError::EpochOutflowExceeded` if the vault's epoch outflow cap is reached
+/// ===========================================================================
+pub(crate) fn purchase_tokens(
+    ctx: Context<PurchaseTokensContext>,
+    amount: u64,
+    max_price: u64,
+) -> Result<()> {
+    require!(amount > 0, MarketError::InvalidAmount);
+
+    let price_config = &ctx.accounts.price_config;
+    let sale_state = &ctx.accounts.sale_state;
+    let price = if sale_state.is_configured() {
+        sale_state.price_for(amount)?
+    } else {
+        price_config.buy_price
+    };
+    require!(price_config.enabled && price > 0, MarketError::PricingDisabled);
+    require!(price <= max_price, MarketError::PriceAboveMaximum);
+    require!(
+        ctx.accounts.liquidity_vault_token_account.amount >= amount,
+        VaultError::InsufficientVaultBalance
+    );
+
+    let quote_amount = PriceConfig::quote_for(amount, price)?;
+    require!(quote_amount > 0, MarketError::InvalidAmount);
+
+    // Collect payment into the quote treasury
+    collect_quote_payment(
+        &QuotePaymentContext {
+            price_config: ctx.accounts.price_config.clone(),
+            buyer: ctx.accounts.caller.to_account_info(),
+            quote_treasury: ctx.accounts.quote_treasury.to_account_info(),
+            buyer_quote_account: ctx.accounts.buyer_quote_account.clone(),
+            quote_treasury_token_account: ctx.accounts.quote_treasury_token_account.clone(),
+            system_program: ctx.accounts.system_program.to_account_info(),
+            token_program: ctx.accounts.token_program.to_account_info(),
+        },
+        quote_amount,
+    )?;
+
+    ctx.accounts.sale_state.record_sale(amount)?;
+    ctx.accounts
+        .price_accumulator
+        .record(price, Clock::get()?.unix_timestamp)?;
+
+    // Enforce the vault's epoch outflow limits (may trip the circuit breaker)
+    record_vault_outflow(
+        &ctx.accounts.vault_limits,
+        &mut ctx.accounts.token_state,
+        &ctx.accounts.caller.key(),
+        amount,
+    )?;
+
+    // Calculate net and fee portions from the fee schedule
+    let (net_amount, fee_amount, discount_bps) = calculate_fee(
+        &ctx.accounts.token_state,
+        ctx.accounts.user_access.as_deref(),
+        FeeOperation::Buy,
+        amount,
+    )?;
+
+    let signer_seeds: &[&[u8]] = &[b"liquidity_vault", &[ctx.bumps.liquidity_vault]];
+    let signer_seeds_nested = &[signer_seeds];
+
+    // Transfer net amount to buyer
+    let transfer_ctx = CpiContext::new_with_signer(
+        ctx.accounts.token_program.to_account_info(),
+        Transfer {
+            from: ctx.accounts.liquidity_vault_token_account.to_account_info(),
+            to: ctx.accounts.buyer_token_account.to_account_info(),
+            authority: ctx.accounts.liquidity_vault.to_account_info(),
+        },
+        signer_seeds_nested,
+    );
+    token::transfer(transfer_ctx, net_amount)?;
+
+    // Distribute fee to vaults
+    let (to_rewards, to_airdrop, to_revenue) = distribute_fees(
+        &FeeDistributionContext {
+            token_state: ctx.accounts.token_state.clone(),
+            token_program: ctx.accounts.token_program.to_account_info(),
+            source_token_account: ctx.accounts.liquidity_vault_token_account.to_account_info(),
+            rewards_vault_token_account: ctx.accounts.rewards_vault_token_account.to_account_info(),
+            rewards_ledger: ctx.accounts.rewards_ledger.to_account_info(),
+            airdrop_vault_token_account: ctx.accounts.airdrop_vault_token_account.to_account_info(),
+            airdrop_ledger: ctx.accounts.airdrop_ledger.to_account_info(),
+            revenue_vault_token_account: ctx.accounts.revenue_vault_token_account.to_account_info(),
+            revenue_ledger: ctx.accounts.revenue_ledger.to_account_info(),
+            authority: ctx.accounts.liquidity_vault.to_account_info(),
+        },
+        fee_amount,
+        Some(signer_seeds_nested),
+    )?;
+    record_vault_flow(&ctx.accounts.liquidity_ledger, LedgerFlow::Outflow, LedgerCategory::Market, net_amount)?;
+    record_vault_flow(&ctx.accounts.liquidity_ledger, LedgerFlow::Outflow, LedgerCategory::Fees, fee_amount)?;
+
+    // Credit the buyer's referrer, if any
+    credit_referral_fee(
+        &ctx.accounts.token_state,
+        &ctx.accounts.referral,
+        &ctx.accounts.referrer_account,
+        fee_amount,
+        to_revenue,
+    )?;
+
+    msg!(
+        "🛒 Buyer {} paid {} quote units for {} SCTK ({} units) | 📈 Fee: {} SCTK ({} units → {} to revenue, {} to rewards, {} to airdrop)",
+        ctx.accounts.caller.key(),
+        quote_amount,
+        format_sctk(net_amount),
+        net_amount,
+        format_sctk(fee_amount),
+        fee_amount,
+        to_revenue,
+        to_rewards,
+        to_airdrop,
+    );
+
+    emit!(QuotePaymentReceived {
+        buyer: ctx.accounts.caller.key(),
+        quote_mint: ctx.accounts.price_config.quote_mint,
+        quote_amount,
+        price,
+        sctk_amount: amount,
+    });
+
+    emit!(TokensPurchased {
+        buyer: ctx.accounts.caller.key(),
+        amount,
+        net_received: net_amount,
+        fee_charged: fee_amount,
+        to_rewards,
+        to_airdrop,
+        to_revenue,
+        price,
+        discount_bps,
+    });
+
+    Ok(())
+}
+
+/// ===========================================================================
+/// Moves the quote payment from the buyer into the quote treasury.
+///
+/// Shared by `purchase_tokens` and `buy_tokens_with_quote`.
+///
+/// ## Behavior:
+/// - Native SOL: system transfer from buyer to the `quote_treasury` PDA
+/// - SPL mint: token transfer from the buyer's quote account to the
+///   treasury ATA, after validating mint, owner and ATA address
+///
+/// ## Errors:
+/// - `MarketError::InvalidQuoteAccount` on missing or mismatched accounts
+/// - `MarketError::InsufficientFunds` if the buyer's quote balance is too low
+/// ===========================================================================
+pub(crate) fn collect_quote_payment(
+    ctx: &QuotePaymentContext,
+    quote_amount: u64,
+) -> Result<()> {
+    let price_config = &ctx.price_config;
+
+    if price_config.is_native() {
+        let cpi_ctx = CpiContext::new(
+            ctx.system_program.clone(),
+            system_program::Transfer {
+                from: ctx.buyer.clone(),
+                to: ctx.quote_treasury.clone(),
+            },
+        );
+        return system_program::transfer(cpi_ctx, quote_amount);
+    }
+
+    let buyer_quote = ctx
+        .buyer_quote_account
+        .as_ref()
+        .ok_or(MarketError::InvalidQuoteAccount)?;
+    let treasury_quote = ctx
+        .quote_treasury_token_account
+        .as_ref()
+        .ok_or(MarketError::InvalidQuoteAccount)?;
+
+    require_keys_eq!(buyer_quote.mint, price_config.quote_mint, MarketError::InvalidQuoteAccount);
+    require_keys_eq!(buyer_quote.owner, ctx.buyer.key(), MarketError::InvalidQuoteAccount);
+    require_keys_eq!(
+        treasury_quote.key(),
+        get_associated_token_address(&ctx.quote_treasury.key(), &price_config.quote_mint),
+        MarketError::InvalidQuoteAccount
+    );
+    require!(buyer_quote.amount >= quote_amount, MarketError::InsufficientFunds);
+
+    let cpi_ctx = CpiContext::new(
+        ctx.token_program.clone(),
+        Transfer {
+            from: buyer_quote.to_account_info(),
+            to: treasury_quote.to_account_info(),
+            authority: ctx.buyer.clone(),
+        },
+    );
+    token::transfer(cpi_ctx, quote_amount)
+}
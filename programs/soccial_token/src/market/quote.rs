@@ -0,0 +1,308 @@
+// ===========================================================================
+// Signed Price Quotes – Soccial Token (SCTK)
+// ---------------------------------------------------------------------------
+//
+// This module implements self-submitted purchases bound to a price the
+// backend committed to. The API authority signs a `PriceQuote` off-chain and
+// the buyer submits it together with a native ed25519 verification.
+//
+// ---------------------------------------------------------------------------
+// ## Flow:
+// 1. Backend signs `QUOTE_DOMAIN || price || amount || buyer || expires_at || nonce`
+// 2. Buyer sends an ed25519 program instruction with that signature, followed
+//    by `buy_tokens_with_quote` carrying the same terms as arguments
+// 3. The program reads the ed25519 instruction through the instructions
+//    sysvar and checks the signer is `core.api_authority` and the message
+//    matches the arguments byte for byte
+// 4. The `QuoteNonce` PDA is created, so the quote cannot be redeemed twice
+// 5. The buyer pays the executed curve price into the quote treasury, like
+//    `purchase_tokens`; the signed price only caps it
+// 6. Tokens are released from the liquidity vault like `buy_tokens`
+//
+// ---------------------------------------------------------------------------
+// Author: Paulo Rodrigues
+// Project: Soccial Token
+// Website: https://www.soccial.com/thetoken
+// License: MIT
+// ===========================================================================
+
+use anchor_lang::{
+    prelude::*,
+    solana_program::{
+        ed25519_program,
+        sysvar::instructions::{load_current_index_checked, load_instruction_at_checked},
+    },
+};
+use anchor_spl::token::{self, Transfer};
+
+use crate::{
+    economics::state::FeeOperation,
+    market::{
+        calculate_fee, collect_quote_payment, context::*, distribute_fees, error::MarketError,
+        state::{PriceConfig, PriceQuote},
+        QuotePaymentReceived, TokensPurchased,
+    },
+    referral::credit_referral_fee,
+    utils::math::format_sctk,
+    vaults::{error::VaultError, record_vault_flow, record_vault_outflow, LedgerCategory, LedgerFlow},
+};
+
+/// Signature count, padding and one set of offsets in an ed25519 instruction.
+const ED25519_HEADER_LEN: usize = 16;
+
+/// Size of an ed25519 public key.
+const ED25519_PUBKEY_LEN: usize = 32;
+
+#[event]
+pub struct PriceQuoteRedeemed {
+    pub buyer: Pubkey,
+    pub nonce: u64,
+    pub price: u64,
+    pub amount: u64,
+    pub expires_at: i64,
+}
+
+/// ===========================================================================
+/// Purchases tokens from the liquidity vault at a price signed by the API
+/// authority, submitted by the buyer itself.
+///
+/// ## Behavior:
+/// - Rejects expired quotes
+/// - Verifies the preceding ed25519 instruction (see `verify_quote_signature`)
+/// - Records the nonce, so the quote is single-use
+/// - Prices the purchase on the `SaleState` curve (flat `buy_price` while
+///   unset); this executed price must not exceed the quote
+/// - Collects the executed price in the `PriceConfig` quote currency
+/// - Advances the curve and records the executed price in the quote mint's `PriceAccumulator`
+/// - Sends the net amount to the buyer and routes the buy fee to vaults
+/// - Posts the net (`Market`) and fee (`Fees`) outflows to the liquidity vault ledger
+///
+/// ## Errors:
+/// - `MarketError::QuoteExpired` if `expires_at` has passed
+/// - `MarketError::MissingQuoteSignature` / `InvalidQuoteSignature` if the
+///   quote was not signed by the API authority
+/// - `MarketError::PricingDisabled` if pricing is disabled or unset
+/// - `MarketError::PriceAboveMaximum` if the curve price exceeds the quote
+/// - `MarketError::InvalidQuoteAccount` if quote accounts are missing or mismatched
+/// - `VaultError::InsufficientVaultBalance` if liquidity is insufficient
+/// - `VaultError::EpochOutflowExceeded` if the vault's epoch outflow cap is reached
+/// ===========================================================================
+pub(crate) fn buy_tokens_with_quote(
+    ctx: Context<BuyTokensWithQuoteContext>,
+    quote: PriceQuote,
+) -> Result<()> {
+    let now = Clock::get()?.unix_timestamp;
+    require!(quote.amount > 0, VaultError::InvalidVaultAmount);
+    require!(now <= quote.expires_at, MarketError::QuoteExpired);
+    require!(
+        ctx.accounts.liquidity_vault_token_account.amount >= quote.amount,
+        VaultError::InsufficientVaultBalance
+    );
+
+    verify_quote_signature(
+        &ctx.accounts.instructions_sysvar,
+        &ctx.accounts.token_state.core.api_authority,
+        &quote.message(),
+    )?;
+
+    let quote_nonce = &mut ctx.accounts.quote_nonce;
+    quote_nonce.buyer = quote.buyer;
+    quote_nonce.nonce = quote.nonce;
+    quote_nonce.price = quote.price;
+    quote_nonce.amount = quote.amount;
+    quote_nonce.redeemed_at = now;
+    quote_nonce.bump = ctx.bumps.quote_nonce;
+
+    // The curve still prices the trade; the signed price acts as the buyer's maximum
+    let sale_state = &ctx.accounts.sale_state;
+    let price = if sale_state.is_configured() {
+        sale_state.price_for(quote.amount)?
+    } else {
+        ctx.accounts.price_config.buy_price
+    };
+    require!(ctx.accounts.price_config.enabled && price > 0, MarketError::PricingDisabled);
+    require!(price <= quote.price, MarketError::PriceAboveMaximum);
+
+    let quote_amount = PriceConfig::quote_for(quote.amount, price)?;
+    require!(quote_amount > 0, MarketError::InvalidAmount);
+
+    // Collect payment into the quote treasury before any SCTK is released
+    collect_quote_payment(
+        &QuotePaymentContext {
+            price_config: ctx.accounts.price_config.clone(),
+            buyer: ctx.accounts.caller.to_account_info(),
+            quote_treasury: ctx.accounts.quote_treasury.to_account_info(),
+            buyer_quote_account: ctx.accounts.buyer_quote_account.clone(),
+            quote_treasury_token_account: ctx.accounts.quote_treasury_token_account.clone(),
+            system_program: ctx.accounts.system_program.to_account_info(),
+            token_program: ctx.accounts.token_program.to_account_info(),
+        },
+        quote_amount,
+    )?;
+
+    ctx.accounts.sale_state.record_sale(quote.amount)?;
+    ctx.accounts.price_accumulator.record(price, now)?;
+
+    // Enforce the vault's epoch outflow limits (may trip the circuit breaker)
+    record_vault_outflow(
+        &ctx.accounts.vault_limits,
+        &mut ctx.accounts.token_state,
+        &ctx.accounts.caller.key(),
+        quote.amount,
+    )?;
+
+    let (net_amount, fee_amount, discount_bps) = calculate_fee(
+        &ctx.accounts.token_state,
+        ctx.accounts.user_access.as_deref(),
+        FeeOperation::Buy,
+        quote.amount,
+    )?;
+
+    let signer_seeds: &[&[u8]] = &[b"liquidity_vault", &[ctx.bumps.liquidity_vault]];
+    let signer_seeds_nested = &[signer_seeds];
+
+    token::transfer(
+        CpiContext::new_with_signer(
+            ctx.accounts.token_program.to_account_info(),
+            Transfer {
+                from: ctx.accounts.liquidity_vault_token_account.to_account_info(),
+                to: ctx.accounts.buyer_token_account.to_account_info(),
+                authority: ctx.accounts.liquidity_vault.to_account_info(),
+            },
+            signer_seeds_nested,
+        ),
+        net_amount,
+    )?;
+
+    let (to_rewards, to_airdrop, to_revenue) = distribute_fees(
+        &FeeDistributionContext {
+            token_state: ctx.accounts.token_state.clone(),
+            token_program: ctx.accounts.token_program.to_account_info(),
+            source_token_account: ctx.accounts.liquidity_vault_token_account.to_account_info(),
+            rewards_vault_token_account: ctx.accounts.rewards_vault_token_account.to_account_info(),
+            rewards_ledger: ctx.accounts.rewards_ledger.to_account_info(),
+            airdrop_vault_token_account: ctx.accounts.airdrop_vault_token_account.to_account_info(),
+            airdrop_ledger: ctx.accounts.airdrop_ledger.to_account_info(),
+            revenue_vault_token_account: ctx.accounts.revenue_vault_token_account.to_account_info(),
+            revenue_ledger: ctx.accounts.revenue_ledger.to_account_info(),
+            authority: ctx.accounts.liquidity_vault.to_account_info(),
+        },
+        fee_amount,
+        Some(signer_seeds_nested),
+    )?;
+    record_vault_flow(&ctx.accounts.liquidity_ledger, LedgerFlow::Outflow, LedgerCategory::Market, net_amount)?;
+    record_vault_flow(&ctx.accounts.liquidity_ledger, LedgerFlow::Outflow, LedgerCategory::Fees, fee_amount)?;
+
+    credit_referral_fee(
+        &ctx.accounts.token_state,
+        &ctx.accounts.referral,
+        &ctx.accounts.referrer_account,
+        fee_amount,
+        to_revenue,
+    )?;
+
+    msg!(
+        "🧾 User redeemed quote #{} for {} SCTK ({} units) at price {} (max {}) | 💵 Paid: {} quote units | 📈 Fee: {} SCTK ({} units → {} to revenue, {} to rewards, {} to airdrop) | 🏷️ Discount: {} BPS",
+        quote.nonce,
+        format_sctk(net_amount),
+        net_amount,
+        price,
+        quote.price,
+        quote_amount,
+        format_sctk(fee_amount),
+        fee_amount,
+        to_revenue,
+        to_rewards,
+        to_airdrop,
+        discount_bps,
+    );
+
+    emit!(QuotePaymentReceived {
+        buyer: quote.buyer,
+        quote_mint: ctx.accounts.price_config.quote_mint,
+        quote_amount,
+        price,
+        sctk_amount: quote.amount,
+    });
+
+    emit!(PriceQuoteRedeemed {
+        buyer: quote.buyer,
+        nonce: quote.nonce,
+        price: quote.price,
+        amount: quote.amount,
+        expires_at: quote.expires_at,
+    });
+
+    emit!(TokensPurchased {
+        buyer: quote.buyer,
+        amount: quote.amount,
+        net_received: net_amount,
+        fee_charged: fee_amount,
+        to_rewards,
+        to_airdrop,
+        to_revenue,
+        price,
+        discount_bps,
+    });
+
+    Ok(())
+}
+
+/// ===========================================================================
+/// Checks that the instruction right before the current one is a native
+/// ed25519 verification of `message` signed by `authority`.
+///
+/// ## Behavior:
+/// - Loads the previous instruction through the instructions sysvar
+/// - Requires exactly one signature whose key, signature and message are all
+///   stored inside that same instruction
+/// - Compares the public key and the message byte for byte; the signature
+///   itself was already verified by the runtime
+///
+/// ## Errors:
+/// - `MarketError::MissingQuoteSignature` if no ed25519 instruction precedes
+/// - `MarketError::InvalidQuoteSignature` if the key or message do not match
+/// ===========================================================================
+pub(crate) fn verify_quote_signature(
+    instructions_sysvar: &AccountInfo,
+    authority: &Pubkey,
+    message: &[u8],
+) -> Result<()> {
+    let current_index = load_current_index_checked(instructions_sysvar)? as usize;
+    require!(current_index > 0, MarketError::MissingQuoteSignature);
+
+    let ix = load_instruction_at_checked(current_index - 1, instructions_sysvar)?;
+    require_keys_eq!(ix.program_id, ed25519_program::ID, MarketError::MissingQuoteSignature);
+
+    let data = &ix.data;
+    require!(
+        data.len() >= ED25519_HEADER_LEN && data[0] == 1,
+        MarketError::InvalidQuoteSignature
+    );
+
+    let read_u16 = |at: usize| u16::from_le_bytes([data[at], data[at + 1]]);
+    let signature_ix = read_u16(4);
+    let pubkey_offset = read_u16(6) as usize;
+    let pubkey_ix = read_u16(8);
+    let message_offset = read_u16(10) as usize;
+    let message_size = read_u16(12) as usize;
+    let message_ix = read_u16(14);
+
+    // Key, signature and message must live in the ed25519 instruction itself
+    require!(
+        signature_ix == u16::MAX && pubkey_ix == u16::MAX && message_ix == u16::MAX,
+        MarketError::InvalidQuoteSignature
+    );
+
+    let signer = data
+        .get(pubkey_offset..pubkey_offset + ED25519_PUBKEY_LEN)
+        .ok_or(MarketError::InvalidQuoteSignature)?;
+    let signed_message = data
+        .get(message_offset..message_offset + message_size)
+        .ok_or(MarketError::InvalidQuoteSignature)?;
+
+    require!(signer == authority.as_ref(), MarketError::InvalidQuoteSignature);
+    require!(signed_message == message, MarketError::InvalidQuoteSignature);
+
+    Ok(())
+}
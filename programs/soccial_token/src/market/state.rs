@@ -0,0 +1,855 @@
+// ===========================================================================
+// Market State – Soccial Token (SCTK)
+// ---------------------------------------------------------------------------
+//
+// This module defines the on-chain pricing configuration used by priced
+// market operations, where the buyer pays for SCTK directly on-chain
+// instead of relying on the API authority to settle payment off-chain.
+//
+// ---------------------------------------------------------------------------
+// ## Components:
+// - `PriceConfig`: Governance-managed price and quote currency settings
+// - Buyback window: per-epoch cap on SCTK sold back to the protocol
+// - `SaleState`: Bonding curve pricing primary sales by tokens already sold
+// - `WithdrawalReceipt`: Idempotency record for off-chain reserve withdrawals
+// - `CreditLedger` / `CreditReceipt`: Sequenced credits into the off-chain wallet
+// - `BatchFeeMode`: How fees are charged on batch transfers
+// - `PriceQuote` / `QuoteNonce`: API-signed purchase quotes, redeemable once
+// - `WithdrawalAddressBook`: User-registered destinations for withdrawals
+// - `WithdrawalSettings`: Governed cooldown before new destinations activate
+//
+// ---------------------------------------------------------------------------
+// ## Quote Currency:
+// - `quote_mint == Pubkey::default()` → payment in native SOL (lamports)
+// - Any other mint → payment in that SPL token
+// - Prices are expressed in quote base units per 1 SCTK (10^9 units)
+//
+// ---------------------------------------------------------------------------
+// Author: Paulo Rodrigues
+// Project: Soccial Token
+// Website: https://www.soccial.com/thetoken
+// License: MIT
+// ===========================================================================
+
+use anchor_lang::prelude::*;
+use crate::{
+    economy::{fee::{FEE_BPS_BASE, MAX_FEE_BPS}, TOKEN_DECIMAL},
+    market::error::MarketError,
+};
+
+#[event]
+pub struct SaleCurveUpdated {
+    pub curve_type: CurveType,
+    pub base_price: u64,
+    pub slope: u64,
+    pub growth_bps: u16,
+    pub step_size: u64,
+    pub updated_at: i64,
+}
+
+#[event]
+pub struct PriceConfigUpdated {
+    pub quote_mint: Pubkey,
+    pub buy_price: u64,
+    pub sell_price: u64,
+    pub sell_fee_bps: u16,
+    pub buyback_epoch_cap: u64,
+    pub enabled: bool,
+    pub updated_at: i64,
+}
+
+/// ===========================================================================
+/// Account: PriceConfig
+///
+/// Global pricing settings for priced purchases. Created on the first
+/// governance-approved update and edited only through approved proposals.
+///
+/// ## Fields:
+/// - `quote_mint`: Currency accepted as payment (`Pubkey::default()` = SOL)
+/// - `buy_price`: Quote base units charged per 1 SCTK
+/// - `sell_price`: Quote base units paid per 1 SCTK on buyback (0 = disabled)
+/// - `sell_fee_bps`: Fee deducted from the SCTK side of a sale
+/// - `buyback_epoch_duration` / `buyback_epoch_cap`: Buyback window and cap
+/// - `buyback_epoch_start` / `buyback_epoch_sold`: Current window tracking
+/// - `enabled`: Master switch for priced purchases and sales
+/// - `updated_at`: Unix timestamp of the last update
+/// ===========================================================================
+#[account]
+pub struct PriceConfig {
+    pub quote_mint: Pubkey,
+    pub buy_price: u64,
+    pub sell_price: u64,
+    pub sell_fee_bps: u16,
+    pub buyback_epoch_duration: i64,
+    pub buyback_epoch_cap: u64,
+    pub buyback_epoch_start: i64,
+    pub buyback_epoch_sold: u64,
+    pub enabled: bool,
+    pub updated_at: i64,
+}
+
+impl PriceConfig {
+    pub const LEN: usize =
+        8 +     // Anchor discriminator
+        32 +    // quote_mint (Pubkey)
+        8 +     // buy_price (u64)
+        8 +     // sell_price (u64)
+        2 +     // sell_fee_bps (u16)
+        8 +     // buyback_epoch_duration (i64)
+        8 +     // buyback_epoch_cap (u64)
+        8 +     // buyback_epoch_start (i64)
+        8 +     // buyback_epoch_sold (u64)
+        1 +     // enabled (bool)
+        8;      // updated_at (i64)
+
+    /// Returns `true` when payments are settled in native SOL.
+    pub fn is_native(&self) -> bool {
+        self.quote_mint == Pubkey::default()
+    }
+
+    /// Computes the quote amount owed for `amount` SCTK units at `price`.
+    ///
+    /// The result is rounded up so fractional quote units always favor the
+    /// protocol.
+    ///
+    /// ## Errors:
+    /// - `Overflow` if the result does not fit in a `u64`
+    pub fn quote_for(amount: u64, price: u64) -> Result<u64> {
+        let unit = 10u128.pow(TOKEN_DECIMAL as u32);
+        let cost = (amount as u128)
+            .checked_mul(price as u128)
+            .ok_or(MarketError::Overflow)?
+            .div_ceil(unit);
+
+        u64::try_from(cost).map_err(|_| MarketError::Overflow.into())
+    }
+
+    /// Computes the quote amount paid out for `amount` SCTK units at `price`.
+    ///
+    /// The result is rounded down so fractional quote units always favor the
+    /// protocol.
+    ///
+    /// ## Errors:
+    /// - `Overflow` if the result does not fit in a `u64`
+    pub fn payout_for(amount: u64, price: u64) -> Result<u64> {
+        let unit = 10u128.pow(TOKEN_DECIMAL as u32);
+        let payout = (amount as u128)
+            .checked_mul(price as u128)
+            .ok_or(MarketError::Overflow)?
+            / unit;
+
+        u64::try_from(payout).map_err(|_| MarketError::Overflow.into())
+    }
+
+    /// Registers `amount` SCTK against the current buyback epoch.
+    ///
+    /// Starts a new epoch when the previous one has elapsed, then enforces
+    /// `buyback_epoch_cap` on the cumulative amount sold in the epoch.
+    ///
+    /// ## Errors:
+    /// - `BuybackCapExceeded` if the sale would exceed the epoch cap
+    /// - `Overflow` on arithmetic overflow
+    pub(crate) fn consume_buyback(&mut self, amount: u64, now: i64) -> Result<()> {
+        let epoch_end = self
+            .buyback_epoch_start
+            .checked_add(self.buyback_epoch_duration)
+            .ok_or(MarketError::Overflow)?;
+
+        if now >= epoch_end {
+            self.buyback_epoch_start = now;
+            self.buyback_epoch_sold = 0;
+        }
+
+        let sold = self
+            .buyback_epoch_sold
+            .checked_add(amount)
+            .ok_or(MarketError::Overflow)?;
+        require!(sold <= self.buyback_epoch_cap, MarketError::BuybackCapExceeded);
+
+        self.buyback_epoch_sold = sold;
+        Ok(())
+    }
+
+    /// Dynamically updates pricing parameters via string-based key=value pairs.
+    ///
+    /// ## Supported Keys:
+    /// - `"quote_mint"` → SPL mint address, or `SOL` for native lamports
+    /// - `"buy_price"` → quote base units per 1 SCTK
+    /// - `"sell_price"` → quote base units paid per 1 SCTK on buyback
+    /// - `"sell_fee_bps"` → fee applied to sales (≤ `MAX_FEE_BPS`)
+    /// - `"buyback_epoch_duration"` → buyback window length in seconds
+    /// - `"buyback_epoch_cap"` → max SCTK units bought back per window
+    /// - `"enabled"` → `true` / `false`
+    ///
+    /// ## Errors:
+    /// - `NotEnoughArguments` if input is empty
+    /// - `InvalidArgument` if any key is unknown or parsing fails
+    /// - `FeeTooHigh` if the fee exceeds `MAX_FEE_BPS`
+    /// - `InvalidPriceSpread` if the resulting `sell_price` exceeds `buy_price`
+    ///   or the base price of a configured `sale_state` curve
+    pub(crate) fn apply_updates(&mut self, args: Vec<String>, sale_state: &SaleState) -> Result<()> {
+        require!(!args.is_empty(), crate::ErrorCode::NotEnoughArguments);
+
+        let mut log: Vec<String> = vec![];
+
+        for arg in args {
+            let parts: Vec<&str> = arg.split('=').collect();
+            require!(parts.len() == 2, crate::ErrorCode::InvalidArgument);
+            let key = parts[0].trim();
+            let value = parts[1].trim();
+
+            match key {
+                "quote_mint" => {
+                    let val = if value.eq_ignore_ascii_case("sol") {
+                        Pubkey::default()
+                    } else {
+                        value.parse::<Pubkey>().map_err(|_| crate::ErrorCode::InvalidArgument)?
+                    };
+                    self.quote_mint = val;
+                    log.push(format!("quote_mint: {}", val));
+                }
+                "buy_price" => {
+                    let val = value.parse::<u64>().map_err(|_| crate::ErrorCode::InvalidArgument)?;
+                    self.buy_price = val;
+                    log.push(format!("buy_price: {}", val));
+                }
+                "sell_price" => {
+                    let val = value.parse::<u64>().map_err(|_| crate::ErrorCode::InvalidArgument)?;
+                    self.sell_price = val;
+                    log.push(format!("sell_price: {}", val));
+                }
+                "sell_fee_bps" => {
+                    let val = value.parse::<u16>().map_err(|_| crate::ErrorCode::InvalidArgument)?;
+                    require!(val <= MAX_FEE_BPS, MarketError::FeeTooHigh);
+                    self.sell_fee_bps = val;
+                    log.push(format!("sell_fee_bps: {}", val));
+                }
+                "buyback_epoch_duration" => {
+                    let val = value.parse::<i64>().map_err(|_| crate::ErrorCode::InvalidArgument)?;
+                    require!(val > 0, crate::ErrorCode::InvalidArgument);
+                    self.buyback_epoch_duration = val;
+                    log.push(format!("buyback_epoch_duration: {}s", val));
+                }
+                "buyback_epoch_cap" => {
+                    let val = value.parse::<u64>().map_err(|_| crate::ErrorCode::InvalidArgument)?;
+                    self.buyback_epoch_cap = val;
+                    log.push(format!("buyback_epoch_cap: {}", val));
+                }
+                "enabled" => {
+                    let val = value.parse::<bool>().map_err(|_| crate::ErrorCode::InvalidArgument)?;
+                    self.enabled = val;
+                    log.push(format!("enabled: {}", val));
+                }
+                _ => return Err(crate::ErrorCode::InvalidArgument.into()),
+            }
+        }
+
+        // Buying back above the sale price would let anyone drain the quote
+        // treasury by buying and selling in a loop
+        require!(
+            self.buy_price == 0 || self.sell_price <= self.buy_price,
+            MarketError::InvalidPriceSpread
+        );
+        // Once a curve prices purchases, its lowest price is the base price
+        require!(
+            !sale_state.is_configured() || self.sell_price <= sale_state.base_price,
+            MarketError::InvalidPriceSpread
+        );
+
+        let now = Clock::get()?.unix_timestamp;
+        self.updated_at = now;
+
+        msg!("⚙️ Updated price config → {}", log.join(" | "));
+
+        emit!(PriceConfigUpdated {
+            quote_mint: self.quote_mint,
+            buy_price: self.buy_price,
+            sell_price: self.sell_price,
+            sell_fee_bps: self.sell_fee_bps,
+            buyback_epoch_cap: self.buyback_epoch_cap,
+            enabled: self.enabled,
+            updated_at: now,
+        });
+
+        Ok(())
+    }
+}
+
+/// Shape of the primary-sale bonding curve.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug)]
+pub enum CurveType {
+    /// `price = base_price + slope * sold / 1 SCTK`
+    Linear,
+    /// `price = base_price * (1 + growth_bps)^(sold / step_size)`
+    Exponential,
+}
+
+/// Fixed-point scale used when compounding the exponential curve.
+const CURVE_SCALE: u128 = 1_000_000_000_000;
+
+/// ===========================================================================
+/// Account: SaleState
+///
+/// Bonding curve for primary sales from the liquidity vault (`buy_tokens`,
+/// `purchase_tokens`, `buy_tokens_with_quote`). The price depends only on
+/// the SCTK already sold through the curve, so it rises predictably as the
+/// economy grows. Created unset by `initialize_token`; while `base_price`
+/// is 0, `purchase_tokens` falls back to `PriceConfig.buy_price`.
+///
+/// ## Fields:
+/// - `curve_type`: Linear or exponential curve
+/// - `base_price`: Price of the first SCTK, in quote base units per 1 SCTK
+///   (0 = curve not configured)
+/// - `slope`: Linear increase per 1 SCTK sold
+/// - `growth_bps` / `step_size`: Exponential growth per `step_size` units sold
+/// - `tokens_sold`: SCTK units sold through the curve so far
+/// - `updated_at`: Unix timestamp of the last update
+/// ===========================================================================
+#[account]
+pub struct SaleState {
+    pub curve_type: CurveType,
+    pub base_price: u64,
+    pub slope: u64,
+    pub growth_bps: u16,
+    pub step_size: u64,
+    pub tokens_sold: u64,
+    pub updated_at: i64,
+}
+
+impl SaleState {
+    pub const LEN: usize =
+        8 +     // Anchor discriminator
+        1 +     // curve_type (enum)
+        8 +     // base_price (u64)
+        8 +     // slope (u64)
+        2 +     // growth_bps (u16)
+        8 +     // step_size (u64)
+        8 +     // tokens_sold (u64)
+        8;      // updated_at (i64)
+
+    /// Whether governance has configured the curve.
+    pub fn is_configured(&self) -> bool {
+        self.base_price > 0
+    }
+
+    /// Returns the spot price once `sold` SCTK units have been sold.
+    ///
+    /// ## Errors:
+    /// - `Overflow` if the price does not fit in a `u64`
+    pub fn spot_price(&self, sold: u64) -> Result<u64> {
+        let base = self.base_price as u128;
+
+        let price = match self.curve_type {
+            CurveType::Linear => {
+                let unit = 10u128.pow(TOKEN_DECIMAL as u32);
+                base + self.slope as u128 * sold as u128 / unit
+            }
+            CurveType::Exponential => {
+                if self.step_size == 0 {
+                    base
+                } else {
+                    let mut steps = sold / self.step_size;
+                    let mut factor = CURVE_SCALE + CURVE_SCALE * self.growth_bps as u128 / FEE_BPS_BASE as u128;
+                    let mut multiplier = CURVE_SCALE;
+
+                    while steps > 0 {
+                        if steps & 1 == 1 {
+                            multiplier = multiplier
+                                .checked_mul(factor)
+                                .ok_or(MarketError::Overflow)?
+                                / CURVE_SCALE;
+                        }
+                        steps >>= 1;
+                        if steps > 0 {
+                            factor = factor.checked_mul(factor).ok_or(MarketError::Overflow)? / CURVE_SCALE;
+                        }
+                    }
+
+                    base.checked_mul(multiplier).ok_or(MarketError::Overflow)? / CURVE_SCALE
+                }
+            }
+        };
+
+        u64::try_from(price).map_err(|_| MarketError::Overflow.into())
+    }
+
+    /// Returns the realized price for buying `amount` units from the current
+    /// position on the curve.
+    ///
+    /// The curve is evaluated at the midpoint of the purchase, which is the
+    /// exact average price for a linear curve.
+    pub fn price_for(&self, amount: u64) -> Result<u64> {
+        let midpoint = self
+            .tokens_sold
+            .checked_add(amount / 2)
+            .ok_or(MarketError::Overflow)?;
+
+        self.spot_price(midpoint)
+    }
+
+    /// Records `amount` units as sold through the curve.
+    pub(crate) fn record_sale(&mut self, amount: u64) -> Result<()> {
+        self.tokens_sold = self
+            .tokens_sold
+            .checked_add(amount)
+            .ok_or(MarketError::Overflow)?;
+        Ok(())
+    }
+
+    /// Dynamically updates curve parameters via string-based key=value pairs.
+    ///
+    /// ## Supported Keys:
+    /// - `"curve_type"` → `linear` / `exponential`
+    /// - `"base_price"` → price units per 1 SCTK at zero sold
+    /// - `"slope"` → linear increase per 1 SCTK sold
+    /// - `"growth_bps"` → exponential growth per step (in BPS)
+    /// - `"step_size"` → SCTK units per exponential step (> 0)
+    ///
+    /// ## Errors:
+    /// - `NotEnoughArguments` if input is empty
+    /// - `InvalidArgument` if any key is unknown or parsing fails
+    /// - `InvalidPriceSpread` if the resulting `base_price` is below the
+    ///   `price_config` sell price
+    pub(crate) fn apply_updates(&mut self, args: Vec<String>, price_config: &PriceConfig) -> Result<()> {
+        require!(!args.is_empty(), crate::ErrorCode::NotEnoughArguments);
+
+        let mut log: Vec<String> = vec![];
+
+        for arg in args {
+            let parts: Vec<&str> = arg.split('=').collect();
+            require!(parts.len() == 2, crate::ErrorCode::InvalidArgument);
+            let key = parts[0].trim();
+            let value = parts[1].trim();
+
+            match key {
+                "curve_type" => {
+                    let val = match value.to_ascii_lowercase().as_str() {
+                        "linear" => CurveType::Linear,
+                        "exponential" => CurveType::Exponential,
+                        _ => return Err(crate::ErrorCode::InvalidArgument.into()),
+                    };
+                    self.curve_type = val;
+                    log.push(format!("curve_type: {:?}", val));
+                }
+                "base_price" => {
+                    let val = value.parse::<u64>().map_err(|_| crate::ErrorCode::InvalidArgument)?;
+                    self.base_price = val;
+                    log.push(format!("base_price: {}", val));
+                }
+                "slope" => {
+                    let val = value.parse::<u64>().map_err(|_| crate::ErrorCode::InvalidArgument)?;
+                    self.slope = val;
+                    log.push(format!("slope: {}", val));
+                }
+                "growth_bps" => {
+                    let val = value.parse::<u16>().map_err(|_| crate::ErrorCode::InvalidArgument)?;
+                    self.growth_bps = val;
+                    log.push(format!("growth_bps: {}", val));
+                }
+                "step_size" => {
+                    let val = value.parse::<u64>().map_err(|_| crate::ErrorCode::InvalidArgument)?;
+                    require!(val > 0, crate::ErrorCode::InvalidArgument);
+                    self.step_size = val;
+                    log.push(format!("step_size: {}", val));
+                }
+                _ => return Err(crate::ErrorCode::InvalidArgument.into()),
+            }
+        }
+
+        // The curve never prices below its base, so a base under the buyback
+        // price would let anyone drain the quote treasury by buying and
+        // selling in a loop
+        require!(
+            !self.is_configured() || price_config.sell_price <= self.base_price,
+            MarketError::InvalidPriceSpread
+        );
+
+        let now = Clock::get()?.unix_timestamp;
+        self.updated_at = now;
+
+        msg!("⚙️ Updated sale curve → {}", log.join(" | "));
+
+        emit!(SaleCurveUpdated {
+            curve_type: self.curve_type,
+            base_price: self.base_price,
+            slope: self.slope,
+            growth_bps: self.growth_bps,
+            step_size: self.step_size,
+            updated_at: now,
+        });
+
+        Ok(())
+    }
+}
+
+/// Maximum length (in bytes) of a backend request ID (fits a UUID).
+pub const MAX_REQUEST_ID_LEN: usize = 64;
+
+/// Seed of the `WithdrawalReceipt` for `request_id`: its SHA-256 hash, so
+/// IDs of any length map to a valid 32-byte PDA seed.
+pub fn request_id_seed(request_id: &str) -> [u8; 32] {
+    anchor_lang::solana_program::hash::hash(request_id.as_bytes()).to_bytes()
+}
+
+/// ===========================================================================
+/// Account: WithdrawalReceipt
+///
+/// Created once per backend request ID by `deposit_tokens`. Because the PDA
+/// is seeded by the hash of the request ID (`request_id_seed`), a retried
+/// submission with the same ID fails deterministically instead of paying
+/// the user twice.
+///
+/// ## Fields:
+/// - `request_id`: Backend idempotency key
+/// - `recipient`: Owner of the destination token account
+/// - `destination`: Token account credited
+/// - `amount`: Gross amount taken from the off-chain reserve
+/// - `fee_charged`: Fee routed to the vaults
+/// - `created_at`: Unix timestamp of the withdrawal
+/// - `bump`: PDA bump seed
+/// ===========================================================================
+#[account]
+pub struct WithdrawalReceipt {
+    pub request_id: String,
+    pub recipient: Pubkey,
+    pub destination: Pubkey,
+    pub amount: u64,
+    pub fee_charged: u64,
+    pub created_at: i64,
+    pub bump: u8,
+}
+
+impl WithdrawalReceipt {
+    pub const LEN: usize =
+        8 +                           // Anchor discriminator
+        4 + MAX_REQUEST_ID_LEN +      // request_id (String)
+        32 +                          // recipient (Pubkey)
+        32 +                          // destination (Pubkey)
+        8 +                           // amount (u64)
+        8 +                           // fee_charged (u64)
+        8 +                           // created_at (i64)
+        1;                            // bump (u8)
+}
+
+/// Default delay before a new address book entry becomes usable: 24 hours.
+pub const DEFAULT_ADDRESS_COOLDOWN_SECS: i64 = 86_400;
+
+/// Shortest cooldown governance may set: 1 hour.
+pub const MIN_ADDRESS_COOLDOWN_SECS: i64 = 3_600;
+
+/// Longest cooldown governance may set: 30 days.
+pub const MAX_ADDRESS_COOLDOWN_SECS: i64 = 30 * 86_400;
+
+/// Maximum number of destinations in a `WithdrawalAddressBook`.
+pub const MAX_ADDRESS_BOOK_ENTRIES: usize = 10;
+
+#[event]
+pub struct WithdrawalSettingsUpdated {
+    pub address_cooldown_secs: i64,
+}
+
+/// 🔐 Withdrawal safeguards stored in `TokenState`.
+///
+/// `address_cooldown_secs` delays every new `WithdrawalAddressBook` entry,
+/// so a compromised `api_authority` cannot immediately route withdrawals
+/// to a fresh address. Editable only through an approved
+/// `UpdateGovernance` proposal.
+#[derive(Clone, Copy, Debug, AnchorSerialize, AnchorDeserialize)]
+pub struct WithdrawalSettings {
+    pub address_cooldown_secs: i64,
+}
+
+impl WithdrawalSettings {
+    /// Updates the settings via string-based key=value pairs.
+    ///
+    /// ## Supported Keys:
+    /// - `"address_cooldown"` → delay before new destinations activate (seconds)
+    ///
+    /// ## Errors:
+    /// - `NotEnoughArguments` if input is empty
+    /// - `InvalidArgument` if any key is unknown or parsing fails
+    /// - `InvalidCooldown` if the value is outside the allowed range
+    pub(crate) fn apply_updates(&mut self, args: Vec<String>) -> Result<()> {
+        require!(!args.is_empty(), crate::ErrorCode::NotEnoughArguments);
+
+        let mut log: Vec<String> = vec![];
+
+        for arg in args {
+            let parts: Vec<&str> = arg.split('=').collect();
+            require!(parts.len() == 2, crate::ErrorCode::InvalidArgument);
+            let key = parts[0].trim();
+            let val = parts[1].trim().parse::<i64>().map_err(|_| crate::ErrorCode::InvalidArgument)?;
+
+            match key {
+                "address_cooldown" => {
+                    require!(
+                        (MIN_ADDRESS_COOLDOWN_SECS..=MAX_ADDRESS_COOLDOWN_SECS).contains(&val),
+                        MarketError::InvalidCooldown
+                    );
+                    self.address_cooldown_secs = val;
+                }
+                _ => return Err(crate::ErrorCode::InvalidArgument.into()),
+            }
+            log.push(format!("{}: {}s", key, val));
+        }
+
+        msg!("✅ Withdrawal settings updated → {}", log.join(" | "));
+
+        emit!(WithdrawalSettingsUpdated {
+            address_cooldown_secs: self.address_cooldown_secs,
+        });
+
+        Ok(())
+    }
+
+    /// Constant space used by this struct in bytes.
+    pub const LEN: usize = 8; // i64
+}
+
+/// A destination registered in a `WithdrawalAddressBook`.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq, Eq)]
+pub struct AddressBookEntry {
+    pub token_account: Pubkey,
+    pub active_at: i64,
+}
+
+impl AddressBookEntry {
+    pub const LEN: usize = 32 + 8; // Pubkey + i64
+}
+
+/// ===========================================================================
+/// Account: WithdrawalAddressBook
+///
+/// Destinations a user allows `deposit_tokens` to pay into. Only the user
+/// can add or remove entries; a new entry becomes usable `active_at`, after
+/// the governed cooldown.
+///
+/// ## Fields:
+/// - `owner`: User owning the book (seeds the PDA)
+/// - `entries`: Registered token accounts and their activation time
+/// - `bump`: PDA bump seed
+/// ===========================================================================
+#[account]
+pub struct WithdrawalAddressBook {
+    pub owner: Pubkey,
+    pub entries: Vec<AddressBookEntry>,
+    pub bump: u8,
+}
+
+impl WithdrawalAddressBook {
+    pub const LEN: usize =
+        8 +                                                 // Anchor discriminator
+        32 +                                                // owner (Pubkey)
+        4 + AddressBookEntry::LEN * MAX_ADDRESS_BOOK_ENTRIES + // entries (Vec)
+        1;                                                  // bump (u8)
+
+    /// Registers `token_account`, usable from `active_at`.
+    ///
+    /// ## Errors:
+    /// - `AddressAlreadyRegistered` if the account is already in the book
+    /// - `AddressBookFull` if `MAX_ADDRESS_BOOK_ENTRIES` is reached
+    pub(crate) fn add(&mut self, token_account: Pubkey, active_at: i64) -> Result<()> {
+        require!(
+            !self.entries.iter().any(|entry| entry.token_account == token_account),
+            MarketError::AddressAlreadyRegistered
+        );
+        require!(self.entries.len() < MAX_ADDRESS_BOOK_ENTRIES, MarketError::AddressBookFull);
+
+        self.entries.push(AddressBookEntry { token_account, active_at });
+        Ok(())
+    }
+
+    /// Removes `token_account` from the book.
+    ///
+    /// ## Errors:
+    /// - `AddressNotRegistered` if the account is not in the book
+    pub(crate) fn remove(&mut self, token_account: &Pubkey) -> Result<()> {
+        let position = self
+            .entries
+            .iter()
+            .position(|entry| entry.token_account == *token_account)
+            .ok_or(MarketError::AddressNotRegistered)?;
+
+        self.entries.remove(position);
+        Ok(())
+    }
+
+    /// Requires `token_account` to be registered and past its cooldown.
+    ///
+    /// ## Errors:
+    /// - `AddressNotRegistered` if the account is not in the book
+    /// - `AddressInCooldown` if the entry is not active yet
+    pub fn require_active(&self, token_account: &Pubkey, now: i64) -> Result<()> {
+        let entry = self
+            .entries
+            .iter()
+            .find(|entry| entry.token_account == *token_account)
+            .ok_or(MarketError::AddressNotRegistered)?;
+        require!(now >= entry.active_at, MarketError::AddressInCooldown);
+
+        Ok(())
+    }
+}
+
+/// Maximum length (in bytes) of a Soccial Wallet user reference.
+pub const MAX_USER_REFERENCE_LEN: usize = 64;
+
+/// ===========================================================================
+/// Account: CreditLedger
+///
+/// Per-holder counter for credits into the off-chain Soccial Wallet
+/// (seeds: `["credit_ledger", owner]`). Each `credit_offchain_wallet` call
+/// consumes the holder's next sequence number, giving the backend a gap-free
+/// stream per holder to consume exactly once. Being per holder, concurrent
+/// credits from different holders never compete for the same receipt seed.
+///
+/// ## Fields:
+/// - `last_sequence`: Sequence number assigned to the holder's next credit
+/// - `total_credited`: SCTK units credited by the holder so far
+/// ===========================================================================
+#[account]
+pub struct CreditLedger {
+    pub last_sequence: u64,
+    pub total_credited: u64,
+}
+
+impl CreditLedger {
+    pub const LEN: usize =
+        8 +     // Anchor discriminator
+        8 +     // last_sequence (u64)
+        8;      // total_credited (u64)
+}
+
+/// ===========================================================================
+/// Account: CreditReceipt
+///
+/// Record of SCTK moved from a user's SPL wallet into `offchain_reserve_vault`
+/// to be credited to their Soccial Wallet balance
+/// (seeds: `["credit_receipt", owner, sequence]`).
+///
+/// ## Fields:
+/// - `sequence`: Position in the owner's `CreditLedger` stream
+/// - `user_reference`: Soccial user reference supplied by the sender
+/// - `owner`: Wallet that signed the credit
+/// - `amount`: SCTK units credited
+/// - `created_at`: Unix timestamp of the credit
+/// - `bump`: PDA bump seed
+/// ===========================================================================
+#[account]
+pub struct CreditReceipt {
+    pub sequence: u64,
+    pub user_reference: String,
+    pub owner: Pubkey,
+    pub amount: u64,
+    pub created_at: i64,
+    pub bump: u8,
+}
+
+impl CreditReceipt {
+    pub const LEN: usize =
+        8 +                             // Anchor discriminator
+        8 +                             // sequence (u64)
+        4 + MAX_USER_REFERENCE_LEN +    // user_reference (String)
+        32 +                            // owner (Pubkey)
+        8 +                             // amount (u64)
+        8 +                             // created_at (i64)
+        1;                              // bump (u8)
+}
+
+/// Maximum number of recipients in a single `batch_transfer_tokens` call.
+pub const MAX_BATCH_TRANSFER_LEGS: usize = 16;
+
+/// How the transfer fee is charged on a batch transfer.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug)]
+pub enum BatchFeeMode {
+    /// Fee is computed and deducted from each leg; recipients receive net amounts.
+    PerLeg,
+    /// Fee is computed once on the batch total and paid by the sender on top;
+    /// recipients receive the full amounts.
+    Aggregate,
+}
+
+impl core::str::FromStr for BatchFeeMode {
+    type Err = ();
+
+    fn from_str(value: &str) -> core::result::Result<Self, Self::Err> {
+        match value.trim().to_ascii_lowercase().as_str() {
+            "per_leg" | "perleg" => Ok(BatchFeeMode::PerLeg),
+            "aggregate" => Ok(BatchFeeMode::Aggregate),
+            _ => Err(()),
+        }
+    }
+}
+
+/// Domain separator prefixed to every signed `PriceQuote` message, so an
+/// API authority signature over any other payload can never be redeemed.
+pub const QUOTE_DOMAIN: &[u8] = b"SCTK_PRICE_QUOTE_V1";
+
+/// ===========================================================================
+/// Struct: PriceQuote
+///
+/// Purchase terms committed to by `core.api_authority`. The signed message is
+/// `QUOTE_DOMAIN` followed by the Borsh encoding of this struct.
+///
+/// ## Fields:
+/// - `price`: Price per 1 SCTK the buyer agreed to pay
+/// - `amount`: Gross SCTK units purchased
+/// - `buyer`: Wallet allowed to redeem the quote
+/// - `expires_at`: Unix timestamp after which the quote is rejected
+/// - `nonce`: Unique per buyer; seeds the `QuoteNonce` PDA
+/// ===========================================================================
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq, Eq)]
+pub struct PriceQuote {
+    pub price: u64,
+    pub amount: u64,
+    pub buyer: Pubkey,
+    pub expires_at: i64,
+    pub nonce: u64,
+}
+
+impl PriceQuote {
+    /// Returns the exact bytes the API authority must sign.
+    pub fn message(&self) -> Vec<u8> {
+        let mut message = QUOTE_DOMAIN.to_vec();
+        message.extend_from_slice(&self.price.to_le_bytes());
+        message.extend_from_slice(&self.amount.to_le_bytes());
+        message.extend_from_slice(self.buyer.as_ref());
+        message.extend_from_slice(&self.expires_at.to_le_bytes());
+        message.extend_from_slice(&self.nonce.to_le_bytes());
+        message
+    }
+}
+
+/// ===========================================================================
+/// Account: QuoteNonce
+///
+/// Created once per (buyer, nonce) by `buy_tokens_with_quote`. Because the PDA
+/// is seeded by the nonce, a signed quote can only be redeemed once.
+///
+/// ## Fields:
+/// - `buyer`: Wallet that redeemed the quote
+/// - `nonce`: Quote nonce
+/// - `price` / `amount`: Redeemed quote terms
+/// - `redeemed_at`: Unix timestamp of the purchase
+/// - `bump`: PDA bump seed
+/// ===========================================================================
+#[account]
+pub struct QuoteNonce {
+    pub buyer: Pubkey,
+    pub nonce: u64,
+    pub price: u64,
+    pub amount: u64,
+    pub redeemed_at: i64,
+    pub bump: u8,
+}
+
+impl QuoteNonce {
+    pub const LEN: usize =
+        8 +     // Anchor discriminator
+        32 +    // buyer (Pubkey)
+        8 +     // nonce (u64)
+        8 +     // price (u64)
+        8 +     // amount (u64)
+        8 +     // redeemed_at (i64)
+        1;      // bump (u8)
+}
@@ -0,0 +1,82 @@
+// ======================================================================
+// Soccial Token – Oracle Contexts
+//
+// Defines account contexts for the program-owned price feed and the
+// TWAP price accumulator.
+//
+// Contexts include PDA validations, optional user access,
+// and global token state references.
+//
+// License: MIT License
+// Author: Paulo Rodrigues
+// Project: Soccial Token
+// ======================================================================
+
+use anchor_lang::prelude::*;
+use crate::{
+    auth::user::UserAccessAccount,
+    oracle::state::{ManualPriceFeed, PriceAccumulator},
+    token::state::TokenState,
+};
+
+#[derive(Accounts)]
+pub struct SetPriceContext<'info> {
+    /// The price publisher (must hold `set_price`; pays on first use).
+    #[account(mut)]
+    pub caller: Signer<'info>,
+
+    /// Program-owned SCTK price feed, created on first use.
+    #[account(
+        init_if_needed,
+        payer = caller,
+        space = ManualPriceFeed::LEN,
+        seeds = [b"manual_price_feed"],
+        bump
+    )]
+    pub price_feed: Account<'info, ManualPriceFeed>,
+
+    /// Optional access control account for the caller.
+    #[account(
+        seeds = [b"user_access", caller.key().as_ref()],
+        bump
+    )]
+    pub user_access: Option<Account<'info, UserAccessAccount>>,
+
+    /// Global token configuration.
+    pub token_state: Account<'info, TokenState>,
+
+    /// System program required to create the feed.
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct ReadPriceFeedContext<'info> {
+    /// Any caller reading the price.
+    pub caller: Signer<'info>,
+
+    /// CHECK: Price feed account; owner and layout verified by `load_price_feed`.
+    pub price_feed: AccountInfo<'info>,
+
+    /// Global token configuration.
+    pub token_state: Account<'info, TokenState>,
+}
+
+#[derive(Accounts)]
+pub struct ReadTwapContext<'info> {
+    /// Any caller reading the TWAP.
+    pub caller: Signer<'info>,
+
+    /// CHECK: Quote mint the TWAP is expressed in (system program ID for SOL);
+    /// only used as a seed.
+    pub quote_mint: AccountInfo<'info>,
+
+    /// Cumulative price-time of SCTK trades in `quote_mint`.
+    #[account(
+        seeds = [b"price_accumulator", quote_mint.key().as_ref()],
+        bump
+    )]
+    pub price_accumulator: Account<'info, PriceAccumulator>,
+
+    /// Global token configuration.
+    pub token_state: Account<'info, TokenState>,
+}
@@ -0,0 +1,48 @@
+use anchor_lang::error_code;
+
+// ======================================================================
+// Soccial Token – Oracle Error Definitions
+//
+// This module defines custom errors for price feeds, such as invalid
+// prices, stale readings, confidence intervals that are too wide and
+// TWAP windows the price history cannot cover.
+//
+// License: MIT License
+// Author: Paulo Rodrigues
+// Project: Soccial Token
+// ======================================================================
+
+#[error_code]
+pub enum OracleError {
+    /// The price is zero or the confidence interval is larger than the price.
+    #[msg("Invalid price.")]
+    InvalidPrice,
+
+    /// The price was published too long ago (or in the future).
+    #[msg("Price feed is stale.")]
+    StalePrice,
+
+    /// The confidence interval exceeds the accepted bound.
+    #[msg("Price confidence interval is too wide.")]
+    ConfidenceTooWide,
+
+    /// The account is not a price feed owned by this program.
+    #[msg("Invalid price feed account.")]
+    InvalidPriceFeed,
+
+    /// The requested TWAP window is not positive.
+    #[msg("Invalid TWAP window.")]
+    InvalidWindow,
+
+    /// No priced trade has been recorded yet.
+    #[msg("No price observations recorded.")]
+    NoPriceObservations,
+
+    /// The requested window starts before the oldest stored observation.
+    #[msg("Not enough price history for the requested window.")]
+    InsufficientHistory,
+
+    /// An arithmetic overflow occurred in the price accumulator.
+    #[msg("Arithmetic overflow in price accumulator.")]
+    Overflow,
+}
@@ -0,0 +1,183 @@
+// ===========================================================================
+// Price Feed – Soccial Token (SCTK)
+// ---------------------------------------------------------------------------
+//
+// This module defines the `PriceFeed` abstraction used by any logic that
+// needs to value SCTK (staking, buybacks, ...). A feed exposes a raw
+// reading; `validated_price()` enforces staleness and confidence bounds, so
+// every consumer applies the same safety checks.
+//
+// ---------------------------------------------------------------------------
+// ## Validation:
+// - Price must be non-zero and larger than its confidence interval
+// - `publish_time` must be within `max_age` seconds of now (and not ahead)
+// - `confidence / price` must not exceed `max_confidence_bps`
+//
+// ---------------------------------------------------------------------------
+// ## Components:
+// - `PriceFeed`: Trait implemented by oracle-style accounts
+// - `PriceReading`: Price, confidence and publish time
+// - `load_price_feed()`: Reads a `ManualPriceFeed` from an unchecked account
+// - `set_price()`: Updates the program-owned `ManualPriceFeed`
+// - `get_price()`: Returns a validated reading as instruction return data
+//
+// ---------------------------------------------------------------------------
+// Author: Paulo Rodrigues
+// Project: Soccial Token
+// Website: https://www.soccial.com/thetoken
+// License: MIT
+// ===========================================================================
+
+use anchor_lang::prelude::*;
+
+use crate::{
+    economy::fee::FEE_BPS_BASE,
+    oracle::{context::*, error::OracleError, state::ManualPriceFeed},
+};
+
+/// Default maximum age of a price reading: 5 minutes.
+pub const DEFAULT_MAX_PRICE_AGE_SECS: i64 = 300;
+
+/// Default maximum confidence interval: 2% of the price (200 BPS).
+pub const DEFAULT_MAX_CONFIDENCE_BPS: u16 = 200;
+
+#[event]
+pub struct PriceUpdated {
+    pub feed: Pubkey,
+    pub price: u64,
+    pub confidence: u64,
+    pub publish_time: i64,
+    pub updated_by: Pubkey,
+}
+
+/// A single price observation.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq, Eq)]
+pub struct PriceReading {
+    pub price: u64,
+    pub confidence: u64,
+    pub publish_time: i64,
+}
+
+/// 🔮 Oracle-style price source.
+///
+/// Implementors only expose their latest reading; callers should use
+/// `validated_price()` rather than `reading()` so staleness and confidence
+/// bounds are always enforced.
+pub trait PriceFeed {
+    /// Returns the latest raw reading, without validation.
+    fn reading(&self) -> PriceReading;
+
+    /// Returns the latest reading if it is fresh and precise enough.
+    ///
+    /// ## Errors:
+    /// - `OracleError::InvalidPrice` if the price is zero or below its confidence
+    /// - `OracleError::StalePrice` if older than `max_age` seconds or in the future
+    /// - `OracleError::ConfidenceTooWide` if above `max_confidence_bps` of the price
+    fn validated_price(&self, now: i64, max_age: i64, max_confidence_bps: u16) -> Result<PriceReading> {
+        let reading = self.reading();
+
+        require!(
+            reading.price > 0 && reading.confidence < reading.price,
+            OracleError::InvalidPrice
+        );
+        require!(
+            reading.publish_time <= now && now - reading.publish_time <= max_age,
+            OracleError::StalePrice
+        );
+        require!(
+            reading.confidence as u128 * FEE_BPS_BASE as u128
+                <= reading.price as u128 * max_confidence_bps as u128,
+            OracleError::ConfidenceTooWide
+        );
+
+        Ok(reading)
+    }
+}
+
+impl PriceFeed for ManualPriceFeed {
+    fn reading(&self) -> PriceReading {
+        PriceReading {
+            price: self.price,
+            confidence: self.confidence,
+            publish_time: self.publish_time,
+        }
+    }
+}
+
+/// ===========================================================================
+/// Reads a price feed from an unchecked account (e.g. `remaining_accounts`).
+///
+/// ## Behavior:
+/// - Requires the account to be owned by this program
+/// - Deserializes it as a `ManualPriceFeed` (discriminator checked)
+///
+/// ## Errors:
+/// - `OracleError::InvalidPriceFeed` if the owner or layout does not match
+/// ===========================================================================
+pub(crate) fn load_price_feed(info: &AccountInfo) -> Result<ManualPriceFeed> {
+    require_keys_eq!(*info.owner, crate::ID, OracleError::InvalidPriceFeed);
+
+    let data = info.try_borrow_data()?;
+    ManualPriceFeed::try_deserialize(&mut &data[..]).map_err(|_| OracleError::InvalidPriceFeed.into())
+}
+
+/// ===========================================================================
+/// Pushes a new price into the program-owned `ManualPriceFeed`.
+///
+/// ## Behavior:
+/// - Creates the feed on first use
+/// - Stamps the reading with the current clock time and the caller
+///
+/// ## Errors:
+/// - `OracleError::InvalidPrice` if the price is zero or below its confidence
+/// ===========================================================================
+pub(crate) fn set_price(ctx: Context<SetPriceContext>, price: u64, confidence: u64) -> Result<()> {
+    require!(price > 0 && confidence < price, OracleError::InvalidPrice);
+
+    let caller = ctx.accounts.caller.key();
+    let feed = &mut ctx.accounts.price_feed;
+    feed.price = price;
+    feed.confidence = confidence;
+    feed.publish_time = Clock::get()?.unix_timestamp;
+    feed.updated_by = caller;
+    feed.bump = ctx.bumps.price_feed;
+
+    msg!(
+        "🔮 Price updated → {} ± {} per SCTK (published at {})",
+        price,
+        confidence,
+        feed.publish_time,
+    );
+
+    emit!(PriceUpdated {
+        feed: feed.key(),
+        price,
+        confidence,
+        publish_time: feed.publish_time,
+        updated_by: caller,
+    });
+
+    Ok(())
+}
+
+/// ===========================================================================
+/// Returns the validated reading of a price feed.
+///
+/// ## Behavior:
+/// - Loads the feed with `load_price_feed`
+/// - Applies `validated_price` with the given bounds
+/// - The reading is returned to the caller as instruction return data
+///
+/// ## Errors:
+/// - Any `OracleError` raised by loading or validation
+/// ===========================================================================
+pub(crate) fn get_price(
+    ctx: Context<ReadPriceFeedContext>,
+    max_age: i64,
+    max_confidence_bps: u16,
+) -> Result<PriceReading> {
+    let feed = load_price_feed(&ctx.accounts.price_feed)?;
+    let now = Clock::get()?.unix_timestamp;
+
+    feed.validated_price(now, max_age, max_confidence_bps)
+}
@@ -0,0 +1,11 @@
+pub mod error;
+pub mod context;
+pub mod state;
+pub mod feed;
+pub mod twap;
+
+pub use error::*;
+pub use context::*;
+pub use state::*;
+pub use feed::*;
+pub use twap::*;
@@ -0,0 +1,208 @@
+// ===========================================================================
+// Oracle State – Soccial Token (SCTK)
+// ---------------------------------------------------------------------------
+//
+// This module defines the program-owned stand-in price feed. It exposes the
+// same price / confidence / publish time triple as an external oracle, so
+// valuation logic can be written against `PriceFeed` and tested in
+// `solana-program-test` without external services.
+//
+// ---------------------------------------------------------------------------
+// ## Components:
+// - `ManualPriceFeed`: Price pushed by holders of the `set_price` permission
+// - `PriceAccumulator`: Cumulative price-time of SCTK trades per quote mint, for TWAPs
+//
+// ---------------------------------------------------------------------------
+// ## Units:
+// - Prices are expressed in quote base units per 1 SCTK (10^9 units),
+//   the same convention as `PriceConfig`
+//
+// ---------------------------------------------------------------------------
+// Author: Paulo Rodrigues
+// Project: Soccial Token
+// Website: https://www.soccial.com/thetoken
+// License: MIT
+// ===========================================================================
+
+use anchor_lang::prelude::*;
+use crate::oracle::error::OracleError;
+
+/// ===========================================================================
+/// Account: ManualPriceFeed
+///
+/// Program-owned price feed for SCTK, updated by `set_price`.
+///
+/// ## Fields:
+/// - `price`: Quote base units per 1 SCTK
+/// - `confidence`: Confidence interval (±, same units as `price`)
+/// - `publish_time`: Unix timestamp of the last update
+/// - `updated_by`: Wallet that pushed the last update
+/// - `bump`: PDA bump seed
+/// ===========================================================================
+#[account]
+pub struct ManualPriceFeed {
+    pub price: u64,
+    pub confidence: u64,
+    pub publish_time: i64,
+    pub updated_by: Pubkey,
+    pub bump: u8,
+}
+
+impl ManualPriceFeed {
+    pub const LEN: usize =
+        8 +                         // Anchor discriminator
+        8 +                         // price (u64)
+        8 +                         // confidence (u64)
+        8 +                         // publish_time (i64)
+        32 +                        // updated_by (Pubkey)
+        1;                          // bump (u8)
+}
+
+/// Number of observations kept in the `PriceAccumulator` ring buffer.
+pub const MAX_PRICE_OBSERVATIONS: usize = 32;
+
+/// Minimum spacing between two stored observations: 5 minutes. With 32
+/// slots, the accumulator always covers at least the last ~2.6 hours.
+pub const MIN_OBSERVATION_INTERVAL_SECS: i64 = 300;
+
+/// A snapshot of the cumulative price-time at a given moment.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Default, Debug, PartialEq, Eq)]
+pub struct PriceObservation {
+    pub timestamp: i64,
+    pub cumulative_price_time: u128,
+}
+
+impl PriceObservation {
+    pub const LEN: usize = 8 + 16; // i64 + u128
+}
+
+/// ===========================================================================
+/// Account: PriceAccumulator
+///
+/// Time-weighted record of the prices at which SCTK traded (buy, sell,
+/// swap) in a single quote mint, seeded by that mint (the default key for
+/// SOL). Each priced operation adds `last_price × elapsed` to the running
+/// cumulative and stores the new price; a snapshot is kept every
+/// `MIN_OBSERVATION_INTERVAL_SECS` so TWAPs can be read over past windows.
+///
+/// ## Fields:
+/// - `cumulative_price_time`: Σ price × seconds since the first trade
+/// - `last_price`: Price of the latest trade
+/// - `last_timestamp`: Unix timestamp of the latest trade (0 = none yet)
+/// - `observation_index`: Slot of the newest observation
+/// - `observation_count`: Number of filled slots
+/// - `observations`: Ring buffer of snapshots
+/// ===========================================================================
+#[account]
+pub struct PriceAccumulator {
+    pub cumulative_price_time: u128,
+    pub last_price: u64,
+    pub last_timestamp: i64,
+    pub observation_index: u8,
+    pub observation_count: u8,
+    pub observations: [PriceObservation; MAX_PRICE_OBSERVATIONS],
+}
+
+impl PriceAccumulator {
+    pub const LEN: usize =
+        8 +                                                 // Anchor discriminator
+        16 +                                                // cumulative_price_time (u128)
+        8 +                                                 // last_price (u64)
+        8 +                                                 // last_timestamp (i64)
+        1 +                                                 // observation_index (u8)
+        1 +                                                 // observation_count (u8)
+        PriceObservation::LEN * MAX_PRICE_OBSERVATIONS;     // observations
+
+    /// Records a trade at `price`.
+    ///
+    /// ## Behavior:
+    /// - Accrues the previous price over the elapsed time
+    /// - Stores `price` as the latest price
+    /// - Pushes a snapshot if the newest one is older than the minimum interval
+    ///
+    /// ## Errors:
+    /// - `OracleError::Overflow` if the cumulative overflows
+    pub(crate) fn record(&mut self, price: u64, now: i64) -> Result<()> {
+        self.cumulative_price_time = self.cumulative_at(now)?;
+        self.last_price = price;
+        self.last_timestamp = now;
+
+        let newest = self.observations[self.observation_index as usize];
+        if self.observation_count == 0 || now - newest.timestamp >= MIN_OBSERVATION_INTERVAL_SECS {
+            if self.observation_count > 0 {
+                self.observation_index = ((self.observation_index as usize + 1) % MAX_PRICE_OBSERVATIONS) as u8;
+            }
+            self.observations[self.observation_index as usize] = PriceObservation {
+                timestamp: now,
+                cumulative_price_time: self.cumulative_price_time,
+            };
+            self.observation_count = (self.observation_count as usize + 1).min(MAX_PRICE_OBSERVATIONS) as u8;
+        }
+
+        Ok(())
+    }
+
+    /// Returns the cumulative price-time at `now`, assuming the last price held.
+    pub fn cumulative_at(&self, now: i64) -> Result<u128> {
+        if self.last_timestamp == 0 {
+            return Ok(self.cumulative_price_time);
+        }
+
+        let elapsed = now.saturating_sub(self.last_timestamp).max(0) as u128;
+        self.cumulative_price_time
+            .checked_add(self.last_price as u128 * elapsed)
+            .ok_or(OracleError::Overflow.into())
+    }
+
+    /// Returns the time-weighted average price over the `window` seconds before `now`.
+    ///
+    /// ## Behavior:
+    /// - Uses the stored snapshots, the latest trade and `now` as known points
+    /// - Between two points, the cumulative is interpolated linearly
+    ///
+    /// ## Errors:
+    /// - `OracleError::InvalidWindow` if `window` is not positive
+    /// - `OracleError::NoPriceObservations` if no trade was recorded yet
+    /// - `OracleError::InsufficientHistory` if the window starts before the
+    ///   oldest snapshot
+    pub fn twap(&self, now: i64, window: i64) -> Result<u64> {
+        require!(window > 0, OracleError::InvalidWindow);
+        require!(self.observation_count > 0, OracleError::NoPriceObservations);
+
+        let count = self.observation_count as usize;
+        let oldest = (self.observation_index as usize + MAX_PRICE_OBSERVATIONS + 1 - count) % MAX_PRICE_OBSERVATIONS;
+
+        let mut points: Vec<(i64, u128)> = (0..count)
+            .map(|k| self.observations[(oldest + k) % MAX_PRICE_OBSERVATIONS])
+            .map(|observation| (observation.timestamp, observation.cumulative_price_time))
+            .collect();
+        for point in [
+            (self.last_timestamp, self.cumulative_price_time),
+            (now, self.cumulative_at(now)?),
+        ] {
+            if point.0 > points[points.len() - 1].0 {
+                points.push(point);
+            }
+        }
+
+        let start = now - window;
+        require!(start >= points[0].0, OracleError::InsufficientHistory);
+
+        let end_cumulative = points[points.len() - 1].1;
+        let position = points
+            .iter()
+            .rposition(|(timestamp, _)| *timestamp <= start)
+            .ok_or(OracleError::InsufficientHistory)?;
+
+        let start_cumulative = match points.get(position + 1) {
+            Some(&(next_timestamp, next_cumulative)) => {
+                let (timestamp, cumulative) = points[position];
+                cumulative + (next_cumulative - cumulative) * (start - timestamp) as u128
+                    / (next_timestamp - timestamp) as u128
+            }
+            None => points[position].1,
+        };
+
+        Ok(((end_cumulative - start_cumulative) / window as u128) as u64)
+    }
+}
@@ -0,0 +1,65 @@
+// ===========================================================================
+// TWAP – Soccial Token (SCTK)
+// ---------------------------------------------------------------------------
+//
+// Exposes the time-weighted average price of SCTK trades, computed from a
+// `PriceAccumulator`. Prices are only comparable within one quote currency,
+// so there is one accumulator per quote mint (the default key for SOL):
+// - Protocol purchases, quoted buys and sell-backs record in the
+//   `PriceConfig` quote mint
+// - AMM swaps record the spot price in the pool's quote mint
+// Relayed `buy_tokens` sales are settled off-chain and are not recorded.
+//
+// ---------------------------------------------------------------------------
+// ## Components:
+// - `TwapReading`: TWAP over a window, plus the latest trade
+// - `get_twap()`: Returns a `TwapReading` as instruction return data
+//
+// ---------------------------------------------------------------------------
+// Author: Paulo Rodrigues
+// Project: Soccial Token
+// Website: https://www.soccial.com/thetoken
+// License: MIT
+// ===========================================================================
+
+use anchor_lang::prelude::*;
+
+use crate::oracle::context::*;
+
+/// Time-weighted average price over a window, returned by `get_twap`.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq, Eq)]
+pub struct TwapReading {
+    pub twap: u64,
+    pub window: i64,
+    pub last_price: u64,
+    pub last_timestamp: i64,
+}
+
+/// ===========================================================================
+/// Returns the SCTK TWAP over the last `window` seconds.
+///
+/// ## Behavior:
+/// - Reads the `PriceAccumulator` of the given quote mint at the current clock
+/// - The TWAP is expressed in that mint's base units per 1 SCTK
+/// - The reading is returned to the caller as instruction return data
+///
+/// ## Errors:
+/// - `OracleError::InvalidWindow` if `window` is not positive
+/// - `OracleError::NoPriceObservations` if no trade was recorded yet
+/// - `OracleError::InsufficientHistory` if the window exceeds the history
+/// ===========================================================================
+pub(crate) fn get_twap(ctx: Context<ReadTwapContext>, window: i64) -> Result<TwapReading> {
+    let accumulator = &ctx.accounts.price_accumulator;
+    let now = Clock::get()?.unix_timestamp;
+
+    let twap = accumulator.twap(now, window)?;
+
+    msg!("📈 SCTK TWAP over {}s in {} → {}", window, ctx.accounts.quote_mint.key(), twap);
+
+    Ok(TwapReading {
+        twap,
+        window,
+        last_price: accumulator.last_price,
+        last_timestamp: accumulator.last_timestamp,
+    })
+}
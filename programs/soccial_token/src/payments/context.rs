@@ -0,0 +1,620 @@
+// ======================================================================
+// Soccial Token – Payments Contexts
+//
+// Defines account contexts for paying Soccial services and third-party
+// merchants in SCTK: invoices, the merchant registry, merchant payments and
+// recurring subscriptions.
+//
+// Contexts include PDA validations, token account checks, optional user access,
+// and global token state references.
+//
+// License: MIT License
+// Author: Paulo Rodrigues
+// Project: Soccial Token
+// ======================================================================
+
+use anchor_lang::prelude::*;
+use anchor_spl::token::{Mint, Token, TokenAccount};
+use crate::{
+    auth::user::UserAccessAccount,
+    payments::state::{Invoice, Merchant, Subscription},
+    token::state::TokenState,
+};
+
+#[derive(Accounts)]
+#[instruction(args: Vec<String>)]
+pub struct CreateInvoiceContext<'info> {
+    /// The merchant issuing the invoice (pays for the account).
+    #[account(mut)]
+    pub caller: Signer<'info>,
+
+    /// Invoice PDA, one per merchant and external order ID.
+    #[account(
+        init,
+        payer = caller,
+        space = Invoice::LEN,
+        seeds = [
+            b"invoice",
+            caller.key().as_ref(),
+            args.first().map(String::as_bytes).unwrap_or_default()
+        ],
+        bump
+    )]
+    pub invoice: Account<'info, Invoice>,
+
+    /// Optional access control account for the caller.
+    #[account(
+        seeds = [b"user_access", caller.key().as_ref()],
+        bump
+    )]
+    pub user_access: Option<Account<'info, UserAccessAccount>>,
+
+    /// Global token configuration.
+    pub token_state: Account<'info, TokenState>,
+
+    /// System program required to create the invoice.
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct PayInvoiceContext<'info> {
+    /// The payer settling the invoice.
+    pub caller: Signer<'info>,
+
+    // ------------------------------------------------------------------------
+    // Invoice, Payer & Merchant
+    // ------------------------------------------------------------------------
+
+    /// Invoice being paid.
+    #[account(
+        mut,
+        seeds = [b"invoice", invoice.merchant.as_ref(), invoice.order_id.as_bytes()],
+        bump = invoice.bump
+    )]
+    pub invoice: Account<'info, Invoice>,
+
+    /// Token account of the payer (source of the payment and fee).
+    #[account(
+        mut,
+        associated_token::mint = token_mint,
+        associated_token::authority = caller
+    )]
+    pub payer_token_account: Account<'info, TokenAccount>,
+
+    /// Token account of the merchant receiving the net payment.
+    #[account(
+        mut,
+        associated_token::mint = token_mint,
+        associated_token::authority = invoice.merchant
+    )]
+    pub merchant_token_account: Account<'info, TokenAccount>,
+
+    // ------------------------------------------------------------------------
+    // Rewards Vault
+    // ------------------------------------------------------------------------
+
+    /// CHECK: PDA authority for rewards logic. Verified in handler.
+    #[account(seeds = [b"rewards_vault"], bump)]
+    pub rewards_vault: AccountInfo<'info>,
+
+    /// Token account holding tokens designated for rewards.
+    #[account(
+        mut,
+        associated_token::mint = token_mint,
+        associated_token::authority = rewards_vault
+    )]
+    pub rewards_vault_token_account: Account<'info, TokenAccount>,
+
+    /// CHECK: Ledger PDA of `rewards_vault`. Posted to in logic once opened.
+    #[account(
+        mut,
+        seeds = [b"vault_ledger", rewards_vault.key().as_ref()],
+        bump
+    )]
+    pub rewards_ledger: AccountInfo<'info>,
+
+    // ------------------------------------------------------------------------
+    // Revenue Vault
+    // ------------------------------------------------------------------------
+
+    /// CHECK: PDA authority for revenue logic. Verified in handler.
+    #[account(seeds = [b"revenue_vault"], bump)]
+    pub revenue_vault: AccountInfo<'info>,
+
+    /// Token account holding tokens allocated for revenue collection.
+    #[account(
+        mut,
+        associated_token::mint = token_mint,
+        associated_token::authority = revenue_vault
+    )]
+    pub revenue_vault_token_account: Account<'info, TokenAccount>,
+
+    /// CHECK: Ledger PDA of `revenue_vault`. Posted to in logic once opened.
+    #[account(
+        mut,
+        seeds = [b"vault_ledger", revenue_vault.key().as_ref()],
+        bump
+    )]
+    pub revenue_ledger: AccountInfo<'info>,
+
+    // ------------------------------------------------------------------------
+    // Airdrop Vault
+    // ------------------------------------------------------------------------
+
+    /// CHECK: PDA authority for airdrop logic. Verified in handler.
+    #[account(seeds = [b"airdrop_vault"], bump)]
+    pub airdrop_vault: AccountInfo<'info>,
+
+    /// Token account holding tokens reserved for airdrops.
+    #[account(
+        mut,
+        associated_token::mint = token_mint,
+        associated_token::authority = airdrop_vault
+    )]
+    pub airdrop_vault_token_account: Account<'info, TokenAccount>,
+
+    /// CHECK: Ledger PDA of `airdrop_vault`. Posted to in logic once opened.
+    #[account(
+        mut,
+        seeds = [b"vault_ledger", airdrop_vault.key().as_ref()],
+        bump
+    )]
+    pub airdrop_ledger: AccountInfo<'info>,
+
+    // ------------------------------------------------------------------------
+    // Misc
+    // ------------------------------------------------------------------------
+
+    /// Optional access control account for the caller.
+    #[account(
+        seeds = [b"user_access", caller.key().as_ref()],
+        bump
+    )]
+    pub user_access: Option<Account<'info, UserAccessAccount>>,
+
+    /// SPL Token mint (SCTK).
+    pub token_mint: Account<'info, Mint>,
+
+    /// Global token configuration.
+    pub token_state: Account<'info, TokenState>,
+
+    /// SPL Token program.
+    pub token_program: Program<'info, Token>,
+}
+
+#[derive(Accounts)]
+pub struct CancelInvoiceContext<'info> {
+    /// The merchant cancelling the invoice.
+    pub caller: Signer<'info>,
+
+    /// Invoice being cancelled.
+    #[account(
+        mut,
+        seeds = [b"invoice", invoice.merchant.as_ref(), invoice.order_id.as_bytes()],
+        bump = invoice.bump
+    )]
+    pub invoice: Account<'info, Invoice>,
+
+    /// Optional access control account for the caller.
+    #[account(
+        seeds = [b"user_access", caller.key().as_ref()],
+        bump
+    )]
+    pub user_access: Option<Account<'info, UserAccessAccount>>,
+
+    /// Global token configuration.
+    pub token_state: Account<'info, TokenState>,
+}
+
+#[derive(Accounts)]
+pub struct ManageMerchantContext<'info> {
+    /// The caller registering or updating the merchant (must be authorized).
+    #[account(mut)]
+    pub caller: Signer<'info>,
+
+    // ------------------------------------------------------------------------
+    // Merchant
+    // ------------------------------------------------------------------------
+
+    /// CHECK: Merchant wallet identifying the registry entry. Used only as seed.
+    pub merchant_authority: AccountInfo<'info>,
+
+    /// Merchant registry entry, created on first use.
+    #[account(
+        init_if_needed,
+        payer = caller,
+        space = Merchant::LEN,
+        seeds = [b"merchant", merchant_authority.key().as_ref()],
+        bump
+    )]
+    pub merchant: Account<'info, Merchant>,
+
+    /// SCTK account receiving the merchant's net payments.
+    #[account(token::mint = token_mint)]
+    pub settlement_token_account: Account<'info, TokenAccount>,
+
+    // ------------------------------------------------------------------------
+    // Misc
+    // ------------------------------------------------------------------------
+
+    /// SPL Token mint (SCTK).
+    pub token_mint: Account<'info, Mint>,
+
+    /// Optional access control account for the caller.
+    #[account(
+        seeds = [b"user_access", caller.key().as_ref()],
+        bump
+    )]
+    pub user_access: Option<Account<'info, UserAccessAccount>>,
+
+    /// Global token configuration.
+    pub token_state: Account<'info, TokenState>,
+
+    /// System program required to create the merchant entry.
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct MerchantPaymentContext<'info> {
+    /// The payer settling the purchase.
+    pub caller: Signer<'info>,
+
+    // ------------------------------------------------------------------------
+    // Merchant & Payer
+    // ------------------------------------------------------------------------
+
+    /// Merchant registry entry receiving the payment.
+    #[account(
+        mut,
+        seeds = [b"merchant", merchant.authority.as_ref()],
+        bump = merchant.bump
+    )]
+    pub merchant: Account<'info, Merchant>,
+
+    /// Merchant settlement account (must match the registry entry).
+    #[account(
+        mut,
+        address = merchant.settlement_token_account
+    )]
+    pub settlement_token_account: Account<'info, TokenAccount>,
+
+    /// Token account of the payer (source of the payment and fee).
+    #[account(
+        mut,
+        associated_token::mint = token_mint,
+        associated_token::authority = caller
+    )]
+    pub payer_token_account: Account<'info, TokenAccount>,
+
+    /// Referrer token account, required when the merchant has a referrer.
+    #[account(mut, token::mint = token_mint)]
+    pub referrer_token_account: Option<Account<'info, TokenAccount>>,
+
+    // ------------------------------------------------------------------------
+    // Rewards Vault
+    // ------------------------------------------------------------------------
+
+    /// CHECK: PDA authority for rewards logic. Verified in handler.
+    #[account(seeds = [b"rewards_vault"], bump)]
+    pub rewards_vault: AccountInfo<'info>,
+
+    /// Token account holding tokens designated for rewards.
+    #[account(
+        mut,
+        associated_token::mint = token_mint,
+        associated_token::authority = rewards_vault
+    )]
+    pub rewards_vault_token_account: Account<'info, TokenAccount>,
+
+    /// CHECK: Ledger PDA of `rewards_vault`. Posted to in logic once opened.
+    #[account(
+        mut,
+        seeds = [b"vault_ledger", rewards_vault.key().as_ref()],
+        bump
+    )]
+    pub rewards_ledger: AccountInfo<'info>,
+
+    // ------------------------------------------------------------------------
+    // Revenue Vault
+    // ------------------------------------------------------------------------
+
+    /// CHECK: PDA authority for revenue logic. Verified in handler.
+    #[account(seeds = [b"revenue_vault"], bump)]
+    pub revenue_vault: AccountInfo<'info>,
+
+    /// Token account holding tokens allocated for revenue collection.
+    #[account(
+        mut,
+        associated_token::mint = token_mint,
+        associated_token::authority = revenue_vault
+    )]
+    pub revenue_vault_token_account: Account<'info, TokenAccount>,
+
+    /// CHECK: Ledger PDA of `revenue_vault`. Posted to in logic once opened.
+    #[account(
+        mut,
+        seeds = [b"vault_ledger", revenue_vault.key().as_ref()],
+        bump
+    )]
+    pub revenue_ledger: AccountInfo<'info>,
+
+    // ------------------------------------------------------------------------
+    // Airdrop Vault
+    // ------------------------------------------------------------------------
+
+    /// CHECK: PDA authority for airdrop logic. Verified in handler.
+    #[account(seeds = [b"airdrop_vault"], bump)]
+    pub airdrop_vault: AccountInfo<'info>,
+
+    /// Token account holding tokens reserved for airdrops.
+    #[account(
+        mut,
+        associated_token::mint = token_mint,
+        associated_token::authority = airdrop_vault
+    )]
+    pub airdrop_vault_token_account: Account<'info, TokenAccount>,
+
+    /// CHECK: Ledger PDA of `airdrop_vault`. Posted to in logic once opened.
+    #[account(
+        mut,
+        seeds = [b"vault_ledger", airdrop_vault.key().as_ref()],
+        bump
+    )]
+    pub airdrop_ledger: AccountInfo<'info>,
+
+    // ------------------------------------------------------------------------
+    // Misc
+    // ------------------------------------------------------------------------
+
+    /// Optional access control account for the caller.
+    #[account(
+        seeds = [b"user_access", caller.key().as_ref()],
+        bump
+    )]
+    pub user_access: Option<Account<'info, UserAccessAccount>>,
+
+    /// SPL Token mint (SCTK).
+    pub token_mint: Account<'info, Mint>,
+
+    /// Global token configuration.
+    pub token_state: Account<'info, TokenState>,
+
+    /// SPL Token program.
+    pub token_program: Program<'info, Token>,
+}
+
+#[derive(Accounts)]
+pub struct CreateSubscriptionContext<'info> {
+    /// The payer subscribing (pays for the account and approves the delegate).
+    #[account(mut)]
+    pub caller: Signer<'info>,
+
+    /// CHECK: Merchant wallet receiving the payments. Used only as seed.
+    pub merchant: AccountInfo<'info>,
+
+    /// Subscription PDA, one per payer and merchant.
+    #[account(
+        init,
+        payer = caller,
+        space = Subscription::LEN,
+        seeds = [b"subscription", caller.key().as_ref(), merchant.key().as_ref()],
+        bump
+    )]
+    pub subscription: Account<'info, Subscription>,
+
+    /// Token account of the payer, on which the delegate is approved.
+    #[account(
+        mut,
+        associated_token::mint = token_mint,
+        associated_token::authority = caller
+    )]
+    pub payer_token_account: Account<'info, TokenAccount>,
+
+    /// CHECK: PDA approved as delegate to pull subscription charges.
+    #[account(seeds = [b"subscription_delegate"], bump)]
+    pub subscription_delegate: AccountInfo<'info>,
+
+    // ------------------------------------------------------------------------
+    // Misc
+    // ------------------------------------------------------------------------
+
+    /// SPL Token mint (SCTK).
+    pub token_mint: Account<'info, Mint>,
+
+    /// Optional access control account for the caller.
+    #[account(
+        seeds = [b"user_access", caller.key().as_ref()],
+        bump
+    )]
+    pub user_access: Option<Account<'info, UserAccessAccount>>,
+
+    /// Global token configuration.
+    pub token_state: Account<'info, TokenState>,
+
+    /// SPL Token program.
+    pub token_program: Program<'info, Token>,
+
+    /// System program required to create the subscription.
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct ChargeSubscriptionContext<'info> {
+    /// Anyone may crank a due subscription.
+    pub caller: Signer<'info>,
+
+    // ------------------------------------------------------------------------
+    // Subscription, Payer & Merchant
+    // ------------------------------------------------------------------------
+
+    /// Subscription being charged.
+    #[account(
+        mut,
+        seeds = [b"subscription", subscription.payer.as_ref(), subscription.merchant.as_ref()],
+        bump = subscription.bump
+    )]
+    pub subscription: Account<'info, Subscription>,
+
+    /// Token account of the payer (source of the charge and fee).
+    #[account(
+        mut,
+        associated_token::mint = token_mint,
+        associated_token::authority = subscription.payer
+    )]
+    pub payer_token_account: Account<'info, TokenAccount>,
+
+    /// Token account of the merchant receiving the net charge.
+    #[account(
+        mut,
+        associated_token::mint = token_mint,
+        associated_token::authority = subscription.merchant
+    )]
+    pub merchant_token_account: Account<'info, TokenAccount>,
+
+    /// CHECK: PDA delegate signing the pull from the payer ATA.
+    #[account(seeds = [b"subscription_delegate"], bump)]
+    pub subscription_delegate: AccountInfo<'info>,
+
+    // ------------------------------------------------------------------------
+    // Rewards Vault
+    // ------------------------------------------------------------------------
+
+    /// CHECK: PDA authority for rewards logic. Verified in handler.
+    #[account(seeds = [b"rewards_vault"], bump)]
+    pub rewards_vault: AccountInfo<'info>,
+
+    /// Token account holding tokens designated for rewards.
+    #[account(
+        mut,
+        associated_token::mint = token_mint,
+        associated_token::authority = rewards_vault
+    )]
+    pub rewards_vault_token_account: Account<'info, TokenAccount>,
+
+    /// CHECK: Ledger PDA of `rewards_vault`. Posted to in logic once opened.
+    #[account(
+        mut,
+        seeds = [b"vault_ledger", rewards_vault.key().as_ref()],
+        bump
+    )]
+    pub rewards_ledger: AccountInfo<'info>,
+
+    // ------------------------------------------------------------------------
+    // Revenue Vault
+    // ------------------------------------------------------------------------
+
+    /// CHECK: PDA authority for revenue logic. Verified in handler.
+    #[account(seeds = [b"revenue_vault"], bump)]
+    pub revenue_vault: AccountInfo<'info>,
+
+    /// Token account holding tokens allocated for revenue collection.
+    #[account(
+        mut,
+        associated_token::mint = token_mint,
+        associated_token::authority = revenue_vault
+    )]
+    pub revenue_vault_token_account: Account<'info, TokenAccount>,
+
+    /// CHECK: Ledger PDA of `revenue_vault`. Posted to in logic once opened.
+    #[account(
+        mut,
+        seeds = [b"vault_ledger", revenue_vault.key().as_ref()],
+        bump
+    )]
+    pub revenue_ledger: AccountInfo<'info>,
+
+    // ------------------------------------------------------------------------
+    // Airdrop Vault
+    // ------------------------------------------------------------------------
+
+    /// CHECK: PDA authority for airdrop logic. Verified in handler.
+    #[account(seeds = [b"airdrop_vault"], bump)]
+    pub airdrop_vault: AccountInfo<'info>,
+
+    /// Token account holding tokens reserved for airdrops.
+    #[account(
+        mut,
+        associated_token::mint = token_mint,
+        associated_token::authority = airdrop_vault
+    )]
+    pub airdrop_vault_token_account: Account<'info, TokenAccount>,
+
+    /// CHECK: Ledger PDA of `airdrop_vault`. Posted to in logic once opened.
+    #[account(
+        mut,
+        seeds = [b"vault_ledger", airdrop_vault.key().as_ref()],
+        bump
+    )]
+    pub airdrop_ledger: AccountInfo<'info>,
+
+    // ------------------------------------------------------------------------
+    // Misc
+    // ------------------------------------------------------------------------
+
+    /// Optional access control account of the payer (fee discounts).
+    #[account(
+        seeds = [b"user_access", subscription.payer.as_ref()],
+        bump
+    )]
+    pub payer_access: Option<Account<'info, UserAccessAccount>>,
+
+    /// SPL Token mint (SCTK).
+    pub token_mint: Account<'info, Mint>,
+
+    /// Global token configuration.
+    pub token_state: Account<'info, TokenState>,
+
+    /// SPL Token program.
+    pub token_program: Program<'info, Token>,
+}
+
+#[derive(Accounts)]
+pub struct CancelSubscriptionContext<'info> {
+    /// The payer or merchant cancelling the subscription.
+    pub caller: Signer<'info>,
+
+    /// Subscription being cancelled; rent is returned to the payer.
+    #[account(
+        mut,
+        close = payer,
+        seeds = [b"subscription", subscription.payer.as_ref(), subscription.merchant.as_ref()],
+        bump = subscription.bump
+    )]
+    pub subscription: Account<'info, Subscription>,
+
+    /// CHECK: Payer wallet receiving the rent refund.
+    #[account(mut, address = subscription.payer)]
+    pub payer: AccountInfo<'info>,
+
+    /// Token account of the payer, whose delegate allowance is lowered.
+    #[account(
+        mut,
+        associated_token::mint = token_mint,
+        associated_token::authority = subscription.payer
+    )]
+    pub payer_token_account: Account<'info, TokenAccount>,
+
+    /// CHECK: PDA approved as delegate to pull subscription charges.
+    #[account(seeds = [b"subscription_delegate"], bump)]
+    pub subscription_delegate: AccountInfo<'info>,
+
+    // ------------------------------------------------------------------------
+    // Misc
+    // ------------------------------------------------------------------------
+
+    /// SPL Token mint (SCTK).
+    pub token_mint: Account<'info, Mint>,
+
+    /// Optional access control account for the caller.
+    #[account(
+        seeds = [b"user_access", caller.key().as_ref()],
+        bump
+    )]
+    pub user_access: Option<Account<'info, UserAccessAccount>>,
+
+    /// Global token configuration.
+    pub token_state: Account<'info, TokenState>,
+
+    /// SPL Token program.
+    pub token_program: Program<'info, Token>,
+}
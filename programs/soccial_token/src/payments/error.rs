@@ -0,0 +1,79 @@
+use anchor_lang::error_code;
+
+// ======================================================================
+// Soccial Token – Payments Error Definitions
+//
+// This module defines custom errors for paying Soccial services in SCTK,
+// such as invoices, third-party merchant payments and subscriptions.
+//
+// License: MIT License
+// Author: Paulo Rodrigues
+// Project: Soccial Token
+// ======================================================================
+
+#[error_code]
+pub enum PaymentsError {
+    /// The provided amount is invalid (e.g., zero).
+    #[msg("Invalid amount.")]
+    InvalidAmount,
+
+    /// The external order ID is empty or longer than `MAX_ORDER_ID_LEN`.
+    #[msg("Invalid order ID.")]
+    InvalidOrderId,
+
+    /// The expiry timestamp is already in the past.
+    #[msg("Invalid invoice expiry.")]
+    InvalidExpiry,
+
+    /// The invoice is not open (already paid or cancelled).
+    #[msg("Invoice is not open.")]
+    InvoiceNotOpen,
+
+    /// The invoice expired before being paid.
+    #[msg("Invoice has expired.")]
+    InvoiceExpired,
+
+    /// The amount accepted by the payer does not match the invoice.
+    #[msg("Payment amount does not match the invoice.")]
+    AmountMismatch,
+
+    /// The payer does not have enough balance to complete the payment.
+    #[msg("Insufficient funds.")]
+    InsufficientFunds,
+
+    /// Only the invoice merchant can perform this action.
+    #[msg("Caller is not the invoice merchant.")]
+    NotInvoiceMerchant,
+
+    /// The merchant fee tier exceeds the allowed maximum.
+    #[msg("Invalid merchant fee.")]
+    InvalidMerchantFee,
+
+    /// The referral share exceeds 100% of the fee.
+    #[msg("Invalid referral share.")]
+    InvalidReferralShare,
+
+    /// The merchant is deactivated and cannot receive payments.
+    #[msg("Merchant is not active.")]
+    MerchantInactive,
+
+    /// The referrer token account is missing or does not match the merchant.
+    #[msg("Invalid referrer account.")]
+    InvalidReferrerAccount,
+
+    /// Overflow occurred during calculation.
+    #[msg("Overflow occurred during calculation.")]
+    Overflow,
+
+    /// The subscription period must be positive.
+    #[msg("Invalid subscription period.")]
+    InvalidPeriod,
+
+    /// The next subscription charge is not due yet.
+    #[msg("Subscription charge is not due yet.")]
+    SubscriptionNotDue,
+
+    /// Only the subscription payer or merchant can perform this action.
+    #[msg("Caller is not part of the subscription.")]
+    NotSubscriptionParty,
+}
@@ -0,0 +1,260 @@
+// ===========================================================================
+// Invoices – Soccial Token (SCTK)
+// ---------------------------------------------------------------------------
+//
+// This module lets Soccial services request payment in SCTK through on-chain
+// invoices, so an order can be verified as paid from chain state alone.
+//
+// ---------------------------------------------------------------------------
+// ## Flow:
+// 1. The merchant creates an `Invoice` for an external order ID
+// 2. A payer settles it once; the market transfer fee is split to the vaults
+// 3. `InvoicePaid` is emitted as the payment receipt
+//
+// ---------------------------------------------------------------------------
+// ## Components:
+// - `create_invoice()`: Opens a payment request for an order
+// - `pay_invoice()`: Transfers SCTK from the payer to the merchant, with fee
+// - `cancel_invoice()`: Closes an unpaid invoice
+//
+// ---------------------------------------------------------------------------
+// Author: Paulo Rodrigues
+// Project: Soccial Token
+// Website: https://www.soccial.com/thetoken
+// License: MIT
+// ===========================================================================
+
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, Transfer};
+
+use crate::{
+    economics::state::FeeOperation,
+    market::{calculate_fee, distribute_fees, FeeDistributionContext},
+    payments::{
+        context::*,
+        error::PaymentsError,
+        state::{InvoiceStatus, MAX_ORDER_ID_LEN},
+    },
+    utils::math::format_sctk,
+};
+
+#[event]
+pub struct InvoiceCreated {
+    pub invoice: Pubkey,
+    pub merchant: Pubkey,
+    pub order_id: String,
+    pub amount: u64,
+    pub expires_at: i64,
+}
+
+#[event]
+pub struct InvoicePaid {
+    pub invoice: Pubkey,
+    pub merchant: Pubkey,
+    pub payer: Pubkey,
+    pub order_id: String,
+    pub amount: u64,
+    pub net_received: u64,
+    pub fee_charged: u64,
+    pub to_rewards: u64,
+    pub to_airdrop: u64,
+    pub to_revenue: u64,
+    pub discount_bps: u16,
+    pub paid_at: i64,
+}
+
+#[event]
+pub struct InvoiceCancelled {
+    pub invoice: Pubkey,
+    pub merchant: Pubkey,
+    pub order_id: String,
+}
+
+/// ===========================================================================
+/// Creates an open invoice for an external order ID, payable once in SCTK.
+///
+/// ## Behavior:
+/// - The caller becomes the invoice merchant and pays for the account
+/// - `expires_at = 0` creates an invoice that never expires
+///
+/// ## Errors:
+/// - `PaymentsError::InvalidOrderId` if the order ID is empty or too long
+/// - `PaymentsError::InvalidAmount` on zero amount
+/// - `PaymentsError::InvalidExpiry` if the expiry is already in the past
+/// ===========================================================================
+pub(crate) fn create_invoice(
+    ctx: Context<CreateInvoiceContext>,
+    order_id: String,
+    amount: u64,
+    expires_at: i64,
+) -> Result<()> {
+    require!(
+        !order_id.is_empty() && order_id.len() <= MAX_ORDER_ID_LEN,
+        PaymentsError::InvalidOrderId
+    );
+    require!(amount > 0, PaymentsError::InvalidAmount);
+
+    let now = Clock::get()?.unix_timestamp;
+    require!(expires_at == 0 || expires_at > now, PaymentsError::InvalidExpiry);
+
+    let invoice = &mut ctx.accounts.invoice;
+    invoice.merchant = ctx.accounts.caller.key();
+    invoice.order_id = order_id.clone();
+    invoice.amount = amount;
+    invoice.expires_at = expires_at;
+    invoice.status = InvoiceStatus::Open;
+    invoice.payer = Pubkey::default();
+    invoice.fee_charged = 0;
+    invoice.created_at = now;
+    invoice.paid_at = 0;
+    invoice.bump = ctx.bumps.invoice;
+
+    msg!(
+        "🧾 Invoice {} created for {} SCTK ({} units) | ⏳ Expires at: {}",
+        order_id,
+        format_sctk(amount),
+        amount,
+        expires_at,
+    );
+
+    emit!(InvoiceCreated {
+        invoice: invoice.key(),
+        merchant: invoice.merchant,
+        order_id,
+        amount,
+        expires_at,
+    });
+
+    Ok(())
+}
+
+/// ===========================================================================
+/// Pays an open invoice from the caller's wallet and marks it as paid.
+///
+/// ## Behavior:
+/// - The payer is charged the invoice amount; the transfer fee from the
+///   `FeeSchedule` (after flag discounts) is deducted from it
+/// - The net amount goes to the merchant and the fee is split to the vaults
+/// - `amount` must match the invoice, so the payer confirms what is charged
+///
+/// ## Errors:
+/// - `PaymentsError::InvoiceNotOpen` if already paid or cancelled
+/// - `PaymentsError::InvoiceExpired` if the expiry has passed
+/// - `PaymentsError::AmountMismatch` if `amount` differs from the invoice
+/// - `PaymentsError::InsufficientFunds` if the payer lacks SCTK
+/// ===========================================================================
+pub(crate) fn pay_invoice(
+    ctx: Context<PayInvoiceContext>,
+    amount: u64,
+) -> Result<()> {
+    let now = Clock::get()?.unix_timestamp;
+    let invoice = &ctx.accounts.invoice;
+
+    require!(invoice.status == InvoiceStatus::Open, PaymentsError::InvoiceNotOpen);
+    require!(!invoice.is_expired(now), PaymentsError::InvoiceExpired);
+    require!(amount == invoice.amount, PaymentsError::AmountMismatch);
+    require!(
+        ctx.accounts.payer_token_account.amount >= amount,
+        PaymentsError::InsufficientFunds
+    );
+
+    let (net_amount, fee_amount, discount_bps) = calculate_fee(
+        &ctx.accounts.token_state,
+        ctx.accounts.user_access.as_deref(),
+        FeeOperation::Transfer,
+        amount,
+    )?;
+
+    let cpi_ctx_net = CpiContext::new(
+        ctx.accounts.token_program.to_account_info(),
+        Transfer {
+            from: ctx.accounts.payer_token_account.to_account_info(),
+            to: ctx.accounts.merchant_token_account.to_account_info(),
+            authority: ctx.accounts.caller.to_account_info(),
+        },
+    );
+    token::transfer(cpi_ctx_net, net_amount)?;
+
+    let (to_rewards, to_airdrop, to_revenue) = distribute_fees(
+        &FeeDistributionContext {
+            token_state: ctx.accounts.token_state.clone(),
+            token_program: ctx.accounts.token_program.to_account_info(),
+            source_token_account: ctx.accounts.payer_token_account.to_account_info(),
+            rewards_vault_token_account: ctx.accounts.rewards_vault_token_account.to_account_info(),
+            rewards_ledger: ctx.accounts.rewards_ledger.to_account_info(),
+            airdrop_vault_token_account: ctx.accounts.airdrop_vault_token_account.to_account_info(),
+            airdrop_ledger: ctx.accounts.airdrop_ledger.to_account_info(),
+            revenue_vault_token_account: ctx.accounts.revenue_vault_token_account.to_account_info(),
+            revenue_ledger: ctx.accounts.revenue_ledger.to_account_info(),
+            authority: ctx.accounts.caller.to_account_info(),
+        },
+        fee_amount,
+        None,
+    )?;
+
+    let invoice = &mut ctx.accounts.invoice;
+    invoice.status = InvoiceStatus::Paid;
+    invoice.payer = ctx.accounts.caller.key();
+    invoice.fee_charged = fee_amount;
+    invoice.paid_at = now;
+
+    msg!(
+        "✅ Invoice {} paid: {} SCTK ({} units) to merchant | 📈 Fee: {} SCTK ({} units → {} to revenue, {} to rewards, {} to airdrop) | 🏷️ Discount: {} BPS",
+        invoice.order_id,
+        format_sctk(net_amount),
+        net_amount,
+        format_sctk(fee_amount),
+        fee_amount,
+        to_revenue,
+        to_rewards,
+        to_airdrop,
+        discount_bps,
+    );
+
+    emit!(InvoicePaid {
+        invoice: invoice.key(),
+        merchant: invoice.merchant,
+        payer: invoice.payer,
+        order_id: invoice.order_id.clone(),
+        amount,
+        net_received: net_amount,
+        fee_charged: fee_amount,
+        to_rewards,
+        to_airdrop,
+        to_revenue,
+        discount_bps,
+        paid_at: now,
+    });
+
+    Ok(())
+}
+
+/// ===========================================================================
+/// Cancels an open invoice so it can no longer be paid.
+///
+/// ## Errors:
+/// - `PaymentsError::NotInvoiceMerchant` if the caller is not the merchant
+/// - `PaymentsError::InvoiceNotOpen` if already paid or cancelled
+/// ===========================================================================
+pub(crate) fn cancel_invoice(ctx: Context<CancelInvoiceContext>) -> Result<()> {
+    let invoice = &mut ctx.accounts.invoice;
+
+    require_keys_eq!(
+        invoice.merchant,
+        ctx.accounts.caller.key(),
+        PaymentsError::NotInvoiceMerchant
+    );
+    require!(invoice.status == InvoiceStatus::Open, PaymentsError::InvoiceNotOpen);
+
+    invoice.status = InvoiceStatus::Cancelled;
+
+    msg!("🚫 Invoice {} cancelled by merchant", invoice.order_id);
+
+    emit!(InvoiceCancelled {
+        invoice: invoice.key(),
+        merchant: invoice.merchant,
+        order_id: invoice.order_id.clone(),
+    });
+
+    Ok(())
+}
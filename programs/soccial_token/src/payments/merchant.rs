@@ -0,0 +1,240 @@
+// ===========================================================================
+// Merchant Registry – Soccial Token (SCTK)
+// ---------------------------------------------------------------------------
+//
+// This module lets third-party merchants accept SCTK as a payment method.
+// Each registered merchant has a settlement account, its own fee tier and an
+// optional referrer that receives part of the fee.
+//
+// ---------------------------------------------------------------------------
+// ## Fee Logic:
+// - The merchant `fee_bps` is deducted from each payment
+// - `referral_share_bps` of that fee goes to the referrer, if any
+// - The remaining fee follows the standard rewards / airdrop / revenue split
+//
+// ---------------------------------------------------------------------------
+// ## Components:
+// - `manage_merchant()`: Registers or updates a merchant (`manage_merchants`)
+// - `merchant_payment()`: Pays a merchant and updates its volume counters
+//
+// ---------------------------------------------------------------------------
+// Author: Paulo Rodrigues
+// Project: Soccial Token
+// Website: https://www.soccial.com/thetoken
+// License: MIT
+// ===========================================================================
+
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, Transfer};
+
+use crate::{
+    economy::fee::FEE_BPS_BASE,
+    market::{apply_fee_bps, distribute_fees, FeeDistributionContext},
+    payments::{context::*, error::PaymentsError},
+    utils::math::format_sctk,
+};
+
+#[event]
+pub struct MerchantUpdated {
+    pub merchant: Pubkey,
+    pub authority: Pubkey,
+    pub settlement_token_account: Pubkey,
+    pub fee_bps: u16,
+    pub referrer_token_account: Pubkey,
+    pub referral_share_bps: u16,
+    pub active: bool,
+    pub updated_at: i64,
+}
+
+#[event]
+pub struct MerchantPaymentProcessed {
+    pub merchant: Pubkey,
+    pub payer: Pubkey,
+    pub amount: u64,
+    pub net_received: u64,
+    pub fee_charged: u64,
+    pub to_referrer: u64,
+    pub to_rewards: u64,
+    pub to_airdrop: u64,
+    pub to_revenue: u64,
+    pub total_volume: u64,
+    pub payment_count: u64,
+}
+
+/// ===========================================================================
+/// Registers a merchant on first use, or updates an existing entry.
+///
+/// ## Behavior:
+/// - New merchants start active with the default transfer fee as their tier
+/// - The settlement account is always taken from the passed SCTK account
+/// - `args` are optional `key=value` updates (see `Merchant::apply_updates`)
+///
+/// ## Errors:
+/// - `PaymentsError::InvalidMerchantFee` if `fee_bps` exceeds `MAX_FEE_BPS`
+/// - `PaymentsError::InvalidReferralShare` if the share exceeds 100%
+/// ===========================================================================
+pub(crate) fn manage_merchant(
+    ctx: Context<ManageMerchantContext>,
+    args: Vec<String>,
+) -> Result<()> {
+    let now = Clock::get()?.unix_timestamp;
+    let merchant = &mut ctx.accounts.merchant;
+
+    if merchant.authority == Pubkey::default() {
+        merchant.authority = ctx.accounts.merchant_authority.key();
+        merchant.fee_bps = ctx.accounts.token_state.fee_schedule.transfer_fee_bps;
+        merchant.active = true;
+        merchant.created_at = now;
+        merchant.bump = ctx.bumps.merchant;
+
+        msg!("🏪 Registered merchant {}", merchant.authority);
+    }
+
+    merchant.settlement_token_account = ctx.accounts.settlement_token_account.key();
+    merchant.apply_updates(args)?;
+    merchant.updated_at = now;
+
+    emit!(MerchantUpdated {
+        merchant: merchant.key(),
+        authority: merchant.authority,
+        settlement_token_account: merchant.settlement_token_account,
+        fee_bps: merchant.fee_bps,
+        referrer_token_account: merchant.referrer_token_account,
+        referral_share_bps: merchant.referral_share_bps,
+        active: merchant.active,
+        updated_at: now,
+    });
+
+    Ok(())
+}
+
+/// ===========================================================================
+/// Pays a registered merchant from the caller's wallet.
+///
+/// ## Behavior:
+/// - The merchant fee tier is deducted from `amount`
+/// - The referrer share of the fee (if any) goes to the referrer account
+/// - The rest of the fee is split across the vaults via `distribute_fees`
+/// - Merchant volume, fee and payment counters are updated for reporting
+///
+/// ## Errors:
+/// - `PaymentsError::InvalidAmount` on zero amount
+/// - `PaymentsError::MerchantInactive` if the merchant is deactivated
+/// - `PaymentsError::InsufficientFunds` if the payer lacks SCTK
+/// - `PaymentsError::InvalidReferrerAccount` if the referrer account is
+///   missing or does not match the merchant entry
+/// ===========================================================================
+pub(crate) fn merchant_payment(
+    ctx: Context<MerchantPaymentContext>,
+    amount: u64,
+) -> Result<()> {
+    let merchant = &ctx.accounts.merchant;
+
+    require!(amount > 0, PaymentsError::InvalidAmount);
+    require!(merchant.active, PaymentsError::MerchantInactive);
+    require!(
+        ctx.accounts.payer_token_account.amount >= amount,
+        PaymentsError::InsufficientFunds
+    );
+
+    let (net_amount, fee_amount) = apply_fee_bps(amount, merchant.fee_bps)?;
+
+    let to_referrer = if merchant.has_referrer() {
+        (fee_amount as u128)
+            .checked_mul(merchant.referral_share_bps as u128)
+            .ok_or(PaymentsError::Overflow)?
+            .checked_div(FEE_BPS_BASE as u128)
+            .ok_or(PaymentsError::Overflow)? as u64
+    } else {
+        0
+    };
+
+    token::transfer(
+        CpiContext::new(
+            ctx.accounts.token_program.to_account_info(),
+            Transfer {
+                from: ctx.accounts.payer_token_account.to_account_info(),
+                to: ctx.accounts.settlement_token_account.to_account_info(),
+                authority: ctx.accounts.caller.to_account_info(),
+            },
+        ),
+        net_amount,
+    )?;
+
+    if to_referrer > 0 {
+        let referrer_token_account = ctx
+            .accounts
+            .referrer_token_account
+            .as_ref()
+            .ok_or(PaymentsError::InvalidReferrerAccount)?;
+        require_keys_eq!(
+            referrer_token_account.key(),
+            merchant.referrer_token_account,
+            PaymentsError::InvalidReferrerAccount
+        );
+
+        token::transfer(
+            CpiContext::new(
+                ctx.accounts.token_program.to_account_info(),
+                Transfer {
+                    from: ctx.accounts.payer_token_account.to_account_info(),
+                    to: referrer_token_account.to_account_info(),
+                    authority: ctx.accounts.caller.to_account_info(),
+                },
+            ),
+            to_referrer,
+        )?;
+    }
+
+    let (to_rewards, to_airdrop, to_revenue) = distribute_fees(
+        &FeeDistributionContext {
+            token_state: ctx.accounts.token_state.clone(),
+            token_program: ctx.accounts.token_program.to_account_info(),
+            source_token_account: ctx.accounts.payer_token_account.to_account_info(),
+            rewards_vault_token_account: ctx.accounts.rewards_vault_token_account.to_account_info(),
+            rewards_ledger: ctx.accounts.rewards_ledger.to_account_info(),
+            airdrop_vault_token_account: ctx.accounts.airdrop_vault_token_account.to_account_info(),
+            airdrop_ledger: ctx.accounts.airdrop_ledger.to_account_info(),
+            revenue_vault_token_account: ctx.accounts.revenue_vault_token_account.to_account_info(),
+            revenue_ledger: ctx.accounts.revenue_ledger.to_account_info(),
+            authority: ctx.accounts.caller.to_account_info(),
+        },
+        fee_amount - to_referrer,
+        None,
+    )?;
+
+    let merchant = &mut ctx.accounts.merchant;
+    merchant.total_volume = merchant.total_volume.checked_add(amount).ok_or(PaymentsError::Overflow)?;
+    merchant.total_fees = merchant.total_fees.checked_add(fee_amount).ok_or(PaymentsError::Overflow)?;
+    merchant.total_referral_paid = merchant.total_referral_paid.checked_add(to_referrer).ok_or(PaymentsError::Overflow)?;
+    merchant.payment_count = merchant.payment_count.checked_add(1).ok_or(PaymentsError::Overflow)?;
+
+    msg!(
+        "🛒 Paid {} SCTK ({} units) to merchant {} | 📈 Fee: {} SCTK ({} units → {} to referrer, {} to revenue, {} to rewards, {} to airdrop)",
+        format_sctk(net_amount),
+        net_amount,
+        merchant.authority,
+        format_sctk(fee_amount),
+        fee_amount,
+        to_referrer,
+        to_revenue,
+        to_rewards,
+        to_airdrop,
+    );
+
+    emit!(MerchantPaymentProcessed {
+        merchant: merchant.key(),
+        payer: ctx.accounts.caller.key(),
+        amount,
+        net_received: net_amount,
+        fee_charged: fee_amount,
+        to_referrer,
+        to_rewards,
+        to_airdrop,
+        to_revenue,
+        total_volume: merchant.total_volume,
+        payment_count: merchant.payment_count,
+    });
+
+    Ok(())
+}
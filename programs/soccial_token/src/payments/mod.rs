@@ -0,0 +1,13 @@
+pub mod error;
+pub mod context;
+pub mod state;
+pub mod invoice;
+pub mod merchant;
+pub mod subscription;
+
+pub use error::*;
+pub use context::*;
+pub use state::*;
+pub use invoice::*;
+pub use merchant::*;
+pub use subscription::*;
@@ -0,0 +1,260 @@
+// ===========================================================================
+// Payments State – Soccial Token (SCTK)
+// ---------------------------------------------------------------------------
+//
+// This module defines the on-chain payable orders used to pay Soccial
+// services in SCTK, so the backend can verify payments from chain state.
+//
+// ---------------------------------------------------------------------------
+// ## Components:
+// - `Invoice`: Payment request from a merchant, keyed by external order ID
+// - `InvoiceStatus`: Lifecycle of an invoice (open → paid / cancelled)
+// - `Merchant`: Registered third-party merchant with its fee tier and volume
+// - `Subscription`: Recurring payment pulled through an SPL delegate approval
+//
+// ---------------------------------------------------------------------------
+// Author: Paulo Rodrigues
+// Project: Soccial Token
+// Website: https://www.soccial.com/thetoken
+// License: MIT
+// ===========================================================================
+
+use anchor_lang::prelude::*;
+use crate::{
+    economy::fee::{FEE_BPS_BASE, MAX_FEE_BPS},
+    payments::error::PaymentsError,
+};
+
+/// Maximum length (in bytes) of an external order ID. Bounded by the
+/// 32-byte limit of a single PDA seed.
+pub const MAX_ORDER_ID_LEN: usize = 32;
+
+/// Lifecycle of an `Invoice`.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug)]
+pub enum InvoiceStatus {
+    /// Awaiting payment.
+    Open,
+    /// Paid in full.
+    Paid,
+    /// Cancelled by the merchant.
+    Cancelled,
+}
+
+/// ===========================================================================
+/// Account: Invoice
+///
+/// Payment request created by a merchant and settled once in SCTK.
+/// Seeded by the merchant and the external order ID, so each order maps to
+/// exactly one invoice.
+///
+/// ## Fields:
+/// - `merchant`: Wallet receiving the payment
+/// - `order_id`: External order ID from the Soccial backend
+/// - `amount`: SCTK units due
+/// - `expires_at`: Unix timestamp after which payment fails (0 = never)
+/// - `status`: Open, paid or cancelled
+/// - `payer`: Wallet that paid (default until paid)
+/// - `fee_charged`: Market fee deducted from the payment
+/// - `created_at` / `paid_at`: Unix timestamps
+/// - `bump`: PDA bump seed
+/// ===========================================================================
+#[account]
+pub struct Invoice {
+    pub merchant: Pubkey,
+    pub order_id: String,
+    pub amount: u64,
+    pub expires_at: i64,
+    pub status: InvoiceStatus,
+    pub payer: Pubkey,
+    pub fee_charged: u64,
+    pub created_at: i64,
+    pub paid_at: i64,
+    pub bump: u8,
+}
+
+impl Invoice {
+    pub const LEN: usize =
+        8 +                         // Anchor discriminator
+        32 +                        // merchant (Pubkey)
+        4 + MAX_ORDER_ID_LEN +      // order_id (String)
+        8 +                         // amount (u64)
+        8 +                         // expires_at (i64)
+        1 +                         // status (enum)
+        32 +                        // payer (Pubkey)
+        8 +                         // fee_charged (u64)
+        8 +                         // created_at (i64)
+        8 +                         // paid_at (i64)
+        1;                          // bump (u8)
+
+    /// Returns `true` if the invoice has an expiry and it has passed.
+    pub fn is_expired(&self, now: i64) -> bool {
+        self.expires_at > 0 && now > self.expires_at
+    }
+}
+
+/// ===========================================================================
+/// Account: Merchant
+///
+/// Third-party merchant accepting SCTK through `merchant_payment`.
+/// Seeded by the merchant authority and managed by `manage_merchants`.
+///
+/// ## Fields:
+/// - `authority`: Merchant wallet identifying the registry entry
+/// - `settlement_token_account`: SCTK account receiving net payments
+/// - `fee_bps`: Fee tier charged on each payment (BPS)
+/// - `referrer_token_account`: SCTK account of the referrer (default = none)
+/// - `referral_share_bps`: Share of the fee paid to the referrer (BPS of the fee)
+/// - `active`: Whether the merchant can receive payments
+/// - `total_volume` / `total_fees` / `total_referral_paid` / `payment_count`:
+///   Reporting counters, updated on every payment
+/// - `created_at` / `updated_at`: Unix timestamps
+/// - `bump`: PDA bump seed
+/// ===========================================================================
+#[account]
+pub struct Merchant {
+    pub authority: Pubkey,
+    pub settlement_token_account: Pubkey,
+    pub fee_bps: u16,
+    pub referrer_token_account: Pubkey,
+    pub referral_share_bps: u16,
+    pub active: bool,
+    pub total_volume: u64,
+    pub total_fees: u64,
+    pub total_referral_paid: u64,
+    pub payment_count: u64,
+    pub created_at: i64,
+    pub updated_at: i64,
+    pub bump: u8,
+}
+
+impl Merchant {
+    pub const LEN: usize =
+        8 +                         // Anchor discriminator
+        32 +                        // authority (Pubkey)
+        32 +                        // settlement_token_account (Pubkey)
+        2 +                         // fee_bps (u16)
+        32 +                        // referrer_token_account (Pubkey)
+        2 +                         // referral_share_bps (u16)
+        1 +                         // active (bool)
+        8 +                         // total_volume (u64)
+        8 +                         // total_fees (u64)
+        8 +                         // total_referral_paid (u64)
+        8 +                         // payment_count (u64)
+        8 +                         // created_at (i64)
+        8 +                         // updated_at (i64)
+        1;                          // bump (u8)
+
+    /// Returns `true` if a referrer receives part of the fee.
+    pub fn has_referrer(&self) -> bool {
+        self.referrer_token_account != Pubkey::default() && self.referral_share_bps > 0
+    }
+
+    /// Applies `key=value` updates to the merchant configuration.
+    ///
+    /// Supported keys: `fee_bps`, `referrer` (pubkey or `none`),
+    /// `referral_share_bps`, `active`.
+    pub(crate) fn apply_updates(&mut self, args: Vec<String>) -> Result<()> {
+        let mut log: Vec<String> = vec![];
+
+        for arg in args {
+            let parts: Vec<&str> = arg.split('=').collect();
+            require!(parts.len() == 2, crate::ErrorCode::InvalidArgument);
+            let key = parts[0].trim();
+            let value = parts[1].trim();
+
+            match key {
+                "fee_bps" => {
+                    let val = value.parse::<u16>().map_err(|_| crate::ErrorCode::InvalidArgument)?;
+                    require!(val <= MAX_FEE_BPS, PaymentsError::InvalidMerchantFee);
+                    self.fee_bps = val;
+                    log.push(format!("fee_bps: {}", val));
+                }
+                "referrer" => {
+                    let val = if value.eq_ignore_ascii_case("none") {
+                        Pubkey::default()
+                    } else {
+                        value.parse::<Pubkey>().map_err(|_| crate::ErrorCode::InvalidArgument)?
+                    };
+                    self.referrer_token_account = val;
+                    log.push(format!("referrer: {}", val));
+                }
+                "referral_share_bps" => {
+                    let val = value.parse::<u16>().map_err(|_| crate::ErrorCode::InvalidArgument)?;
+                    require!(val <= FEE_BPS_BASE, PaymentsError::InvalidReferralShare);
+                    self.referral_share_bps = val;
+                    log.push(format!("referral_share_bps: {}", val));
+                }
+                "active" => {
+                    let val = value.parse::<bool>().map_err(|_| crate::ErrorCode::InvalidArgument)?;
+                    self.active = val;
+                    log.push(format!("active: {}", val));
+                }
+                _ => return Err(crate::ErrorCode::InvalidArgument.into()),
+            }
+        }
+
+        if !log.is_empty() {
+            msg!("⚙️ Updated merchant {} → {}", self.authority, log.join(" | "));
+        }
+
+        Ok(())
+    }
+}
+
+/// ===========================================================================
+/// Account: Subscription
+///
+/// Recurring payment from a payer to a merchant. On creation the program's
+/// `subscription_delegate` PDA is approved on the payer ATA, so one period
+/// can be pulled by the permissionless `charge_subscription` crank when due.
+///
+/// ## Fields:
+/// - `payer`: Wallet being charged
+/// - `merchant`: Wallet receiving the payments
+/// - `amount`: SCTK units charged per period
+/// - `period`: Seconds between charges
+/// - `next_charge_at`: Unix timestamp from which the next charge is due
+/// - `remaining_allowance`: Units this subscription may still pull
+/// - `charge_count` / `failed_charges`: Reporting counters
+/// - `created_at`: Unix timestamp
+/// - `bump`: PDA bump seed
+/// ===========================================================================
+#[account]
+pub struct Subscription {
+    pub payer: Pubkey,
+    pub merchant: Pubkey,
+    pub amount: u64,
+    pub period: i64,
+    pub next_charge_at: i64,
+    pub remaining_allowance: u64,
+    pub charge_count: u64,
+    pub failed_charges: u64,
+    pub created_at: i64,
+    pub bump: u8,
+}
+
+impl Subscription {
+    pub const LEN: usize =
+        8 +                         // Anchor discriminator
+        32 +                        // payer (Pubkey)
+        32 +                        // merchant (Pubkey)
+        8 +                         // amount (u64)
+        8 +                         // period (i64)
+        8 +                         // next_charge_at (i64)
+        8 +                         // remaining_allowance (u64)
+        8 +                         // charge_count (u64)
+        8 +                         // failed_charges (u64)
+        8 +                         // created_at (i64)
+        1;                          // bump (u8)
+}
+
+/// Reason a due subscription charge could not be collected.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug)]
+pub enum ChargeFailureReason {
+    /// The payer ATA holds less than one period's amount.
+    InsufficientFunds,
+    /// The subscription's approved budget is used up.
+    AllowanceExhausted,
+    /// The payer revoked or lowered the delegate approval.
+    DelegateRevoked,
+}
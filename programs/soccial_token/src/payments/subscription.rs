@@ -0,0 +1,374 @@
+// ===========================================================================
+// Subscriptions – Soccial Token (SCTK)
+// ---------------------------------------------------------------------------
+//
+// This module implements recurring SCTK payments (e.g. premium memberships)
+// without a fresh user signature for every charge.
+//
+// ---------------------------------------------------------------------------
+// ## Flow:
+// 1. The payer creates a `Subscription` and approves `subscription_delegate`
+//    on their ATA for `amount × approved_periods`
+// 2. Anyone cranks `charge_subscription` once a period is due; the delegate
+//    pulls one period, with the market transfer fee split to the vaults
+// 3. Either party cancels, closing the subscription
+//
+// ---------------------------------------------------------------------------
+// ## Allowance:
+// - Approvals stack when the delegate already holds an allowance on the ATA,
+//   so several subscriptions can share one payer account
+// - Each subscription can only pull its own `remaining_allowance`
+// - When the payer cancels, the delegate allowance is lowered by the
+//   subscription's `remaining_allowance`, or revoked once nothing is left
+// - When the merchant cancels, the payer does not sign, so the leftover stays
+//   approved but unusable by the program; the payer may revoke it directly
+//   through the SPL Token program
+//
+// ---------------------------------------------------------------------------
+// ## Components:
+// - `create_subscription()`: Opens the subscription and approves the delegate
+// - `charge_subscription()`: Permissionless crank pulling one due period
+// - `cancel_subscription()`: Closes the subscription (payer or merchant)
+//
+// ---------------------------------------------------------------------------
+// Author: Paulo Rodrigues
+// Project: Soccial Token
+// Website: https://www.soccial.com/thetoken
+// License: MIT
+// ===========================================================================
+
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::program_option::COption;
+use anchor_spl::token::{self, Approve, Revoke, Transfer};
+
+use crate::{
+    economics::state::FeeOperation,
+    market::{calculate_fee, distribute_fees, FeeDistributionContext},
+    payments::{context::*, error::PaymentsError, state::ChargeFailureReason},
+    utils::math::format_sctk,
+};
+
+#[event]
+pub struct SubscriptionCreated {
+    pub subscription: Pubkey,
+    pub payer: Pubkey,
+    pub merchant: Pubkey,
+    pub amount: u64,
+    pub period: i64,
+    pub approved_allowance: u64,
+    pub next_charge_at: i64,
+}
+
+#[event]
+pub struct SubscriptionCharged {
+    pub subscription: Pubkey,
+    pub payer: Pubkey,
+    pub merchant: Pubkey,
+    pub amount: u64,
+    pub net_received: u64,
+    pub fee_charged: u64,
+    pub to_rewards: u64,
+    pub to_airdrop: u64,
+    pub to_revenue: u64,
+    pub charge_count: u64,
+    pub next_charge_at: i64,
+}
+
+#[event]
+pub struct SubscriptionChargeFailed {
+    pub subscription: Pubkey,
+    pub payer: Pubkey,
+    pub merchant: Pubkey,
+    pub amount: u64,
+    pub reason: ChargeFailureReason,
+    pub failed_charges: u64,
+}
+
+#[event]
+pub struct SubscriptionCancelled {
+    pub subscription: Pubkey,
+    pub payer: Pubkey,
+    pub merchant: Pubkey,
+    pub cancelled_by: Pubkey,
+    pub charge_count: u64,
+    pub released_allowance: u64,
+}
+
+/// ===========================================================================
+/// Creates a subscription and approves the program delegate on the payer ATA.
+///
+/// ## Behavior:
+/// - The first period is due immediately
+/// - The delegate allowance grows by `amount × approved_periods`
+///
+/// ## Errors:
+/// - `PaymentsError::InvalidAmount` on zero amount or zero approved periods
+/// - `PaymentsError::InvalidPeriod` on a non-positive period
+/// ===========================================================================
+pub(crate) fn create_subscription(
+    ctx: Context<CreateSubscriptionContext>,
+    amount: u64,
+    period: i64,
+    approved_periods: u64,
+) -> Result<()> {
+    require!(amount > 0 && approved_periods > 0, PaymentsError::InvalidAmount);
+    require!(period > 0, PaymentsError::InvalidPeriod);
+
+    let allowance = amount.checked_mul(approved_periods).ok_or(PaymentsError::Overflow)?;
+
+    let payer_token_account = &ctx.accounts.payer_token_account;
+    let existing = match payer_token_account.delegate {
+        COption::Some(delegate) if delegate == ctx.accounts.subscription_delegate.key() => {
+            payer_token_account.delegated_amount
+        }
+        _ => 0,
+    };
+    let delegated_amount = existing.checked_add(allowance).ok_or(PaymentsError::Overflow)?;
+
+    token::approve(
+        CpiContext::new(
+            ctx.accounts.token_program.to_account_info(),
+            Approve {
+                to: ctx.accounts.payer_token_account.to_account_info(),
+                delegate: ctx.accounts.subscription_delegate.to_account_info(),
+                authority: ctx.accounts.caller.to_account_info(),
+            },
+        ),
+        delegated_amount,
+    )?;
+
+    let now = Clock::get()?.unix_timestamp;
+    let subscription = &mut ctx.accounts.subscription;
+    subscription.payer = ctx.accounts.caller.key();
+    subscription.merchant = ctx.accounts.merchant.key();
+    subscription.amount = amount;
+    subscription.period = period;
+    subscription.next_charge_at = now;
+    subscription.remaining_allowance = allowance;
+    subscription.charge_count = 0;
+    subscription.failed_charges = 0;
+    subscription.created_at = now;
+    subscription.bump = ctx.bumps.subscription;
+
+    msg!(
+        "🔁 Subscription to {} created: {} SCTK ({} units) every {}s | ✅ Approved: {} units",
+        subscription.merchant,
+        format_sctk(amount),
+        amount,
+        period,
+        allowance,
+    );
+
+    emit!(SubscriptionCreated {
+        subscription: subscription.key(),
+        payer: subscription.payer,
+        merchant: subscription.merchant,
+        amount,
+        period,
+        approved_allowance: allowance,
+        next_charge_at: now,
+    });
+
+    Ok(())
+}
+
+/// ===========================================================================
+/// Pulls one due period from the payer through the program delegate.
+///
+/// ## Behavior:
+/// - The transfer fee from the `FeeSchedule` (after the payer's flag
+///   discounts) is deducted and split to the vaults
+/// - `next_charge_at` advances by one period on success
+/// - If the charge cannot be collected, `SubscriptionChargeFailed` is emitted,
+///   `failed_charges` is incremented and the period stays due
+///
+/// ## Errors:
+/// - `PaymentsError::SubscriptionNotDue` before `next_charge_at`
+/// ===========================================================================
+pub(crate) fn charge_subscription(ctx: Context<ChargeSubscriptionContext>) -> Result<()> {
+    let now = Clock::get()?.unix_timestamp;
+    let subscription = &ctx.accounts.subscription;
+    let amount = subscription.amount;
+
+    require!(now >= subscription.next_charge_at, PaymentsError::SubscriptionNotDue);
+
+    let payer_token_account = &ctx.accounts.payer_token_account;
+    let delegate_ok = payer_token_account.delegate == COption::Some(ctx.accounts.subscription_delegate.key())
+        && payer_token_account.delegated_amount >= amount;
+
+    let failure = if subscription.remaining_allowance < amount {
+        Some(ChargeFailureReason::AllowanceExhausted)
+    } else if !delegate_ok {
+        Some(ChargeFailureReason::DelegateRevoked)
+    } else if payer_token_account.amount < amount {
+        Some(ChargeFailureReason::InsufficientFunds)
+    } else {
+        None
+    };
+
+    if let Some(reason) = failure {
+        let subscription = &mut ctx.accounts.subscription;
+        subscription.failed_charges = subscription.failed_charges.checked_add(1).ok_or(PaymentsError::Overflow)?;
+
+        msg!("⚠️ Subscription charge failed: {:?}", reason);
+
+        emit!(SubscriptionChargeFailed {
+            subscription: subscription.key(),
+            payer: subscription.payer,
+            merchant: subscription.merchant,
+            amount,
+            reason,
+            failed_charges: subscription.failed_charges,
+        });
+
+        return Ok(());
+    }
+
+    let (net_amount, fee_amount, discount_bps) = calculate_fee(
+        &ctx.accounts.token_state,
+        ctx.accounts.payer_access.as_deref(),
+        FeeOperation::Transfer,
+        amount,
+    )?;
+
+    let signer_seeds: &[&[u8]] = &[b"subscription_delegate", &[ctx.bumps.subscription_delegate]];
+    let signer_seeds_nested = &[signer_seeds];
+
+    token::transfer(
+        CpiContext::new_with_signer(
+            ctx.accounts.token_program.to_account_info(),
+            Transfer {
+                from: ctx.accounts.payer_token_account.to_account_info(),
+                to: ctx.accounts.merchant_token_account.to_account_info(),
+                authority: ctx.accounts.subscription_delegate.to_account_info(),
+            },
+            signer_seeds_nested,
+        ),
+        net_amount,
+    )?;
+
+    let (to_rewards, to_airdrop, to_revenue) = distribute_fees(
+        &FeeDistributionContext {
+            token_state: ctx.accounts.token_state.clone(),
+            token_program: ctx.accounts.token_program.to_account_info(),
+            source_token_account: ctx.accounts.payer_token_account.to_account_info(),
+            rewards_vault_token_account: ctx.accounts.rewards_vault_token_account.to_account_info(),
+            rewards_ledger: ctx.accounts.rewards_ledger.to_account_info(),
+            airdrop_vault_token_account: ctx.accounts.airdrop_vault_token_account.to_account_info(),
+            airdrop_ledger: ctx.accounts.airdrop_ledger.to_account_info(),
+            revenue_vault_token_account: ctx.accounts.revenue_vault_token_account.to_account_info(),
+            revenue_ledger: ctx.accounts.revenue_ledger.to_account_info(),
+            authority: ctx.accounts.subscription_delegate.to_account_info(),
+        },
+        fee_amount,
+        Some(signer_seeds_nested),
+    )?;
+
+    let subscription = &mut ctx.accounts.subscription;
+    subscription.remaining_allowance -= amount;
+    subscription.charge_count = subscription.charge_count.checked_add(1).ok_or(PaymentsError::Overflow)?;
+    subscription.next_charge_at = subscription.next_charge_at
+        .checked_add(subscription.period)
+        .ok_or(PaymentsError::Overflow)?;
+
+    msg!(
+        "🔁 Subscription charged {} SCTK ({} units) | 📈 Fee: {} SCTK ({} units → {} to revenue, {} to rewards, {} to airdrop) | 🏷️ Discount: {} BPS",
+        format_sctk(net_amount),
+        net_amount,
+        format_sctk(fee_amount),
+        fee_amount,
+        to_revenue,
+        to_rewards,
+        to_airdrop,
+        discount_bps,
+    );
+
+    emit!(SubscriptionCharged {
+        subscription: subscription.key(),
+        payer: subscription.payer,
+        merchant: subscription.merchant,
+        amount,
+        net_received: net_amount,
+        fee_charged: fee_amount,
+        to_rewards,
+        to_airdrop,
+        to_revenue,
+        charge_count: subscription.charge_count,
+        next_charge_at: subscription.next_charge_at,
+    });
+
+    Ok(())
+}
+
+/// ===========================================================================
+/// Cancels a subscription and returns its rent to the payer.
+///
+/// ## Behavior:
+/// - When the payer cancels, the delegate allowance on their ATA is lowered by
+///   `remaining_allowance`, and revoked when it reaches zero
+/// - When the merchant cancels, the allowance is left untouched
+///
+/// ## Errors:
+/// - `PaymentsError::NotSubscriptionParty` if the caller is neither the payer
+///   nor the merchant
+/// ===========================================================================
+pub(crate) fn cancel_subscription(ctx: Context<CancelSubscriptionContext>) -> Result<()> {
+    let subscription = &ctx.accounts.subscription;
+    let caller = ctx.accounts.caller.key();
+
+    require!(
+        caller == subscription.payer || caller == subscription.merchant,
+        PaymentsError::NotSubscriptionParty
+    );
+
+    // Only the ATA owner can change its delegate, so this needs the payer's signature
+    let payer_token_account = &ctx.accounts.payer_token_account;
+    let is_delegate = payer_token_account.delegate == COption::Some(ctx.accounts.subscription_delegate.key());
+
+    let mut released_allowance = 0;
+    if caller == subscription.payer && is_delegate {
+        let delegated_amount = payer_token_account.delegated_amount;
+        released_allowance = subscription.remaining_allowance.min(delegated_amount);
+        let left = delegated_amount - released_allowance;
+
+        if left == 0 {
+            token::revoke(CpiContext::new(
+                ctx.accounts.token_program.to_account_info(),
+                Revoke {
+                    source: ctx.accounts.payer_token_account.to_account_info(),
+                    authority: ctx.accounts.caller.to_account_info(),
+                },
+            ))?;
+        } else {
+            token::approve(
+                CpiContext::new(
+                    ctx.accounts.token_program.to_account_info(),
+                    Approve {
+                        to: ctx.accounts.payer_token_account.to_account_info(),
+                        delegate: ctx.accounts.subscription_delegate.to_account_info(),
+                        authority: ctx.accounts.caller.to_account_info(),
+                    },
+                ),
+                left,
+            )?;
+        }
+    }
+
+    msg!(
+        "🚫 Subscription to {} cancelled by {} | 🔓 Released allowance: {} units",
+        subscription.merchant,
+        caller,
+        released_allowance,
+    );
+
+    emit!(SubscriptionCancelled {
+        subscription: subscription.key(),
+        payer: subscription.payer,
+        merchant: subscription.merchant,
+        cancelled_by: caller,
+        charge_count: subscription.charge_count,
+        released_allowance,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,147 @@
+// ======================================================================
+// Soccial Token – Referral Contexts
+//
+// Defines account contexts for the referral program: binding a referee to
+// a referrer and claiming the referrer's credited fee share.
+//
+// Contexts include PDA validations, token account checks, optional user access,
+// and global token state references.
+//
+// License: MIT License
+// Author: Paulo Rodrigues
+// Project: Soccial Token
+// ======================================================================
+
+use anchor_lang::prelude::*;
+use anchor_spl::token::{Mint, Token, TokenAccount};
+use crate::{
+    auth::user::UserAccessAccount,
+    referral::state::{Referral, ReferrerAccount},
+    token::state::TokenState,
+};
+
+#[derive(Accounts)]
+pub struct RegisterReferralContext<'info> {
+    /// The referee binding itself to a referrer (pays for the accounts).
+    #[account(mut)]
+    pub caller: Signer<'info>,
+
+    /// CHECK: Referrer wallet; only its key is stored.
+    pub referrer: AccountInfo<'info>,
+
+    // ─────────────────────────────────────────────────────────────
+    // Referral
+    // ─────────────────────────────────────────────────────────────
+
+    /// Referral link, seeded by the referee. `init` makes the binding one-time.
+    #[account(
+        init,
+        payer = caller,
+        space = Referral::LEN,
+        seeds = [b"referral", caller.key().as_ref()],
+        bump
+    )]
+    pub referral: Account<'info, Referral>,
+
+    /// Referrer stats and claimable balance, created on the first referral.
+    #[account(
+        init_if_needed,
+        payer = caller,
+        space = ReferrerAccount::LEN,
+        seeds = [b"referrer", referrer.key().as_ref()],
+        bump
+    )]
+    pub referrer_account: Account<'info, ReferrerAccount>,
+
+    // ─────────────────────────────────────────────────────────────
+    // Access Control & State
+    // ─────────────────────────────────────────────────────────────
+
+    /// Optional access control account for the caller.
+    #[account(
+        seeds = [b"user_access", caller.key().as_ref()],
+        bump
+    )]
+    pub user_access: Option<Account<'info, UserAccessAccount>>,
+
+    /// TokenState account for system-wide configuration.
+    pub token_state: Account<'info, TokenState>,
+
+    /// System program required for account creation.
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct ClaimReferralRewardsContext<'info> {
+    /// The referrer claiming its credited balance.
+    pub caller: Signer<'info>,
+
+    /// Referrer stats and claimable balance.
+    #[account(
+        mut,
+        seeds = [b"referrer", caller.key().as_ref()],
+        bump = referrer_account.bump
+    )]
+    pub referrer_account: Account<'info, ReferrerAccount>,
+
+    /// Token account of the referrer, destination of the claim.
+    #[account(
+        mut,
+        associated_token::mint = token_mint,
+        associated_token::authority = caller
+    )]
+    pub referrer_token_account: Account<'info, TokenAccount>,
+
+    // ------------------------------------------------------------------------
+    // Revenue Vault
+    // ------------------------------------------------------------------------
+
+    /// CHECK: PDA authority for revenue logic. Verified in handler.
+    #[account(seeds = [b"revenue_vault"], bump)]
+    pub revenue_vault: AccountInfo<'info>,
+
+    /// Token account holding tokens allocated for revenue collection.
+    #[account(
+        mut,
+        associated_token::mint = token_mint,
+        associated_token::authority = revenue_vault
+    )]
+    pub revenue_vault_token_account: Account<'info, TokenAccount>,
+
+    /// CHECK: Outflow limits PDA of `revenue_vault`. Enforced in logic once configured.
+    #[account(
+        mut,
+        seeds = [b"vault_limits", revenue_vault.key().as_ref()],
+        bump
+    )]
+    pub vault_limits: AccountInfo<'info>,
+
+    /// CHECK: Ledger PDA of `revenue_vault`. Posted to in logic once opened.
+    #[account(
+        mut,
+        seeds = [b"vault_ledger", revenue_vault.key().as_ref()],
+        bump
+    )]
+    pub revenue_ledger: AccountInfo<'info>,
+
+    // ─────────────────────────────────────────────────────────────
+    // Mint, Access Control & State
+    // ─────────────────────────────────────────────────────────────
+
+    /// Token mint (SCTK).
+    pub token_mint: Account<'info, Mint>,
+
+    /// Optional access control account for the caller.
+    #[account(
+        seeds = [b"user_access", caller.key().as_ref()],
+        bump
+    )]
+    pub user_access: Option<Account<'info, UserAccessAccount>>,
+
+    /// TokenState account for system-wide configuration.
+    #[account(mut)]
+    pub token_state: Account<'info, TokenState>,
+
+    /// Token program required for token transfers.
+    pub token_program: Program<'info, Token>,
+}
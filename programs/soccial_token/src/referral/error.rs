@@ -0,0 +1,31 @@
+use anchor_lang::error_code;
+
+// ======================================================================
+// Soccial Token – Referral Error Definitions
+//
+// This module defines custom errors for the referral program, such as
+// binding a user to a referrer, crediting fee shares and claiming them.
+//
+// License: MIT License
+// Author: Paulo Rodrigues
+// Project: Soccial Token
+// ======================================================================
+
+#[error_code]
+pub enum ReferralError {
+    /// A user cannot refer themselves.
+    #[msg("A user cannot be their own referrer.")]
+    SelfReferral,
+
+    /// The referrer account is missing or does not belong to the referee's referrer.
+    #[msg("Invalid referrer account.")]
+    InvalidReferrerAccount,
+
+    /// The referrer has no claimable balance.
+    #[msg("No referral rewards to claim.")]
+    NothingToClaim,
+
+    /// An arithmetic overflow occurred while updating referral totals.
+    #[msg("Arithmetic overflow in referral totals.")]
+    Overflow,
+}
@@ -0,0 +1,9 @@
+pub mod error;
+pub mod context;
+pub mod state;
+pub mod referral;
+
+pub use error::*;
+pub use context::*;
+pub use state::*;
+pub use referral::*;
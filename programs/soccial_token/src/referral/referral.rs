@@ -0,0 +1,285 @@
+// ===========================================================================
+// Referral – Soccial Token (SCTK)
+// ---------------------------------------------------------------------------
+//
+// This module implements the referral program. A user binds itself to a
+// referrer exactly once; afterwards a governed share of the fees it pays on
+// `buy_tokens`, `buy_tokens_with_quote`, `purchase_tokens`, `transfer_tokens`
+// and `batch_transfer_tokens` is credited to the referrer.
+//
+// ---------------------------------------------------------------------------
+// ## Crediting Rules:
+// - The share comes from `TokenState.referral.share_bps` (governed)
+// - The fee is still distributed to the vaults; the credit is bookkeeping
+// - A credit never exceeds the revenue portion of the fee it comes from,
+//   so the Revenue Vault always received what it later pays out
+// - Claims transfer the whole claimable balance from the Revenue Vault
+//
+// ---------------------------------------------------------------------------
+// ## Components:
+// - `register_referral()`: Binds the caller to a referrer
+// - `credit_referral_fee()`: Credits a referee fee to its referrer
+// - `claim_referral_rewards()`: Pays the referrer's claimable balance
+//
+// ---------------------------------------------------------------------------
+// Author: Paulo Rodrigues
+// Project: Soccial Token
+// Website: https://www.soccial.com/thetoken
+// License: MIT
+// ===========================================================================
+
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, Transfer};
+
+use crate::{
+    referral::{
+        context::*,
+        error::ReferralError,
+        state::{Referral, ReferrerAccount},
+    },
+    token::state::TokenState,
+    utils::math::format_sctk,
+    vaults::{error::VaultError, record_vault_flow, record_vault_outflow, LedgerCategory, LedgerFlow},
+};
+
+#[event]
+pub struct ReferralRegistered {
+    pub referral: Pubkey,
+    pub referee: Pubkey,
+    pub referrer: Pubkey,
+    pub referral_count: u64,
+}
+
+#[event]
+pub struct ReferralRewardCredited {
+    pub referee: Pubkey,
+    pub referrer: Pubkey,
+    pub fee_charged: u64,
+    pub credited: u64,
+    pub share_bps: u16,
+    pub total_fees_generated: u64,
+    pub claimable: u64,
+}
+
+#[event]
+pub struct ReferralRewardsClaimed {
+    pub referrer: Pubkey,
+    pub amount: u64,
+    pub total_earned: u64,
+    pub total_claimed: u64,
+    pub referral_count: u64,
+}
+
+/// ===========================================================================
+/// Binds the caller (referee) to a referrer.
+///
+/// ## Behavior:
+/// - Creates the `Referral` PDA of the caller; a second call fails because
+///   the account already exists
+/// - Creates the referrer's `ReferrerAccount` on its first referral
+/// - Increments the referrer's `referral_count`
+///
+/// ## Errors:
+/// - `ReferralError::SelfReferral` if the referrer is the caller
+/// - `ReferralError::Overflow` if the referral count overflows
+/// ===========================================================================
+pub(crate) fn register_referral(ctx: Context<RegisterReferralContext>) -> Result<()> {
+    let referee = ctx.accounts.caller.key();
+    let referrer = ctx.accounts.referrer.key();
+    require_keys_neq!(referee, referrer, ReferralError::SelfReferral);
+
+    let referral = &mut ctx.accounts.referral;
+    referral.referee = referee;
+    referral.referrer = referrer;
+    referral.created_at = Clock::get()?.unix_timestamp;
+    referral.total_fees_generated = 0;
+    referral.bump = ctx.bumps.referral;
+
+    let referrer_account = &mut ctx.accounts.referrer_account;
+    referrer_account.referrer = referrer;
+    referrer_account.bump = ctx.bumps.referrer_account;
+    referrer_account.referral_count = referrer_account
+        .referral_count
+        .checked_add(1)
+        .ok_or(ReferralError::Overflow)?;
+
+    msg!(
+        "🤝 User {} referred by {} | 👥 Referrals: {}",
+        referee,
+        referrer,
+        referrer_account.referral_count,
+    );
+
+    emit!(ReferralRegistered {
+        referral: referral.key(),
+        referee,
+        referrer,
+        referral_count: referrer_account.referral_count,
+    });
+
+    Ok(())
+}
+
+/// ===========================================================================
+/// Credits the referrer share of a fee paid by a referee.
+///
+/// `referral` is the referee's always-present `Referral` PDA, so a relayer
+/// cannot drop the credit by omitting it; an empty account means the referee
+/// has no referrer.
+///
+/// ## Behavior:
+/// - No-op (returns `0`) if `referral` was never created
+/// - Adds `fee_amount` to the referee's `total_fees_generated`
+/// - Credits `fee_amount × share_bps`, capped at `to_revenue`, to the
+///   referrer's claimable balance
+///
+/// ## Returns:
+/// - The credited amount (SCTK units)
+///
+/// ## Errors:
+/// - `ErrorCode::Unauthorized` if `referral` is not a program-owned `Referral`
+/// - `ReferralError::InvalidReferrerAccount` if the referrer account is not
+///   the `ReferrerAccount` of the referral's referrer
+/// - `ReferralError::Overflow` if any total overflows
+/// ===========================================================================
+pub(crate) fn credit_referral_fee(
+    token_state: &TokenState,
+    referral_info: &AccountInfo,
+    referrer_info: &AccountInfo,
+    fee_amount: u64,
+    to_revenue: u64,
+) -> Result<u64> {
+    if referral_info.data_is_empty() {
+        return Ok(0);
+    }
+
+    require_keys_eq!(*referral_info.owner, crate::ID, crate::ErrorCode::Unauthorized);
+    require_keys_eq!(*referrer_info.owner, crate::ID, ReferralError::InvalidReferrerAccount);
+
+    let mut referral_data = referral_info.try_borrow_mut_data()?;
+    let mut referral = Referral::try_deserialize(&mut &referral_data[..])?;
+
+    let mut referrer_data = referrer_info.try_borrow_mut_data()?;
+    let mut referrer_account = ReferrerAccount::try_deserialize(&mut &referrer_data[..])
+        .map_err(|_| ReferralError::InvalidReferrerAccount)?;
+    require_keys_eq!(
+        referrer_account.referrer,
+        referral.referrer,
+        ReferralError::InvalidReferrerAccount
+    );
+
+    let share_bps = token_state.referral.share_bps;
+    let credited = token_state.referral.share_of(fee_amount).min(to_revenue);
+
+    referral.total_fees_generated = referral
+        .total_fees_generated
+        .checked_add(fee_amount)
+        .ok_or(ReferralError::Overflow)?;
+    referrer_account.claimable = referrer_account
+        .claimable
+        .checked_add(credited)
+        .ok_or(ReferralError::Overflow)?;
+    referrer_account.total_earned = referrer_account
+        .total_earned
+        .checked_add(credited)
+        .ok_or(ReferralError::Overflow)?;
+
+    referral.try_serialize(&mut &mut referral_data[..])?;
+    referrer_account.try_serialize(&mut &mut referrer_data[..])?;
+
+    msg!(
+        "🤝 Referrer {} credited {} SCTK ({} units) from {} fee | 💰 Claimable: {}",
+        referral.referrer,
+        format_sctk(credited),
+        credited,
+        referral.referee,
+        referrer_account.claimable,
+    );
+
+    emit!(ReferralRewardCredited {
+        referee: referral.referee,
+        referrer: referral.referrer,
+        fee_charged: fee_amount,
+        credited,
+        share_bps,
+        total_fees_generated: referral.total_fees_generated,
+        claimable: referrer_account.claimable,
+    });
+
+    Ok(credited)
+}
+
+/// ===========================================================================
+/// Pays the caller's claimable referral balance from the Revenue Vault.
+///
+/// ## Behavior:
+/// - Transfers the whole `claimable` balance to the referrer's token account
+/// - Resets `claimable` and adds it to `total_claimed`
+/// - Posts a `Fees` outflow to the revenue vault ledger
+/// - Counts the payout against the revenue vault's outflow limits
+///
+/// ## Errors:
+/// - `ReferralError::NothingToClaim` if the claimable balance is zero
+/// - `VaultError::InsufficientVaultBalance` if the Revenue Vault cannot pay
+/// - `ReferralError::Overflow` if the claimed total overflows
+/// - `VaultError::EpochOutflowExceeded` if the vault's epoch outflow cap is reached
+/// ===========================================================================
+pub(crate) fn claim_referral_rewards(ctx: Context<ClaimReferralRewardsContext>) -> Result<()> {
+    let amount = ctx.accounts.referrer_account.claimable;
+    require!(amount > 0, ReferralError::NothingToClaim);
+    require!(
+        ctx.accounts.revenue_vault_token_account.amount >= amount,
+        VaultError::InsufficientVaultBalance
+    );
+
+    // Enforce the vault's epoch outflow limits (may trip the circuit breaker)
+    record_vault_outflow(
+        &ctx.accounts.vault_limits,
+        &mut ctx.accounts.token_state,
+        &ctx.accounts.caller.key(),
+        amount,
+    )?;
+
+    let signer_seeds: &[&[u8]] = &[b"revenue_vault", &[ctx.bumps.revenue_vault]];
+    let signer_seeds_nested = &[signer_seeds];
+
+    token::transfer(
+        CpiContext::new_with_signer(
+            ctx.accounts.token_program.to_account_info(),
+            Transfer {
+                from: ctx.accounts.revenue_vault_token_account.to_account_info(),
+                to: ctx.accounts.referrer_token_account.to_account_info(),
+                authority: ctx.accounts.revenue_vault.to_account_info(),
+            },
+            signer_seeds_nested,
+        ),
+        amount,
+    )?;
+    record_vault_flow(&ctx.accounts.revenue_ledger, LedgerFlow::Outflow, LedgerCategory::Fees, amount)?;
+
+    let referrer_account = &mut ctx.accounts.referrer_account;
+    referrer_account.claimable = 0;
+    referrer_account.total_claimed = referrer_account
+        .total_claimed
+        .checked_add(amount)
+        .ok_or(ReferralError::Overflow)?;
+
+    msg!(
+        "🎁 Referrer {} claimed {} SCTK ({} units) from revenue vault | 📊 Earned: {} | Claimed: {}",
+        referrer_account.referrer,
+        format_sctk(amount),
+        amount,
+        referrer_account.total_earned,
+        referrer_account.total_claimed,
+    );
+
+    emit!(ReferralRewardsClaimed {
+        referrer: referrer_account.referrer,
+        amount,
+        total_earned: referrer_account.total_earned,
+        total_claimed: referrer_account.total_claimed,
+        referral_count: referrer_account.referral_count,
+    });
+
+    Ok(())
+}
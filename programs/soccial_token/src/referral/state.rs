@@ -0,0 +1,88 @@
+// ===========================================================================
+// Referral State – Soccial Token (SCTK)
+// ---------------------------------------------------------------------------
+//
+// This module defines the on-chain referral links and the per-referrer
+// balances credited from the fees generated by referred users.
+//
+// ---------------------------------------------------------------------------
+// ## Components:
+// - `Referral`: Binds a referee to a referrer, exactly once
+// - `ReferrerAccount`: Referral stats and claimable balance of a referrer
+//
+// ---------------------------------------------------------------------------
+// Author: Paulo Rodrigues
+// Project: Soccial Token
+// Website: https://www.soccial.com/thetoken
+// License: MIT
+// ===========================================================================
+
+use anchor_lang::prelude::*;
+
+/// ===========================================================================
+/// Account: Referral
+///
+/// Link between a referee and its referrer. The PDA is seeded by the referee
+/// and created with `init`, so a user can only be bound once.
+///
+/// ## Fields:
+/// - `referee`: Referred wallet (PDA seed)
+/// - `referrer`: Wallet credited with a share of the referee's fees
+/// - `created_at`: Unix timestamp of the binding
+/// - `total_fees_generated`: Fees (SCTK units) paid by the referee since binding
+/// - `bump`: PDA bump seed
+/// ===========================================================================
+#[account]
+pub struct Referral {
+    pub referee: Pubkey,
+    pub referrer: Pubkey,
+    pub created_at: i64,
+    pub total_fees_generated: u64,
+    pub bump: u8,
+}
+
+impl Referral {
+    pub const LEN: usize =
+        8 +                         // Anchor discriminator
+        32 +                        // referee (Pubkey)
+        32 +                        // referrer (Pubkey)
+        8 +                         // created_at (i64)
+        8 +                         // total_fees_generated (u64)
+        1;                          // bump (u8)
+}
+
+/// ===========================================================================
+/// Account: ReferrerAccount
+///
+/// Stats and claimable balance of a referrer. Credits are bookkeeping only:
+/// the fee is still distributed to the vaults, and claims are paid from the
+/// Revenue Vault.
+///
+/// ## Fields:
+/// - `referrer`: Referrer wallet (PDA seed)
+/// - `referral_count`: Number of users bound to this referrer
+/// - `total_earned`: SCTK units credited over the account lifetime
+/// - `claimable`: SCTK units credited but not yet claimed
+/// - `total_claimed`: SCTK units paid out
+/// - `bump`: PDA bump seed
+/// ===========================================================================
+#[account]
+pub struct ReferrerAccount {
+    pub referrer: Pubkey,
+    pub referral_count: u64,
+    pub total_earned: u64,
+    pub claimable: u64,
+    pub total_claimed: u64,
+    pub bump: u8,
+}
+
+impl ReferrerAccount {
+    pub const LEN: usize =
+        8 +                         // Anchor discriminator
+        32 +                        // referrer (Pubkey)
+        8 +                         // referral_count (u64)
+        8 +                         // total_earned (u64)
+        8 +                         // claimable (u64)
+        8 +                         // total_claimed (u64)
+        1;                          // bump (u8)
+}
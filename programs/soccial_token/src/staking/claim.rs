@@ -47,6 +47,7 @@ use spl_associated_token_account::get_associated_token_address;
 
 use crate::staking::{context::*, StakingErrorCode};
 use crate::utils::error::ErrorCode;
+use crate::vaults::{record_vault_flow, record_vault_outflow, LedgerCategory, LedgerFlow};
 
 #[event]
 pub struct StakingRewardClaimed {
@@ -80,6 +81,8 @@ pub struct StakingWithdrawn {
 /// - Calculates the reward based on stake and APR
 /// - Transfers reward tokens from the staking vault to the user
 /// - Updates cycle state (`start_time`, `total_rewards_claimed`, etc.)
+/// - Posts the payout as a `StakingReserves` outflow to the staking vault ledger
+/// - Counts the payout against the staking vault's outflow limits
 ///
 /// ## Notes:
 /// - Multiple claims are allowed, one per cycle
@@ -88,6 +91,7 @@ pub struct StakingWithdrawn {
 /// ## Errors:
 /// - `StakingPeriodNotOver` if lockup time hasn't passed
 /// - `RewardOverflow`, `InsufficientVaultBalance`
+/// - `VaultError::EpochOutflowExceeded` if the vault's epoch outflow cap is reached
 /// ===========================================================================
 pub(crate) fn claim_rewards(
     ctx: &mut Context<ReleaseStaked>
@@ -118,6 +122,14 @@ pub(crate) fn claim_rewards(
         StakingErrorCode::InsufficientVaultBalance
     );
 
+    // Enforce the vault's epoch outflow limits (may trip the circuit breaker)
+    record_vault_outflow(
+        &ctx.accounts.vault_limits,
+        &mut ctx.accounts.token_state,
+        &ctx.accounts.caller.key(),
+        reward,
+    )?;
+
     // -------------------------------------
     // Step 4: Transfer reward from staking vault to user
     // -------------------------------------
@@ -136,6 +148,7 @@ pub(crate) fn claim_rewards(
     );
 
     transfer(cpi_ctx, reward)?;
+    record_vault_flow(&ctx.accounts.staking_ledger, LedgerFlow::Outflow, LedgerCategory::StakingReserves, reward)?;
 
     // Step 5: Update staking state
     staking_account.start_time = clock.unix_timestamp;
@@ -179,6 +192,8 @@ pub(crate) fn claim_rewards(
 /// - Checks PDA integrity (security)
 /// - Calculates reward and total payout
 /// - Transfers tokens from `staking_vault` to the user’s ATA
+/// - Posts the payout as a `StakingReserves` outflow to the staking vault ledger
+/// - Counts the payout against the staking vault's outflow limits
 ///
 /// ## Notes:
 /// - Mutually exclusive with `claim_rewards` – both mark `withdrawn = true`
@@ -187,6 +202,7 @@ pub(crate) fn claim_rewards(
 /// ## Errors:
 /// - `StakingPeriodNotOver`, `AlreadyWithdrawn`
 /// - `Unauthorized`, `RewardOverflow`, `Overflow`
+/// - `VaultError::EpochOutflowExceeded` if the vault's epoch outflow cap is reached
 /// ===========================================================================
 pub(crate) fn withdraw_staked_tokens(
     ctx: &mut Context<WithdrawStaked>
@@ -248,6 +264,14 @@ pub(crate) fn withdraw_staked_tokens(
         ErrorCode::Unauthorized 
     );
 
+    // Enforce the vault's epoch outflow limits (may trip the circuit breaker)
+    record_vault_outflow(
+        &ctx.accounts.vault_limits,
+        &mut ctx.accounts.token_state,
+        &ctx.accounts.caller.key(),
+        total_payout,
+    )?;
+
     // Step 7: Transfer staked + reward from staking vault to participant
     let seeds: &[&[u8]] = &[b"staking_vault", &[ctx.bumps.staking_vault]];
     let signer: &[&[&[u8]]] = &[seeds];
@@ -262,6 +286,7 @@ pub(crate) fn withdraw_staked_tokens(
         signer,
     );
     transfer(cpi_ctx, total_payout)?;
+    record_vault_flow(&ctx.accounts.staking_ledger, LedgerFlow::Outflow, LedgerCategory::StakingReserves, total_payout)?;
 
     // Step 8: Final log
     msg!(
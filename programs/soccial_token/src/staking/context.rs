@@ -51,6 +51,7 @@ pub struct BuyAndStakeTokens<'info> {
     pub token_mint: Account<'info, Mint>,
 
     /// Global token configuration and fee parameters.
+    #[account(mut)]
     pub token_state: Account<'info, TokenState>,
 
     // =========================================================================
@@ -72,6 +73,22 @@ pub struct BuyAndStakeTokens<'info> {
     )]
     pub liquidity_vault_token_account: Account<'info, TokenAccount>,
 
+    /// CHECK: Outflow limits PDA of `liquidity_vault`. Enforced in logic once configured.
+    #[account(
+        mut,
+        seeds = [b"vault_limits", liquidity_vault.key().as_ref()],
+        bump
+    )]
+    pub vault_limits: AccountInfo<'info>,
+
+    /// CHECK: Ledger PDA of `liquidity_vault`. Posted to in logic once opened.
+    #[account(
+        mut,
+        seeds = [b"vault_ledger", liquidity_vault.key().as_ref()],
+        bump
+    )]
+    pub liquidity_ledger: AccountInfo<'info>,
+
     // =========================================================================
     // Staking Vault (Destination of stake and rewards)
     // =========================================================================
@@ -91,6 +108,14 @@ pub struct BuyAndStakeTokens<'info> {
     )]
     pub staking_vault_token_account: Account<'info, TokenAccount>,
 
+    /// CHECK: Ledger PDA of `staking_vault`. Posted to in logic once opened.
+    #[account(
+        mut,
+        seeds = [b"vault_ledger", staking_vault.key().as_ref()],
+        bump
+    )]
+    pub staking_ledger: AccountInfo<'info>,
+
     // =========================================================================
     // Staking State & Account
     // =========================================================================
@@ -214,6 +239,14 @@ pub struct StakeTokens<'info> {
     )]
     pub staking_vault_token_account: Account<'info, TokenAccount>,
 
+    /// CHECK: Ledger PDA of `staking_vault`. Posted to in logic once opened.
+    #[account(
+        mut,
+        seeds = [b"vault_ledger", staking_vault.key().as_ref()],
+        bump
+    )]
+    pub staking_ledger: AccountInfo<'info>,
+
     /// CHECK: PDA authority for liquidity operations. No data is read or written.
     #[account(
         seeds = [b"liquidity_vault"],
@@ -229,6 +262,22 @@ pub struct StakeTokens<'info> {
     )]
     pub liquidity_vault_token_account: Account<'info, TokenAccount>,
 
+    /// CHECK: Outflow limits PDA of `liquidity_vault`. Enforced in logic once configured.
+    #[account(
+        mut,
+        seeds = [b"vault_limits", liquidity_vault.key().as_ref()],
+        bump
+    )]
+    pub vault_limits: AccountInfo<'info>,
+
+    /// CHECK: Ledger PDA of `liquidity_vault`. Posted to in logic once opened.
+    #[account(
+        mut,
+        seeds = [b"vault_ledger", liquidity_vault.key().as_ref()],
+        bump
+    )]
+    pub liquidity_ledger: AccountInfo<'info>,
+
     // =========================================================================
     // Token Mint & Authority
     // =========================================================================
@@ -245,6 +294,7 @@ pub struct StakeTokens<'info> {
     pub mint_authority: AccountInfo<'info>,
 
     /// Global token configuration.
+    #[account(mut)]
     pub token_state: Account<'info, TokenState>,
 
     // =========================================================================
@@ -333,6 +383,14 @@ pub struct ReinforceStake<'info> {
     )]
     pub staking_vault_token_account: Account<'info, TokenAccount>,
 
+    /// CHECK: Ledger PDA of `staking_vault`. Posted to in logic once opened.
+    #[account(
+        mut,
+        seeds = [b"vault_ledger", staking_vault.key().as_ref()],
+        bump
+    )]
+    pub staking_ledger: AccountInfo<'info>,
+
     /// CHECK: PDA authority for liquidity operations. No data is read or written.
     #[account(
         seeds = [b"liquidity_vault"],
@@ -348,6 +406,22 @@ pub struct ReinforceStake<'info> {
     )]
     pub liquidity_vault_token_account: Account<'info, TokenAccount>,
 
+    /// CHECK: Outflow limits PDA of `liquidity_vault`. Enforced in logic once configured.
+    #[account(
+        mut,
+        seeds = [b"vault_limits", liquidity_vault.key().as_ref()],
+        bump
+    )]
+    pub vault_limits: AccountInfo<'info>,
+
+    /// CHECK: Ledger PDA of `liquidity_vault`. Posted to in logic once opened.
+    #[account(
+        mut,
+        seeds = [b"vault_ledger", liquidity_vault.key().as_ref()],
+        bump
+    )]
+    pub liquidity_ledger: AccountInfo<'info>,
+
     // =========================================================================
     // Token Mint & Authority
     // =========================================================================
@@ -364,6 +438,7 @@ pub struct ReinforceStake<'info> {
     pub mint_authority: AccountInfo<'info>,
 
     /// Global token configuration.
+    #[account(mut)]
     pub token_state: Account<'info, TokenState>,
 
     // =========================================================================
@@ -423,6 +498,7 @@ pub struct ReleaseStaked<'info> {
     pub mint_authority: AccountInfo<'info>,
 
     /// Token configuration and core settings.
+    #[account(mut)]
     pub token_state: Account<'info, TokenState>,
 
     // =========================================================================
@@ -445,6 +521,22 @@ pub struct ReleaseStaked<'info> {
     )]
     pub staking_vault_token_account: Account<'info, TokenAccount>,
 
+    /// CHECK: Outflow limits PDA of `staking_vault`. Enforced in logic once configured.
+    #[account(
+        mut,
+        seeds = [b"vault_limits", staking_vault.key().as_ref()],
+        bump
+    )]
+    pub vault_limits: AccountInfo<'info>,
+
+    /// CHECK: Ledger PDA of `staking_vault`. Posted to in logic once opened.
+    #[account(
+        mut,
+        seeds = [b"vault_ledger", staking_vault.key().as_ref()],
+        bump
+    )]
+    pub staking_ledger: AccountInfo<'info>,
+
     /// The destination ATA for the user receiving released tokens.
     #[account(mut)]
     pub destination_token_account: Account<'info, TokenAccount>,
@@ -515,6 +607,7 @@ pub struct WithdrawStaked<'info> {
     pub mint_authority: AccountInfo<'info>,
 
     /// Global token configuration and settings.
+    #[account(mut)]
     pub token_state: Account<'info, TokenState>,
 
     // =========================================================================
@@ -537,6 +630,22 @@ pub struct WithdrawStaked<'info> {
     )]
     pub staking_vault_token_account: Account<'info, TokenAccount>,
 
+    /// CHECK: Outflow limits PDA of `staking_vault`. Enforced in logic once configured.
+    #[account(
+        mut,
+        seeds = [b"vault_limits", staking_vault.key().as_ref()],
+        bump
+    )]
+    pub vault_limits: AccountInfo<'info>,
+
+    /// CHECK: Ledger PDA of `staking_vault`. Posted to in logic once opened.
+    #[account(
+        mut,
+        seeds = [b"vault_ledger", staking_vault.key().as_ref()],
+        bump
+    )]
+    pub staking_ledger: AccountInfo<'info>,
+
     /// The destination ATA for the user receiving released tokens.
     #[account(mut)]
     pub destination_token_account: Account<'info, TokenAccount>,
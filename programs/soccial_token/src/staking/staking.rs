@@ -37,6 +37,7 @@ use anchor_lang::{prelude::*, solana_program};
 use anchor_spl::token::{Transfer, transfer};
 
 use crate::staking::{context::*, StakingErrorCode};
+use crate::vaults::{record_vault_flow, record_vault_outflow, LedgerCategory, LedgerFlow};
 use solana_program::sysvar::clock::Clock;
 
 #[event]
@@ -108,9 +109,14 @@ pub struct StakeTokensEvent {
 /// - Calculates reward estimate
 /// - Transfers `amount + estimated_reward` from liquidity → staking vault
 /// - Registers new `StakingAccount` with full tracking for cyclic staking
+/// - Posts the transfer as `StakingReserves` to the liquidity and staking vault ledgers
+/// - Counts the transfer against the liquidity vault's outflow limits
 ///
 /// ## Notes:
 /// - Stake will auto-renew after each lockup if not withdrawn
+///
+/// ## Errors:
+/// - `VaultError::EpochOutflowExceeded` if the vault's epoch outflow cap is reached
 /// ===========================================================================
 pub fn buy_and_stake_tokens(
     ctx: Context<BuyAndStakeTokens>,
@@ -141,6 +147,14 @@ pub fn buy_and_stake_tokens(
         StakingErrorCode::InsufficientVaultBalance
     );
 
+    // Enforce the vault's epoch outflow limits (may trip the circuit breaker)
+    record_vault_outflow(
+        &ctx.accounts.vault_limits,
+        &mut ctx.accounts.token_state,
+        &ctx.accounts.caller.key(),
+        total_required,
+    )?;
+
     // Step 4: Transfer stake + reward reserve from liquidity vault to staking vault
     let signer_seeds: &[&[u8]] = &[b"liquidity_vault", &[ctx.bumps.liquidity_vault]];
     let signer = &[signer_seeds];
@@ -156,6 +170,8 @@ pub fn buy_and_stake_tokens(
     );
 
     transfer(cpi_ctx, total_required)?;
+    record_vault_flow(&ctx.accounts.liquidity_ledger, LedgerFlow::Outflow, LedgerCategory::StakingReserves, total_required)?;
+    record_vault_flow(&ctx.accounts.staking_ledger, LedgerFlow::Inflow, LedgerCategory::StakingReserves, total_required)?;
 
     // Step 5: Register staking metadata (new format)
     staking_account.participant = ctx.accounts.participant.key();
@@ -205,6 +221,8 @@ pub fn buy_and_stake_tokens(
 /// - Transfers stake from user to vault
 /// - Reserves reward from liquidity vault
 /// - Registers staking metadata (with support for renewal cycles)
+/// - Posts the stake and reserve as `StakingReserves` to the vault ledgers
+/// - Counts the reserve against the liquidity vault's outflow limits
 ///
 /// ## Parameters:
 /// - `ctx`: Anchor context including user wallet and vaults
@@ -214,6 +232,7 @@ pub fn buy_and_stake_tokens(
 /// ## Errors:
 /// - `InvalidStakingPlan`, `InsufficientUserBalance`
 /// - `Overflow`, `InsufficientVaultBalance`
+/// - `VaultError::EpochOutflowExceeded` if the vault's epoch outflow cap is reached
 /// ===========================================================================
 pub fn stake_tokens(
     ctx: &mut Context<StakeTokens>, 
@@ -245,6 +264,14 @@ pub fn stake_tokens(
         StakingErrorCode::InsufficientVaultBalance
     );
 
+    // Enforce the vault's epoch outflow limits (may trip the circuit breaker)
+    record_vault_outflow(
+        &ctx.accounts.vault_limits,
+        &mut ctx.accounts.token_state,
+        &ctx.accounts.caller.key(),
+        reward_estimate,
+    )?;
+
     // Step 5: Reserve reward from liquidity vault
     let seeds: &[&[u8]] = &[b"liquidity_vault", &[ctx.bumps.liquidity_vault]];
     let signer = &[seeds];
@@ -260,6 +287,8 @@ pub fn stake_tokens(
     );
 
     transfer(reward_transfer, reward_estimate)?;
+    record_vault_flow(&ctx.accounts.liquidity_ledger, LedgerFlow::Outflow, LedgerCategory::StakingReserves, reward_estimate)?;
+    record_vault_flow(&ctx.accounts.staking_ledger, LedgerFlow::Inflow, LedgerCategory::StakingReserves, reward_estimate)?;
     msg!(
         "📥 Reserved {} tokens from liquidity vault to staking vault (future rewards for participant {} in plan {}).",
         reward_estimate,
@@ -277,6 +306,7 @@ pub fn stake_tokens(
         },
     );
     transfer(user_to_staking, amount)?;
+    record_vault_flow(&ctx.accounts.staking_ledger, LedgerFlow::Inflow, LedgerCategory::StakingReserves, amount)?;
 
     // Step 7: Register staking metadata
     staking_account.participant = ctx.accounts.participant.key();
@@ -353,6 +383,7 @@ pub fn stake_tokens(
 /// - `InsufficientUserBalance` if participant lacks funds
 /// - `InsufficientVaultBalance` if the vault cannot cover rewards
 /// - `Overflow`, `RewardOverflow` if arithmetic overflows
+/// - `VaultError::EpochOutflowExceeded` if the vault's epoch outflow cap is reached
 /// ===========================================================================
 pub fn add_to_stake(
     ctx: &mut Context<ReinforceStake>,
@@ -412,6 +443,7 @@ pub fn add_to_stake(
             },
         );
         transfer(transfer_user, amount)?;
+        record_vault_flow(&ctx.accounts.staking_ledger, LedgerFlow::Inflow, LedgerCategory::StakingReserves, amount)?;
     }
 
     // Step 2: Calculate new reward based on full updated stake
@@ -443,6 +475,14 @@ pub fn add_to_stake(
             StakingErrorCode::InsufficientVaultBalance
         );
 
+        // Enforce the vault's epoch outflow limits (may trip the circuit breaker)
+        record_vault_outflow(
+            &ctx.accounts.vault_limits,
+            &mut ctx.accounts.token_state,
+            &ctx.accounts.caller.key(),
+            reward_delta,
+        )?;
+
         let seeds: &[&[u8]] = &[b"liquidity_vault", &[ctx.bumps.liquidity_vault]];
         let signer = &[seeds];
 
@@ -456,6 +496,8 @@ pub fn add_to_stake(
             signer,
         );
         transfer(transfer_rewards, reward_delta)?;
+        record_vault_flow(&ctx.accounts.liquidity_ledger, LedgerFlow::Outflow, LedgerCategory::StakingReserves, reward_delta)?;
+        record_vault_flow(&ctx.accounts.staking_ledger, LedgerFlow::Inflow, LedgerCategory::StakingReserves, reward_delta)?;
     }
 
     // Step 5: Final updates
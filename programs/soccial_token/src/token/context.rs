@@ -105,6 +105,45 @@ pub struct ManageContractGovernance<'info> {
     pub system_program: Program<'info, System>,
 }
 
+#[derive(Accounts)]
+pub struct MigrateTokenStateContext<'info> {
+    // ─────────────────────────────────────────────────────────────
+    // Signer
+    // ─────────────────────────────────────────────────────────────
+
+    /// The contract owner, paying rent for the extra space.
+    #[account(mut)]
+    pub caller: Signer<'info>,
+
+    // ─────────────────────────────────────────────────────────────
+    // State
+    // ─────────────────────────────────────────────────────────────
+
+    /// CHECK: TokenState in a possibly older layout that no longer
+    /// deserializes. Ownership and discriminator verified in handler.
+    #[account(
+        mut,
+        seeds = [b"token_state"],
+        bump
+    )]
+    pub token_state: AccountInfo<'info>,
+
+    /// Governance proposal approval needed for the instruction.
+    #[account(mut)]
+    pub proposal: Account<'info, ProposalAccount>,
+
+    /// Governance state account (quorum config, etc).
+    #[account()]
+    pub governance_state: Account<'info, GovernanceState>,
+
+    // ─────────────────────────────────────────────────────────────
+    // Programs
+    // ─────────────────────────────────────────────────────────────
+
+    /// System program for the rent top-up.
+    pub system_program: Program<'info, System>,
+}
+
 #[derive(Accounts)]
 pub struct EmitSystemLog<'info> {
     // ─────────────────────────────────────────────────────────────
@@ -32,4 +32,12 @@ pub enum TokenError {
     /// Total token supply would exceed the capped maximum.
     #[msg("Total supply exceeded.")]
     MaxSupplyExceeded,
+
+    /// The `TokenState` account already has the current layout.
+    #[msg("Token state is already up to date.")]
+    StateAlreadyCurrent,
+
+    /// The `TokenState` account does not hold a known layout.
+    #[msg("Invalid token state layout.")]
+    InvalidStateLayout,
 }
@@ -0,0 +1,156 @@
+// ===========================================================================
+// TokenState Migration – Soccial Token (SCTK)
+// ---------------------------------------------------------------------------
+//
+// `TokenState` grows by appending settings sections at the end of the
+// account. Deployments created before a section existed hold a shorter
+// account that no longer deserializes; `migrate_token_state` reallocates it
+// to `TokenState::LEN` and fills every missing section with the defaults
+// `initialize_token` writes today.
+//
+// ---------------------------------------------------------------------------
+// ## Layout history (sections appended in this order):
+// - `core` + `fee`: original layout
+// - `fee_schedule`: per-operation fee schedule
+// - `fee_discounts`: flag-based fee discounts
+// - `referral`: referrer share of referee fees
+// - `withdrawal`: withdrawal address book cooldown
+//
+// ---------------------------------------------------------------------------
+// ## Rules:
+// - Only the contract owner, with an approved `UpdateGovernance` proposal
+// - Sections already present are kept byte for byte
+// - The caller tops up rent for the extra space
+//
+// ---------------------------------------------------------------------------
+// Author: Paulo Rodrigues
+// Project: Soccial Token
+// Website: https://www.soccial.com/thetoken
+// License: MIT
+// ===========================================================================
+
+use anchor_lang::{prelude::*, system_program};
+
+use crate::{
+    economics::state::{FeeDiscounts, FeeDistribution, FeeSchedule, ReferralSettings},
+    economy::fee,
+    market::state::{WithdrawalSettings, DEFAULT_ADDRESS_COOLDOWN_SECS},
+    token::{context::MigrateTokenStateContext, CoreSettings, TokenError, TokenState},
+};
+
+#[event]
+pub struct TokenStateMigrated {
+    pub previous_len: u64,
+    pub new_len: u64,
+    pub caller: Pubkey,
+}
+
+/// Size of the original layout: discriminator, `core` and `fee`.
+const BASE_LEN: usize = 8 + CoreSettings::LEN + FeeDistribution::LEN;
+
+/// End offset of each appended section.
+const FEE_SCHEDULE_END: usize = BASE_LEN + FeeSchedule::LEN;
+const FEE_DISCOUNTS_END: usize = FEE_SCHEDULE_END + FeeDiscounts::LEN;
+const REFERRAL_END: usize = FEE_DISCOUNTS_END + ReferralSettings::LEN;
+
+/// ===========================================================================
+/// Reads the owner of a `TokenState` account of any layout version.
+///
+/// ## Errors:
+/// - `ErrorCode::Unauthorized` if the account is not program-owned
+/// - `TokenError::InvalidStateLayout` if the base layout is missing
+/// ===========================================================================
+pub(crate) fn legacy_owner(token_state: &AccountInfo) -> Result<Pubkey> {
+    require_keys_eq!(*token_state.owner, crate::ID, crate::ErrorCode::Unauthorized);
+
+    let data = token_state.try_borrow_data()?;
+    require!(data.len() >= BASE_LEN, TokenError::InvalidStateLayout);
+    require!(
+        &data[..8] == TokenState::DISCRIMINATOR,
+        TokenError::InvalidStateLayout
+    );
+
+    let core = CoreSettings::deserialize(&mut &data[8..])?;
+    Ok(core.owner)
+}
+
+/// ===========================================================================
+/// Grows `TokenState` to the current layout.
+///
+/// ## Behavior:
+/// - Tops up rent from the caller, then reallocates to `TokenState::LEN`
+/// - Keeps every section already present
+/// - Writes `initialize_token` defaults into the sections that were missing
+///
+/// ## Errors:
+/// - `TokenError::StateAlreadyCurrent` if the account already has the current size
+/// - `TokenError::InvalidStateLayout` if the account predates the base layout
+/// ===========================================================================
+pub(crate) fn migrate_token_state(ctx: &Context<MigrateTokenStateContext>) -> Result<()> {
+    let token_state = ctx.accounts.token_state.to_account_info();
+    let previous_len = token_state.data_len();
+
+    require!(previous_len >= BASE_LEN, TokenError::InvalidStateLayout);
+    require!(previous_len < TokenState::LEN, TokenError::StateAlreadyCurrent);
+
+    // Rent for the extra space is paid by the caller
+    let required = Rent::get()?.minimum_balance(TokenState::LEN);
+    let missing = required.saturating_sub(token_state.lamports());
+    if missing > 0 {
+        system_program::transfer(
+            CpiContext::new(
+                ctx.accounts.system_program.to_account_info(),
+                system_program::Transfer {
+                    from: ctx.accounts.caller.to_account_info(),
+                    to: token_state.clone(),
+                },
+            ),
+            missing,
+        )?;
+    }
+
+    token_state.realloc(TokenState::LEN, true)?;
+
+    let mut state = {
+        let data = token_state.try_borrow_data()?;
+        TokenState::try_deserialize(&mut &data[..])?
+    };
+
+    if previous_len < FEE_SCHEDULE_END {
+        state.fee_schedule = FeeSchedule {
+            buy_fee_bps: fee::DEFAULT_BUY_FEE_BPS,
+            withdrawal_fee_bps: fee::DEFAULT_WITHDRAWAL_FEE_BPS,
+            transfer_fee_bps: fee::DEFAULT_TRANSFER_FEE_BPS,
+        };
+    }
+    if previous_len < FEE_DISCOUNTS_END {
+        state.fee_discounts = FeeDiscounts::default();
+    }
+    if previous_len < REFERRAL_END {
+        state.referral = ReferralSettings {
+            share_bps: fee::DEFAULT_REFERRAL_SHARE_BPS,
+        };
+    }
+    // `withdrawal` is the last section, always missing at this point
+    state.withdrawal = WithdrawalSettings {
+        address_cooldown_secs: DEFAULT_ADDRESS_COOLDOWN_SECS,
+    };
+
+    let mut data = token_state.try_borrow_mut_data()?;
+    state.try_serialize(&mut *data)?;
+
+    msg!(
+        "🧬 TokenState migrated from {} to {} bytes by {}",
+        previous_len,
+        TokenState::LEN,
+        ctx.accounts.caller.key(),
+    );
+
+    emit!(TokenStateMigrated {
+        previous_len: previous_len as u64,
+        new_len: TokenState::LEN as u64,
+        caller: ctx.accounts.caller.key(),
+    });
+
+    Ok(())
+}
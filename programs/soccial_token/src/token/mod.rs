@@ -2,8 +2,10 @@ pub mod state;
 pub mod error;
 pub mod context;
 pub mod metadata;
+pub mod migration;
 
 pub use error::*;
 pub use state::*;
 pub use context::*;
-pub use metadata::*;
\ No newline at end of file
+pub use metadata::*;
+pub use migration::*;
\ No newline at end of file
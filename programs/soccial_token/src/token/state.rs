@@ -3,7 +3,8 @@
 // ---------------------------------------------------------------------------
 //
 // This module defines the global `TokenState` for the Soccial Token contract,
-// including core configuration (owner, status flags, versioning) and fee settings.
+// including core configuration (owner, status flags, versioning) and fee settings
+// (vault distribution and the per-operation fee schedule).
 //
 // ---------------------------------------------------------------------------
 // ## Main Components:
@@ -25,7 +26,8 @@
 // ===========================================================================
 
 use anchor_lang::prelude::*;
-use crate::economics::state::FeeDistribution;
+use crate::economics::state::{FeeDiscounts, FeeDistribution, FeeSchedule, ReferralSettings};
+use crate::market::state::WithdrawalSettings;
 
 #[event]
 pub struct ContractPaused {
@@ -59,6 +61,10 @@ pub struct ContractVersionUpdated {
 pub struct TokenState {
     pub core: CoreSettings,
     pub fee: FeeDistribution,
+    pub fee_schedule: FeeSchedule,
+    pub fee_discounts: FeeDiscounts,
+    pub referral: ReferralSettings,
+    pub withdrawal: WithdrawalSettings,
 }
 
 impl TokenState {
@@ -68,13 +74,21 @@ impl TokenState {
     /// - Anchor account discriminator (8 bytes)
     /// - `CoreSettings` struct
     /// - `FeeDistribution` struct
+    /// - `FeeSchedule` struct
+    /// - `FeeDiscounts` struct
+    /// - `ReferralSettings` struct
+    /// - `WithdrawalSettings` struct
     ///
     /// Used for allocating the correct space when initializing the account.
 
     pub const LEN: usize =
         8    // Anchor account discriminator
         + CoreSettings::LEN
-        + FeeDistribution::LEN;
+        + FeeDistribution::LEN
+        + FeeSchedule::LEN
+        + FeeDiscounts::LEN
+        + ReferralSettings::LEN
+        + WithdrawalSettings::LEN;
 }
 
 /// Returns the static size (in bytes) of the `VersionInfo` struct.
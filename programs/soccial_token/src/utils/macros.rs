@@ -53,7 +53,7 @@ macro_rules! check {
     ($ctx:expr, $caller:expr) => {
         crate::utils::system::check_core(
             $caller,
-            $ctx.accounts.user_access.as_ref(),
+            $ctx.accounts.user_access.as_deref(),
             &$ctx.accounts.token_state
         )
     };
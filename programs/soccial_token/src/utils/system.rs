@@ -114,9 +114,9 @@ impl UserContext {
 /// - `ContractPaused` if user lacks bypass permission and contract is paused
 ///
 /// ===========================================================================
-pub(crate) fn check_core<'info>(
+pub(crate) fn check_core(
     caller: &Pubkey,
-    user_access: Option<&Account<'info, UserAccessAccount>>,
+    user_access: Option<&UserAccessAccount>,
     token_state: &TokenState,
 ) -> Result<UserContext> {
     use UserRole::*;
@@ -14,7 +14,15 @@
 
 use anchor_lang::prelude::*;
 use anchor_spl::token::{Token, TokenAccount, Mint};
-use crate::{auth::user::UserAccessAccount, governance::{GovernanceState, ProposalAccount}, token::state::TokenState};
+use crate::{
+    auth::user::UserAccessAccount,
+    governance::{GovernanceState, ProposalAccount},
+    token::state::TokenState,
+    vaults::{
+        error::VaultError,
+        state::{ApprovalCouncil, CouncilOperation, PendingWithdrawal, VaultLedger, VaultLimits, VaultPolicy},
+    },
+};
 
 // ======================================================================
 // Vault Type Enum
@@ -69,6 +77,14 @@ pub struct VaultDepositContext<'info> {
     #[account(mut)]
     pub vault: AccountInfo<'info>,
 
+    /// CHECK: Ledger PDA of `vault`. Posted to in logic once opened.
+    #[account(
+        mut,
+        seeds = [b"vault_ledger", vault.key().as_ref()],
+        bump
+    )]
+    pub vault_ledger: AccountInfo<'info>,
+
     /// CHECK: Vault authority PDA (must match seeds in logic).
     pub vault_authority: AccountInfo<'info>,
 
@@ -109,7 +125,9 @@ pub struct VaultWithdrawContext<'info> {
     // Caller
     // =========================================================================
 
-    /// The user initiating the withdrawal (must be authorized).
+    /// The user initiating the withdrawal (must be authorized; pays for the
+    /// direct outflow window on first use).
+    #[account(mut)]
     pub caller: Signer<'info>,
 
     // =========================================================================
@@ -132,14 +150,48 @@ pub struct VaultWithdrawContext<'info> {
     #[account(mut)]
     pub vault: AccountInfo<'info>,
 
+    /// CHECK: Outflow limits PDA of `vault`. Enforced in logic once configured.
+    #[account(
+        mut,
+        seeds = [b"vault_limits", vault.key().as_ref()],
+        bump
+    )]
+    pub vault_limits: AccountInfo<'info>,
+
+    /// CHECK: Ledger PDA of `vault`. Posted to in logic once opened.
+    #[account(
+        mut,
+        seeds = [b"vault_ledger", vault.key().as_ref()],
+        bump
+    )]
+    pub vault_ledger: AccountInfo<'info>,
+
+    /// CHECK: Direct outflow window PDA of `vault`. Created in logic on the
+    /// first direct outflow from a timelocked vault.
+    #[account(
+        mut,
+        seeds = [b"direct_outflow", vault.key().as_ref()],
+        bump
+    )]
+    pub direct_outflow: AccountInfo<'info>,
+
     /// CHECK: Vault authority PDA (must match seeds in logic).
     pub vault_authority: AccountInfo<'info>,
 
+    /// CHECK: Approval council PDA. Once initialized, treasury and insurance
+    /// outflows must go through council operations (checked in logic).
+    #[account(
+        seeds = [b"approval_council"],
+        bump
+    )]
+    pub approval_council: AccountInfo<'info>,
+
     // =========================================================================
     // Token State
     // =========================================================================
 
     /// Global TokenState configuration account.
+    #[account(mut)]
     pub token_state: Account<'info, TokenState>,
 
     // =========================================================================
@@ -159,6 +211,9 @@ pub struct VaultWithdrawContext<'info> {
 
     /// SPL Token Program.
     pub token_program: Program<'info, Token>,
+
+    /// System program required to create the direct outflow window.
+    pub system_program: Program<'info, System>,
 }
 
 #[derive(Accounts)]
@@ -167,7 +222,9 @@ pub struct VaultTransferContext<'info> {
     // Caller & Access Control
     // =========================================================================
 
-    /// The user triggering the vault-to-vault transfer (must be authorized).
+    /// The user triggering the vault-to-vault transfer (must be authorized;
+    /// pays for the direct outflow window on first use).
+    #[account(mut)]
     pub caller: Signer<'info>,
 
     /// Optional access control for transfer permission checks.
@@ -196,9 +253,57 @@ pub struct VaultTransferContext<'info> {
     /// CHECK: Metadata account for the source vault. Must be validated in logic.
     pub source_vault: AccountInfo<'info>,
 
+    /// CHECK: Outflow limits PDA of `source_vault`. Enforced in logic once configured.
+    #[account(
+        mut,
+        seeds = [b"vault_limits", source_vault.key().as_ref()],
+        bump
+    )]
+    pub vault_limits: AccountInfo<'info>,
+
+    /// CHECK: Ledger PDA of `source_vault`. Posted to in logic once opened.
+    #[account(
+        mut,
+        seeds = [b"vault_ledger", source_vault.key().as_ref()],
+        bump
+    )]
+    pub source_vault_ledger: AccountInfo<'info>,
+
+    /// CHECK: Direct outflow window PDA of `source_vault`. Created in logic on
+    /// the first direct outflow from a timelocked vault.
+    #[account(
+        mut,
+        seeds = [b"direct_outflow", source_vault.key().as_ref()],
+        bump
+    )]
+    pub direct_outflow: AccountInfo<'info>,
+
+    /// CHECK: Ledger PDA of the destination vault (owner of `destination_vault_token_account`). Posted to in logic once opened.
+    #[account(
+        mut,
+        seeds = [b"vault_ledger", destination_vault_token_account.owner.as_ref()],
+        bump
+    )]
+    pub destination_vault_ledger: AccountInfo<'info>,
+
     /// CHECK: Authority PDA for the source vault.
     pub source_vault_authority: AccountInfo<'info>,
 
+    /// CHECK: Approval council PDA. Once initialized, treasury and insurance
+    /// outflows must go through council operations (checked in logic).
+    #[account(
+        seeds = [b"approval_council"],
+        bump
+    )]
+    pub approval_council: AccountInfo<'info>,
+
+    /// CHECK: Vault transfer policy PDA. Built-in rules apply until it is created.
+    #[account(
+        seeds = [b"vault_policy"],
+        bump
+    )]
+    pub vault_policy: AccountInfo<'info>,
+
     // =========================================================================
     // Governance & Token State
     // =========================================================================
@@ -211,6 +316,7 @@ pub struct VaultTransferContext<'info> {
     pub governance_state: Account<'info, GovernanceState>,
 
     /// Global TokenState configuration account.
+    #[account(mut)]
     pub token_state: Account<'info, TokenState>,
 
     // =========================================================================
@@ -219,6 +325,9 @@ pub struct VaultTransferContext<'info> {
 
     /// SPL Token Program.
     pub token_program: Program<'info, Token>,
+
+    /// System program required to create the direct outflow window.
+    pub system_program: Program<'info, System>,
 }
 
 
@@ -266,14 +375,738 @@ pub struct ContractToVaultContext<'info> {
     #[account(mut)]
     pub destination_vault: AccountInfo<'info>,
 
+    /// CHECK: Ledger PDA of `destination_vault`. Posted to in logic once opened.
+    #[account(
+        mut,
+        seeds = [b"vault_ledger", destination_vault.key().as_ref()],
+        bump
+    )]
+    pub destination_vault_ledger: AccountInfo<'info>,
+
+    // =========================================================================
+    // Token State & Program
+    // =========================================================================
+
+    /// Global TokenState configuration account.
+    pub token_state: Account<'info, TokenState>,
+
+    /// SPL Token Program.
+    pub token_program: Program<'info, Token>,
+}
+
+
+#[derive(Accounts)]
+#[instruction(args: Vec<String>)]
+pub struct QueueVaultWithdrawalContext<'info> {
+    // =========================================================================
+    // Caller & Access Control
+    // =========================================================================
+
+    /// The caller queuing the withdrawal (must hold `manage_vaults`; pays rent).
+    #[account(mut)]
+    pub caller: Signer<'info>,
+
+    /// Optional access control for permission checks.
+    #[account(
+        seeds = [b"user_access", caller.key().as_ref()],
+        bump
+    )]
+    pub user_access: Option<Account<'info, UserAccessAccount>>,
+
+    // =========================================================================
+    // Pending Withdrawal
+    // =========================================================================
+
+    /// The queued withdrawal, keyed by `args[0]` (withdrawal id).
+    #[account(
+        init,
+        payer = caller,
+        space = PendingWithdrawal::LEN,
+        seeds = [
+            b"pending_withdrawal".as_ref(),
+            &args.first().and_then(|id| id.parse::<u64>().ok()).unwrap_or_default().to_le_bytes(),
+        ],
+        bump
+    )]
+    pub pending_withdrawal: Account<'info, PendingWithdrawal>,
+
+    // =========================================================================
+    // Token Accounts
+    // =========================================================================
+
+    /// Vault's ATA the tokens will be withdrawn from.
+    #[account(
+        constraint = vault_token_account.owner == vault.key() @ VaultError::InvalidVaultTokenAccount
+    )]
+    pub vault_token_account: Account<'info, TokenAccount>,
+
+    /// Token account that will receive the tokens once executed.
+    #[account(
+        constraint = recipient_token_account.mint == vault_token_account.mint @ VaultError::InvalidVaultTokenAccount
+    )]
+    pub recipient_token_account: Account<'info, TokenAccount>,
+
+    // =========================================================================
+    // Vault PDA
+    // =========================================================================
+
+    /// CHECK: Vault PDA. Must be validated in logic.
+    pub vault: AccountInfo<'info>,
+
+    /// CHECK: Approval council PDA. Once initialized, treasury and insurance
+    /// outflows must go through council operations (checked in logic).
+    #[account(
+        seeds = [b"approval_council"],
+        bump
+    )]
+    pub approval_council: AccountInfo<'info>,
+
+    // =========================================================================
+    // Token State & Program
+    // =========================================================================
+
+    /// Global TokenState configuration account.
+    pub token_state: Account<'info, TokenState>,
+
+    /// System Program (for PDA creation).
+    pub system_program: Program<'info, System>,
+}
+
+
+#[derive(Accounts)]
+#[instruction(args: Vec<String>)]
+pub struct ExecuteVaultWithdrawalContext<'info> {
+    // =========================================================================
+    // Caller & Access Control
+    // =========================================================================
+
+    /// The caller executing the withdrawal (must hold `manage_vaults`; receives the rent).
+    #[account(mut)]
+    pub caller: Signer<'info>,
+
+    /// Optional access control for permission checks.
+    #[account(
+        seeds = [b"user_access", caller.key().as_ref()],
+        bump
+    )]
+    pub user_access: Option<Account<'info, UserAccessAccount>>,
+
+    // =========================================================================
+    // Pending Withdrawal
+    // =========================================================================
+
+    /// The queued withdrawal (`args[0]`), closed once executed.
+    #[account(
+        mut,
+        close = caller,
+        seeds = [
+            b"pending_withdrawal".as_ref(),
+            &args.first().and_then(|id| id.parse::<u64>().ok()).unwrap_or_default().to_le_bytes(),
+        ],
+        bump = pending_withdrawal.bump
+    )]
+    pub pending_withdrawal: Account<'info, PendingWithdrawal>,
+
+    // =========================================================================
+    // Token Accounts
+    // =========================================================================
+
+    /// Vault's ATA recorded in the queued withdrawal.
+    #[account(
+        mut,
+        address = pending_withdrawal.vault_token_account @ VaultError::InvalidVaultTokenAccount
+    )]
+    pub vault_token_account: Account<'info, TokenAccount>,
+
+    /// Recipient token account recorded in the queued withdrawal.
+    #[account(
+        mut,
+        address = pending_withdrawal.recipient @ VaultError::InvalidVaultTokenAccount
+    )]
+    pub recipient_token_account: Account<'info, TokenAccount>,
+
+    // =========================================================================
+    // Vault PDA
+    // =========================================================================
+
+    /// CHECK: Vault PDA recorded in the queued withdrawal (signs the transfer).
+    #[account(
+        address = pending_withdrawal.vault @ VaultError::UnknownVaultType
+    )]
+    pub vault: AccountInfo<'info>,
+
+    /// CHECK: Outflow limits PDA of `vault`. Enforced in logic once configured.
+    #[account(
+        mut,
+        seeds = [b"vault_limits", vault.key().as_ref()],
+        bump
+    )]
+    pub vault_limits: AccountInfo<'info>,
+
+    /// CHECK: Ledger PDA of `vault`. Posted to in logic once opened.
+    #[account(
+        mut,
+        seeds = [b"vault_ledger", vault.key().as_ref()],
+        bump
+    )]
+    pub vault_ledger: AccountInfo<'info>,
+
+    /// CHECK: Approval council PDA. Once initialized, treasury and insurance
+    /// outflows must go through council operations (checked in logic).
+    #[account(
+        seeds = [b"approval_council"],
+        bump
+    )]
+    pub approval_council: AccountInfo<'info>,
+
     // =========================================================================
     // Token State & Program
     // =========================================================================
 
     /// Global TokenState configuration account.
+    #[account(mut)]
     pub token_state: Account<'info, TokenState>,
 
     /// SPL Token Program.
     pub token_program: Program<'info, Token>,
 }
 
+
+#[derive(Accounts)]
+#[instruction(args: Vec<String>)]
+pub struct CancelVaultWithdrawalContext<'info> {
+    // =========================================================================
+    // Caller & Access Control
+    // =========================================================================
+
+    /// The admin or guardian cancelling the withdrawal (receives the rent).
+    #[account(mut)]
+    pub caller: Signer<'info>,
+
+    /// Optional access control for permission checks.
+    #[account(
+        seeds = [b"user_access", caller.key().as_ref()],
+        bump
+    )]
+    pub user_access: Option<Account<'info, UserAccessAccount>>,
+
+    // =========================================================================
+    // Pending Withdrawal
+    // =========================================================================
+
+    /// The queued withdrawal (`args[0]`), closed on cancellation.
+    #[account(
+        mut,
+        close = caller,
+        seeds = [
+            b"pending_withdrawal".as_ref(),
+            &args.first().and_then(|id| id.parse::<u64>().ok()).unwrap_or_default().to_le_bytes(),
+        ],
+        bump = pending_withdrawal.bump
+    )]
+    pub pending_withdrawal: Account<'info, PendingWithdrawal>,
+
+    // =========================================================================
+    // Token State
+    // =========================================================================
+
+    /// Global TokenState configuration account.
+    pub token_state: Account<'info, TokenState>,
+}
+
+
+#[derive(Accounts)]
+pub struct UpdateApprovalCouncilContext<'info> {
+    // =========================================================================
+    // Caller & Access Control
+    // =========================================================================
+
+    /// The caller applying the approved council change (pays rent on creation).
+    #[account(mut)]
+    pub caller: Signer<'info>,
+
+    /// Optional access control for permission checks.
+    #[account(
+        seeds = [b"user_access", caller.key().as_ref()],
+        bump
+    )]
+    pub user_access: Option<Account<'info, UserAccessAccount>>,
+
+    // =========================================================================
+    // Council
+    // =========================================================================
+
+    /// The approval council, created on first use.
+    #[account(
+        init_if_needed,
+        payer = caller,
+        space = ApprovalCouncil::LEN,
+        seeds = [b"approval_council"],
+        bump
+    )]
+    pub approval_council: Account<'info, ApprovalCouncil>,
+
+    // =========================================================================
+    // Governance & Token State
+    // =========================================================================
+
+    /// Approved `UpdateGovernance` proposal authorizing the change.
+    #[account(mut)]
+    pub proposal: Account<'info, ProposalAccount>,
+
+    /// Global governance configuration account.
+    pub governance_state: Account<'info, GovernanceState>,
+
+    /// Global TokenState configuration account.
+    pub token_state: Account<'info, TokenState>,
+
+    // =========================================================================
+    // Program Dependencies
+    // =========================================================================
+
+    /// System Program (for PDA creation).
+    pub system_program: Program<'info, System>,
+}
+
+
+#[derive(Accounts)]
+#[instruction(args: Vec<String>)]
+pub struct ProposeCouncilOperationContext<'info> {
+    // =========================================================================
+    // Caller & Access Control
+    // =========================================================================
+
+    /// The council member proposing the operation (pays rent).
+    #[account(mut)]
+    pub caller: Signer<'info>,
+
+    /// Optional access control (used for the paused-contract bypass).
+    #[account(
+        seeds = [b"user_access", caller.key().as_ref()],
+        bump
+    )]
+    pub user_access: Option<Account<'info, UserAccessAccount>>,
+
+    // =========================================================================
+    // Council & Operation
+    // =========================================================================
+
+    /// The approval council.
+    #[account(
+        seeds = [b"approval_council"],
+        bump = approval_council.bump
+    )]
+    pub approval_council: Account<'info, ApprovalCouncil>,
+
+    /// The new operation, keyed by `args[0]` (operation id).
+    #[account(
+        init,
+        payer = caller,
+        space = CouncilOperation::LEN,
+        seeds = [
+            b"council_operation".as_ref(),
+            &args.first().and_then(|id| id.parse::<u64>().ok()).unwrap_or_default().to_le_bytes(),
+        ],
+        bump
+    )]
+    pub council_operation: Account<'info, CouncilOperation>,
+
+    // =========================================================================
+    // Vault & Token Accounts
+    // =========================================================================
+
+    /// CHECK: Source vault PDA. Must be validated in logic.
+    pub source_vault: AccountInfo<'info>,
+
+    /// Source vault's ATA.
+    #[account(
+        constraint = source_vault_token_account.owner == source_vault.key() @ VaultError::InvalidVaultTokenAccount
+    )]
+    pub source_vault_token_account: Account<'info, TokenAccount>,
+
+    /// Token account credited on execution (a vault ATA for transfers).
+    #[account(
+        constraint = destination_token_account.mint == source_vault_token_account.mint @ VaultError::InvalidVaultTokenAccount
+    )]
+    pub destination_token_account: Account<'info, TokenAccount>,
+
+    /// CHECK: Vault transfer policy PDA. Built-in rules apply until it is created.
+    #[account(
+        seeds = [b"vault_policy"],
+        bump
+    )]
+    pub vault_policy: AccountInfo<'info>,
+
+    // =========================================================================
+    // Governance & Token State
+    // =========================================================================
+
+    /// Optional proposal approving a transfer whose policy rule requires one.
+    #[account(mut)]
+    pub proposal: Option<Account<'info, ProposalAccount>>,
+
+    /// Global governance configuration account (vault transfer policy).
+    pub governance_state: Account<'info, GovernanceState>,
+
+    /// Global TokenState configuration account.
+    pub token_state: Account<'info, TokenState>,
+
+    // =========================================================================
+    // Program Dependencies
+    // =========================================================================
+
+    /// System Program (for PDA creation).
+    pub system_program: Program<'info, System>,
+}
+
+
+#[derive(Accounts)]
+#[instruction(args: Vec<String>)]
+pub struct ApproveCouncilOperationContext<'info> {
+    // =========================================================================
+    // Caller & Access Control
+    // =========================================================================
+
+    /// The council member approving the operation.
+    pub caller: Signer<'info>,
+
+    /// Optional access control (used for the paused-contract bypass).
+    #[account(
+        seeds = [b"user_access", caller.key().as_ref()],
+        bump
+    )]
+    pub user_access: Option<Account<'info, UserAccessAccount>>,
+
+    // =========================================================================
+    // Council & Operation
+    // =========================================================================
+
+    /// The approval council.
+    #[account(
+        seeds = [b"approval_council"],
+        bump = approval_council.bump
+    )]
+    pub approval_council: Account<'info, ApprovalCouncil>,
+
+    /// The operation being approved (`args[0]`).
+    #[account(
+        mut,
+        seeds = [
+            b"council_operation".as_ref(),
+            &args.first().and_then(|id| id.parse::<u64>().ok()).unwrap_or_default().to_le_bytes(),
+        ],
+        bump = council_operation.bump
+    )]
+    pub council_operation: Account<'info, CouncilOperation>,
+
+    // =========================================================================
+    // Token State
+    // =========================================================================
+
+    /// Global TokenState configuration account.
+    pub token_state: Account<'info, TokenState>,
+}
+
+
+#[derive(Accounts)]
+#[instruction(args: Vec<String>)]
+pub struct ExecuteCouncilOperationContext<'info> {
+    // =========================================================================
+    // Caller & Access Control
+    // =========================================================================
+
+    /// The council member executing the operation (receives the rent).
+    #[account(mut)]
+    pub caller: Signer<'info>,
+
+    /// Optional access control (used for the paused-contract bypass).
+    #[account(
+        seeds = [b"user_access", caller.key().as_ref()],
+        bump
+    )]
+    pub user_access: Option<Account<'info, UserAccessAccount>>,
+
+    // =========================================================================
+    // Council & Operation
+    // =========================================================================
+
+    /// The approval council.
+    #[account(
+        seeds = [b"approval_council"],
+        bump = approval_council.bump
+    )]
+    pub approval_council: Account<'info, ApprovalCouncil>,
+
+    /// The operation being executed (`args[0]`), closed once executed.
+    #[account(
+        mut,
+        close = caller,
+        seeds = [
+            b"council_operation".as_ref(),
+            &args.first().and_then(|id| id.parse::<u64>().ok()).unwrap_or_default().to_le_bytes(),
+        ],
+        bump = council_operation.bump
+    )]
+    pub council_operation: Account<'info, CouncilOperation>,
+
+    // =========================================================================
+    // Vault & Token Accounts
+    // =========================================================================
+
+    /// CHECK: Source vault PDA recorded in the operation (signs the transfer).
+    #[account(
+        address = council_operation.source_vault @ VaultError::UnknownVaultType
+    )]
+    pub source_vault: AccountInfo<'info>,
+
+    /// CHECK: Outflow limits PDA of `source_vault`. Enforced in logic once configured.
+    #[account(
+        mut,
+        seeds = [b"vault_limits", source_vault.key().as_ref()],
+        bump
+    )]
+    pub vault_limits: AccountInfo<'info>,
+
+    /// CHECK: Ledger PDA of `source_vault`. Posted to in logic once opened.
+    #[account(
+        mut,
+        seeds = [b"vault_ledger", source_vault.key().as_ref()],
+        bump
+    )]
+    pub source_vault_ledger: AccountInfo<'info>,
+
+    /// CHECK: Ledger PDA of the destination (owner of `destination_token_account`); only posted to for transfers. Posted to in logic once opened.
+    #[account(
+        mut,
+        seeds = [b"vault_ledger", destination_token_account.owner.as_ref()],
+        bump
+    )]
+    pub destination_ledger: AccountInfo<'info>,
+
+    /// Source vault's ATA recorded in the operation.
+    #[account(
+        mut,
+        address = council_operation.source_vault_token_account @ VaultError::InvalidVaultTokenAccount
+    )]
+    pub source_vault_token_account: Account<'info, TokenAccount>,
+
+    /// Destination token account recorded in the operation.
+    #[account(
+        mut,
+        address = council_operation.destination @ VaultError::InvalidVaultTokenAccount
+    )]
+    pub destination_token_account: Account<'info, TokenAccount>,
+
+    /// CHECK: Vault transfer policy PDA, re-evaluated for transfers at execution.
+    #[account(
+        seeds = [b"vault_policy"],
+        bump
+    )]
+    pub vault_policy: AccountInfo<'info>,
+
+    // =========================================================================
+    // Governance, Token State & Program
+    // =========================================================================
+
+    /// Optional proposal approving a transfer whose policy rule requires one.
+    #[account(mut)]
+    pub proposal: Option<Account<'info, ProposalAccount>>,
+
+    /// Global governance configuration account (vault transfer policy).
+    pub governance_state: Account<'info, GovernanceState>,
+
+    /// Global TokenState configuration account.
+    #[account(mut)]
+    pub token_state: Account<'info, TokenState>,
+
+    /// SPL Token Program.
+    pub token_program: Program<'info, Token>,
+}
+
+
+#[derive(Accounts)]
+pub struct SetVaultLimitsContext<'info> {
+    // =========================================================================
+    // Caller & Access Control
+    // =========================================================================
+
+    /// The caller applying the approved limits (pays rent on creation).
+    #[account(mut)]
+    pub caller: Signer<'info>,
+
+    /// Optional access control for permission checks.
+    #[account(
+        seeds = [b"user_access", caller.key().as_ref()],
+        bump
+    )]
+    pub user_access: Option<Account<'info, UserAccessAccount>>,
+
+    // =========================================================================
+    // Vault & Limits
+    // =========================================================================
+
+    /// CHECK: Vault PDA the limits apply to. Must be validated in logic.
+    pub vault: AccountInfo<'info>,
+
+    /// Outflow limits of the vault, created on first use.
+    #[account(
+        init_if_needed,
+        payer = caller,
+        space = VaultLimits::LEN,
+        seeds = [b"vault_limits", vault.key().as_ref()],
+        bump
+    )]
+    pub vault_limits: Account<'info, VaultLimits>,
+
+    // =========================================================================
+    // Governance & Token State
+    // =========================================================================
+
+    /// Approved `UpdateGovernance` proposal authorizing the change.
+    #[account(mut)]
+    pub proposal: Account<'info, ProposalAccount>,
+
+    /// Global governance configuration account.
+    pub governance_state: Account<'info, GovernanceState>,
+
+    /// Global TokenState configuration account.
+    pub token_state: Account<'info, TokenState>,
+
+    // =========================================================================
+    // Program Dependencies
+    // =========================================================================
+
+    /// System Program (for PDA creation).
+    pub system_program: Program<'info, System>,
+}
+
+
+#[derive(Accounts)]
+pub struct OpenVaultLedgerContext<'info> {
+    // =========================================================================
+    // Caller & Access Control
+    // =========================================================================
+
+    /// The caller opening the ledger (pays rent).
+    #[account(mut)]
+    pub caller: Signer<'info>,
+
+    /// Optional access control for permission checks.
+    #[account(
+        seeds = [b"user_access", caller.key().as_ref()],
+        bump
+    )]
+    pub user_access: Option<Account<'info, UserAccessAccount>>,
+
+    // =========================================================================
+    // Vault & Ledger
+    // =========================================================================
+
+    /// CHECK: Vault PDA the ledger belongs to. Must be validated in logic.
+    pub vault: AccountInfo<'info>,
+
+    /// Vault's ATA, whose balance becomes the opening balance.
+    #[account(
+        constraint = vault_token_account.owner == vault.key() @ VaultError::InvalidVaultTokenAccount,
+        constraint = vault_token_account.mint == token_state.core.mint @ VaultError::InvalidVaultTokenAccount
+    )]
+    pub vault_token_account: Account<'info, TokenAccount>,
+
+    /// Ledger of the vault, created here.
+    #[account(
+        init,
+        payer = caller,
+        space = VaultLedger::LEN,
+        seeds = [b"vault_ledger", vault.key().as_ref()],
+        bump
+    )]
+    pub vault_ledger: Account<'info, VaultLedger>,
+
+    // =========================================================================
+    // Token State & Program
+    // =========================================================================
+
+    /// Global TokenState configuration account.
+    pub token_state: Account<'info, TokenState>,
+
+    /// System Program (for PDA creation).
+    pub system_program: Program<'info, System>,
+}
+
+
+#[derive(Accounts)]
+pub struct ReadVaultLedgerContext<'info> {
+    /// Any caller reading the ledger.
+    pub caller: Signer<'info>,
+
+    /// Ledger being read.
+    #[account(
+        seeds = [b"vault_ledger", vault_ledger.vault.as_ref()],
+        bump = vault_ledger.bump
+    )]
+    pub vault_ledger: Account<'info, VaultLedger>,
+
+    /// Vault's ATA, reconciled against the ledger.
+    #[account(
+        constraint = vault_token_account.owner == vault_ledger.vault @ VaultError::InvalidVaultTokenAccount,
+        constraint = vault_token_account.mint == token_state.core.mint @ VaultError::InvalidVaultTokenAccount
+    )]
+    pub vault_token_account: Account<'info, TokenAccount>,
+
+    /// Global TokenState configuration account.
+    pub token_state: Account<'info, TokenState>,
+}
+
+
+#[derive(Accounts)]
+pub struct SetVaultTransferRuleContext<'info> {
+    // =========================================================================
+    // Caller & Access Control
+    // =========================================================================
+
+    /// The caller applying the approved rule (pays rent on creation).
+    #[account(mut)]
+    pub caller: Signer<'info>,
+
+    /// Optional access control for permission checks.
+    #[account(
+        seeds = [b"user_access", caller.key().as_ref()],
+        bump
+    )]
+    pub user_access: Option<Account<'info, UserAccessAccount>>,
+
+    // =========================================================================
+    // Policy
+    // =========================================================================
+
+    /// Vault transfer policy, created (with the built-in rules) on first use.
+    #[account(
+        init_if_needed,
+        payer = caller,
+        space = VaultPolicy::LEN,
+        seeds = [b"vault_policy"],
+        bump
+    )]
+    pub vault_policy: Account<'info, VaultPolicy>,
+
+    // =========================================================================
+    // Governance & Token State
+    // =========================================================================
+
+    /// Approved `UpdateGovernance` proposal authorizing the change.
+    #[account(mut)]
+    pub proposal: Account<'info, ProposalAccount>,
+
+    /// Global governance configuration account.
+    pub governance_state: Account<'info, GovernanceState>,
+
+    /// Global TokenState configuration account.
+    pub token_state: Account<'info, TokenState>,
+
+    // =========================================================================
+    // Program Dependencies
+    // =========================================================================
+
+    /// System Program (for PDA creation).
+    pub system_program: Program<'info, System>,
+}
@@ -0,0 +1,408 @@
+// ===========================================================================
+// Approval Council – Soccial Token (SCTK)
+// ---------------------------------------------------------------------------
+//
+// M-of-N sign-off for privileged vault outflows. Once the council is
+// configured (through an approved `UpdateGovernance` proposal), the
+// treasury and insurance vaults stop accepting single-key withdrawals and
+// transfers: a member proposes a `CouncilOperation`, other members approve
+// it, and it executes once the threshold is reached.
+//
+// ---------------------------------------------------------------------------
+// ## Rules:
+// - Only council members can propose, approve and execute operations
+// - The proposer's approval is counted automatically
+// - Any membership change invalidates operations proposed before it
+// - Large withdrawals still wait for the vault's withdrawal delay
+// - Transfers follow the same vault policy as `transfer_between_vaults`
+//
+// ---------------------------------------------------------------------------
+// ## Components:
+// - `update_approval_council()`: Sets members and threshold
+// - `propose_council_operation()`: Creates an operation PDA
+// - `approve_council_operation()`: Records a member approval
+// - `execute_council_operation()`: Moves the funds once approved
+// - `require_no_council()`: Guard used by the single-key vault paths
+//
+// ---------------------------------------------------------------------------
+// Author: Paulo Rodrigues
+// Project: Soccial Token
+// Website: https://www.soccial.com/thetoken
+// License: MIT
+// ===========================================================================
+
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, Transfer};
+
+use crate::vaults::{
+    context::*,
+    ledger::record_vault_flow,
+    limits::record_vault_outflow,
+    state::*,
+    vaults::{detect_vault_type, is_transfer_allowed, resolve_vault_seeds, vault_type_from_seed, VaultAction, VaultType},
+    VaultError,
+};
+
+#[event]
+pub struct ApprovalCouncilUpdated {
+    pub members: Vec<Pubkey>,
+    pub threshold: u8,
+    pub nonce: u64,
+}
+
+#[event]
+pub struct CouncilOperationProposed {
+    pub id: u64,
+    pub kind: CouncilOperationKind,
+    pub vault: String,
+    pub destination: Pubkey,
+    pub amount: u64,
+    pub reason: String,
+    pub proposer: Pubkey,
+    pub executable_at: i64,
+}
+
+#[event]
+pub struct CouncilOperationApproved {
+    pub id: u64,
+    pub member: Pubkey,
+    pub approvals: u32,
+    pub threshold: u8,
+}
+
+#[event]
+pub struct CouncilOperationExecuted {
+    pub id: u64,
+    pub kind: CouncilOperationKind,
+    pub vault: String,
+    pub destination: Pubkey,
+    pub amount: u64,
+    pub executed_by: Pubkey,
+}
+
+/// ===========================================================================
+/// Rejects single-key outflows from council-guarded vaults once the council
+/// has been configured.
+///
+/// ## Errors:
+/// - `CouncilApprovalRequired` if the council exists and `vault_type` is
+///   guarded by it
+/// ===========================================================================
+pub(crate) fn require_no_council(council: &AccountInfo, vault_type: VaultType) -> Result<()> {
+    require!(
+        !vault_type.requires_council() || council.data_is_empty(),
+        VaultError::CouncilApprovalRequired
+    );
+
+    Ok(())
+}
+
+/// ===========================================================================
+/// Replaces the council members and threshold.
+///
+/// ## Behavior:
+/// - Requires 1..=`MAX_COUNCIL_MEMBERS` unique members
+/// - Requires 1 <= threshold <= members
+/// - Bumps the council nonce, invalidating pending operations
+///
+/// ## Errors:
+/// - `InvalidCouncilConfig` on an invalid member list or threshold
+/// ===========================================================================
+pub(crate) fn update_approval_council(
+    ctx: Context<UpdateApprovalCouncilContext>,
+    threshold: u8,
+    members: Vec<Pubkey>,
+) -> Result<()> {
+    require!(
+        !members.is_empty() && members.len() <= MAX_COUNCIL_MEMBERS,
+        VaultError::InvalidCouncilConfig
+    );
+    require!(
+        threshold > 0 && threshold as usize <= members.len(),
+        VaultError::InvalidCouncilConfig
+    );
+    for (i, member) in members.iter().enumerate() {
+        require!(
+            *member != Pubkey::default() && !members[..i].contains(member),
+            VaultError::InvalidCouncilConfig
+        );
+    }
+
+    let council = &mut ctx.accounts.approval_council;
+    council.members = members;
+    council.threshold = threshold;
+    council.nonce = council.nonce.wrapping_add(1);
+    council.bump = ctx.bumps.approval_council;
+
+    msg!(
+        "🛡️ Approval council updated → {}-of-{} (nonce {})",
+        threshold,
+        council.members.len(),
+        council.nonce,
+    );
+
+    emit!(ApprovalCouncilUpdated {
+        members: council.members.clone(),
+        threshold,
+        nonce: council.nonce,
+    });
+
+    Ok(())
+}
+
+/// ===========================================================================
+/// Proposes a treasury or insurance outflow to the council.
+///
+/// ## Behavior:
+/// - Validates the source vault and, for transfers, the vault policy
+///   (with the optional `proposal` when the rule requires one)
+/// - Sets `executable_at` to the vault delay for large withdrawals
+/// - Records the proposer's approval
+///
+/// ## Errors:
+/// - `NotCouncilMember` if the caller is not a member
+/// - `InvalidVaultAmount` / `WithdrawalReasonTooLong` on invalid input
+/// - `UnknownVaultType` if a vault cannot be resolved
+/// - `UnauthorizedVaultAccess` if the source vault is not council-guarded
+/// - `InvalidItselfVaultTransfer` / `UnauthorizedVaultTransfer` for
+///   transfers rejected by the vault policy
+/// - `MissingProposalApproval` / `GovernanceError::*` if the policy requires
+///   a proposal and none, or an invalid one, is provided
+/// ===========================================================================
+pub(crate) fn propose_council_operation(
+    ctx: Context<ProposeCouncilOperationContext>,
+    id: u64,
+    kind: CouncilOperationKind,
+    amount: u64,
+    reason: Option<String>,
+) -> Result<()> {
+    let caller = ctx.accounts.caller.key();
+    let council = &ctx.accounts.approval_council;
+    let index = council.member_index(&caller).ok_or(VaultError::NotCouncilMember)?;
+
+    require!(amount > 0, VaultError::InvalidVaultAmount);
+    let reason = reason.unwrap_or_default();
+    require!(reason.len() <= MAX_WITHDRAWAL_REASON_LEN, VaultError::WithdrawalReasonTooLong);
+
+    let action = match kind {
+        CouncilOperationKind::Withdraw => VaultAction::Withdraw,
+        CouncilOperationKind::Transfer => VaultAction::Operation,
+    };
+    let (seed, _) = resolve_vault_seeds(&ctx.accounts.source_vault, action)?;
+    let vault_type = vault_type_from_seed(seed).ok_or(VaultError::UnknownVaultType)?;
+    require!(vault_type.requires_council(), VaultError::UnauthorizedVaultAccess);
+
+    let now = Clock::get()?.unix_timestamp;
+    let mut executable_at = now;
+
+    match kind {
+        CouncilOperationKind::Withdraw => {
+            if let Some(delay) = vault_type.withdrawal_delay() {
+                if amount >= LARGE_WITHDRAWAL_THRESHOLD {
+                    executable_at = now.checked_add(delay).ok_or(VaultError::VaultInvariantViolation)?;
+                }
+            }
+        }
+        CouncilOperationKind::Transfer => {
+            require!(
+                ctx.accounts.source_vault_token_account.key() != ctx.accounts.destination_token_account.key(),
+                VaultError::InvalidItselfVaultTransfer
+            );
+            let destination_type = detect_vault_type(&ctx.accounts.destination_token_account)
+                .ok_or(VaultError::UnknownVaultType)?;
+            let is_allowed = is_transfer_allowed(
+                vault_type,
+                destination_type,
+                &ctx.accounts.vault_policy,
+                ctx.accounts.proposal.as_mut(),
+                &ctx.accounts.governance_state,
+            )?;
+            require!(is_allowed, VaultError::UnauthorizedVaultTransfer);
+        }
+    }
+
+    let destination = ctx.accounts.destination_token_account.key();
+
+    let operation = &mut ctx.accounts.council_operation;
+    operation.id = id;
+    operation.kind = kind;
+    operation.source_vault = ctx.accounts.source_vault.key();
+    operation.source_vault_token_account = ctx.accounts.source_vault_token_account.key();
+    operation.destination = destination;
+    operation.amount = amount;
+    operation.reason = reason.clone();
+    operation.proposer = caller;
+    operation.council_nonce = council.nonce;
+    operation.approvals = 1 << index;
+    operation.proposed_at = now;
+    operation.executable_at = executable_at;
+    operation.bump = ctx.bumps.council_operation;
+
+    msg!(
+        "📝 Council operation #{} proposed → {:?} {} tokens from vault '{}' (1/{} approvals) | Reason: {}",
+        id,
+        kind,
+        amount,
+        vault_type.as_str(),
+        council.threshold,
+        reason,
+    );
+
+    emit!(CouncilOperationProposed {
+        id,
+        kind,
+        vault: vault_type.as_str().to_string(),
+        destination,
+        amount,
+        reason,
+        proposer: caller,
+        executable_at,
+    });
+
+    Ok(())
+}
+
+/// ===========================================================================
+/// Records a council member's approval of an operation.
+///
+/// ## Errors:
+/// - `StaleCouncilOperation` if the council changed since the proposal
+/// - `NotCouncilMember` if the caller is not a member
+/// - `OperationAlreadyApproved` if the member already approved
+/// ===========================================================================
+pub(crate) fn approve_council_operation(ctx: Context<ApproveCouncilOperationContext>) -> Result<()> {
+    let caller = ctx.accounts.caller.key();
+    let council = &ctx.accounts.approval_council;
+    let operation = &mut ctx.accounts.council_operation;
+
+    require!(operation.council_nonce == council.nonce, VaultError::StaleCouncilOperation);
+    let index = council.member_index(&caller).ok_or(VaultError::NotCouncilMember)?;
+
+    let bit = 1u16 << index;
+    require!(operation.approvals & bit == 0, VaultError::OperationAlreadyApproved);
+    operation.approvals |= bit;
+
+    let approvals = operation.approval_count();
+
+    msg!(
+        "✍️ Council operation #{} approved by {} ({}/{})",
+        operation.id,
+        caller,
+        approvals,
+        council.threshold,
+    );
+
+    emit!(CouncilOperationApproved {
+        id: operation.id,
+        member: caller,
+        approvals,
+        threshold: council.threshold,
+    });
+
+    Ok(())
+}
+
+/// ===========================================================================
+/// Executes an operation that reached the council threshold.
+///
+/// ## Behavior:
+/// - Requires the threshold and `executable_at` to be reached
+/// - Re-evaluates the vault policy for transfers, as it may have changed
+///   since the proposal
+/// - Transfers the amount from the source vault to the destination
+/// - Posts `Manual` entries to the vault ledgers involved
+/// - Closes the `CouncilOperation` PDA
+///
+/// ## Errors:
+/// - `StaleCouncilOperation` if the council changed since the proposal
+/// - `NotCouncilMember` if the caller is not a member
+/// - `OperationThresholdNotMet` if not enough members approved
+/// - `WithdrawalTimelocked` if the vault delay has not elapsed
+/// - `InsufficientVaultBalance` if the vault cannot cover the amount
+/// - `UnauthorizedVaultTransfer` / `MissingProposalApproval` if the vault
+///   policy no longer allows the transfer
+/// - `EpochOutflowExceeded` if the vault's epoch outflow cap is reached
+/// ===========================================================================
+pub(crate) fn execute_council_operation(ctx: Context<ExecuteCouncilOperationContext>) -> Result<()> {
+    let caller = ctx.accounts.caller.key();
+    let council = &ctx.accounts.approval_council;
+    let operation = &ctx.accounts.council_operation;
+
+    require!(operation.council_nonce == council.nonce, VaultError::StaleCouncilOperation);
+    require!(council.member_index(&caller).is_some(), VaultError::NotCouncilMember);
+    require!(
+        operation.approval_count() >= council.threshold as u32,
+        VaultError::OperationThresholdNotMet
+    );
+
+    let now = Clock::get()?.unix_timestamp;
+    require!(now >= operation.executable_at, VaultError::WithdrawalTimelocked);
+    require!(
+        ctx.accounts.source_vault_token_account.amount >= operation.amount,
+        VaultError::InsufficientVaultBalance
+    );
+
+    let (seed, bump) = resolve_vault_seeds(&ctx.accounts.source_vault, VaultAction::Operation)?;
+    let vault_name = core::str::from_utf8(seed).unwrap_or("unknown");
+
+    // The policy may have changed while the operation collected approvals
+    if operation.kind == CouncilOperationKind::Transfer {
+        let source_type = vault_type_from_seed(seed).ok_or(VaultError::UnknownVaultType)?;
+        let destination_type = detect_vault_type(&ctx.accounts.destination_token_account)
+            .ok_or(VaultError::UnknownVaultType)?;
+        let is_allowed = is_transfer_allowed(
+            source_type,
+            destination_type,
+            &ctx.accounts.vault_policy,
+            ctx.accounts.proposal.as_mut(),
+            &ctx.accounts.governance_state,
+        )?;
+        require!(is_allowed, VaultError::UnauthorizedVaultTransfer);
+    }
+
+    // Enforce the vault's epoch outflow limits (may trip the circuit breaker)
+    record_vault_outflow(
+        &ctx.accounts.vault_limits,
+        &mut ctx.accounts.token_state,
+        &ctx.accounts.caller.key(),
+        operation.amount,
+    )?;
+
+    let signer_seeds: &[&[u8]] = &[seed, &[bump]];
+    let signer_seeds_nested: &[&[&[u8]]] = &[signer_seeds];
+
+    let cpi_ctx = CpiContext::new_with_signer(
+        ctx.accounts.token_program.to_account_info(),
+        Transfer {
+            from: ctx.accounts.source_vault_token_account.to_account_info(),
+            to: ctx.accounts.destination_token_account.to_account_info(),
+            authority: ctx.accounts.source_vault.to_account_info(),
+        },
+        signer_seeds_nested,
+    );
+
+    token::transfer(cpi_ctx, operation.amount)?;
+    record_vault_flow(&ctx.accounts.source_vault_ledger, LedgerFlow::Outflow, LedgerCategory::Manual, operation.amount)?;
+    if operation.kind == CouncilOperationKind::Transfer {
+        record_vault_flow(&ctx.accounts.destination_ledger, LedgerFlow::Inflow, LedgerCategory::Manual, operation.amount)?;
+    }
+
+    msg!(
+        "🏛️ Council operation #{} executed → {:?} {} tokens from vault '{}'",
+        operation.id,
+        operation.kind,
+        operation.amount,
+        vault_name,
+    );
+
+    emit!(CouncilOperationExecuted {
+        id: operation.id,
+        kind: operation.kind,
+        vault: vault_name.to_string(),
+        destination: operation.destination,
+        amount: operation.amount,
+        executed_by: caller,
+    });
+
+    Ok(())
+}
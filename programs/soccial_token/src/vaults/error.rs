@@ -53,4 +53,69 @@ pub enum VaultError {
     #[msg("This action requires a valid and approved governance proposal.")]
     MissingProposalApproval,
 
+    /// Direct outflows from this vault reached the threshold for the current
+    /// window; the rest must go through `queue_vault_withdrawal`.
+    #[msg("Withdrawals of this size from this vault must be queued.")]
+    WithdrawalRequiresTimelock,
+
+    /// The vault has no withdrawal delay, so nothing can be queued for it.
+    #[msg("This vault does not use the withdrawal queue.")]
+    VaultNotTimelocked,
+
+    /// The queued withdrawal is still inside its delay window.
+    #[msg("The queued withdrawal is not executable yet.")]
+    WithdrawalTimelocked,
+
+    /// The token account is not owned by the given vault, or does not match the queued withdrawal.
+    #[msg("Token account does not match the vault withdrawal.")]
+    InvalidVaultTokenAccount,
+
+    /// The withdrawal reason exceeds `MAX_WITHDRAWAL_REASON_LEN`.
+    #[msg("Withdrawal reason is too long.")]
+    WithdrawalReasonTooLong,
+
+    /// Outflows from this vault must be proposed and approved through the approval council.
+    #[msg("This vault operation requires approval council sign-off.")]
+    CouncilApprovalRequired,
+
+    /// The council configuration is invalid (members, duplicates or threshold).
+    #[msg("Invalid approval council configuration.")]
+    InvalidCouncilConfig,
+
+    /// The approval council has not been configured.
+    #[msg("The approval council is not configured.")]
+    CouncilNotConfigured,
+
+    /// The caller is not a member of the approval council.
+    #[msg("Caller is not an approval council member.")]
+    NotCouncilMember,
+
+    /// The member already approved this operation.
+    #[msg("Operation already approved by this member.")]
+    OperationAlreadyApproved,
+
+    /// The operation has not reached the council threshold yet.
+    #[msg("Operation has not reached the approval threshold.")]
+    OperationThresholdNotMet,
+
+    /// The council changed since the operation was proposed.
+    #[msg("Operation was proposed under a previous council.")]
+    StaleCouncilOperation,
+
+    /// The operation kind is not recognized.
+    #[msg("Unknown council operation kind.")]
+    InvalidOperationKind,
+
+    /// The vault limits configuration is invalid (epoch, cap or threshold).
+    #[msg("Invalid vault limits configuration.")]
+    InvalidVaultLimits,
+
+    /// The outflow would exceed the vault's cap for the current epoch.
+    #[msg("Vault outflow limit for this epoch exceeded.")]
+    EpochOutflowExceeded,
+
+    /// The transfer rule is not `Allowed`, `Forbidden` or a known proposal type.
+    #[msg("Invalid vault transfer rule.")]
+    InvalidTransferRule,
+
 }
\ No newline at end of file
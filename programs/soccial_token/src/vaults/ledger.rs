@@ -0,0 +1,183 @@
+// ===========================================================================
+// Vault Ledger – Soccial Token (SCTK)
+// ---------------------------------------------------------------------------
+//
+// On-chain accounting of vault flows. Each vault may carry a `VaultLedger`
+// PDA (seeds: `["vault_ledger", vault]`) with its cumulative inflows and
+// outflows by `LedgerCategory`. Every instruction moving vault funds posts
+// to the ledgers of the vaults involved, so flows no longer have to be
+// rebuilt from `VaultDeposit` / `VaultWithdraw` / `VaultToVaultTransfer`
+// logs.
+//
+// ---------------------------------------------------------------------------
+// ## Rules:
+// - Ledgers are opened per vault and start from the vault balance at that
+//   time (`opening_balance`); flows before opening are not recorded
+// - Posting to a ledger that was not opened is a no-op
+// - `opening_balance + inflows - outflows` must match the vault balance,
+//   which `get_vault_ledger` reports as `reconciled`
+//
+// ---------------------------------------------------------------------------
+// ## Components:
+// - `open_vault_ledger()`: Creates the ledger of a vault
+// - `get_vault_ledger()`: Returns a `VaultLedgerSnapshot` as return data
+// - `record_vault_flow()`: Posts an inflow or outflow to a ledger
+//
+// ---------------------------------------------------------------------------
+// Author: Paulo Rodrigues
+// Project: Soccial Token
+// Website: https://www.soccial.com/thetoken
+// License: MIT
+// ===========================================================================
+
+use anchor_lang::prelude::*;
+
+use crate::vaults::{
+    context::*,
+    state::{LedgerCategory, LedgerFlow, VaultLedger, LEDGER_CATEGORY_COUNT},
+    vaults::{resolve_vault_seeds, vault_type_from_seed, VaultAction, VaultType},
+    VaultError,
+};
+
+#[event]
+pub struct VaultLedgerOpened {
+    pub vault: Pubkey,
+    pub vault_type: VaultType,
+    pub opening_balance: u64,
+    pub opened_by: Pubkey,
+}
+
+/// Full ledger of a vault, returned by `get_vault_ledger`.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug, PartialEq, Eq)]
+pub struct VaultLedgerSnapshot {
+    pub vault: Pubkey,
+    pub vault_type: VaultType,
+    pub opening_balance: u64,
+    pub inflows: [u128; LEDGER_CATEGORY_COUNT],
+    pub outflows: [u128; LEDGER_CATEGORY_COUNT],
+    pub total_inflow: u128,
+    pub total_outflow: u128,
+    pub expected_balance: i128,
+    pub actual_balance: u64,
+    pub reconciled: bool,
+    pub opened_at: i64,
+    pub updated_at: i64,
+}
+
+/// ===========================================================================
+/// Opens the ledger of a vault.
+///
+/// ## Behavior:
+/// - Validates the vault PDA via `resolve_vault_seeds`
+/// - Records the current vault balance as `opening_balance`
+///
+/// ## Errors:
+/// - `UnknownVaultType` if the vault is not a known vault
+/// - Account creation fails if the ledger is already open
+/// ===========================================================================
+pub(crate) fn open_vault_ledger(ctx: Context<OpenVaultLedgerContext>) -> Result<()> {
+    let (seed, _) = resolve_vault_seeds(&ctx.accounts.vault, VaultAction::Operation)?;
+    let vault_type = vault_type_from_seed(seed).ok_or(VaultError::UnknownVaultType)?;
+
+    let now = Clock::get()?.unix_timestamp;
+    let vault = ctx.accounts.vault.key();
+    let opening_balance = ctx.accounts.vault_token_account.amount;
+
+    let ledger = &mut ctx.accounts.vault_ledger;
+    ledger.vault = vault;
+    ledger.vault_type = vault_type;
+    ledger.opening_balance = opening_balance;
+    ledger.inflows = [0; LEDGER_CATEGORY_COUNT];
+    ledger.outflows = [0; LEDGER_CATEGORY_COUNT];
+    ledger.opened_at = now;
+    ledger.updated_at = now;
+    ledger.bump = ctx.bumps.vault_ledger;
+
+    msg!(
+        "📒 Ledger opened for vault '{}' → opening balance {}",
+        vault_type.as_str(),
+        opening_balance,
+    );
+
+    emit!(VaultLedgerOpened {
+        vault,
+        vault_type,
+        opening_balance,
+        opened_by: ctx.accounts.caller.key(),
+    });
+
+    Ok(())
+}
+
+/// ===========================================================================
+/// Returns the ledger of a vault, reconciled against its balance.
+///
+/// ## Behavior:
+/// - The snapshot is returned to the caller as instruction return data
+/// - `reconciled` is true when `opening_balance + inflows - outflows`
+///   equals the current vault balance
+/// ===========================================================================
+pub(crate) fn get_vault_ledger(ctx: Context<ReadVaultLedgerContext>) -> Result<VaultLedgerSnapshot> {
+    let ledger = &ctx.accounts.vault_ledger;
+
+    let total_inflow = ledger.total_inflow();
+    let total_outflow = ledger.total_outflow();
+    let expected_balance = ledger.opening_balance as i128 + total_inflow as i128 - total_outflow as i128;
+    let actual_balance = ctx.accounts.vault_token_account.amount;
+    let reconciled = expected_balance == actual_balance as i128;
+
+    msg!(
+        "📒 Vault '{}' ledger → in {} | out {} | balance {} ({})",
+        ledger.vault_type.as_str(),
+        total_inflow,
+        total_outflow,
+        actual_balance,
+        if reconciled { "reconciled" } else { "mismatch" },
+    );
+
+    Ok(VaultLedgerSnapshot {
+        vault: ledger.vault,
+        vault_type: ledger.vault_type,
+        opening_balance: ledger.opening_balance,
+        inflows: ledger.inflows,
+        outflows: ledger.outflows,
+        total_inflow,
+        total_outflow,
+        expected_balance,
+        actual_balance,
+        reconciled,
+        opened_at: ledger.opened_at,
+        updated_at: ledger.updated_at,
+    })
+}
+
+/// ===========================================================================
+/// Posts `amount` to the ledger held by `vault_ledger`.
+///
+/// ## Behavior:
+/// - No-op while the vault has no `VaultLedger` opened, or for zero amounts
+///
+/// ## Errors:
+/// - `ErrorCode::Unauthorized` if the ledger account is not program-owned
+/// - `VaultInvariantViolation` if a total overflows
+/// ===========================================================================
+pub(crate) fn record_vault_flow(
+    vault_ledger: &AccountInfo,
+    flow: LedgerFlow,
+    category: LedgerCategory,
+    amount: u64,
+) -> Result<()> {
+    if amount == 0 || vault_ledger.data_is_empty() {
+        return Ok(());
+    }
+
+    require_keys_eq!(*vault_ledger.owner, crate::ID, crate::ErrorCode::Unauthorized);
+
+    let mut data = vault_ledger.try_borrow_mut_data()?;
+    let mut ledger = VaultLedger::try_deserialize(&mut &data[..])?;
+
+    ledger.post(flow, category, amount, Clock::get()?.unix_timestamp)?;
+    ledger.try_serialize(&mut &mut data[..])?;
+
+    Ok(())
+}
@@ -0,0 +1,168 @@
+// ===========================================================================
+// Vault Limits – Soccial Token (SCTK)
+// ---------------------------------------------------------------------------
+//
+// Rate limits on how fast a vault can be drained. Each vault may carry a
+// `VaultLimits` PDA (seeds: `["vault_limits", vault]`) capping its outflow
+// per epoch. Outflows beyond the cap are rejected; the outflow that crosses
+// the lower `pause_threshold` still goes through but pauses the contract
+// (`CoreSettings::pause`), so the failure is persisted instead of reverted.
+//
+// ---------------------------------------------------------------------------
+// ## Enforced on:
+// - `vault_withdraw`, `transfer_between_vaults`, queued withdrawals and
+//   council operations (source vault)
+// - `airdrop` and `redeem_voucher` (airdrop vault)
+// - `buy_tokens`, `buy_tokens_with_quote`, `purchase_tokens` (liquidity vault)
+// - `deposit_tokens` (off-chain reserve vault)
+// - `initialize_amm_pool`, `add_amm_liquidity`, `buy_and_stake_tokens`,
+//   `stake_tokens`, `add_tokens_to_stake` (liquidity vault)
+// - `claim_referral_rewards` (revenue vault)
+// - `claim_staking_rewards`, `withdraw_staked_tokens` (staking vault)
+// - `claim_vested_tokens` (vesting vault)
+//
+// ---------------------------------------------------------------------------
+// ## Components:
+// - `set_vault_limits()`: Configures the limits of a vault
+// - `record_vault_outflow()`: Checks and records an outflow
+//
+// ---------------------------------------------------------------------------
+// Author: Paulo Rodrigues
+// Project: Soccial Token
+// Website: https://www.soccial.com/thetoken
+// License: MIT
+// ===========================================================================
+
+use anchor_lang::prelude::*;
+
+use crate::{
+    token::state::TokenState,
+    vaults::{
+        context::*,
+        state::{VaultLimits, MAX_LIMITS_EPOCH_SECS, MIN_LIMITS_EPOCH_SECS},
+        vaults::{resolve_vault_seeds, VaultAction},
+        VaultError,
+    },
+};
+
+#[event]
+pub struct VaultLimitsUpdated {
+    pub vault: Pubkey,
+    pub epoch_duration: i64,
+    pub max_outflow: u64,
+    pub pause_threshold: u64,
+}
+
+#[event]
+pub struct VaultCircuitBreakerTripped {
+    pub vault: Pubkey,
+    pub epoch_outflow: u64,
+    pub pause_threshold: u64,
+    pub tripped_by: Pubkey,
+}
+
+/// ===========================================================================
+/// Configures the outflow limits of a vault.
+///
+/// ## Behavior:
+/// - Validates the vault PDA via `resolve_vault_seeds`
+/// - Stores the epoch duration, cap and circuit-breaker threshold
+/// - Keeps the running epoch counters of an existing configuration
+///
+/// ## Errors:
+/// - `UnknownVaultType` if the vault is not a known vault
+/// - `InvalidVaultLimits` on an out-of-range epoch, a zero cap, or a
+///   threshold above the cap
+/// ===========================================================================
+pub(crate) fn set_vault_limits(
+    ctx: Context<SetVaultLimitsContext>,
+    epoch_duration: i64,
+    max_outflow: u64,
+    pause_threshold: u64,
+) -> Result<()> {
+    resolve_vault_seeds(&ctx.accounts.vault, VaultAction::Operation)?;
+
+    require!(
+        (MIN_LIMITS_EPOCH_SECS..=MAX_LIMITS_EPOCH_SECS).contains(&epoch_duration),
+        VaultError::InvalidVaultLimits
+    );
+    require!(max_outflow > 0, VaultError::InvalidVaultLimits);
+    require!(pause_threshold <= max_outflow, VaultError::InvalidVaultLimits);
+
+    let vault = ctx.accounts.vault.key();
+
+    let limits = &mut ctx.accounts.vault_limits;
+    limits.vault = vault;
+    limits.epoch_duration = epoch_duration;
+    limits.max_outflow = max_outflow;
+    limits.pause_threshold = pause_threshold;
+    limits.bump = ctx.bumps.vault_limits;
+
+    msg!(
+        "🚦 Vault limits set for {} → {} per {}s epoch, breaker at {}",
+        vault,
+        max_outflow,
+        epoch_duration,
+        pause_threshold,
+    );
+
+    emit!(VaultLimitsUpdated {
+        vault,
+        epoch_duration,
+        max_outflow,
+        pause_threshold,
+    });
+
+    Ok(())
+}
+
+/// ===========================================================================
+/// Checks and records `amount` leaving the vault guarded by `vault_limits`.
+///
+/// ## Behavior:
+/// - No-op while the vault has no `VaultLimits` configured
+/// - Rejects outflows above the epoch cap
+/// - Pauses the contract when the circuit-breaker threshold is crossed
+///
+/// ## Errors:
+/// - `EpochOutflowExceeded` if the epoch cap would be exceeded
+/// - `ErrorCode::Unauthorized` if the limits account is not program-owned
+/// ===========================================================================
+pub(crate) fn record_vault_outflow(
+    vault_limits: &AccountInfo,
+    token_state: &mut TokenState,
+    caller: &Pubkey,
+    amount: u64,
+) -> Result<()> {
+    if vault_limits.data_is_empty() {
+        return Ok(());
+    }
+
+    require_keys_eq!(*vault_limits.owner, crate::ID, crate::ErrorCode::Unauthorized);
+
+    let mut data = vault_limits.try_borrow_mut_data()?;
+    let mut limits = VaultLimits::try_deserialize(&mut &data[..])?;
+
+    let tripped = limits.record(amount, Clock::get()?.unix_timestamp)?;
+    limits.try_serialize(&mut &mut data[..])?;
+
+    if tripped && !token_state.core.paused {
+        token_state.core.pause(*caller);
+
+        msg!(
+            "🧯 Circuit breaker tripped on vault {} → {} out this epoch (threshold {})",
+            limits.vault,
+            limits.epoch_outflow,
+            limits.pause_threshold,
+        );
+
+        emit!(VaultCircuitBreakerTripped {
+            vault: limits.vault,
+            epoch_outflow: limits.epoch_outflow,
+            pause_threshold: limits.pause_threshold,
+            tripped_by: *caller,
+        });
+    }
+
+    Ok(())
+}
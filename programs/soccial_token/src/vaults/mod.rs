@@ -1,6 +1,18 @@
 pub mod error;
+pub mod ledger;
+pub mod limits;
+pub mod policy;
 pub mod context;
+pub mod council;
+pub mod state;
+pub mod timelock;
 pub mod vaults;
 
+pub use council::*;
 pub use error::*;
+pub use ledger::*;
+pub use limits::*;
+pub use policy::*;
+pub use state::*;
+pub use timelock::*;
 pub use vaults::*;
\ No newline at end of file
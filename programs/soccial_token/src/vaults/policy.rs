@@ -0,0 +1,219 @@
+// ===========================================================================
+// Vault Transfer Policy – Soccial Token (SCTK)
+// ---------------------------------------------------------------------------
+//
+// Decides which vaults may transfer to each other. The rules live in the
+// `VaultPolicy` PDA (seeds: `["vault_policy"]`) as a matrix of
+// `TransferRule`s indexed by (source, destination) vault type, so they can
+// be changed through governance instead of a program upgrade.
+//
+// ---------------------------------------------------------------------------
+// ## Rules:
+// - Until the policy is created, `default_transfer_rule` applies
+// - The policy is created on its first update, seeded with the defaults
+// - Updates require an approved `UpdateGovernance` proposal
+//
+// ---------------------------------------------------------------------------
+// ## Components:
+// - `default_transfer_rule()`: Built-in transfer matrix
+// - `resolve_transfer_rule()`: Rule in force for a vault pair
+// - `set_vault_transfer_rule()`: Updates the rule of a vault pair
+//
+// ---------------------------------------------------------------------------
+// Author: Paulo Rodrigues
+// Project: Soccial Token
+// Website: https://www.soccial.com/thetoken
+// License: MIT
+// ===========================================================================
+
+use anchor_lang::prelude::*;
+
+use crate::{
+    governance::ProposalTypeBit,
+    vaults::{
+        context::*,
+        state::{TransferRule, VaultPolicy, VAULT_TYPE_COUNT},
+        vaults::VaultType,
+        VaultError,
+    },
+};
+
+#[event]
+pub struct VaultTransferRuleUpdated {
+    pub source: VaultType,
+    pub destination: VaultType,
+    pub rule: TransferRule,
+    pub updated_by: Pubkey,
+}
+
+/// All vault types, in `VaultType` discriminant order.
+const ALL_VAULT_TYPES: [VaultType; VAULT_TYPE_COUNT] = [
+    VaultType::Airdrop,
+    VaultType::Insurance,
+    VaultType::Liquidity,
+    VaultType::OffchainReserve,
+    VaultType::ReservedSupply,
+    VaultType::Revenue,
+    VaultType::Rewards,
+    VaultType::Staking,
+    VaultType::Treasury,
+    VaultType::Vesting,
+];
+
+/// Returns the built-in rule for `source → dest`, applied while no
+/// `VaultPolicy` exists and used to seed it.
+pub(crate) fn default_transfer_rule(source: VaultType, dest: VaultType) -> TransferRule {
+    use TransferRule::{Allowed, RequiresProposal};
+
+    match (source, dest) {
+        // Static allowed transfers.
+        // Insurance
+        // Enables compensation in case of system failures
+        (VaultType::Insurance, VaultType::Rewards) => Allowed,
+        (VaultType::Insurance, VaultType::Staking) => Allowed,
+        (VaultType::Insurance, VaultType::Vesting) => Allowed,
+        (VaultType::Insurance, VaultType::OffchainReserve) => Allowed,
+
+        // Liquidity
+        (VaultType::Liquidity, VaultType::Airdrop) => Allowed,
+        (VaultType::Liquidity, VaultType::Insurance) => Allowed,
+        (VaultType::Liquidity, VaultType::ReservedSupply) => Allowed,
+
+        // OffchainReserve
+        (VaultType::OffchainReserve, VaultType::Liquidity) => Allowed,
+        (VaultType::OffchainReserve, VaultType::ReservedSupply) => Allowed,
+
+        // ReservedSupply
+        (VaultType::ReservedSupply, VaultType::Liquidity) => Allowed,
+        (VaultType::ReservedSupply, VaultType::Insurance) => Allowed,
+
+        // Revenue
+        // This is team free resource to use it for any logic purpose
+        (VaultType::Revenue, VaultType::Airdrop) => Allowed,
+        (VaultType::Revenue, VaultType::Liquidity) => Allowed,
+        (VaultType::Revenue, VaultType::ReservedSupply) => Allowed,
+        (VaultType::Revenue, VaultType::Treasury) => Allowed,
+        (VaultType::Revenue, VaultType::Insurance) => Allowed,
+        (VaultType::Revenue, VaultType::Staking) => Allowed,
+        (VaultType::Revenue, VaultType::Rewards) => Allowed,
+        (VaultType::Revenue, VaultType::Vesting) => Allowed,
+        (VaultType::Revenue, VaultType::OffchainReserve) => Allowed,
+
+        // Treasury
+        (VaultType::Treasury, VaultType::Airdrop) => Allowed,
+        (VaultType::Treasury, VaultType::Liquidity) => Allowed,
+        (VaultType::Treasury, VaultType::ReservedSupply) => Allowed,
+        (VaultType::Treasury, VaultType::Insurance) => Allowed,
+        (VaultType::Treasury, VaultType::Rewards) => Allowed,
+
+        ////////////////////////
+        // Governance-only paths
+        ////////////////////////
+
+        // Ask the community for vaults allocation
+        (VaultType::ReservedSupply, VaultType::Airdrop) => RequiresProposal(ProposalTypeBit::AidropAllocation),
+        (VaultType::ReservedSupply, VaultType::Rewards) => RequiresProposal(ProposalTypeBit::RewardsAllocation),
+
+        // Recover excessive tokens on vaults in case of system failures
+        (VaultType::Airdrop, VaultType::ReservedSupply) => RequiresProposal(ProposalTypeBit::RecoverFromAidrop),
+        (VaultType::Insurance, VaultType::ReservedSupply) => RequiresProposal(ProposalTypeBit::RecoverFromInsurance),
+        (VaultType::Staking, VaultType::ReservedSupply) => RequiresProposal(ProposalTypeBit::RecoverFromStaking),
+        (VaultType::Vesting, VaultType::ReservedSupply) => RequiresProposal(ProposalTypeBit::RecoverFromVesting),
+
+        // Recover vaults funds caused by a miss calculation or system failure
+        (VaultType::ReservedSupply, VaultType::Treasury) => RequiresProposal(ProposalTypeBit::TreasuryAllocation),
+        (VaultType::ReservedSupply, VaultType::Staking) => RequiresProposal(ProposalTypeBit::StakingRecover),
+        (VaultType::ReservedSupply, VaultType::Vesting) => RequiresProposal(ProposalTypeBit::VestingRecover),
+
+        // All others disallowed
+        _ => TransferRule::Forbidden,
+    }
+}
+
+/// Builds the full matrix of `default_transfer_rule`.
+fn default_transfer_rules() -> [[TransferRule; VAULT_TYPE_COUNT]; VAULT_TYPE_COUNT] {
+    ALL_VAULT_TYPES.map(|source| ALL_VAULT_TYPES.map(|dest| default_transfer_rule(source, dest)))
+}
+
+/// Parses a rule argument: `Allowed`, `Forbidden`, or a proposal type name
+/// (e.g. `TreasuryAllocation`) for transfers that need that proposal.
+pub(crate) fn parse_transfer_rule(value: &str) -> Result<TransferRule> {
+    match value {
+        "Allowed" => Ok(TransferRule::Allowed),
+        "Forbidden" => Ok(TransferRule::Forbidden),
+        name => ProposalTypeBit::from_str(name)
+            .map(TransferRule::RequiresProposal)
+            .ok_or(VaultError::InvalidTransferRule.into()),
+    }
+}
+
+/// ===========================================================================
+/// Returns the rule in force for `source → dest`.
+///
+/// ## Behavior:
+/// - Falls back to `default_transfer_rule` while `vault_policy` is not created
+///
+/// ## Errors:
+/// - `ErrorCode::Unauthorized` if the policy account is not program-owned
+/// ===========================================================================
+pub(crate) fn resolve_transfer_rule(
+    vault_policy: &AccountInfo,
+    source: VaultType,
+    dest: VaultType,
+) -> Result<TransferRule> {
+    if vault_policy.data_is_empty() {
+        return Ok(default_transfer_rule(source, dest));
+    }
+
+    require_keys_eq!(*vault_policy.owner, crate::ID, crate::ErrorCode::Unauthorized);
+
+    let data = vault_policy.try_borrow_data()?;
+    let policy = VaultPolicy::try_deserialize(&mut &data[..])?;
+
+    Ok(policy.rule(source, dest))
+}
+
+/// ===========================================================================
+/// Sets the transfer rule of a vault pair.
+///
+/// ## Behavior:
+/// - Creates the policy on first use, seeded with `default_transfer_rule`
+/// - Overwrites the rule of `source → destination` only
+///
+/// ## Errors:
+/// - `InvalidItselfVaultTransfer` if source and destination are the same
+/// ===========================================================================
+pub(crate) fn set_vault_transfer_rule(
+    ctx: Context<SetVaultTransferRuleContext>,
+    source: VaultType,
+    destination: VaultType,
+    rule: TransferRule,
+) -> Result<()> {
+    require!(source != destination, VaultError::InvalidItselfVaultTransfer);
+
+    let now = Clock::get()?.unix_timestamp;
+
+    let policy = &mut ctx.accounts.vault_policy;
+    if policy.updated_at == 0 {
+        policy.rules = default_transfer_rules();
+        policy.bump = ctx.bumps.vault_policy;
+    }
+    policy.rules[source as usize][destination as usize] = rule;
+    policy.updated_at = now;
+
+    msg!(
+        "🧭 Vault transfer rule '{}' → '{}' set to {:?}",
+        source.as_str(),
+        destination.as_str(),
+        rule,
+    );
+
+    emit!(VaultTransferRuleUpdated {
+        source,
+        destination,
+        rule,
+        updated_by: ctx.accounts.caller.key(),
+    });
+
+    Ok(())
+}
@@ -0,0 +1,495 @@
+// ===========================================================================
+// Vault Safeguards State – Soccial Token (SCTK)
+// ---------------------------------------------------------------------------
+//
+// Large withdrawals from the treasury, insurance, liquidity and revenue
+// vaults are not executed immediately. They are first queued as a
+// `PendingWithdrawal` and can only be executed once the per-vault delay has
+// elapsed, giving the community a window to react (and guardians a chance
+// to cancel) before funds leave. Direct outflows below the threshold are
+// summed per vault in a `DirectOutflowWindow` over the vault's delay, so a
+// large withdrawal cannot be split into smaller calls.
+//
+// Once an `ApprovalCouncil` is configured, outflows from the treasury and
+// insurance vaults no longer accept a single key: they are proposed as a
+// `CouncilOperation` and executed only after M of the N members approved.
+//
+// Each vault may also carry `VaultLimits`: a cap on how much can leave it per
+// epoch, and a circuit-breaker threshold that pauses the contract when
+// crossed, so a compromised key can only cause a bounded loss.
+//
+// Once opened, a `VaultLedger` keeps the cumulative inflows and outflows of
+// a vault by category, so its history can be audited without replaying
+// every event.
+//
+// Which vaults may transfer to each other is governed by `VaultPolicy`: an
+// allowed / forbidden / requires-proposal matrix that governance can edit
+// without a program upgrade.
+//
+// ---------------------------------------------------------------------------
+// ## Components:
+// - `LARGE_WITHDRAWAL_THRESHOLD`: Direct outflow, per delay window, from which
+//   a timelocked vault refuses to pay out without a queued withdrawal
+// - `*_WITHDRAWAL_DELAY_SECS`: Per-vault delays
+// - `PendingWithdrawal`: A queued withdrawal waiting for its delay
+// - `DirectOutflowWindow`: Direct outflows of a timelocked vault in its window
+// - `ApprovalCouncil`: Council members and approval threshold
+// - `CouncilOperation`: A treasury/insurance outflow collecting approvals
+// - `VaultLimits`: Per-epoch outflow cap and circuit breaker of a vault
+// - `VaultLedger`: Cumulative in/out accounting of a vault
+// - `VaultPolicy`: Vault-to-vault transfer rules
+//
+// ---------------------------------------------------------------------------
+// Author: Paulo Rodrigues
+// Project: Soccial Token
+// Website: https://www.soccial.com/thetoken
+// License: MIT
+// ===========================================================================
+
+use anchor_lang::prelude::*;
+use crate::{economy::TOKEN_DECIMAL, governance::ProposalTypeBit, vaults::{error::VaultError, vaults::VaultType}};
+
+/// Direct outflows from a timelocked vault must stay below 100,000 SCTK per
+/// delay window; anything larger must be queued.
+pub const LARGE_WITHDRAWAL_THRESHOLD: u64 = 100_000 * 10u64.pow(TOKEN_DECIMAL as u32);
+
+/// Delay before a queued treasury withdrawal can be executed (72 hours).
+pub const TREASURY_WITHDRAWAL_DELAY_SECS: i64 = 72 * 3_600;
+
+/// Delay before a queued insurance withdrawal can be executed (72 hours).
+pub const INSURANCE_WITHDRAWAL_DELAY_SECS: i64 = 72 * 3_600;
+
+/// Delay before a queued liquidity withdrawal can be executed (48 hours).
+pub const LIQUIDITY_WITHDRAWAL_DELAY_SECS: i64 = 48 * 3_600;
+
+/// Delay before a queued revenue withdrawal can be executed (24 hours).
+pub const REVENUE_WITHDRAWAL_DELAY_SECS: i64 = 24 * 3_600;
+
+/// Maximum length of the reason attached to a queued withdrawal.
+pub const MAX_WITHDRAWAL_REASON_LEN: usize = 64;
+
+/// ===========================================================================
+/// Account: PendingWithdrawal
+///
+/// A vault withdrawal queued by `queue_vault_withdrawal`, executable once
+/// `executable_at` is reached and closed on execution or cancellation.
+///
+/// ## Fields:
+/// - `id`: Identifier chosen by the caller (seeds the PDA)
+/// - `vault`: Vault PDA the tokens are withdrawn from
+/// - `vault_token_account`: Vault ATA debited on execution
+/// - `recipient`: Token account credited on execution
+/// - `amount`: SCTK base units to withdraw
+/// - `reason`: Free-form justification shown to the community
+/// - `queued_by` / `queued_at`: Who queued the withdrawal and when
+/// - `executable_at`: Unix timestamp from which it may be executed
+/// ===========================================================================
+#[account]
+pub struct PendingWithdrawal {
+    pub id: u64,
+    pub vault: Pubkey,
+    pub vault_token_account: Pubkey,
+    pub recipient: Pubkey,
+    pub amount: u64,
+    pub reason: String,
+    pub queued_by: Pubkey,
+    pub queued_at: i64,
+    pub executable_at: i64,
+    pub bump: u8,
+}
+
+impl PendingWithdrawal {
+    pub const LEN: usize =
+        8 +                                 // Anchor discriminator
+        8 +                                 // id (u64)
+        32 +                                // vault (Pubkey)
+        32 +                                // vault_token_account (Pubkey)
+        32 +                                // recipient (Pubkey)
+        8 +                                 // amount (u64)
+        4 + MAX_WITHDRAWAL_REASON_LEN +     // reason (String)
+        32 +                                // queued_by (Pubkey)
+        8 +                                 // queued_at (i64)
+        8 +                                 // executable_at (i64)
+        1;                                  // bump (u8)
+}
+
+/// ===========================================================================
+/// Account: DirectOutflowWindow
+///
+/// Running total of the direct (not queued) outflows of a timelocked vault,
+/// seeded by the vault PDA. The window lasts the vault's withdrawal delay.
+///
+/// ## Fields:
+/// - `vault`: Vault PDA the window tracks
+/// - `window_start` / `window_outflow`: Current window and what left so far
+/// - `bump`: PDA bump seed
+/// ===========================================================================
+#[account]
+pub struct DirectOutflowWindow {
+    pub vault: Pubkey,
+    pub window_start: i64,
+    pub window_outflow: u64,
+    pub bump: u8,
+}
+
+impl DirectOutflowWindow {
+    pub const LEN: usize =
+        8 +     // Anchor discriminator
+        32 +    // vault (Pubkey)
+        8 +     // window_start (i64)
+        8 +     // window_outflow (u64)
+        1;      // bump (u8)
+
+    /// Records `amount` leaving the vault directly at `now`, starting a new
+    /// window once `window` seconds have passed.
+    ///
+    /// ## Errors:
+    /// - `WithdrawalRequiresTimelock` if the window total would reach
+    ///   `LARGE_WITHDRAWAL_THRESHOLD`
+    pub fn record(&mut self, amount: u64, now: i64, window: i64) -> Result<()> {
+        if now >= self.window_start.saturating_add(window) {
+            self.window_start = now;
+            self.window_outflow = 0;
+        }
+
+        let total = self
+            .window_outflow
+            .checked_add(amount)
+            .ok_or(VaultError::WithdrawalRequiresTimelock)?;
+        require!(total < LARGE_WITHDRAWAL_THRESHOLD, VaultError::WithdrawalRequiresTimelock);
+
+        self.window_outflow = total;
+
+        Ok(())
+    }
+}
+
+/// Maximum number of members in the approval council.
+pub const MAX_COUNCIL_MEMBERS: usize = 16;
+
+/// ===========================================================================
+/// Account: ApprovalCouncil
+///
+/// M-of-N council guarding treasury and insurance outflows. Editable only
+/// through an approved `UpdateGovernance` proposal.
+///
+/// ## Fields:
+/// - `members`: Council member wallets (at most `MAX_COUNCIL_MEMBERS`)
+/// - `threshold`: Approvals required to execute an operation
+/// - `nonce`: Bumped on every membership change; operations proposed under
+///   an older nonce can no longer be approved or executed
+/// ===========================================================================
+#[account]
+pub struct ApprovalCouncil {
+    pub members: Vec<Pubkey>,
+    pub threshold: u8,
+    pub nonce: u64,
+    pub bump: u8,
+}
+
+impl ApprovalCouncil {
+    pub const LEN: usize =
+        8 +                                 // Anchor discriminator
+        4 + 32 * MAX_COUNCIL_MEMBERS +      // members (Vec<Pubkey>)
+        1 +                                 // threshold (u8)
+        8 +                                 // nonce (u64)
+        1;                                  // bump (u8)
+
+    /// Returns the position of `member` in the council, if any.
+    pub fn member_index(&self, member: &Pubkey) -> Option<usize> {
+        self.members.iter().position(|m| m == member)
+    }
+}
+
+/// Kind of outflow carried by a `CouncilOperation`.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq, Eq)]
+pub enum CouncilOperationKind {
+    /// Vault → any SCTK token account (`vault_withdraw` equivalent).
+    Withdraw,
+    /// Vault → vault (`transfer_between_vaults` equivalent).
+    Transfer,
+}
+
+impl std::str::FromStr for CouncilOperationKind {
+    type Err = ();
+
+    fn from_str(value: &str) -> std::result::Result<Self, Self::Err> {
+        match value.to_lowercase().as_str() {
+            "withdraw" => Ok(CouncilOperationKind::Withdraw),
+            "transfer" => Ok(CouncilOperationKind::Transfer),
+            _ => Err(()),
+        }
+    }
+}
+
+/// ===========================================================================
+/// Account: CouncilOperation
+///
+/// A treasury or insurance outflow proposed by a council member and
+/// executed once `threshold` members approved it. Closed on execution.
+///
+/// ## Fields:
+/// - `id`: Identifier chosen by the proposer (seeds the PDA)
+/// - `kind`: Withdraw or vault-to-vault transfer
+/// - `source_vault` / `source_vault_token_account`: Vault being debited
+/// - `destination`: Token account credited on execution
+/// - `approvals`: Bitmask of approving members (by council position)
+/// - `council_nonce`: Council nonce at proposal time
+/// - `executable_at`: Earliest execution time (per-vault delay for large
+///   withdrawals, otherwise the proposal time)
+/// ===========================================================================
+#[account]
+pub struct CouncilOperation {
+    pub id: u64,
+    pub kind: CouncilOperationKind,
+    pub source_vault: Pubkey,
+    pub source_vault_token_account: Pubkey,
+    pub destination: Pubkey,
+    pub amount: u64,
+    pub reason: String,
+    pub proposer: Pubkey,
+    pub council_nonce: u64,
+    pub approvals: u16,
+    pub proposed_at: i64,
+    pub executable_at: i64,
+    pub bump: u8,
+}
+
+impl CouncilOperation {
+    pub const LEN: usize =
+        8 +                                 // Anchor discriminator
+        8 +                                 // id (u64)
+        1 +                                 // kind (enum)
+        32 +                                // source_vault (Pubkey)
+        32 +                                // source_vault_token_account (Pubkey)
+        32 +                                // destination (Pubkey)
+        8 +                                 // amount (u64)
+        4 + MAX_WITHDRAWAL_REASON_LEN +     // reason (String)
+        32 +                                // proposer (Pubkey)
+        8 +                                 // council_nonce (u64)
+        2 +                                 // approvals (u16)
+        8 +                                 // proposed_at (i64)
+        8 +                                 // executable_at (i64)
+        1;                                  // bump (u8)
+
+    /// Number of members that approved the operation.
+    pub fn approval_count(&self) -> u32 {
+        self.approvals.count_ones()
+    }
+}
+
+/// Shortest epoch accepted for vault outflow limits (1 hour).
+pub const MIN_LIMITS_EPOCH_SECS: i64 = 3_600;
+
+/// Longest epoch accepted for vault outflow limits (30 days).
+pub const MAX_LIMITS_EPOCH_SECS: i64 = 30 * 86_400;
+
+/// ===========================================================================
+/// Account: VaultLimits
+///
+/// Outflow limits of a single vault, seeded by the vault PDA. Editable only
+/// through an approved `UpdateGovernance` proposal.
+///
+/// ## Fields:
+/// - `vault`: Vault PDA the limits apply to
+/// - `epoch_duration`: Length of an accounting epoch, in seconds
+/// - `max_outflow`: Hard cap per epoch; outflows beyond it are rejected
+/// - `pause_threshold`: Epoch outflow that trips the circuit breaker and
+///   pauses the contract (`0` disables it)
+/// - `epoch_start` / `epoch_outflow`: Current epoch and what left so far
+/// ===========================================================================
+#[account]
+pub struct VaultLimits {
+    pub vault: Pubkey,
+    pub epoch_duration: i64,
+    pub max_outflow: u64,
+    pub pause_threshold: u64,
+    pub epoch_start: i64,
+    pub epoch_outflow: u64,
+    pub bump: u8,
+}
+
+impl VaultLimits {
+    pub const LEN: usize =
+        8 +     // Anchor discriminator
+        32 +    // vault (Pubkey)
+        8 +     // epoch_duration (i64)
+        8 +     // max_outflow (u64)
+        8 +     // pause_threshold (u64)
+        8 +     // epoch_start (i64)
+        8 +     // epoch_outflow (u64)
+        1;      // bump (u8)
+
+    /// Records `amount` leaving the vault at `now`, starting a new epoch when
+    /// the current one is over.
+    ///
+    /// Returns `true` when this outflow crosses `pause_threshold`.
+    ///
+    /// ## Errors:
+    /// - `EpochOutflowExceeded` if the epoch total would exceed `max_outflow`
+    pub fn record(&mut self, amount: u64, now: i64) -> Result<bool> {
+        if now >= self.epoch_start.saturating_add(self.epoch_duration) {
+            self.epoch_start = now;
+            self.epoch_outflow = 0;
+        }
+
+        let total = self
+            .epoch_outflow
+            .checked_add(amount)
+            .ok_or(VaultError::EpochOutflowExceeded)?;
+        require!(total <= self.max_outflow, VaultError::EpochOutflowExceeded);
+
+        let tripped = self.pause_threshold > 0
+            && self.epoch_outflow < self.pause_threshold
+            && total >= self.pause_threshold;
+        self.epoch_outflow = total;
+
+        Ok(tripped)
+    }
+}
+
+/// Number of `LedgerCategory` variants tracked by a `VaultLedger`.
+pub const LEDGER_CATEGORY_COUNT: usize = 6;
+
+/// Reason a vault balance moved, used to bucket `VaultLedger` totals.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq, Eq)]
+pub enum LedgerCategory {
+    /// Fee shares routed to the rewards, airdrop and revenue vaults, and
+    /// referral rewards paid out of them.
+    Fees,
+    /// Airdrops and voucher redemptions.
+    Airdrops,
+    /// Staked principal and reserved staking rewards.
+    StakingReserves,
+    /// Vesting schedules funding and releases.
+    VestingFunding,
+    /// Token sales, off-chain reserve movements and AMM liquidity.
+    Market,
+    /// Administrative deposits, withdrawals and vault-to-vault transfers.
+    Manual,
+}
+
+/// Direction of a `VaultLedger` entry.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum LedgerFlow {
+    Inflow,
+    Outflow,
+}
+
+/// ===========================================================================
+/// Account: VaultLedger
+///
+/// Cumulative accounting of a single vault, seeded by the vault PDA. Totals
+/// start at `opening_balance` when the ledger is opened; every instruction
+/// moving the vault's funds afterwards posts to it.
+///
+/// ## Fields:
+/// - `vault` / `vault_type`: Vault the ledger belongs to
+/// - `opening_balance`: Vault balance when the ledger was opened
+/// - `inflows` / `outflows`: Cumulative totals, indexed by `LedgerCategory`
+/// - `opened_at` / `updated_at`: When it was opened and last posted to
+/// ===========================================================================
+#[account]
+pub struct VaultLedger {
+    pub vault: Pubkey,
+    pub vault_type: VaultType,
+    pub opening_balance: u64,
+    pub inflows: [u128; LEDGER_CATEGORY_COUNT],
+    pub outflows: [u128; LEDGER_CATEGORY_COUNT],
+    pub opened_at: i64,
+    pub updated_at: i64,
+    pub bump: u8,
+}
+
+impl VaultLedger {
+    pub const LEN: usize =
+        8 +                                 // Anchor discriminator
+        32 +                                // vault (Pubkey)
+        1 +                                 // vault_type (enum)
+        8 +                                 // opening_balance (u64)
+        16 * LEDGER_CATEGORY_COUNT +        // inflows ([u128; N])
+        16 * LEDGER_CATEGORY_COUNT +        // outflows ([u128; N])
+        8 +                                 // opened_at (i64)
+        8 +                                 // updated_at (i64)
+        1;                                  // bump (u8)
+
+    /// Adds `amount` to the `flow` total of `category`.
+    ///
+    /// ## Errors:
+    /// - `VaultInvariantViolation` if the total overflows
+    pub fn post(&mut self, flow: LedgerFlow, category: LedgerCategory, amount: u64, now: i64) -> Result<()> {
+        let totals = match flow {
+            LedgerFlow::Inflow => &mut self.inflows,
+            LedgerFlow::Outflow => &mut self.outflows,
+        };
+        let total = &mut totals[category as usize];
+        *total = total
+            .checked_add(amount as u128)
+            .ok_or(VaultError::VaultInvariantViolation)?;
+        self.updated_at = now;
+
+        Ok(())
+    }
+
+    /// Sum of all inflows.
+    pub fn total_inflow(&self) -> u128 {
+        self.inflows.iter().sum()
+    }
+
+    /// Sum of all outflows.
+    pub fn total_outflow(&self) -> u128 {
+        self.outflows.iter().sum()
+    }
+}
+
+/// Number of `VaultType` variants, i.e. the size of the `VaultPolicy` matrix.
+pub const VAULT_TYPE_COUNT: usize = 10;
+
+/// How a vault-to-vault transfer between two vault types is handled.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq)]
+pub enum TransferRule {
+    /// The transfer is rejected.
+    Forbidden,
+    /// The transfer goes through without governance.
+    Allowed,
+    /// The transfer needs an approved proposal of the given type.
+    RequiresProposal(ProposalTypeBit),
+}
+
+impl TransferRule {
+    /// Serialized size of a rule (variant tag + proposal type).
+    pub const LEN: usize = 2;
+}
+
+/// ===========================================================================
+/// Account: VaultPolicy
+///
+/// Governance-editable transfer matrix evaluated by `transfer_between_vaults`
+/// and council transfers. Until it is created, the built-in
+/// `default_transfer_rule` matrix applies; on creation it is seeded with it.
+///
+/// ## Fields:
+/// - `rules`: `rules[source][destination]`, indexed by `VaultType`
+/// - `updated_at`: Last time a rule was changed
+/// ===========================================================================
+#[account]
+pub struct VaultPolicy {
+    pub rules: [[TransferRule; VAULT_TYPE_COUNT]; VAULT_TYPE_COUNT],
+    pub updated_at: i64,
+    pub bump: u8,
+}
+
+impl VaultPolicy {
+    pub const LEN: usize =
+        8 +                                 // Anchor discriminator
+        TransferRule::LEN
+            * VAULT_TYPE_COUNT
+            * VAULT_TYPE_COUNT +            // rules ([[TransferRule; N]; N])
+        8 +                                 // updated_at (i64)
+        1;                                  // bump (u8)
+
+    /// Returns the rule applied to `source → destination`.
+    pub fn rule(&self, source: VaultType, destination: VaultType) -> TransferRule {
+        self.rules[source as usize][destination as usize]
+    }
+}
@@ -0,0 +1,331 @@
+// ===========================================================================
+// Vault Withdrawal Queue – Soccial Token (SCTK)
+// ---------------------------------------------------------------------------
+//
+// Two-phase withdrawals for the timelocked vaults (treasury, insurance,
+// liquidity, revenue). A withdrawal is first queued as a `PendingWithdrawal`
+// PDA and can only be executed once the vault's delay has elapsed. Until
+// then, any admin or `guard_vaults` holder can cancel it.
+//
+// ---------------------------------------------------------------------------
+// ## Flow:
+// 1. `queue_vault_withdrawal()` → records vault, amount, recipient, reason
+//    and `executable_at = now + delay`
+// 2. `execute_vault_withdrawal()` → after `executable_at`, pays the recipient
+//    and closes the PDA
+// 3. `cancel_vault_withdrawal()` → at any time before execution, closes the
+//    PDA without moving funds
+//
+// Direct outflows (`vault_withdraw`, `transfer_between_vaults`) from these
+// vaults go through `record_direct_outflow()`, which sums them over the
+// vault's delay and refuses them once `LARGE_WITHDRAWAL_THRESHOLD` is reached.
+//
+// ---------------------------------------------------------------------------
+// Author: Paulo Rodrigues
+// Project: Soccial Token
+// Website: https://www.soccial.com/thetoken
+// License: MIT
+// ===========================================================================
+
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::{
+    program::{invoke, invoke_signed},
+    system_instruction,
+};
+use anchor_spl::token::{self, Transfer};
+
+use crate::vaults::{
+    context::*,
+    council::require_no_council,
+    ledger::record_vault_flow,
+    limits::record_vault_outflow,
+    state::{DirectOutflowWindow, LedgerCategory, LedgerFlow, MAX_WITHDRAWAL_REASON_LEN},
+    vaults::{resolve_vault_seeds, vault_type_from_seed, VaultAction, VaultType},
+    VaultError,
+};
+
+#[event]
+pub struct VaultWithdrawalQueued {
+    pub id: u64,
+    pub vault: String,
+    pub amount: u64,
+    pub recipient: Pubkey,
+    pub reason: String,
+    pub queued_by: Pubkey,
+    pub executable_at: i64,
+}
+
+#[event]
+pub struct VaultWithdrawalExecuted {
+    pub id: u64,
+    pub vault: String,
+    pub amount: u64,
+    pub recipient: Pubkey,
+    pub executed_by: Pubkey,
+}
+
+#[event]
+pub struct VaultWithdrawalCancelled {
+    pub id: u64,
+    pub amount: u64,
+    pub recipient: Pubkey,
+    pub cancelled_by: Pubkey,
+}
+
+/// ===========================================================================
+/// Queues a withdrawal from a timelocked vault.
+///
+/// ## Behavior:
+/// - Validates the vault PDA via `resolve_vault_seeds`
+/// - Refuses council-guarded vaults once the approval council is configured
+/// - Looks up the vault's withdrawal delay
+/// - Stores the withdrawal with `executable_at = now + delay`
+///
+/// ## Errors:
+/// - `InvalidVaultAmount` if amount is zero
+/// - `WithdrawalReasonTooLong` if the reason exceeds `MAX_WITHDRAWAL_REASON_LEN`
+/// - `UnknownVaultType` if the vault is not valid for withdrawals
+/// - `VaultNotTimelocked` if the vault has no withdrawal delay
+/// - `CouncilApprovalRequired` if the withdrawal must go through the council
+/// ===========================================================================
+pub(crate) fn queue_vault_withdrawal(
+    ctx: Context<QueueVaultWithdrawalContext>,
+    id: u64,
+    amount: u64,
+    reason: Option<String>,
+) -> Result<()> {
+    require!(amount > 0, VaultError::InvalidVaultAmount);
+
+    let reason = reason.unwrap_or_default();
+    require!(reason.len() <= MAX_WITHDRAWAL_REASON_LEN, VaultError::WithdrawalReasonTooLong);
+
+    let (seed, _) = resolve_vault_seeds(&ctx.accounts.vault, VaultAction::Withdraw)?;
+    let vault_type = vault_type_from_seed(seed).ok_or(VaultError::UnknownVaultType)?;
+    require_no_council(&ctx.accounts.approval_council, vault_type)?;
+    let delay = vault_type.withdrawal_delay().ok_or(VaultError::VaultNotTimelocked)?;
+
+    let now = Clock::get()?.unix_timestamp;
+    let executable_at = now.checked_add(delay).ok_or(VaultError::VaultInvariantViolation)?;
+
+    let caller = ctx.accounts.caller.key();
+    let recipient = ctx.accounts.recipient_token_account.key();
+
+    let pending = &mut ctx.accounts.pending_withdrawal;
+    pending.id = id;
+    pending.vault = ctx.accounts.vault.key();
+    pending.vault_token_account = ctx.accounts.vault_token_account.key();
+    pending.recipient = recipient;
+    pending.amount = amount;
+    pending.reason = reason.clone();
+    pending.queued_by = caller;
+    pending.queued_at = now;
+    pending.executable_at = executable_at;
+    pending.bump = ctx.bumps.pending_withdrawal;
+
+    msg!(
+        "⏳ Withdrawal #{} of {} tokens from vault '{}' queued → executable at {} | Reason: {}",
+        id,
+        amount,
+        vault_type.as_str(),
+        executable_at,
+        reason,
+    );
+
+    emit!(VaultWithdrawalQueued {
+        id,
+        vault: vault_type.as_str().to_string(),
+        amount,
+        recipient,
+        reason,
+        queued_by: caller,
+        executable_at,
+    });
+
+    Ok(())
+}
+
+/// ===========================================================================
+/// Executes a queued withdrawal once its delay has elapsed.
+///
+/// ## Behavior:
+/// - Requires `now >= executable_at`
+/// - Refuses council-guarded vaults once the approval council is configured
+/// - Transfers the queued amount from the vault to the recorded recipient
+/// - Posts a `Manual` outflow to the vault ledger
+/// - Closes the `PendingWithdrawal` PDA
+///
+/// ## Errors:
+/// - `WithdrawalTimelocked` if the delay has not elapsed
+/// - `InsufficientVaultBalance` if the vault cannot cover the amount
+/// - `UnknownVaultType` if the recorded vault is not valid for withdrawals
+/// - `CouncilApprovalRequired` if the withdrawal must go through the council
+/// - `EpochOutflowExceeded` if the vault's epoch outflow cap is reached
+/// ===========================================================================
+pub(crate) fn execute_vault_withdrawal(ctx: Context<ExecuteVaultWithdrawalContext>) -> Result<()> {
+    let now = Clock::get()?.unix_timestamp;
+    let pending = &ctx.accounts.pending_withdrawal;
+
+    require!(now >= pending.executable_at, VaultError::WithdrawalTimelocked);
+    require!(
+        ctx.accounts.vault_token_account.amount >= pending.amount,
+        VaultError::InsufficientVaultBalance
+    );
+
+    let (seed, bump) = resolve_vault_seeds(&ctx.accounts.vault, VaultAction::Withdraw)?;
+    let vault_name = core::str::from_utf8(seed).unwrap_or("unknown");
+
+    let vault_type = vault_type_from_seed(seed).ok_or(VaultError::UnknownVaultType)?;
+    require_no_council(&ctx.accounts.approval_council, vault_type)?;
+
+    // Enforce the vault's epoch outflow limits (may trip the circuit breaker)
+    record_vault_outflow(
+        &ctx.accounts.vault_limits,
+        &mut ctx.accounts.token_state,
+        &ctx.accounts.caller.key(),
+        pending.amount,
+    )?;
+
+    let signer_seeds: &[&[u8]] = &[seed, &[bump]];
+    let signer_seeds_nested: &[&[&[u8]]] = &[signer_seeds];
+
+    let cpi_ctx = CpiContext::new_with_signer(
+        ctx.accounts.token_program.to_account_info(),
+        Transfer {
+            from: ctx.accounts.vault_token_account.to_account_info(),
+            to: ctx.accounts.recipient_token_account.to_account_info(),
+            authority: ctx.accounts.vault.to_account_info(),
+        },
+        signer_seeds_nested,
+    );
+
+    token::transfer(cpi_ctx, pending.amount)?;
+    record_vault_flow(&ctx.accounts.vault_ledger, LedgerFlow::Outflow, LedgerCategory::Manual, pending.amount)?;
+
+    msg!(
+        "🏦 Queued withdrawal #{} executed → {} tokens from vault '{}'",
+        pending.id,
+        pending.amount,
+        vault_name,
+    );
+
+    emit!(VaultWithdrawalExecuted {
+        id: pending.id,
+        vault: vault_name.to_string(),
+        amount: pending.amount,
+        recipient: pending.recipient,
+        executed_by: ctx.accounts.caller.key(),
+    });
+
+    Ok(())
+}
+
+/// ===========================================================================
+/// Cancels a queued withdrawal before it is executed.
+///
+/// ## Behavior:
+/// - Closes the `PendingWithdrawal` PDA without moving any funds
+/// ===========================================================================
+pub(crate) fn cancel_vault_withdrawal(ctx: Context<CancelVaultWithdrawalContext>) -> Result<()> {
+    let pending = &ctx.accounts.pending_withdrawal;
+    let caller = ctx.accounts.caller.key();
+
+    msg!(
+        "🛑 Queued withdrawal #{} of {} tokens cancelled by {}",
+        pending.id,
+        pending.amount,
+        caller,
+    );
+
+    emit!(VaultWithdrawalCancelled {
+        id: pending.id,
+        amount: pending.amount,
+        recipient: pending.recipient,
+        cancelled_by: caller,
+    });
+
+    Ok(())
+}
+
+/// ===========================================================================
+/// Checks and records a direct (not queued) outflow from `vault`.
+///
+/// ## Behavior:
+/// - No-op for vaults without a withdrawal delay
+/// - Creates the vault's `DirectOutflowWindow` PDA on first use (paid by `payer`),
+///   also when someone already sent lamports to its address
+/// - Adds `amount` to the window, which restarts after the vault's delay
+///
+/// ## Errors:
+/// - `WithdrawalRequiresTimelock` if the window total would reach
+///   `LARGE_WITHDRAWAL_THRESHOLD`
+/// - `ErrorCode::Unauthorized` if the window account is not program-owned
+/// ===========================================================================
+pub(crate) fn record_direct_outflow<'info>(
+    direct_outflow: &AccountInfo<'info>,
+    bump: u8,
+    payer: &AccountInfo<'info>,
+    system_program: &AccountInfo<'info>,
+    vault: Pubkey,
+    vault_type: VaultType,
+    amount: u64,
+) -> Result<()> {
+    let Some(delay) = vault_type.withdrawal_delay() else {
+        return Ok(());
+    };
+
+    let mut window = if direct_outflow.data_is_empty() {
+        let rent = Rent::get()?.minimum_balance(DirectOutflowWindow::LEN);
+        let seeds: &[&[u8]] = &[b"direct_outflow", vault.as_ref(), &[bump]];
+
+        if direct_outflow.lamports() == 0 {
+            invoke_signed(
+                &system_instruction::create_account(
+                    payer.key,
+                    direct_outflow.key,
+                    rent,
+                    DirectOutflowWindow::LEN as u64,
+                    &crate::ID,
+                ),
+                &[payer.clone(), direct_outflow.clone(), system_program.clone()],
+                &[seeds],
+            )?;
+        } else {
+            // `create_account` rejects a pre-funded address, so top it up to
+            // rent exemption and allocate and assign it instead
+            let top_up = rent.saturating_sub(direct_outflow.lamports());
+            if top_up > 0 {
+                invoke(
+                    &system_instruction::transfer(payer.key, direct_outflow.key, top_up),
+                    &[payer.clone(), direct_outflow.clone(), system_program.clone()],
+                )?;
+            }
+            invoke_signed(
+                &system_instruction::allocate(direct_outflow.key, DirectOutflowWindow::LEN as u64),
+                &[direct_outflow.clone(), system_program.clone()],
+                &[seeds],
+            )?;
+            invoke_signed(
+                &system_instruction::assign(direct_outflow.key, &crate::ID),
+                &[direct_outflow.clone(), system_program.clone()],
+                &[seeds],
+            )?;
+        }
+
+        DirectOutflowWindow {
+            vault,
+            window_start: 0,
+            window_outflow: 0,
+            bump,
+        }
+    } else {
+        require_keys_eq!(*direct_outflow.owner, crate::ID, crate::ErrorCode::Unauthorized);
+        DirectOutflowWindow::try_deserialize(&mut &direct_outflow.try_borrow_data()?[..])?
+    };
+
+    window.record(amount, Clock::get()?.unix_timestamp, delay)?;
+
+    let mut data = direct_outflow.try_borrow_mut_data()?;
+    window.try_serialize(&mut &mut data[..])?;
+
+    Ok(())
+}
@@ -37,7 +37,10 @@
 
 use anchor_lang::prelude::*;
 use anchor_spl::token::{self, TokenAccount, Transfer};
-use crate::{governance::{ProposalAccount, ProposalTypeBit}, vaults::{context::*, VaultError}};
+use crate::{
+    governance::ProposalAccount,
+    vaults::{context::*, council::require_no_council, ledger::record_vault_flow, limits::record_vault_outflow, policy::resolve_transfer_rule, state::*, timelock::record_direct_outflow, VaultError},
+};
 
 
 #[event]
@@ -77,7 +80,7 @@ pub enum VaultAction {
 }
 
 /// Enum defining all supported vault types in the Soccial Token system.
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(AnchorSerialize, AnchorDeserialize, Debug, Clone, Copy, PartialEq, Eq)]
 pub enum VaultType {
     Airdrop,
     Insurance,
@@ -105,11 +108,29 @@ static VAULT_MAP: &[(VaultType, &'static [u8])] = &[
     (VaultType::Vesting,         b"vesting_vault"),
 ];
 
+/// Static map associating timelocked VaultType ↔ withdrawal delay (seconds).
+static WITHDRAWAL_DELAYS: &[(VaultType, i64)] = &[
+    (VaultType::Insurance, INSURANCE_WITHDRAWAL_DELAY_SECS),
+    (VaultType::Liquidity, LIQUIDITY_WITHDRAWAL_DELAY_SECS),
+    (VaultType::Revenue,   REVENUE_WITHDRAWAL_DELAY_SECS),
+    (VaultType::Treasury,  TREASURY_WITHDRAWAL_DELAY_SECS),
+];
+
 impl VaultType {
     /// Returns the vault seed as a readable string.
     pub(crate) fn as_str(&self) -> &'static str {
         core::str::from_utf8(seed_from_vault_type(*self)).unwrap_or("unknown")
     }
+
+    /// Returns the queued-withdrawal delay of the vault, or `None` if it is not timelocked.
+    pub(crate) fn withdrawal_delay(&self) -> Option<i64> {
+        WITHDRAWAL_DELAYS.iter().find(|(k, _)| k == self).map(|(_, d)| *d)
+    }
+
+    /// Returns `true` if outflows need approval council sign-off once it is configured.
+    pub(crate) fn requires_council(&self) -> bool {
+        matches!(self, VaultType::Treasury | VaultType::Insurance)
+    }
 }
 
 /// Returns the vault PDA seed from a given VaultType.
@@ -118,12 +139,12 @@ fn seed_from_vault_type(vt: VaultType) -> &'static [u8] {
 }
 
 /// Resolves a VaultType from its PDA seed.
-fn vault_type_from_seed(seed: &[u8]) -> Option<VaultType> {
+pub(crate) fn vault_type_from_seed(seed: &[u8]) -> Option<VaultType> {
     VAULT_MAP.iter().find(|(_, s)| *s == seed).map(|(vt, _)| *vt)
 }
 
 /// Detects the VaultType from the token account's authority field (PDA).
-fn detect_vault_type(token_account: &TokenAccount) -> Option<VaultType> {
+pub(crate) fn detect_vault_type(token_account: &TokenAccount) -> Option<VaultType> {
     let actual_authority = token_account.owner;
 
     for (vault_type, seed) in VAULT_MAP.iter() {
@@ -136,49 +157,13 @@ fn detect_vault_type(token_account: &TokenAccount) -> Option<VaultType> {
     None
 }
 
-/// Describes types of vault transfers that require governance approval.
-pub enum GovernanceType {
-    RecoverFromAidrop,
-    RecoverFromInsurance,
-    RecoverFromStaking,
-    RecoverFromVesting,
-    TreasuryAllocation,
-    AidropAllocation,
-    StakingRecover,
-    RewardsAllocation,
-    VestingRecover,
-}
-
-/// Placeholder governance logic.
-fn needs_governance(
-    action: GovernanceType,
-    proposal: Option<&mut Account<ProposalAccount>>,
-    governance_state: &Account<crate::governance::GovernanceState>,
-) -> Result<()> {
-
-    let proposal = proposal.ok_or(VaultError::MissingProposalApproval)?;
-
-    let expected_type = match action {
-        GovernanceType::RecoverFromAidrop => ProposalTypeBit::RecoverFromAidrop,
-        GovernanceType::RecoverFromInsurance => ProposalTypeBit::RecoverFromInsurance,
-        GovernanceType::RecoverFromStaking => ProposalTypeBit::RecoverFromStaking,
-        GovernanceType::RecoverFromVesting => ProposalTypeBit::RecoverFromVesting,
-        GovernanceType::TreasuryAllocation => ProposalTypeBit::TreasuryAllocation,
-        GovernanceType::AidropAllocation => ProposalTypeBit::AidropAllocation,
-        GovernanceType::RewardsAllocation => ProposalTypeBit::RewardsAllocation,
-        GovernanceType::StakingRecover => ProposalTypeBit::StakingRecover,
-        GovernanceType::VestingRecover => ProposalTypeBit::VestingRecover,
-    };
-
-    crate::governance::require_approved_proposal(proposal, governance_state, expected_type)
-}
-
 /// Returns whether a transfer is allowed between two vaults (source → destination),
-/// based on static rules or community-approved governance proposals.
+/// based on the `VaultPolicy` rules or community-approved governance proposals.
 ///
 /// ## Parameters:
 /// - `source`: Source vault type
 /// - `dest`: Destination vault type
+/// - `vault_policy`: `VaultPolicy` PDA (built-in rules apply while it is not created)
 /// - `proposal`: Proposal account to validate governance (if required)
 /// - `governance_state`: Global governance settings
 ///
@@ -188,110 +173,27 @@ fn needs_governance(
 /// - `Err(...)` if governance check fails
 ///
 /// ## Notes:
-/// - Static transfers return `Ok(true)` immediately
-/// - Governance-controlled transfers call `needs_governance(...)`
+/// - `Allowed` rules return `Ok(true)` immediately
+/// - `RequiresProposal` rules need an approved proposal of that type
 ///
 /// ## Errors:
+/// - `MissingProposalApproval` if a proposal is required but not provided
 /// - `GovernanceError::*` if proposal is invalid, not finalized, or mismatched
-fn is_transfer_allowed(
+pub(crate) fn is_transfer_allowed(
     source: VaultType,
     dest: VaultType,
+    vault_policy: &AccountInfo,
     proposal: Option<&mut Account<ProposalAccount>>,
     governance_state: &Account<crate::governance::GovernanceState>,
 ) -> Result<bool> {
-    
-    match (source, dest) {
-        // Static allowed transfers.
-        // Insurance
-        // Enables compensation in case of system failures 
-        (VaultType::Insurance, VaultType::Rewards) => Ok(true), 
-        (VaultType::Insurance, VaultType::Staking) => Ok(true),
-        (VaultType::Insurance, VaultType::Vesting) => Ok(true),
-        (VaultType::Insurance, VaultType::OffchainReserve) => Ok(true),
-     
-        // Liquidity
-        (VaultType::Liquidity, VaultType::Airdrop) => Ok(true),
-        (VaultType::Liquidity, VaultType::Insurance) => Ok(true),
-        (VaultType::Liquidity, VaultType::ReservedSupply) => Ok(true),
-
-        // OffchainReserve
-        (VaultType::OffchainReserve, VaultType::Liquidity) => Ok(true),
-        (VaultType::OffchainReserve, VaultType::ReservedSupply) => Ok(true),
-
-        // ReservedSupply
-        (VaultType::ReservedSupply, VaultType::Liquidity) => Ok(true),  
-        (VaultType::ReservedSupply, VaultType::Insurance) => Ok(true),  
-
-        // Revenue
-        // This is team free resource to use it for any logic purpose
-        (VaultType::Revenue, VaultType::Airdrop) => Ok(true),
-        (VaultType::Revenue, VaultType::Liquidity) => Ok(true),
-        (VaultType::Revenue, VaultType::ReservedSupply) => Ok(true),
-        (VaultType::Revenue, VaultType::Treasury) => Ok(true),
-        (VaultType::Revenue, VaultType::Insurance) => Ok(true),
-        (VaultType::Revenue, VaultType::Staking) => Ok(true),
-        (VaultType::Revenue, VaultType::Rewards) => Ok(true),
-        (VaultType::Revenue, VaultType::Vesting) => Ok(true),
-        (VaultType::Revenue, VaultType::OffchainReserve) => Ok(true),
-
-        // Treasury
-        (VaultType::Treasury, VaultType::Airdrop) => Ok(true),
-        (VaultType::Treasury, VaultType::Liquidity) => Ok(true),
-        (VaultType::Treasury, VaultType::ReservedSupply) => Ok(true),
-        (VaultType::Treasury, VaultType::Insurance) => Ok(true),
-        (VaultType::Treasury, VaultType::Rewards) => Ok(true),
-
-        // Vesting
-
-        ////////////////////////
-        // Governance-only paths
-        ////////////////////////
-        
-        // Ask the community for vauls allocation
-        (VaultType::ReservedSupply, VaultType::Airdrop) => {
-            needs_governance(GovernanceType::AidropAllocation, proposal, governance_state)?;
-            Ok(true)
-        },
-        (VaultType::ReservedSupply, VaultType::Rewards) => {
-            needs_governance(GovernanceType::RewardsAllocation, proposal, governance_state)?;
-            Ok(true)
-        },
-        
-        // Recover excessive tokens on vaults in case of system failures
-        (VaultType::Airdrop, VaultType::ReservedSupply) => {
-            needs_governance(GovernanceType::RecoverFromAidrop, proposal, governance_state)?;
-            Ok(true)
-        },
-        (VaultType::Insurance, VaultType::ReservedSupply) => {
-            needs_governance(GovernanceType::RecoverFromInsurance, proposal, governance_state)?;
-            Ok(true)
-        },
-        (VaultType::Staking, VaultType::ReservedSupply) => {
-            needs_governance(GovernanceType::RecoverFromStaking, proposal, governance_state)?;
-            Ok(true)
-        },
-        (VaultType::Vesting, VaultType::ReservedSupply) => {
-            needs_governance(GovernanceType::RecoverFromVesting, proposal, governance_state)?;
+    match resolve_transfer_rule(vault_policy, source, dest)? {
+        TransferRule::Allowed => Ok(true),
+        TransferRule::Forbidden => Ok(false),
+        TransferRule::RequiresProposal(expected_type) => {
+            let proposal = proposal.ok_or(VaultError::MissingProposalApproval)?;
+            crate::governance::require_approved_proposal(proposal, governance_state, expected_type)?;
             Ok(true)
-        },
-
-        // Recover vaults funds caused by a miss calculation or system failure
-        (VaultType::ReservedSupply, VaultType::Treasury) => {
-            needs_governance(GovernanceType::TreasuryAllocation, proposal, governance_state)?;
-            Ok(true)
-        },
-
-          (VaultType::ReservedSupply, VaultType::Staking) => {
-            needs_governance(GovernanceType::StakingRecover, proposal, governance_state)?;
-            Ok(true)
-        },
-        (VaultType::ReservedSupply, VaultType::Vesting) => {
-            needs_governance(GovernanceType::VestingRecover, proposal, governance_state)?;
-            Ok(true)
-        },
-        
-        // All others disallowed
-        _ => Ok(false),
+        }
     }
 }
 
@@ -382,6 +284,7 @@ fn withdraw_seeds() -> &'static [&'static [u8]] {
 /// - Validates amount
 /// - Verifies vault via `resolve_vault_seeds`
 /// - Transfers tokens via CPI
+/// - Posts a `Manual` inflow to the vault ledger
 ///
 /// ## Logs:
 /// - Emits a message with vault name and optional reason string
@@ -389,6 +292,7 @@ fn withdraw_seeds() -> &'static [&'static [u8]] {
 /// ## Errors:
 /// - `InvalidVaultAmount` if amount is 0 or negative
 /// - `UnknownVaultType` if vault doesn't match expected seeds
+/// - `InvalidVaultTokenAccount` if the ATA is not owned by the vault
 /// ===========================================================================
 
 pub(crate) fn deposit<'info>(
@@ -400,6 +304,11 @@ pub(crate) fn deposit<'info>(
 
     let (seed, _) = resolve_vault_seeds(&ctx.accounts.vault, VaultAction::Operation)?;
     let vault_name = core::str::from_utf8(seed).unwrap_or("unknown");
+    require_keys_eq!(
+        ctx.accounts.vault_token_account.owner,
+        ctx.accounts.vault.key(),
+        VaultError::InvalidVaultTokenAccount
+    );
 
     let cpi_ctx = CpiContext::new(
         ctx.accounts.token_program.to_account_info(),
@@ -411,6 +320,7 @@ pub(crate) fn deposit<'info>(
     );
 
     token::transfer(cpi_ctx, amount)?;
+    record_vault_flow(&ctx.accounts.vault_ledger, LedgerFlow::Inflow, LedgerCategory::Manual, amount)?;
 
     let log_reason = reason.as_deref().unwrap_or("N/A");
 
@@ -435,7 +345,12 @@ pub(crate) fn deposit<'info>(
 /// ## Behavior:
 /// - Validates amount and vault balance
 /// - Validates vault PDA via `resolve_vault_seeds`
+/// - Sums direct withdrawals from timelocked vaults over the vault's delay and
+///   refuses them once `LARGE_WITHDRAWAL_THRESHOLD` is reached (larger
+///   amounts must be queued with `queue_vault_withdrawal`)
+/// - Refuses council-guarded vaults once the approval council is configured
 /// - Performs CPI transfer with signer seeds
+/// - Posts a `Manual` outflow to the vault ledger
 ///
 /// ## Logs:
 /// - Emits message with vault name and optional reason
@@ -443,6 +358,9 @@ pub(crate) fn deposit<'info>(
 /// ## Errors:
 /// - `InvalidVaultAmount`, `InsufficientVaultBalance`
 /// - `UnknownVaultType` if vault is not valid
+/// - `WithdrawalRequiresTimelock` if the direct outflow window is full
+/// - `CouncilApprovalRequired` if the withdrawal must go through the council
+/// - `EpochOutflowExceeded` if the vault's epoch outflow cap is reached
 /// ===========================================================================
 pub(crate) fn withdraw<'info>(
     ctx: Context<VaultWithdrawContext>,
@@ -457,7 +375,26 @@ pub(crate) fn withdraw<'info>(
 
     let (seed, bump) = resolve_vault_seeds(&ctx.accounts.vault, VaultAction::Withdraw)?;
     let vault_name = core::str::from_utf8(seed).unwrap_or("unknown");
-    
+
+    let vault_type = vault_type_from_seed(seed).ok_or(VaultError::UnknownVaultType)?;
+    require_no_council(&ctx.accounts.approval_council, vault_type)?;
+    record_direct_outflow(
+        &ctx.accounts.direct_outflow,
+        ctx.bumps.direct_outflow,
+        &ctx.accounts.caller.to_account_info(),
+        &ctx.accounts.system_program.to_account_info(),
+        ctx.accounts.vault.key(),
+        vault_type,
+        amount,
+    )?;
+
+    // Enforce the vault's epoch outflow limits (may trip the circuit breaker)
+    record_vault_outflow(
+        &ctx.accounts.vault_limits,
+        &mut ctx.accounts.token_state,
+        &ctx.accounts.caller.key(),
+        amount,
+    )?;
 
     let signer_seeds: &[&[u8]] = &[seed, &[bump]];
     let signer_seeds_nested: &[&[&[u8]]] = &[signer_seeds];
@@ -473,6 +410,7 @@ pub(crate) fn withdraw<'info>(
     );
 
     token::transfer(cpi_ctx, amount)?;
+    record_vault_flow(&ctx.accounts.vault_ledger, LedgerFlow::Outflow, LedgerCategory::Manual, amount)?;
 
     let log_reason = reason.as_deref().unwrap_or("N/A");
 
@@ -500,8 +438,12 @@ pub(crate) fn withdraw<'info>(
 /// - Validates that source and destination vaults are distinct
 /// - Verifies source vault using `resolve_vault_seeds`
 /// - Detects destination vault type via token account ownership
-/// - Checks if the transfer is allowed via `is_transfer_allowed`
+/// - Refuses council-guarded sources once the approval council is configured
+/// - Checks if the transfer is allowed by the `VaultPolicy` via `is_transfer_allowed`
+/// - Counts transfers out of timelocked vaults against the same direct outflow
+///   window as `withdraw`, so the timelock cannot be bypassed via another vault
 /// - Executes CPI transfer with signer authority derived from source vault
+/// - Posts `Manual` entries to the source and destination vault ledgers
 ///
 /// ## Logs:
 /// - Emits a log with amount, source, destination and optional reason
@@ -511,6 +453,9 @@ pub(crate) fn withdraw<'info>(
 /// - `InvalidItselfVaultTransfer` if transferring to the same vault
 /// - `UnknownVaultType` if either vault type is unrecognized
 /// - `UnauthorizedVaultTransfer` if transfer is not allowed by policy
+/// - `WithdrawalRequiresTimelock` if the source's direct outflow window is full
+/// - `CouncilApprovalRequired` if the transfer must go through the council
+/// - `EpochOutflowExceeded` if the vault's epoch outflow cap is reached
 /// ===========================================================================
 pub(crate) fn transfer_between_vaults(
     ctx: Context<VaultTransferContext>,
@@ -527,6 +472,7 @@ pub(crate) fn transfer_between_vaults(
     let (source_seed, bump) = resolve_vault_seeds(&ctx.accounts.source_vault, VaultAction::Operation)?;
     let source_type = vault_type_from_seed(source_seed).ok_or(VaultError::UnknownVaultType)?;
     let source_name = core::str::from_utf8(source_seed).unwrap_or("unknown");
+    require_no_council(&ctx.accounts.approval_council, source_type)?;
 
     let destination_type = detect_vault_type(&ctx.accounts.destination_vault_token_account)
         .ok_or(VaultError::UnknownVaultType)?;
@@ -537,12 +483,31 @@ pub(crate) fn transfer_between_vaults(
     let is_allowed = is_transfer_allowed(
         source_type,
         destination_type,
-        ctx.accounts.proposal.as_mut(), 
+        &ctx.accounts.vault_policy,
+        ctx.accounts.proposal.as_mut(),
         &ctx.accounts.governance_state,
     )?;
 
     require!(is_allowed, VaultError::UnauthorizedVaultTransfer);
 
+    record_direct_outflow(
+        &ctx.accounts.direct_outflow,
+        ctx.bumps.direct_outflow,
+        &ctx.accounts.caller.to_account_info(),
+        &ctx.accounts.system_program.to_account_info(),
+        ctx.accounts.source_vault.key(),
+        source_type,
+        amount,
+    )?;
+
+    // Enforce the vault's epoch outflow limits (may trip the circuit breaker)
+    record_vault_outflow(
+        &ctx.accounts.vault_limits,
+        &mut ctx.accounts.token_state,
+        &ctx.accounts.caller.key(),
+        amount,
+    )?;
+
     let signer_seeds: &[&[u8]] = &[source_seed, &[bump]];
     let signer_seeds_nested: &[&[&[u8]]] = &[signer_seeds];
 
@@ -557,6 +522,8 @@ pub(crate) fn transfer_between_vaults(
     );
 
     token::transfer(cpi_ctx, amount)?;
+    record_vault_flow(&ctx.accounts.source_vault_ledger, LedgerFlow::Outflow, LedgerCategory::Manual, amount)?;
+    record_vault_flow(&ctx.accounts.destination_vault_ledger, LedgerFlow::Inflow, LedgerCategory::Manual, amount)?;
 
     let log_reason = reason.as_deref().unwrap_or("N/A");
 
@@ -597,6 +564,7 @@ pub(crate) fn transfer_between_vaults(
 /// - Resolves destination vault via seed
 /// - Builds CPI with PDA signer: `contract_token_owner`
 /// - Transfers full amount to vault’s ATA
+/// - Posts a `Manual` inflow to the vault ledger
 ///
 /// ## Security:
 /// - Only known vaults are allowed (via `vaults_seeds`)
@@ -604,6 +572,7 @@ pub(crate) fn transfer_between_vaults(
 /// ## Errors:
 /// - Invalid amount
 /// - Invalid vault seed
+/// - `InvalidVaultTokenAccount` if the ATA is not owned by the vault
 /// ===========================================================================
 pub(crate) fn move_from_contract_to_vault(
     ctx: Context<ContractToVaultContext>,
@@ -614,6 +583,11 @@ pub(crate) fn move_from_contract_to_vault(
     // Resolve vault seed and bump based on the destination PDA
     let (seed, _bump) = resolve_vault_seeds(&ctx.accounts.destination_vault, VaultAction::Operation)?;
     let vault_name = core::str::from_utf8(seed).unwrap_or("unknown");
+    require_keys_eq!(
+        ctx.accounts.destination_vault_token_account.owner,
+        ctx.accounts.destination_vault.key(),
+        VaultError::InvalidVaultTokenAccount
+    );
 
     // PDA signer: contract_token_owner
     let signer_seeds: &[&[u8]] = &[b"contract_token_owner", &[ctx.bumps.source_authority]];
@@ -630,6 +604,7 @@ pub(crate) fn move_from_contract_to_vault(
     );
 
     token::transfer(cpi_ctx, amount)?;
+    record_vault_flow(&ctx.accounts.destination_vault_ledger, LedgerFlow::Inflow, LedgerCategory::Manual, amount)?;
 
      msg!(
         "🚚 Moved {} tokens from contract fallback account into vault '{}'",
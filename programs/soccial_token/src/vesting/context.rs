@@ -38,6 +38,7 @@ pub struct ReleaseVestedTokens<'info> {
     pub user_access: Option<Account<'info, UserAccessAccount>>,
 
     /// Global token configuration state.
+    #[account(mut)]
     pub token_state: Account<'info, TokenState>,
 
     // =========================================================================
@@ -96,6 +97,22 @@ pub struct ReleaseVestedTokens<'info> {
     )]
     pub vesting_vault_token_account: Account<'info, TokenAccount>,
 
+    /// CHECK: Outflow limits PDA of `vesting_vault`. Enforced in logic once configured.
+    #[account(
+        mut,
+        seeds = [b"vault_limits", vesting_vault.key().as_ref()],
+        bump
+    )]
+    pub vault_limits: AccountInfo<'info>,
+
+    /// CHECK: Ledger PDA of `vesting_vault`. Posted to in logic once opened.
+    #[account(
+        mut,
+        seeds = [b"vault_ledger", vesting_vault.key().as_ref()],
+        bump
+    )]
+    pub vesting_ledger: AccountInfo<'info>,
+
     /// User’s token account to receive vested tokens.
     #[account(mut)]
     pub destination_token_account: Account<'info, TokenAccount>,
@@ -196,6 +213,14 @@ pub struct ManageVesting<'info> {
     )]
     pub liquidity_vault_token_account: Account<'info, TokenAccount>,
 
+    /// CHECK: Ledger PDA of `liquidity_vault`. Posted to in logic once opened.
+    #[account(
+        mut,
+        seeds = [b"vault_ledger", liquidity_vault.key().as_ref()],
+        bump
+    )]
+    pub liquidity_ledger: AccountInfo<'info>,
+
     /// CHECK: Vesting vault PDA (target where tokens are locked).
     #[account(
         mut,
@@ -212,6 +237,14 @@ pub struct ManageVesting<'info> {
     )]
     pub vesting_vault_token_account: Account<'info, TokenAccount>,
 
+    /// CHECK: Ledger PDA of `vesting_vault`. Posted to in logic once opened.
+    #[account(
+        mut,
+        seeds = [b"vault_ledger", vesting_vault.key().as_ref()],
+        bump
+    )]
+    pub vesting_ledger: AccountInfo<'info>,
+
     /// Participant’s associated token account (optional use).
     #[account(
         init_if_needed,
@@ -310,6 +343,14 @@ pub struct EditVestingSchedule<'info> {
     )]
     pub liquidity_vault_token_account: Account<'info, TokenAccount>,
 
+    /// CHECK: Ledger PDA of `liquidity_vault`. Posted to in logic once opened.
+    #[account(
+        mut,
+        seeds = [b"vault_ledger", liquidity_vault.key().as_ref()],
+        bump
+    )]
+    pub liquidity_ledger: AccountInfo<'info>,
+
     /// CHECK: Vesting vault PDA (holding reserved tokens).
     #[account(
         mut,
@@ -326,6 +367,14 @@ pub struct EditVestingSchedule<'info> {
     )]
     pub vesting_vault_token_account: Account<'info, TokenAccount>,
 
+    /// CHECK: Ledger PDA of `vesting_vault`. Posted to in logic once opened.
+    #[account(
+        mut,
+        seeds = [b"vault_ledger", vesting_vault.key().as_ref()],
+        bump
+    )]
+    pub vesting_ledger: AccountInfo<'info>,
+
     /// Associated token account for participant (may receive refunds).
     #[account(
         mut,
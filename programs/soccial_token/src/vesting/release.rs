@@ -37,6 +37,7 @@ use crate::{
     vesting::context::*,
     vesting::VestingErrorCode,
     utils::error::ErrorCode,
+    vaults::{record_vault_flow, record_vault_outflow, LedgerCategory, LedgerFlow},
 };
 
 #[event]
@@ -59,6 +60,8 @@ pub struct VestedTokensReleased {
 /// - Calculates how many tokens have vested but are still unclaimed
 /// - Ensures release is only made to the participant’s ATA
 /// - Transfers only the unreleased amount using CPI from the vesting vault
+/// - Posts the release as a `VestingFunding` outflow to the vesting vault ledger
+/// - Counts the release against the vesting vault's outflow limits
 ///
 /// ## Permissions:
 /// - Can be called by the participant or by an admin with `"manage_vesting"`
@@ -78,6 +81,7 @@ pub struct VestedTokensReleased {
 /// - `Unauthorized`: If PDA or ATA do not match
 /// - `VestingNotActive`: If the schedule is not active
 /// - `NoTokensToRelease`: If there's nothing new to claim
+/// - `EpochOutflowExceeded`: If the vault's epoch outflow cap is reached
 /// ===========================================================================
 pub(crate) fn release_vested_tokens(
     ctx: &mut Context<ReleaseVestedTokens>,
@@ -137,6 +141,14 @@ pub(crate) fn release_vested_tokens(
     schedule.released_tokens += to_release;
     schedule.last_claim_time = clock.unix_timestamp;
 
+    // Enforce the vault's epoch outflow limits (may trip the circuit breaker)
+    record_vault_outflow(
+        &ctx.accounts.vault_limits,
+        &mut ctx.accounts.token_state,
+        &ctx.accounts.caller.key(),
+        to_release,
+    )?;
+
     // ------------------------------------------------------------------
     // Step 5: Transfer tokens
     // ------------------------------------------------------------------
@@ -154,6 +166,7 @@ pub(crate) fn release_vested_tokens(
         signer,
     );
     transfer(cpi_ctx, to_release)?;
+    record_vault_flow(&ctx.accounts.vesting_ledger, LedgerFlow::Outflow, LedgerCategory::VestingFunding, to_release)?;
 
     msg!(
         "💸 Released {} vested tokens to participant {} (vesting_id: {}).",
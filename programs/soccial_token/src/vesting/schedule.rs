@@ -43,6 +43,7 @@ use anchor_spl::token::{transfer, Transfer};
 use crate::economy::TOTAL_SUPPLY;
 use crate::utils::error::ErrorCode;
 use crate::vesting::VestingErrorCode;
+use crate::vaults::{record_vault_flow, LedgerCategory, LedgerFlow};
 
 #[event]
 pub struct VestingScheduleCreated {
@@ -87,6 +88,7 @@ pub struct VestingScheduleCancelled {
 /// - Assigns a unique `vesting_id` (incremented from `vesting_state`)
 /// - Transfers tokens from the `liquidity_vault` into the `vesting_vault`
 /// - Stores release logic: cliff, cycles, vesting duration, initial unlock
+/// - Posts the funding as `VestingFunding` to the liquidity and vesting vault ledgers
 ///
 /// ## Requirements:
 /// - The vesting account must be uninitialized (`status == 0`)
@@ -151,6 +153,8 @@ pub(crate) fn create_vesting_schedule(
     );
 
     transfer(cpi_ctx, total_tokens)?;
+    record_vault_flow(&ctx.accounts.liquidity_ledger, LedgerFlow::Outflow, LedgerCategory::VestingFunding, total_tokens)?;
+    record_vault_flow(&ctx.accounts.vesting_ledger, LedgerFlow::Inflow, LedgerCategory::VestingFunding, total_tokens)?;
 
     // Initialize the vesting schedule
     schedule.participant = participant;
@@ -242,6 +246,7 @@ pub(crate) fn set_immutable(
 /// ## Behavior:
 /// - Updates cliff, cycles, vesting duration, initial/unlocked tokens
 /// - Adjusts token balances between liquidity and vesting vaults
+/// - Posts any top-up or refund as `VestingFunding` to both vault ledgers
 ///
 /// ## Transfer Logic:
 /// - If new total_tokens > old: transfer the delta into the vesting vault
@@ -302,6 +307,8 @@ pub(crate) fn update_vesting_schedule(
         );
 
         transfer(cpi_ctx, diff)?;
+        record_vault_flow(&ctx.accounts.liquidity_ledger, LedgerFlow::Outflow, LedgerCategory::VestingFunding, diff)?;
+        record_vault_flow(&ctx.accounts.vesting_ledger, LedgerFlow::Inflow, LedgerCategory::VestingFunding, diff)?;
         msg!("📤 Transferred {} tokens from Liquidity Vault to Vesting Vault (increase)", diff);
         
     } else if new_total < old_total {
@@ -323,6 +330,8 @@ pub(crate) fn update_vesting_schedule(
         );
 
         transfer(cpi_ctx, diff)?;
+        record_vault_flow(&ctx.accounts.vesting_ledger, LedgerFlow::Outflow, LedgerCategory::VestingFunding, diff)?;
+        record_vault_flow(&ctx.accounts.liquidity_ledger, LedgerFlow::Inflow, LedgerCategory::VestingFunding, diff)?;
         msg!("📥 Returned {} tokens from Vesting Vault to Liquidity Vault (decrease)", diff);
     }
 
@@ -357,6 +366,7 @@ pub(crate) fn update_vesting_schedule(
 /// ## Behavior:
 /// - Transfers unreleased tokens from the `vesting_vault` back to the `liquidity_vault`
 /// - Marks schedule status as `3` (cancelled)
+/// - Posts the refund as `VestingFunding` to both vault ledgers
 ///
 /// ## Constraints:
 /// - Schedule must be active (`status == 1`)
@@ -418,6 +428,8 @@ pub(crate) fn cancel_vesting_schedule(
         );
 
         transfer(cpi_ctx, remaining)?;
+        record_vault_flow(&ctx.accounts.vesting_ledger, LedgerFlow::Outflow, LedgerCategory::VestingFunding, remaining)?;
+        record_vault_flow(&ctx.accounts.liquidity_ledger, LedgerFlow::Inflow, LedgerCategory::VestingFunding, remaining)?;
         msg!("🔁 Refunded {} tokens from Vesting Vault to Liquidity Vault", remaining);
     }
 
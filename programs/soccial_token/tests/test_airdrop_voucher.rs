@@ -0,0 +1,169 @@
+use soccial_token::airdrop::AirdropError;
+use soccial_token::economy::MAX_AIRDROP_AMOUNT;
+use solana_program_test::*;
+use solana_sdk::signature::Keypair;
+use solana_sdk::signer::Signer;
+use solana_sdk::transport::TransportError;
+
+mod testutils;
+mod trymethods;
+use crate::testutils::basics::{assert_custom_error, get_current_timestamp};
+use crate::testutils::environment::*;
+use crate::trymethods::tryairdrop::*;
+use crate::trymethods::tryuser::try_add_flag;
+
+const CODES: [&str; 3] = ["WELCOME-A1", "WELCOME-B2", "WELCOME-C3"];
+const AMOUNT: u64 = 1_000;
+
+/// Creates batch `batch_id` over `CODES` paying `AMOUNT` per code.
+async fn create_batch(
+    context: &mut EnvProgramTestContext,
+    owner: &Keypair,
+    batch_id: u64,
+    budget: u64,
+) -> Result<Vec<Vec<String>>, TransportError> {
+    let (root, proofs) = build_voucher_tree(&CODES);
+    let expires_at = get_current_timestamp(&mut context.banks_client).await + 3_600;
+
+    try_create_voucher_batch(
+        context,
+        owner,
+        batch_id,
+        vec![
+            batch_id.to_string(),
+            root,
+            CODES.len().to_string(),
+            AMOUNT.to_string(),
+            budget.to_string(),
+            expires_at.to_string(),
+        ],
+    ).await?;
+
+    Ok(proofs)
+}
+
+#[tokio::test]
+async fn test_redeem_voucher_should_succeed_once() -> Result<(), TransportError> {
+    let (mut context, owner) = setup_test_env().await;
+    let proofs = create_batch(&mut context, &owner, 1, AMOUNT * 3).await?;
+
+    let user = Keypair::new();
+    create_user_ata(&mut context, &user).await?;
+    let vault_before = context.get_vault_balance("airdrop").await;
+
+    try_redeem_voucher(&mut context, &user, 1, 1, CODES[1], proofs[1].clone()).await?;
+
+    assert_eq!(context.get_user_balance(&user.pubkey()).await, AMOUNT);
+    assert_eq!(context.get_vault_balance("airdrop").await, vault_before - AMOUNT);
+
+    // The same code cannot be used twice, even by someone else
+    let other = Keypair::new();
+    create_user_ata(&mut context, &other).await?;
+    let result = try_redeem_voucher(&mut context, &other, 1, 1, CODES[1], proofs[1].clone()).await;
+    assert_custom_error(result, AirdropError::VoucherAlreadyRedeemed, "Expected VoucherAlreadyRedeemed");
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_redeem_voucher_with_wrong_code_should_fail() -> Result<(), TransportError> {
+    let (mut context, owner) = setup_test_env().await;
+    let proofs = create_batch(&mut context, &owner, 2, AMOUNT * 3).await?;
+
+    let user = Keypair::new();
+    create_user_ata(&mut context, &user).await?;
+
+    let result = try_redeem_voucher(&mut context, &user, 2, 0, "WELCOME-XX", proofs[0].clone()).await;
+    assert_custom_error(result, AirdropError::InvalidVoucher, "Expected InvalidVoucher for an unknown code");
+
+    // A valid code under another index does not match either
+    let result = try_redeem_voucher(&mut context, &user, 2, 2, CODES[0], proofs[0].clone()).await;
+    assert_custom_error(result, AirdropError::InvalidVoucher, "Expected InvalidVoucher for a mismatched index");
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_front_runner_without_voucher_key_cannot_redeem() -> Result<(), TransportError> {
+    let (mut context, owner) = setup_test_env().await;
+    let proofs = create_batch(&mut context, &owner, 7, AMOUNT * 3).await?;
+
+    let holder = Keypair::new();
+    create_user_ata(&mut context, &holder).await?;
+    let front_runner = Keypair::new();
+    create_user_ata(&mut context, &front_runner).await?;
+
+    // A pending redemption exposes the index and proof, but not the voucher key
+    let result = try_redeem_voucher_with_key(&mut context, &front_runner, 7, 0, &Keypair::new(), proofs[0].clone()).await;
+    assert_custom_error(result, AirdropError::InvalidVoucher, "Expected InvalidVoucher without the code's voucher key");
+    assert_eq!(context.get_user_balance(&front_runner.pubkey()).await, 0);
+
+    try_redeem_voucher(&mut context, &holder, 7, 0, CODES[0], proofs[0].clone()).await?;
+    assert_eq!(context.get_user_balance(&holder.pubkey()).await, AMOUNT);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_redeem_voucher_respects_budget_and_expiry() -> Result<(), TransportError> {
+    let (mut context, owner) = setup_test_env().await;
+    let proofs = create_batch(&mut context, &owner, 3, AMOUNT).await?;
+
+    let user = Keypair::new();
+    create_user_ata(&mut context, &user).await?;
+
+    try_redeem_voucher(&mut context, &user, 3, 0, CODES[0], proofs[0].clone()).await?;
+
+    let result = try_redeem_voucher(&mut context, &user, 3, 1, CODES[1], proofs[1].clone()).await;
+    assert_custom_error(result, AirdropError::VoucherBudgetExhausted, "Expected VoucherBudgetExhausted");
+
+    let proofs = create_batch(&mut context, &owner, 4, AMOUNT * 3).await?;
+    context.warp_forward_seconds(3_601).await;
+    context.refresh().await;
+
+    let result = try_redeem_voucher(&mut context, &user, 4, 0, CODES[0], proofs[0].clone()).await;
+    assert_custom_error(result, AirdropError::VoucherExpired, "Expected VoucherExpired");
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_banned_user_cannot_redeem_voucher() -> Result<(), TransportError> {
+    let (mut context, owner) = setup_test_env().await;
+    let proofs = create_batch(&mut context, &owner, 5, AMOUNT * 3).await?;
+
+    let user = Keypair::new();
+    create_user_ata(&mut context, &user).await?;
+    try_add_flag(&mut context, &owner, &user.pubkey(), vec!["banned".to_string()]).await?;
+    context.refresh().await;
+
+    let result = try_redeem_voucher(&mut context, &user, 5, 0, CODES[0], proofs[0].clone()).await;
+    assert_custom_error(result, AirdropError::RedeemerBanned, "Expected RedeemerBanned");
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_voucher_batch_above_airdrop_limit_should_fail() -> Result<(), TransportError> {
+    let (mut context, owner) = setup_test_env().await;
+    let (root, _) = build_voucher_tree(&CODES);
+    let expires_at = get_current_timestamp(&mut context.banks_client).await + 3_600;
+    let amount = MAX_AIRDROP_AMOUNT + 1;
+
+    let result = try_create_voucher_batch(
+        &mut context,
+        &owner,
+        6,
+        vec![
+            "6".to_string(),
+            root,
+            CODES.len().to_string(),
+            amount.to_string(),
+            amount.to_string(),
+            expires_at.to_string(),
+        ],
+    ).await;
+    assert_custom_error(result, AirdropError::ExceedsPerAirdropLimit, "Expected ExceedsPerAirdropLimit");
+
+    Ok(())
+}
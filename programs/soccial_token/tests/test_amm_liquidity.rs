@@ -0,0 +1,44 @@
+use solana_program_test::*;
+use solana_sdk::signer::Signer;
+use solana_sdk::transport::TransportError;
+use spl_associated_token_account::get_associated_token_address;
+
+mod testutils;
+mod trymethods;
+use crate::testutils::basics::derive_seeds;
+use crate::testutils::environment::*;
+use crate::trymethods::tryamm::*;
+
+#[tokio::test]
+async fn test_remove_amm_liquidity_should_return_reserves() -> Result<(), TransportError> {
+    let (mut context, admin) = setup_test_env().await;
+
+    let sctk_amount: u64 = 1_000_000_000_000;
+    let quote_amount: u64 = 1_000_000_000;
+
+    let quote_mint = create_quote_mint(&mut context).await?;
+    mint_quote_to(&mut context, &quote_mint, &admin.pubkey(), quote_amount).await?;
+
+    let liquidity_before = context.get_vault_balance("liquidity").await;
+
+    try_initialize_amm_pool(&mut context, &admin, &quote_mint, sctk_amount, quote_amount, 30).await?;
+    context.refresh().await;
+
+    let liquidity_seeded = context.get_vault_balance("liquidity").await;
+    assert_eq!(liquidity_before - liquidity_seeded, sctk_amount, "❌ Pool should be seeded from the liquidity vault");
+
+    let shares = (sctk_amount as u128 * quote_amount as u128).isqrt() as u64;
+    try_remove_amm_liquidity(&mut context, &admin, &quote_mint, shares).await?;
+    context.refresh().await;
+
+    let seeds = derive_seeds(&context.program_id, &admin.pubkey());
+    let treasury_quote = get_associated_token_address(&seeds.treasury_vault, &quote_mint);
+
+    let liquidity_after = context.get_vault_balance("liquidity").await;
+    let treasury_quote_balance = fetch_token_balance(&mut context.banks_client, &treasury_quote).await;
+
+    assert_eq!(liquidity_after, liquidity_before, "❌ SCTK should return to the liquidity vault");
+    assert_eq!(treasury_quote_balance, quote_amount, "❌ Quote reserve should go to the treasury vault");
+
+    Ok(())
+}
@@ -0,0 +1,90 @@
+use soccial_token::amm::AmmError;
+use solana_program_test::*;
+use solana_sdk::pubkey::Pubkey;
+use solana_sdk::signature::Keypair;
+use solana_sdk::signer::Signer;
+use solana_sdk::transport::TransportError;
+
+mod testutils;
+mod trymethods;
+use crate::testutils::basics::assert_custom_error;
+use crate::testutils::environment::*;
+use crate::trymethods::tryamm::*;
+
+const POOL_SCTK: u64 = 1_000_000_000_000; // 1,000 SCTK
+const POOL_QUOTE: u64 = 1_000_000_000; // 1,000 quote tokens
+const SWAP_FEE_BPS: u16 = 100; // 1%
+
+/// Seeds the pool and returns a funded trader holding quote tokens.
+async fn setup_pool(
+    context: &mut EnvProgramTestContext,
+    admin: &Keypair,
+) -> Result<(Pubkey, Keypair), TransportError> {
+    let quote_mint = create_quote_mint(context).await?;
+    mint_quote_to(context, &quote_mint, &admin.pubkey(), POOL_QUOTE).await?;
+
+    try_initialize_amm_pool(context, admin, &quote_mint, POOL_SCTK, POOL_QUOTE, SWAP_FEE_BPS).await?;
+
+    let trader = Keypair::new();
+    fund_lamports(context, &trader, 1_000_000_000).await?;
+    create_user_ata(context, &trader).await?;
+    mint_quote_to(context, &quote_mint, &trader.pubkey(), 100_000_000).await?;
+
+    context.refresh().await;
+
+    Ok((quote_mint, trader))
+}
+
+#[tokio::test]
+async fn test_amm_swap_buy_should_succeed() -> Result<(), TransportError> {
+    let (mut context, admin) = setup_test_env().await;
+    let (quote_mint, trader) = setup_pool(&mut context, &admin).await?;
+
+    let amount_in: u64 = 100_000_000;
+    let gross_out = (POOL_SCTK as u128 * amount_in as u128 / (POOL_QUOTE + amount_in) as u128) as u64;
+    let expected_out = gross_out - gross_out * SWAP_FEE_BPS as u64 / 10_000;
+
+    let trader_before = context.get_user_balance(&trader.pubkey()).await;
+
+    try_amm_swap(&mut context, &trader, &quote_mint, "buy", amount_in, expected_out).await?;
+
+    context.refresh().await;
+
+    let trader_after = context.get_user_balance(&trader.pubkey()).await;
+
+    assert_eq!(trader_after - trader_before, expected_out, "❌ Trader should receive the constant-product output net of fee");
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_amm_swap_sell_below_min_out_should_fail() -> Result<(), TransportError> {
+    let (mut context, admin) = setup_test_env().await;
+    let (quote_mint, trader) = setup_pool(&mut context, &admin).await?;
+
+    try_amm_swap(&mut context, &trader, &quote_mint, "buy", 100_000_000, 0).await?;
+    context.refresh().await;
+
+    let result = try_amm_swap(&mut context, &trader, &quote_mint, "sell", 1_000_000_000, u64::MAX).await;
+
+    assert_custom_error(result, AmmError::SlippageExceeded, "Expected SlippageExceeded when output is below min_out");
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_initialize_amm_pool_without_permission_should_fail() -> Result<(), TransportError> {
+    let (mut context, _admin) = setup_test_env().await;
+
+    let intruder = Keypair::new();
+    fund_lamports(&mut context, &intruder, 1_000_000_000).await?;
+
+    let quote_mint = create_quote_mint(&mut context).await?;
+    mint_quote_to(&mut context, &quote_mint, &intruder.pubkey(), POOL_QUOTE).await?;
+
+    let result = try_initialize_amm_pool(&mut context, &intruder, &quote_mint, POOL_SCTK, POOL_QUOTE, SWAP_FEE_BPS).await;
+
+    assert!(result.is_err(), "🚨 Expected failure: caller lacks manage_vaults permission");
+
+    Ok(())
+}
@@ -0,0 +1,157 @@
+use anchor_lang::AccountDeserialize;
+use soccial_token::escrow::{EscrowError, EscrowStatus, MilestoneEscrow};
+use soccial_token::utils::error::ErrorCode;
+use solana_program_test::*;
+use solana_sdk::signer::Signer;
+use solana_sdk::transport::TransportError;
+
+mod testutils;
+mod trymethods;
+use crate::testutils::basics::{assert_custom_error, get_current_timestamp};
+use crate::testutils::environment::*;
+use crate::trymethods::tryescrow::*;
+use crate::trymethods::tryuser::try_assign_permission;
+
+const ONE_SCTK: u64 = 1_000_000_000;
+
+async fn load_milestone_escrow(
+    context: &mut EnvProgramTestContext,
+    id: u64,
+) -> Result<MilestoneEscrow, TransportError> {
+    let account = context.banks_client
+        .get_account(derive_milestone_escrow_pda(&context.program_id, id))
+        .await?
+        .expect("Milestone escrow should exist");
+
+    Ok(MilestoneEscrow::try_deserialize(&mut &account.data[..]).expect("Failed to deserialize"))
+}
+
+#[tokio::test]
+async fn test_release_milestones_individually() -> Result<(), TransportError> {
+    let (mut context, _admin) = setup_test_env().await;
+    let funder = setup_wallet(&mut context, 10 * ONE_SCTK).await?;
+    let recipient = setup_wallet(&mut context, 0).await?;
+
+    let milestones = [ONE_SCTK, 2 * ONE_SCTK, 3 * ONE_SCTK];
+    let deadline = get_current_timestamp(&mut context.banks_client).await + 3_600;
+    try_open_milestone_escrow(&mut context, &funder, 0, &recipient.pubkey(), deadline, &milestones).await?;
+    context.refresh().await;
+
+    assert_eq!(context.get_user_balance(&funder.pubkey()).await, 4 * ONE_SCTK, "❌ Funder should deposit the total");
+
+    let state = context.load_token_state().await;
+    let fee_bps = state.fee_schedule.transfer_fee_bps as u64;
+
+    try_release_milestone(&mut context, &funder, 0, &funder.pubkey(), &recipient.pubkey(), 1).await?;
+    context.refresh().await;
+
+    let net = milestones[1] - milestones[1] * fee_bps / 10_000;
+    assert_eq!(context.get_user_balance(&recipient.pubkey()).await, net, "❌ Recipient should receive the net milestone");
+
+    let escrow = load_milestone_escrow(&mut context, 0).await?;
+    assert!(escrow.is_released(1));
+    assert_eq!(escrow.released_amount, milestones[1]);
+    assert_eq!(escrow.status, EscrowStatus::Open);
+
+    let result = try_release_milestone(&mut context, &funder, 0, &funder.pubkey(), &recipient.pubkey(), 1).await;
+    assert_custom_error(result, EscrowError::MilestoneAlreadyReleased, "Expected MilestoneAlreadyReleased on second release");
+
+    let result = try_release_milestone(&mut context, &funder, 0, &funder.pubkey(), &recipient.pubkey(), 3).await;
+    assert_custom_error(result, EscrowError::InvalidMilestoneIndex, "Expected InvalidMilestoneIndex out of range");
+
+    try_release_milestone(&mut context, &funder, 0, &funder.pubkey(), &recipient.pubkey(), 0).await?;
+    context.refresh().await;
+    try_release_milestone(&mut context, &funder, 0, &funder.pubkey(), &recipient.pubkey(), 2).await?;
+    context.refresh().await;
+
+    let escrow = load_milestone_escrow(&mut context, 0).await?;
+    assert_eq!(escrow.status, EscrowStatus::Released, "❌ All milestones released should complete the escrow");
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_release_milestone_by_arbiter_requires_manage_user() -> Result<(), TransportError> {
+    let (mut context, admin) = setup_test_env().await;
+    let funder = setup_wallet(&mut context, 10 * ONE_SCTK).await?;
+    let recipient = setup_wallet(&mut context, 0).await?;
+    let arbiter = setup_wallet(&mut context, 0).await?;
+
+    let deadline = get_current_timestamp(&mut context.banks_client).await + 3_600;
+    try_open_milestone_escrow(&mut context, &funder, 0, &recipient.pubkey(), deadline, &[ONE_SCTK, ONE_SCTK]).await?;
+    context.refresh().await;
+
+    // The recipient cannot release milestones to themselves
+    let result = try_release_milestone(&mut context, &recipient, 0, &funder.pubkey(), &recipient.pubkey(), 0).await;
+    assert_custom_error(result, ErrorCode::Unauthorized, "Expected Unauthorized for the recipient");
+
+    try_assign_permission(&mut context, &admin, &arbiter.pubkey(), vec!["manage_user".to_string()]).await?;
+    context.refresh().await;
+
+    try_release_milestone(&mut context, &arbiter, 0, &funder.pubkey(), &recipient.pubkey(), 0).await?;
+    context.refresh().await;
+
+    let escrow = load_milestone_escrow(&mut context, 0).await?;
+    assert!(escrow.is_released(0), "❌ Arbiter should be able to release a milestone");
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_refund_milestone_remainder_after_deadline() -> Result<(), TransportError> {
+    let (mut context, _admin) = setup_test_env().await;
+    let funder = setup_wallet(&mut context, 10 * ONE_SCTK).await?;
+    let recipient = setup_wallet(&mut context, 0).await?;
+
+    let deadline = get_current_timestamp(&mut context.banks_client).await + 3_600;
+    try_open_milestone_escrow(&mut context, &funder, 0, &recipient.pubkey(), deadline, &[2 * ONE_SCTK, 3 * ONE_SCTK]).await?;
+    context.refresh().await;
+
+    try_release_milestone(&mut context, &funder, 0, &funder.pubkey(), &recipient.pubkey(), 0).await?;
+    context.refresh().await;
+
+    let result = try_refund_milestone_escrow(&mut context, &funder, 0, &funder.pubkey(), &recipient.pubkey()).await;
+    assert_custom_error(result, EscrowError::DeadlineNotReached, "Expected DeadlineNotReached before the deadline");
+
+    context.warp_forward_seconds(3_601).await;
+    context.refresh().await;
+
+    let funder_before = context.get_user_balance(&funder.pubkey()).await;
+    try_refund_milestone_escrow(&mut context, &funder, 0, &funder.pubkey(), &recipient.pubkey()).await?;
+    context.refresh().await;
+
+    assert_eq!(context.get_user_balance(&funder.pubkey()).await - funder_before, 3 * ONE_SCTK, "❌ Only the unreleased remainder should be refunded");
+
+    let escrow = load_milestone_escrow(&mut context, 0).await?;
+    assert_eq!(escrow.status, EscrowStatus::Refunded);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_dust_in_milestone_holdings_should_not_block_last_release() -> Result<(), TransportError> {
+    let (mut context, _admin) = setup_test_env().await;
+    let funder = setup_wallet(&mut context, 10 * ONE_SCTK).await?;
+    let recipient = setup_wallet(&mut context, ONE_SCTK).await?;
+
+    let deadline = get_current_timestamp(&mut context.banks_client).await + 3_600;
+    try_open_milestone_escrow(&mut context, &funder, 0, &recipient.pubkey(), deadline, &[2 * ONE_SCTK, 3 * ONE_SCTK]).await?;
+    context.refresh().await;
+
+    // The recipient donates one unit straight to the holdings account
+    let escrow_pda = derive_milestone_escrow_pda(&context.program_id, 0);
+    context.transfer_tokens_to_user(&recipient, &escrow_pda, 1).await?;
+    context.refresh().await;
+
+    try_release_milestone(&mut context, &funder, 0, &funder.pubkey(), &recipient.pubkey(), 0).await?;
+    context.refresh().await;
+    try_release_milestone(&mut context, &funder, 0, &funder.pubkey(), &recipient.pubkey(), 1).await?;
+    context.refresh().await;
+
+    assert_eq!(context.get_user_balance(&funder.pubkey()).await, 5 * ONE_SCTK + 1, "❌ Surplus should be swept back to the funder");
+
+    let escrow = load_milestone_escrow(&mut context, 0).await?;
+    assert_eq!(escrow.status, EscrowStatus::Released);
+
+    Ok(())
+}
@@ -0,0 +1,160 @@
+use anchor_lang::AccountDeserialize;
+use soccial_token::escrow::{Escrow, EscrowError, EscrowStatus};
+use solana_program_test::*;
+use solana_sdk::pubkey::Pubkey;
+use solana_sdk::signature::Keypair;
+use solana_sdk::signer::Signer;
+use solana_sdk::transport::TransportError;
+
+mod testutils;
+mod trymethods;
+use crate::testutils::basics::{assert_custom_error, get_current_timestamp};
+use crate::testutils::environment::*;
+use crate::trymethods::tryescrow::*;
+
+const ONE_SCTK: u64 = 1_000_000_000;
+const ITEM_HASH: &str = "5c0cc1a1c0ab0ca7d5000000000000000000000000000000000000000000c0de";
+
+async fn load_escrow(context: &mut EnvProgramTestContext, id: u64) -> Result<Escrow, TransportError> {
+    let account = context.banks_client
+        .get_account(derive_escrow_pda(&context.program_id, id))
+        .await?
+        .expect("Escrow should exist");
+
+    Ok(Escrow::try_deserialize(&mut &account.data[..]).expect("Failed to deserialize"))
+}
+
+async fn open_default_escrow(
+    context: &mut EnvProgramTestContext,
+    maker: &Keypair,
+    taker: &Pubkey,
+    amount: u64,
+    arbiter: Option<Pubkey>,
+) -> Result<i64, TransportError> {
+    let deadline = get_current_timestamp(&mut context.banks_client).await + 3_600;
+    try_open_escrow(context, maker, 0, taker, amount, ITEM_HASH, deadline, arbiter).await?;
+    context.refresh().await;
+
+    Ok(deadline)
+}
+
+#[tokio::test]
+async fn test_release_escrow_should_pay_taker_with_fee() -> Result<(), TransportError> {
+    let (mut context, _admin) = setup_test_env().await;
+    let maker = setup_wallet(&mut context, 10 * ONE_SCTK).await?;
+    let taker = setup_wallet(&mut context, 0).await?;
+
+    let amount = 5 * ONE_SCTK;
+    open_default_escrow(&mut context, &maker, &taker.pubkey(), amount, None).await?;
+
+    assert_eq!(context.get_user_balance(&maker.pubkey()).await, 5 * ONE_SCTK, "❌ Maker funds should be held in escrow");
+
+    let escrow = load_escrow(&mut context, 0).await?;
+    assert_eq!(escrow.status, EscrowStatus::Open);
+    assert_eq!(escrow.item_hash[0], 0x5c);
+    assert_eq!(escrow.item_hash[31], 0xde);
+
+    let state = context.load_token_state().await;
+    let fee = amount * state.fee_schedule.transfer_fee_bps as u64 / 10_000;
+
+    // Only the maker can release
+    let result = try_release_escrow(&mut context, &taker, 0, &maker.pubkey(), &taker.pubkey()).await;
+    assert_custom_error(result, EscrowError::NotEscrowParty, "Expected NotEscrowParty when the taker releases");
+
+    try_release_escrow(&mut context, &maker, 0, &maker.pubkey(), &taker.pubkey()).await?;
+    context.refresh().await;
+
+    assert_eq!(context.get_user_balance(&taker.pubkey()).await, amount - fee, "❌ Taker should receive the net amount");
+
+    let escrow = load_escrow(&mut context, 0).await?;
+    assert_eq!(escrow.status, EscrowStatus::Released);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_refund_escrow_by_taker_and_after_deadline() -> Result<(), TransportError> {
+    let (mut context, _admin) = setup_test_env().await;
+    let maker = setup_wallet(&mut context, 10 * ONE_SCTK).await?;
+    let taker = setup_wallet(&mut context, 0).await?;
+
+    open_default_escrow(&mut context, &maker, &taker.pubkey(), 4 * ONE_SCTK, None).await?;
+
+    // The maker must wait for the deadline
+    let result = try_refund_escrow(&mut context, &maker, 0, &maker.pubkey(), &taker.pubkey()).await;
+    assert_custom_error(result, EscrowError::DeadlineNotReached, "Expected DeadlineNotReached before the deadline");
+
+    context.warp_forward_seconds(3_601).await;
+    context.refresh().await;
+
+    try_refund_escrow(&mut context, &maker, 0, &maker.pubkey(), &taker.pubkey()).await?;
+    context.refresh().await;
+
+    assert_eq!(context.get_user_balance(&maker.pubkey()).await, 10 * ONE_SCTK, "❌ Maker should be fully refunded");
+
+    let escrow = load_escrow(&mut context, 0).await?;
+    assert_eq!(escrow.status, EscrowStatus::Refunded);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_arbitrate_escrow_should_follow_decision() -> Result<(), TransportError> {
+    let (mut context, _admin) = setup_test_env().await;
+    let maker = setup_wallet(&mut context, 10 * ONE_SCTK).await?;
+    let taker = setup_wallet(&mut context, 0).await?;
+    let arbiter = setup_wallet(&mut context, 0).await?;
+
+    open_default_escrow(&mut context, &maker, &taker.pubkey(), 3 * ONE_SCTK, Some(arbiter.pubkey())).await?;
+
+    let result = try_arbitrate_escrow(&mut context, &taker, 0, &maker.pubkey(), &taker.pubkey(), "release").await;
+    assert_custom_error(result, EscrowError::NotEscrowParty, "Expected NotEscrowParty for a non-arbiter");
+
+    try_arbitrate_escrow(&mut context, &arbiter, 0, &maker.pubkey(), &taker.pubkey(), "refund").await?;
+    context.refresh().await;
+
+    assert_eq!(context.get_user_balance(&maker.pubkey()).await, 10 * ONE_SCTK, "❌ Arbiter refund should return all funds");
+
+    // A settled escrow cannot be settled again
+    let result = try_release_escrow(&mut context, &maker, 0, &maker.pubkey(), &taker.pubkey()).await;
+    assert!(result.is_err(), "Expected settled escrow to reject release");
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_open_escrow_with_invalid_hash_should_fail() -> Result<(), TransportError> {
+    let (mut context, _admin) = setup_test_env().await;
+    let maker = setup_wallet(&mut context, 10 * ONE_SCTK).await?;
+    let taker = setup_wallet(&mut context, 0).await?;
+
+    let deadline = get_current_timestamp(&mut context.banks_client).await + 3_600;
+    let result = try_open_escrow(&mut context, &maker, 0, &taker.pubkey(), ONE_SCTK, "not-a-hash", deadline, None).await;
+    assert_custom_error(result, EscrowError::InvalidItemHash, "Expected InvalidItemHash for a malformed hash");
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_dust_in_escrow_holdings_should_not_block_settlement() -> Result<(), TransportError> {
+    let (mut context, _admin) = setup_test_env().await;
+    let maker = setup_wallet(&mut context, 10 * ONE_SCTK).await?;
+    let taker = setup_wallet(&mut context, ONE_SCTK).await?;
+
+    open_default_escrow(&mut context, &maker, &taker.pubkey(), 4 * ONE_SCTK, None).await?;
+
+    // The taker donates one unit straight to the holdings account
+    let escrow_pda = derive_escrow_pda(&context.program_id, 0);
+    context.transfer_tokens_to_user(&taker, &escrow_pda, 1).await?;
+    context.refresh().await;
+
+    try_refund_escrow(&mut context, &taker, 0, &maker.pubkey(), &taker.pubkey()).await?;
+    context.refresh().await;
+
+    assert_eq!(context.get_user_balance(&maker.pubkey()).await, 10 * ONE_SCTK + 1, "❌ Maker should get the whole holdings back");
+
+    let escrow = load_escrow(&mut context, 0).await?;
+    assert_eq!(escrow.status, EscrowStatus::Refunded);
+
+    Ok(())
+}
@@ -0,0 +1,189 @@
+use soccial_token::market::MarketError;
+use soccial_token::utils::error::ErrorCode;
+use solana_program_test::*;
+use solana_sdk::pubkey::Pubkey;
+use solana_sdk::signature::Keypair;
+use solana_sdk::signer::Signer;
+use solana_sdk::transport::TransportError;
+
+mod testutils;
+mod trymethods;
+use crate::testutils::basics::{assert_custom_error, derive_user_ata};
+use crate::testutils::environment::*;
+use crate::trymethods::trymarket::*;
+
+/// Creates a funded sender and `count` recipients with ATAs.
+async fn setup_batch(
+    context: &mut EnvProgramTestContext,
+    sender_balance: u64,
+    count: usize,
+) -> Result<(Keypair, Vec<Keypair>), TransportError> {
+    let sender = Keypair::new();
+    create_user_ata(context, &sender).await?;
+    context.mint_tokens_to_user(&sender.pubkey(), sender_balance).await;
+
+    let mut recipients = vec![];
+    for _ in 0..count {
+        let recipient = Keypair::new();
+        create_user_ata(context, &recipient).await?;
+        recipients.push(recipient);
+    }
+
+    Ok((sender, recipients))
+}
+
+fn atas_for(program_id: &Pubkey, recipients: &[Keypair]) -> Vec<Pubkey> {
+    recipients
+        .iter()
+        .map(|recipient| derive_user_ata(program_id, &recipient.pubkey()))
+        .collect()
+}
+
+#[tokio::test]
+async fn test_batch_transfer_per_leg_should_succeed() -> Result<(), TransportError> {
+    let (mut context, admin) = setup_test_env().await;
+    let program_id = context.program_id;
+
+    let amounts = [5_000_000_000u64, 3_000_000_000, 2_000_000_000];
+    let (sender, recipients) = setup_batch(&mut context, 20_000_000_000, amounts.len()).await?;
+
+    let state = context.load_token_state().await;
+    let fee_bps = state.fee_schedule.transfer_fee_bps as u64;
+
+    let sender_before = context.get_user_balance(&sender.pubkey()).await;
+    let revenue_before = context.get_vault_balance("revenue").await;
+    let rewards_before = context.get_vault_balance("rewards").await;
+    let airdrop_before = context.get_vault_balance("airdrop").await;
+
+    try_batch_transfer_tokens(
+        &mut context,
+        &admin,
+        &sender,
+        "per_leg",
+        &atas_for(&program_id, &recipients),
+        &amounts,
+    ).await?;
+
+    context.refresh().await;
+
+    let mut total_fee = 0;
+    for (recipient, amount) in recipients.iter().zip(amounts) {
+        let fee = amount * fee_bps / 10_000;
+        total_fee += fee;
+        let received = context.get_user_balance(&recipient.pubkey()).await;
+        assert_eq!(received, amount - fee, "❌ Each recipient should receive its net amount");
+    }
+
+    let sender_after = context.get_user_balance(&sender.pubkey()).await;
+    assert_eq!(sender_before - sender_after, amounts.iter().sum::<u64>(), "❌ Sender should pay exactly the batch total");
+
+    let vaults_delta = (context.get_vault_balance("revenue").await - revenue_before)
+        + (context.get_vault_balance("rewards").await - rewards_before)
+        + (context.get_vault_balance("airdrop").await - airdrop_before);
+    assert_eq!(vaults_delta, total_fee, "❌ Vaults should receive the summed per-leg fees");
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_batch_transfer_aggregate_should_charge_sender() -> Result<(), TransportError> {
+    let (mut context, admin) = setup_test_env().await;
+    let program_id = context.program_id;
+
+    let amounts = [4_000_000_000u64, 6_000_000_000];
+    let (sender, recipients) = setup_batch(&mut context, 20_000_000_000, amounts.len()).await?;
+
+    let state = context.load_token_state().await;
+    let total: u64 = amounts.iter().sum();
+    let expected_fee = total * state.fee_schedule.transfer_fee_bps as u64 / 10_000;
+
+    let sender_before = context.get_user_balance(&sender.pubkey()).await;
+
+    try_batch_transfer_tokens(
+        &mut context,
+        &admin,
+        &sender,
+        "aggregate",
+        &atas_for(&program_id, &recipients),
+        &amounts,
+    ).await?;
+
+    context.refresh().await;
+
+    for (recipient, amount) in recipients.iter().zip(amounts) {
+        let received = context.get_user_balance(&recipient.pubkey()).await;
+        assert_eq!(received, amount, "❌ Recipients should receive full amounts in aggregate mode");
+    }
+
+    let sender_after = context.get_user_balance(&sender.pubkey()).await;
+    assert_eq!(sender_before - sender_after, total + expected_fee, "❌ Sender should pay the total plus the aggregate fee");
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_batch_transfer_mismatched_lengths_should_fail() -> Result<(), TransportError> {
+    let (mut context, admin) = setup_test_env().await;
+    let program_id = context.program_id;
+
+    let (sender, recipients) = setup_batch(&mut context, 20_000_000_000, 2).await?;
+
+    // Two recipient accounts, one amount
+    let result = try_batch_transfer_tokens(
+        &mut context,
+        &admin,
+        &sender,
+        "per_leg",
+        &atas_for(&program_id, &recipients),
+        &[1_000_000_000],
+    ).await;
+
+    assert_custom_error(result, MarketError::InvalidBatch, "Expected InvalidBatch when recipients and amounts mismatch");
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_batch_transfer_insufficient_balance_should_fail() -> Result<(), TransportError> {
+    let (mut context, admin) = setup_test_env().await;
+    let program_id = context.program_id;
+
+    let amounts = [5_000_000_000u64, 6_000_000_000];
+    let (sender, recipients) = setup_batch(&mut context, 10_000_000_000, amounts.len()).await?;
+
+    let result = try_batch_transfer_tokens(
+        &mut context,
+        &admin,
+        &sender,
+        "per_leg",
+        &atas_for(&program_id, &recipients),
+        &amounts,
+    ).await;
+
+    assert_custom_error(result, MarketError::InsufficientFunds, "Expected InsufficientFunds when the batch exceeds the balance");
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_batch_transfer_without_permission_should_fail() -> Result<(), TransportError> {
+    let (mut context, _admin) = setup_test_env().await;
+    let program_id = context.program_id;
+
+    let amounts = [1_000_000_000u64];
+    let (sender, recipients) = setup_batch(&mut context, 10_000_000_000, amounts.len()).await?;
+    let intruder = Keypair::new();
+
+    let result = try_batch_transfer_tokens(
+        &mut context,
+        &intruder,
+        &sender,
+        "per_leg",
+        &atas_for(&program_id, &recipients),
+        &amounts,
+    ).await;
+
+    assert_custom_error(result, ErrorCode::Unauthorized, "Expected Unauthorized error for a batch transfer.");
+
+    Ok(())
+}
@@ -1,3 +1,4 @@
+use soccial_token::market::MarketError;
 use soccial_token::utils::error::ErrorCode;
 use soccial_token::vaults::VaultError;
 use solana_program_test::*;
@@ -15,20 +16,21 @@ use crate::trymethods::trymarket::*;
 #[tokio::test]
 async fn test_buy_tokens_with_fee_should_succeed() -> Result<(), TransportError> {
     let (mut context, admin) = setup_test_env().await;
+    configure_linear_curve(&mut context, &admin).await?;
 
     let buyer = Keypair::new();
     create_user_ata(&mut context, &buyer).await?;
 
     let amount = 12_000_000_000;
-    let fee_bps = 300; // 3%
 
     // Get fee config from TokenState
     let state = context.load_token_state().await;
+    let fee_bps = state.fee_schedule.buy_fee_bps as u64;
     let rewards_fee_bps = state.fee.rewards_fee_bps as u64;
     let airdrop_fee_bps = state.fee.airdrop_fee_bps as u64;
 
     // Compute total fee and sub-distributions
-    let fee = amount * fee_bps as u64 / 10_000;
+    let fee = amount * fee_bps / 10_000;
     let to_user = amount - fee;
     let to_rewards = fee * rewards_fee_bps / 10_000;
     let to_airdrop = fee * airdrop_fee_bps / 10_000;
@@ -42,7 +44,7 @@ async fn test_buy_tokens_with_fee_should_succeed() -> Result<(), TransportError>
     let revenue_before = context.get_vault_balance("revenue").await;
 
     // Perform buy
-    try_buy_tokens(&mut context, &admin, &buyer, amount, fee_bps).await?;
+    try_buy_tokens(&mut context, &admin, &buyer, amount, u64::MAX).await?;
 
     context.refresh().await;
 
@@ -101,11 +103,12 @@ async fn test_buy_tokens_with_fee_should_succeed() -> Result<(), TransportError>
 #[tokio::test]
 async fn test_buy_tokens_insufficient_balance_should_fail() -> Result<(), TransportError> {
     let (mut context, admin) = setup_test_env().await;
+    configure_linear_curve(&mut context, &admin).await?;
 
     let buyer = Keypair::new();
     create_user_ata(&mut context, &buyer).await?;
 
-    let result = try_buy_tokens(&mut context, &admin, &buyer, 1_100_000_000_000_000_000, 0).await;
+    let result = try_buy_tokens(&mut context, &admin, &buyer, 1_100_000_000_000_000_000, u64::MAX).await;
 
     assert_custom_error(result, VaultError::InsufficientVaultBalance, "Expected failure due to insufficient vault balance, but got success");
 
@@ -115,15 +118,36 @@ async fn test_buy_tokens_insufficient_balance_should_fail() -> Result<(), Transp
 #[tokio::test]
 async fn test_buy_tokens_without_permission_should_fail() -> Result<(), TransportError> {
 
-    let (mut context, _admin) = setup_test_env().await;
+    let (mut context, admin) = setup_test_env().await;
+    configure_linear_curve(&mut context, &admin).await?;
 
     let intruder = Keypair::new();
     create_user_ata(&mut context, &intruder).await?;
 
-     let result = try_buy_tokens(&mut context, &intruder, &intruder, 10_100, 0).await;
+     let result = try_buy_tokens(&mut context, &intruder, &intruder, 10_100, u64::MAX).await;
 
     assert_custom_error(result, ErrorCode::Unauthorized, "Expected Unauthorized error when withdrawing from vault.");
 
     
     Ok(())
-}
\ No newline at end of file
+}
+
+#[tokio::test]
+async fn test_buy_tokens_above_max_price_should_fail() -> Result<(), TransportError> {
+    let (mut context, admin) = setup_test_env().await;
+    configure_linear_curve(&mut context, &admin).await?;
+
+    let buyer = Keypair::new();
+    create_user_ata(&mut context, &buyer).await?;
+
+    // Buying 10 SCTK from a fresh curve prices at 10_000 + 5 = 10_005
+    try_buy_tokens(&mut context, &admin, &buyer, 10_000_000_000, 10_005).await?;
+    context.refresh().await;
+
+    // The curve has moved forward, so the same bound is now too low
+    let result = try_buy_tokens(&mut context, &admin, &buyer, 10_000_000_000, 10_005).await;
+
+    assert_custom_error(result, MarketError::PriceAboveMaximum, "Expected PriceAboveMaximum once the curve price exceeds max_price");
+
+    Ok(())
+}
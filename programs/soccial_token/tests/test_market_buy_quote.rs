@@ -0,0 +1,159 @@
+use soccial_token::market::MarketError;
+use solana_program_test::*;
+use solana_sdk::signature::Keypair;
+use solana_sdk::signer::Signer;
+use solana_sdk::transport::TransportError;
+
+mod testutils;
+mod trymethods;
+use crate::testutils::basics::{assert_custom_error, get_current_timestamp};
+use crate::testutils::environment::*;
+use crate::trymethods::trymarket::*;
+use crate::trymethods::trytoken::try_set_api_authority;
+
+const ONE_SCTK: u64 = 1_000_000_000;
+
+/// Configures SOL pricing, a linear curve (10_000 + 1 per SCTK sold) and a
+/// fresh API authority.
+async fn setup_quotes(
+    context: &mut EnvProgramTestContext,
+    admin: &Keypair,
+) -> Result<Keypair, TransportError> {
+    try_configure_pricing(
+        context,
+        admin,
+        vec![
+            "quote_mint=SOL".to_string(),
+            "buy_price=10000".to_string(),
+            "enabled=true".to_string(),
+        ],
+    ).await?;
+    try_configure_sale_curve(
+        context,
+        admin,
+        vec![
+            "curve_type=linear".to_string(),
+            "base_price=10000".to_string(),
+            "slope=1".to_string(),
+        ],
+    ).await?;
+
+    let api = Keypair::new();
+    try_set_api_authority(context, admin, &api.pubkey()).await?;
+    context.refresh().await;
+
+    Ok(api)
+}
+
+async fn setup_buyer(context: &mut EnvProgramTestContext) -> Result<Keypair, TransportError> {
+    let buyer = Keypair::new();
+    fund_lamports(context, &buyer, 1_000_000_000).await?;
+    create_user_ata(context, &buyer).await?;
+    context.refresh().await;
+
+    Ok(buyer)
+}
+
+#[tokio::test]
+async fn test_buy_with_quote_should_succeed_once() -> Result<(), TransportError> {
+    let (mut context, admin) = setup_test_env().await;
+    let api = setup_quotes(&mut context, &admin).await?;
+    let buyer = setup_buyer(&mut context).await?;
+
+    let amount = 10 * ONE_SCTK;
+    let expires_at = get_current_timestamp(&mut context.banks_client).await + 300;
+    let state = context.load_token_state().await;
+    let fee = amount * state.fee_schedule.buy_fee_bps as u64 / 10_000;
+
+    let treasury = derive_quote_treasury_pda(&context.program_id);
+    let treasury_before = context.banks_client.get_balance(treasury).await?;
+
+    // Buying 10 SCTK from a fresh curve prices at 10_000 + 5 = 10_005,
+    // below the signed maximum
+    try_buy_tokens_with_quote(&mut context, &buyer, &api, 7, amount, 20_000, expires_at).await?;
+    context.refresh().await;
+
+    let treasury_after = context.banks_client.get_balance(treasury).await?;
+    assert_eq!(
+        treasury_after - treasury_before,
+        10 * 10_005,
+        "❌ Buyer should pay the executed curve price, not the signed maximum"
+    );
+
+    assert_eq!(
+        context.get_user_balance(&buyer.pubkey()).await,
+        amount - fee,
+        "❌ Buyer should receive the quoted amount net of the buy fee"
+    );
+
+    let nonce = context.banks_client
+        .get_account(derive_quote_nonce_pda(&context.program_id, &buyer.pubkey(), 7))
+        .await?;
+    assert!(nonce.is_some(), "❌ Quote nonce should be recorded");
+
+    // The same nonce cannot be redeemed again, even for different terms
+    let result = try_buy_tokens_with_quote(&mut context, &buyer, &api, 7, ONE_SCTK, u64::MAX, expires_at).await;
+    assert!(result.is_err(), "❌ A quote nonce should only be redeemable once");
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_buy_with_quote_not_signed_by_api_should_fail() -> Result<(), TransportError> {
+    let (mut context, admin) = setup_test_env().await;
+    setup_quotes(&mut context, &admin).await?;
+    let buyer = setup_buyer(&mut context).await?;
+    let forger = Keypair::new();
+
+    let expires_at = get_current_timestamp(&mut context.banks_client).await + 300;
+    let result = try_buy_tokens_with_quote(&mut context, &buyer, &forger, 1, ONE_SCTK, u64::MAX, expires_at).await;
+
+    assert_custom_error(result, MarketError::InvalidQuoteSignature, "Expected InvalidQuoteSignature for a quote not signed by the API authority");
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_buy_with_expired_quote_should_fail() -> Result<(), TransportError> {
+    let (mut context, admin) = setup_test_env().await;
+    let api = setup_quotes(&mut context, &admin).await?;
+    let buyer = setup_buyer(&mut context).await?;
+
+    let expires_at = get_current_timestamp(&mut context.banks_client).await - 1;
+    let result = try_buy_tokens_with_quote(&mut context, &buyer, &api, 1, ONE_SCTK, u64::MAX, expires_at).await;
+
+    assert_custom_error(result, MarketError::QuoteExpired, "Expected QuoteExpired for a stale quote");
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_buy_with_quote_below_curve_price_should_fail() -> Result<(), TransportError> {
+    let (mut context, admin) = setup_test_env().await;
+    let api = setup_quotes(&mut context, &admin).await?;
+    let buyer = setup_buyer(&mut context).await?;
+
+    let expires_at = get_current_timestamp(&mut context.banks_client).await + 300;
+    let result = try_buy_tokens_with_quote(&mut context, &buyer, &api, 1, 10 * ONE_SCTK, 10_004, expires_at).await;
+
+    assert_custom_error(result, MarketError::PriceAboveMaximum, "Expected PriceAboveMaximum when the curve price exceeds the quote");
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_buy_with_quote_while_pricing_disabled_should_fail() -> Result<(), TransportError> {
+    let (mut context, admin) = setup_test_env().await;
+    let api = setup_quotes(&mut context, &admin).await?;
+    let buyer = setup_buyer(&mut context).await?;
+
+    try_configure_pricing(&mut context, &admin, vec!["enabled=false".to_string()]).await?;
+    context.refresh().await;
+
+    let expires_at = get_current_timestamp(&mut context.banks_client).await + 300;
+    let result = try_buy_tokens_with_quote(&mut context, &buyer, &api, 1, ONE_SCTK, u64::MAX, expires_at).await;
+
+    assert_custom_error(result, MarketError::PricingDisabled, "Expected PricingDisabled when on-chain pricing is off");
+
+    Ok(())
+}
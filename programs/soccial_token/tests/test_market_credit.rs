@@ -0,0 +1,120 @@
+use anchor_lang::AccountDeserialize;
+use soccial_token::market::{CreditLedger, CreditReceipt, MarketError};
+use solana_program_test::*;
+use solana_sdk::signer::Signer;
+use solana_sdk::transport::TransportError;
+
+mod testutils;
+mod trymethods;
+use crate::testutils::basics::assert_custom_error;
+use crate::testutils::environment::*;
+use crate::trymethods::trymarket::*;
+
+const ONE_SCTK: u64 = 1_000_000_000;
+
+#[tokio::test]
+async fn test_credit_offchain_wallet_should_succeed() -> Result<(), TransportError> {
+    let (mut context, _admin) = setup_test_env().await;
+    let holder = setup_wallet(&mut context, 10 * ONE_SCTK).await?;
+
+    let amount = 4 * ONE_SCTK;
+    let holder_before = context.get_user_balance(&holder.pubkey()).await;
+    let reserve_before = context.get_vault_balance("offchain_reserve").await;
+
+    try_credit_offchain_wallet(&mut context, &holder, amount, "soccial-user-42", 0).await?;
+    context.refresh().await;
+
+    let holder_after = context.get_user_balance(&holder.pubkey()).await;
+    let reserve_after = context.get_vault_balance("offchain_reserve").await;
+
+    assert_eq!(holder_before - holder_after, amount, "❌ Holder should send the full amount");
+    assert_eq!(reserve_after - reserve_before, amount, "❌ Off-chain reserve should receive the full amount");
+
+    let account = context.banks_client
+        .get_account(derive_credit_receipt_pda(&context.program_id, &holder.pubkey(), 0))
+        .await?
+        .expect("Receipt should exist");
+    let receipt = CreditReceipt::try_deserialize(&mut &account.data[..]).expect("Failed to deserialize");
+
+    assert_eq!(receipt.sequence, 0);
+    assert_eq!(receipt.user_reference, "soccial-user-42");
+    assert_eq!(receipt.owner, holder.pubkey());
+    assert_eq!(receipt.amount, amount);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_credit_offchain_wallet_should_advance_sequence() -> Result<(), TransportError> {
+    let (mut context, _admin) = setup_test_env().await;
+    let holder = setup_wallet(&mut context, 10 * ONE_SCTK).await?;
+
+    try_credit_offchain_wallet(&mut context, &holder, ONE_SCTK, "soccial-user-42", 0).await?;
+    context.refresh().await;
+    try_credit_offchain_wallet(&mut context, &holder, 2 * ONE_SCTK, "soccial-user-42", 1).await?;
+    context.refresh().await;
+
+    let account = context.banks_client
+        .get_account(derive_credit_ledger_pda(&context.program_id, &holder.pubkey()))
+        .await?
+        .expect("Ledger should exist");
+    let ledger = CreditLedger::try_deserialize(&mut &account.data[..]).expect("Failed to deserialize");
+
+    assert_eq!(ledger.last_sequence, 2, "❌ Ledger should have consumed two sequence numbers");
+    assert_eq!(ledger.total_credited, 3 * ONE_SCTK);
+
+    // Reusing a consumed sequence cannot create a second receipt
+    let result = try_credit_offchain_wallet(&mut context, &holder, ONE_SCTK, "soccial-user-42", 0).await;
+    assert!(result.is_err(), "❌ Receipt for a consumed sequence must not be recreated");
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_credit_offchain_wallet_sequences_are_per_holder() -> Result<(), TransportError> {
+    let (mut context, _admin) = setup_test_env().await;
+    let first = setup_wallet(&mut context, 10 * ONE_SCTK).await?;
+    let second = setup_wallet(&mut context, 10 * ONE_SCTK).await?;
+
+    // Both holders consume sequence 0 of their own stream
+    try_credit_offchain_wallet(&mut context, &first, ONE_SCTK, "soccial-user-1", 0).await?;
+    try_credit_offchain_wallet(&mut context, &second, 2 * ONE_SCTK, "soccial-user-2", 0).await?;
+    context.refresh().await;
+
+    for (holder, amount) in [(&first, ONE_SCTK), (&second, 2 * ONE_SCTK)] {
+        let account = context.banks_client
+            .get_account(derive_credit_receipt_pda(&context.program_id, &holder.pubkey(), 0))
+            .await?
+            .expect("Receipt should exist");
+        let receipt = CreditReceipt::try_deserialize(&mut &account.data[..]).expect("Failed to deserialize");
+
+        assert_eq!(receipt.owner, holder.pubkey());
+        assert_eq!(receipt.amount, amount);
+    }
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_credit_offchain_wallet_empty_reference_should_fail() -> Result<(), TransportError> {
+    let (mut context, _admin) = setup_test_env().await;
+    let holder = setup_wallet(&mut context, 10 * ONE_SCTK).await?;
+
+    let result = try_credit_offchain_wallet(&mut context, &holder, ONE_SCTK, "", 0).await;
+
+    assert_custom_error(result, MarketError::InvalidUserReference, "Expected InvalidUserReference for an empty reference");
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_credit_offchain_wallet_insufficient_balance_should_fail() -> Result<(), TransportError> {
+    let (mut context, _admin) = setup_test_env().await;
+    let holder = setup_wallet(&mut context, ONE_SCTK).await?;
+
+    let result = try_credit_offchain_wallet(&mut context, &holder, 2 * ONE_SCTK, "soccial-user-42", 0).await;
+
+    assert_custom_error(result, MarketError::InsufficientFunds, "Expected InsufficientFunds when crediting more than the balance");
+
+    Ok(())
+}
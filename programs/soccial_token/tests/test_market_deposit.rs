@@ -3,11 +3,14 @@ use soccial_token::vaults::VaultError;
 use solana_program_test::*;
 use solana_sdk::signature::Keypair;
 use solana_sdk::signer::Signer;
+use solana_sdk::system_instruction::SystemError;
 use solana_sdk::transport::TransportError;
 
 mod testutils;
 mod trymethods;
-use crate::testutils::basics::assert_custom_error;
+use crate::testutils::basics::{assert_custom_error, fund_test_accounts_manual};
+use anchor_lang::AccountDeserialize;
+use soccial_token::market::{MarketError, WithdrawalReceipt, MAX_REQUEST_ID_LEN};
 use crate::testutils::environment::*;
 use crate::testutils::environment::setup_test_env;
 use crate::trymethods::trymarket::*;
@@ -20,11 +23,12 @@ async fn test_deposit_tokens_should_succeed() -> Result<(), TransportError> {
     let (mut context, admin) = setup_test_env().await;
 
     let user = Keypair::new();
-    create_user_ata(&mut context, &user).await?;
+    let user_ata = create_user_ata(&mut context, &user).await?;
     context.mint_tokens(&user, 100_000_000_000).await;
+    fund_lamports(&mut context, &user, 1_000_000_000).await?;
+    try_activate_withdrawal_address(&mut context, &user, &user_ata).await?;
 
     let amount = 12_000_000_000;
-    let fee_bps = 300; // 3%
 
     //context.mint_tokens(&admin, 100_000_000_000).await;
     //try_deposit_offchain_reserve_vault(&mut context, &admin, 100_000_000_000).await?;
@@ -33,11 +37,12 @@ async fn test_deposit_tokens_should_succeed() -> Result<(), TransportError> {
 
     // Get fee config from TokenState
     let state = context.load_token_state().await;
+    let fee_bps = state.fee_schedule.withdrawal_fee_bps as u64;
     let rewards_fee_bps = state.fee.rewards_fee_bps as u64;
     let airdrop_fee_bps = state.fee.airdrop_fee_bps as u64;
 
     // Compute total fee and sub-distributions
-    let fee = amount * fee_bps as u64 / 10_000;
+    let fee = amount * fee_bps / 10_000;
     let to_user = amount - fee;
     let to_rewards = fee * rewards_fee_bps / 10_000;
     let to_airdrop = fee * airdrop_fee_bps / 10_000;
@@ -51,7 +56,7 @@ async fn test_deposit_tokens_should_succeed() -> Result<(), TransportError> {
     let revenue_before = context.get_vault_balance("revenue").await;
    
     // Perform deposit
-    try_deposit_tokens(&mut context, &admin, &user.pubkey(), amount, fee_bps).await?;
+    try_deposit_tokens(&mut context, &admin, &user.pubkey(), amount, "req-success").await?;
     
     context.refresh().await;
 
@@ -115,7 +120,7 @@ async fn test_deposit_tokens_insufficient_vault_balance_should_fail() -> Result<
     let user = Keypair::new();
     create_user_ata(&mut context, &user).await?;
 
-    let result = try_deposit_tokens(&mut context, &admin, &user.pubkey(), 1_100_000_000_000_000_000, 0).await;
+    let result = try_deposit_tokens(&mut context, &admin, &user.pubkey(), 1_100_000_000_000_000_000, "req-insufficient").await;
 
     assert_custom_error(result, VaultError::InsufficientVaultBalance, "Expected failure due to insufficient vault balance, but got success");
 
@@ -131,11 +136,117 @@ async fn test_deposi_without_permission_should_fail() -> Result<(), TransportErr
 
     let intruder = Keypair::new();
     create_user_ata(&mut context, &intruder).await?;
+    // The caller pays for the withdrawal receipt, so the intruder needs lamports
+    fund_test_accounts_manual(&mut context.banks_client, &[&intruder], 10_000_000, &context.payer, &context.recent_blockhash).await;
 
-     let result = try_deposit_tokens(&mut context, &intruder, &intruder.pubkey(), 10_100, 0).await;
+     let result = try_deposit_tokens(&mut context, &intruder, &intruder.pubkey(), 10_100, "req-intruder").await;
 
     assert_custom_error(result, ErrorCode::Unauthorized, "Expected Unauthorized error when withdrawing from vault.");
 
     
     Ok(())
-}
\ No newline at end of file
+}
+
+/// ❌ Replaying the same backend request ID must not pay the user twice.
+#[tokio::test]
+async fn test_deposit_tokens_replayed_request_should_fail() -> Result<(), TransportError> {
+    let (mut context, admin) = setup_test_env().await;
+
+    let user = Keypair::new();
+    let user_ata = create_user_ata(&mut context, &user).await?;
+    context.mint_tokens_to_vault("offchain_reserve", 100_000_000_000).await?;
+    fund_lamports(&mut context, &user, 1_000_000_000).await?;
+    try_activate_withdrawal_address(&mut context, &user, &user_ata).await?;
+
+    let amount = 5_000_000_000;
+    try_deposit_tokens(&mut context, &admin, &user.pubkey(), amount, "req-replay").await?;
+    context.refresh().await;
+
+    let user_before = context.get_user_balance(&user.pubkey()).await;
+
+    // Fresh blockhash, so the replay is not deduplicated as the same transaction
+    context.refresh().await;
+    let result = try_deposit_tokens(&mut context, &admin, &user.pubkey(), amount, "req-replay").await;
+    assert_custom_error(
+        result,
+        SystemError::AccountAlreadyInUse as u32,
+        "Expected the existing receipt to block the replay",
+    );
+
+    context.refresh().await;
+    let user_after = context.get_user_balance(&user.pubkey()).await;
+    assert_eq!(user_after, user_before, "❌ User must not be paid twice");
+
+    Ok(())
+}
+
+/// ✅ The receipt records the withdrawal for off-chain reconciliation.
+#[tokio::test]
+async fn test_deposit_tokens_should_record_receipt() -> Result<(), TransportError> {
+    let (mut context, admin) = setup_test_env().await;
+
+    let user = Keypair::new();
+    let user_ata = create_user_ata(&mut context, &user).await?;
+    context.mint_tokens_to_vault("offchain_reserve", 100_000_000_000).await?;
+    fund_lamports(&mut context, &user, 1_000_000_000).await?;
+    try_activate_withdrawal_address(&mut context, &user, &user_ata).await?;
+
+    let amount = 5_000_000_000;
+    try_deposit_tokens(&mut context, &admin, &user.pubkey(), amount, "req-receipt").await?;
+
+    let state = context.load_token_state().await;
+    let expected_fee = amount * state.fee_schedule.withdrawal_fee_bps as u64 / 10_000;
+
+    let receipt_pda = derive_withdrawal_receipt_pda(&context.program_id, "req-receipt");
+    let account = context.banks_client.get_account(receipt_pda).await?.expect("Receipt should exist");
+    let receipt = WithdrawalReceipt::try_deserialize(&mut &account.data[..]).expect("Failed to deserialize");
+
+    assert_eq!(receipt.request_id, "req-receipt");
+    assert_eq!(receipt.recipient, user.pubkey());
+    assert_eq!(receipt.destination, user_ata);
+    assert_eq!(receipt.amount, amount);
+    assert_eq!(receipt.fee_charged, expected_fee);
+
+    Ok(())
+}
+
+/// ✅ Request IDs longer than a PDA seed (e.g. UUIDs) are accepted.
+#[tokio::test]
+async fn test_deposit_tokens_with_uuid_request_id_should_succeed() -> Result<(), TransportError> {
+    let (mut context, admin) = setup_test_env().await;
+
+    let user = Keypair::new();
+    let user_ata = create_user_ata(&mut context, &user).await?;
+    context.mint_tokens_to_vault("offchain_reserve", 100_000_000_000).await?;
+    fund_lamports(&mut context, &user, 1_000_000_000).await?;
+    try_activate_withdrawal_address(&mut context, &user, &user_ata).await?;
+
+    let request_id = "3f2b8c1e-9a4d-4e7b-b1c6-0d5e8f7a2c94";
+    try_deposit_tokens(&mut context, &admin, &user.pubkey(), 5_000_000_000, request_id).await?;
+
+    let receipt_pda = derive_withdrawal_receipt_pda(&context.program_id, request_id);
+    let account = context.banks_client.get_account(receipt_pda).await?.expect("Receipt should exist");
+    let receipt = WithdrawalReceipt::try_deserialize(&mut &account.data[..]).expect("Failed to deserialize WithdrawalReceipt");
+    assert_eq!(receipt.request_id, request_id, "❌ Receipt should keep the full request ID");
+
+    Ok(())
+}
+
+/// ❌ Request IDs above `MAX_REQUEST_ID_LEN` are rejected.
+#[tokio::test]
+async fn test_deposit_tokens_with_oversized_request_id_should_fail() -> Result<(), TransportError> {
+    let (mut context, admin) = setup_test_env().await;
+
+    let user = Keypair::new();
+    let user_ata = create_user_ata(&mut context, &user).await?;
+    context.mint_tokens_to_vault("offchain_reserve", 100_000_000_000).await?;
+    fund_lamports(&mut context, &user, 1_000_000_000).await?;
+    try_activate_withdrawal_address(&mut context, &user, &user_ata).await?;
+
+    let request_id = "r".repeat(MAX_REQUEST_ID_LEN + 1);
+    let result = try_deposit_tokens(&mut context, &admin, &user.pubkey(), 5_000_000_000, &request_id).await;
+
+    assert_custom_error(result, MarketError::InvalidRequestId, "Expected InvalidRequestId for an oversized request ID");
+
+    Ok(())
+}
@@ -0,0 +1,187 @@
+use soccial_token::economics::EconomicsErrorCode;
+use soccial_token::utils::error::ErrorCode;
+use solana_program_test::*;
+use solana_sdk::signature::Keypair;
+use solana_sdk::signer::Signer;
+use solana_sdk::transport::TransportError;
+
+mod testutils;
+mod trymethods;
+use crate::testutils::basics::{assert_custom_error, derive_user_ata};
+use crate::testutils::environment::*;
+use crate::trymethods::trygovernance::try_approve_proposal_flow;
+use crate::trymethods::trymarket::*;
+use crate::trymethods::trytoken::*;
+use crate::trymethods::tryuser::*;
+
+async fn configure_discounts(
+    context: &mut EnvProgramTestContext,
+    admin: &Keypair,
+    args: Vec<String>,
+) -> Result<(), TransportError> {
+    let proposal_id = try_approve_proposal_flow(
+        context,
+        admin,
+        "fee discounts".to_string(),
+        vec!["AdjustTaxRate".to_string()],
+    ).await?;
+
+    try_update_fee_discounts(context, admin, args, proposal_id).await
+}
+
+async fn transfer_and_measure_fee(
+    context: &mut EnvProgramTestContext,
+    caller: &Keypair,
+    sender: &Keypair,
+    amount: u64,
+) -> Result<u64, TransportError> {
+    let program_id = context.program_id;
+    let recipient = Keypair::new();
+
+    create_user_ata(context, sender).await?;
+    create_user_ata(context, &recipient).await?;
+    context.mint_tokens_to_user(&sender.pubkey(), amount).await;
+
+    try_transfer_tokens(
+        context,
+        caller,
+        sender,
+        derive_user_ata(&program_id, &recipient.pubkey()),
+        amount,
+    ).await?;
+
+    context.refresh().await;
+
+    let received = context.get_user_balance(&recipient.pubkey()).await;
+    Ok(amount - received)
+}
+
+#[tokio::test]
+async fn test_transfer_staff_exempt_should_charge_no_fee() -> Result<(), TransportError> {
+    let (mut context, admin) = setup_test_env().await;
+
+    configure_discounts(&mut context, &admin, vec!["staff=10000".to_string()]).await?;
+    let sender = Keypair::new();
+    try_add_flag(&mut context, &admin, &sender.pubkey(), vec!["staff".to_string()]).await?;
+    context.refresh().await;
+
+    let fee = transfer_and_measure_fee(&mut context, &admin, &sender, 15_000_000_000).await?;
+
+    assert_eq!(fee, 0, "❌ Staff transfers should be fee exempt");
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_transfer_vip_should_pay_half_fee() -> Result<(), TransportError> {
+    let (mut context, admin) = setup_test_env().await;
+
+    configure_discounts(&mut context, &admin, vec!["vip=5000".to_string()]).await?;
+    let sender = Keypair::new();
+    try_add_flag(&mut context, &admin, &sender.pubkey(), vec!["vip".to_string()]).await?;
+    context.refresh().await;
+
+    let amount = 15_000_000_000;
+    let state = context.load_token_state().await;
+    let base_bps = state.fee_schedule.transfer_fee_bps as u64;
+    let expected_fee = amount * (base_bps * 5_000 / 10_000) / 10_000;
+
+    let fee = transfer_and_measure_fee(&mut context, &admin, &sender, amount).await?;
+
+    assert_eq!(fee, expected_fee, "❌ VIP should pay half of the scheduled transfer fee");
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_purchase_vip_should_pay_half_fee() -> Result<(), TransportError> {
+    let (mut context, admin) = setup_test_env().await;
+
+    configure_discounts(&mut context, &admin, vec!["vip=5000".to_string()]).await?;
+    try_configure_pricing(
+        &mut context,
+        &admin,
+        vec![
+            "quote_mint=SOL".to_string(),
+            "buy_price=1000000".to_string(),
+            "enabled=true".to_string(),
+        ],
+    ).await?;
+
+    let buyer = Keypair::new();
+    fund_lamports(&mut context, &buyer, 1_000_000_000).await?;
+    create_user_ata(&mut context, &buyer).await?;
+    try_add_flag(&mut context, &admin, &buyer.pubkey(), vec!["vip".to_string()]).await?;
+    context.refresh().await;
+
+    let amount = 10_000_000_000;
+    let state = context.load_token_state().await;
+    let base_bps = state.fee_schedule.buy_fee_bps as u64;
+    let expected_fee = amount * (base_bps * 5_000 / 10_000) / 10_000;
+
+    try_purchase_tokens(&mut context, &buyer, amount, u64::MAX, None).await?;
+    context.refresh().await;
+
+    let fee = amount - context.get_user_balance(&buyer.pubkey()).await;
+    assert_eq!(fee, expected_fee, "❌ VIP should pay half of the scheduled buy fee on on-chain purchases");
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_transfer_without_flag_should_pay_full_fee() -> Result<(), TransportError> {
+    let (mut context, admin) = setup_test_env().await;
+
+    configure_discounts(&mut context, &admin, vec!["vip=5000".to_string()]).await?;
+
+    let amount = 15_000_000_000;
+    let state = context.load_token_state().await;
+    let expected_fee = amount * state.fee_schedule.transfer_fee_bps as u64 / 10_000;
+
+    let fee = transfer_and_measure_fee(&mut context, &admin, &Keypair::new(), amount).await?;
+
+    assert_eq!(fee, expected_fee, "❌ Senders without flags should pay the full fee");
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_transfer_relayer_flag_should_not_discount_sender() -> Result<(), TransportError> {
+    let (mut context, admin) = setup_test_env().await;
+
+    configure_discounts(&mut context, &admin, vec!["staff=10000".to_string()]).await?;
+    try_add_flag(&mut context, &admin, &admin.pubkey(), vec!["staff".to_string()]).await?;
+    context.refresh().await;
+
+    let amount = 15_000_000_000;
+    let state = context.load_token_state().await;
+    let expected_fee = amount * state.fee_schedule.transfer_fee_bps as u64 / 10_000;
+
+    let fee = transfer_and_measure_fee(&mut context, &admin, &Keypair::new(), amount).await?;
+
+    assert_eq!(fee, expected_fee, "❌ The relayer's flags must not discount the sender's fee");
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_update_fee_discounts_above_max_should_fail() -> Result<(), TransportError> {
+    let (mut context, admin) = setup_test_env().await;
+
+    let result = configure_discounts(&mut context, &admin, vec!["vip=10001".to_string()]).await;
+
+    assert_custom_error(result, EconomicsErrorCode::InvalidDiscountValue, "Discount above 100% should fail");
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_update_fee_discounts_for_banned_should_fail() -> Result<(), TransportError> {
+    let (mut context, admin) = setup_test_env().await;
+
+    let result = configure_discounts(&mut context, &admin, vec!["banned=5000".to_string()]).await;
+
+    assert_custom_error(result, ErrorCode::InvalidArgument, "Banned users cannot receive a discount");
+
+    Ok(())
+}
@@ -0,0 +1,255 @@
+use soccial_token::market::MarketError;
+use solana_program_test::*;
+use solana_sdk::pubkey::Pubkey;
+use solana_sdk::signature::Keypair;
+use solana_sdk::signer::Signer;
+use solana_sdk::transport::TransportError;
+
+mod testutils;
+mod trymethods;
+use crate::testutils::basics::assert_custom_error;
+use crate::testutils::environment::*;
+use crate::trymethods::tryamm::{create_quote_mint, mint_quote_to};
+use crate::trymethods::trymarket::*;
+
+#[tokio::test]
+async fn test_purchase_tokens_with_sol_should_succeed() -> Result<(), TransportError> {
+    let (mut context, admin) = setup_test_env().await;
+
+    let price = 1_000_000; // 0.001 SOL per SCTK
+    try_configure_pricing(
+        &mut context,
+        &admin,
+        vec![
+            "quote_mint=SOL".to_string(),
+            format!("buy_price={}", price),
+            "enabled=true".to_string(),
+        ],
+    ).await?;
+
+    let buyer = Keypair::new();
+    fund_lamports(&mut context, &buyer, 1_000_000_000).await?;
+    create_user_ata(&mut context, &buyer).await?;
+
+    let amount = 10_000_000_000; // 10 SCTK
+    let expected_cost = amount / 1_000_000_000 * price;
+
+    // The buy fee comes from the governed fee schedule
+    let state = context.load_token_state().await;
+    let fee = amount * state.fee_schedule.buy_fee_bps as u64 / 10_000;
+
+    let treasury = derive_quote_treasury_pda(&context.program_id);
+    let treasury_before = context.banks_client.get_balance(treasury).await?;
+    let buyer_before = context.get_user_balance(&buyer.pubkey()).await;
+
+    try_purchase_tokens(&mut context, &buyer, amount, u64::MAX, None).await?;
+
+    context.refresh().await;
+
+    let treasury_after = context.banks_client.get_balance(treasury).await?;
+    let buyer_after = context.get_user_balance(&buyer.pubkey()).await;
+
+    assert_eq!(treasury_after - treasury_before, expected_cost, "❌ Quote treasury should receive the SOL payment");
+    assert_eq!(buyer_after - buyer_before, amount - fee, "❌ Buyer should receive the net SCTK amount");
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_purchase_tokens_should_follow_sale_curve() -> Result<(), TransportError> {
+    let (mut context, admin) = setup_test_env().await;
+
+    try_configure_pricing(
+        &mut context,
+        &admin,
+        vec![
+            "quote_mint=SOL".to_string(),
+            "buy_price=1000000".to_string(),
+            "enabled=true".to_string(),
+        ],
+    ).await?;
+    try_configure_sale_curve(
+        &mut context,
+        &admin,
+        vec![
+            "curve_type=linear".to_string(),
+            "base_price=2000000".to_string(),
+            "slope=100000".to_string(),
+        ],
+    ).await?;
+
+    let buyer = Keypair::new();
+    fund_lamports(&mut context, &buyer, 1_000_000_000).await?;
+    create_user_ata(&mut context, &buyer).await?;
+
+    let amount = 10_000_000_000; // 10 SCTK
+    let treasury = derive_quote_treasury_pda(&context.program_id);
+
+    // Midpoint of the first 10 SCTK is 5 SCTK → 2_500_000 per SCTK
+    let treasury_before = context.banks_client.get_balance(treasury).await?;
+    try_purchase_tokens(&mut context, &buyer, amount, u64::MAX, None).await?;
+    context.refresh().await;
+    let treasury_after = context.banks_client.get_balance(treasury).await?;
+    assert_eq!(treasury_after - treasury_before, 25_000_000, "❌ First purchase should be priced on the curve");
+
+    // The sale moved the curve: midpoint 15 SCTK → 3_500_000 per SCTK
+    try_purchase_tokens(&mut context, &buyer, amount, u64::MAX, None).await?;
+    context.refresh().await;
+    let treasury_final = context.banks_client.get_balance(treasury).await?;
+    assert_eq!(treasury_final - treasury_after, 35_000_000, "❌ Second purchase should be priced further up the curve");
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_purchase_tokens_above_max_price_should_fail() -> Result<(), TransportError> {
+    let (mut context, admin) = setup_test_env().await;
+
+    try_configure_pricing(
+        &mut context,
+        &admin,
+        vec![
+            "quote_mint=SOL".to_string(),
+            "buy_price=1000000".to_string(),
+            "enabled=true".to_string(),
+        ],
+    ).await?;
+    try_configure_sale_curve(
+        &mut context,
+        &admin,
+        vec![
+            "curve_type=linear".to_string(),
+            "base_price=2000000".to_string(),
+            "slope=100000".to_string(),
+        ],
+    ).await?;
+
+    let buyer = Keypair::new();
+    fund_lamports(&mut context, &buyer, 1_000_000_000).await?;
+    create_user_ata(&mut context, &buyer).await?;
+
+    // Buying 10 SCTK from a fresh curve prices at 2_500_000
+    try_purchase_tokens(&mut context, &buyer, 10_000_000_000, 2_500_000, None).await?;
+    context.refresh().await;
+
+    // The curve has moved forward, so the same bound is now too low
+    let result = try_purchase_tokens(&mut context, &buyer, 10_000_000_000, 2_500_000, None).await;
+
+    assert_custom_error(result, MarketError::PriceAboveMaximum, "Expected PriceAboveMaximum once the curve price exceeds max_price");
+
+    Ok(())
+}
+
+/// Configures pricing in a fresh SPL quote mint and funds `buyer` with
+/// `quote_amount` of it. Returns `(quote_mint, buyer_quote_ata, treasury_quote_ata)`.
+async fn setup_spl_pricing(
+    context: &mut EnvProgramTestContext,
+    admin: &Keypair,
+    buyer: &Keypair,
+    price: u64,
+    quote_amount: u64,
+) -> Result<(Pubkey, Pubkey, Pubkey), TransportError> {
+    let quote_mint = create_quote_mint(context).await?;
+
+    try_configure_pricing(
+        context,
+        admin,
+        vec![
+            format!("quote_mint={}", quote_mint),
+            format!("buy_price={}", price),
+            "enabled=true".to_string(),
+        ],
+    ).await?;
+
+    fund_lamports(context, buyer, 1_000_000_000).await?;
+    create_user_ata(context, buyer).await?;
+    let buyer_quote = mint_quote_to(context, &quote_mint, &buyer.pubkey(), quote_amount).await?;
+
+    let treasury = derive_quote_treasury_pda(&context.program_id);
+    let treasury_quote = mint_quote_to(context, &quote_mint, &treasury, 0).await?;
+
+    Ok((quote_mint, buyer_quote, treasury_quote))
+}
+
+#[tokio::test]
+async fn test_purchase_tokens_with_spl_quote_should_succeed() -> Result<(), TransportError> {
+    let (mut context, admin) = setup_test_env().await;
+    let buyer = Keypair::new();
+
+    let price = 2_000_000; // 2 quote tokens (6 decimals) per SCTK
+    let (_, buyer_quote, treasury_quote) = setup_spl_pricing(&mut context, &admin, &buyer, price, 50_000_000).await?;
+
+    let amount = 10_000_000_000; // 10 SCTK
+    let expected_cost = amount / 1_000_000_000 * price;
+
+    try_purchase_tokens(&mut context, &buyer, amount, u64::MAX, Some((buyer_quote, treasury_quote))).await?;
+
+    context.refresh().await;
+
+    let treasury_after = fetch_token_balance(&mut context.banks_client, &treasury_quote).await;
+    let buyer_quote_after = fetch_token_balance(&mut context.banks_client, &buyer_quote).await;
+
+    assert_eq!(treasury_after, expected_cost, "❌ Quote treasury ATA should receive the SPL payment");
+    assert_eq!(buyer_quote_after, 50_000_000 - expected_cost, "❌ Buyer should be charged the quote amount");
+    let state = context.load_token_state().await;
+    let fee = amount * state.fee_schedule.buy_fee_bps as u64 / 10_000;
+    assert_eq!(context.get_user_balance(&buyer.pubkey()).await, amount - fee, "❌ Buyer should receive the net SCTK amount");
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_purchase_tokens_with_mismatched_quote_ata_should_fail() -> Result<(), TransportError> {
+    let (mut context, admin) = setup_test_env().await;
+    let buyer = Keypair::new();
+
+    let (quote_mint, buyer_quote, treasury_quote) = setup_spl_pricing(&mut context, &admin, &buyer, 2_000_000, 50_000_000).await?;
+
+    // A same-mint account that is not the quote treasury ATA
+    let attacker = Keypair::new();
+    let attacker_quote = mint_quote_to(&mut context, &quote_mint, &attacker.pubkey(), 0).await?;
+
+    let result = try_purchase_tokens(&mut context, &buyer, 1_000_000_000, u64::MAX, Some((buyer_quote, attacker_quote))).await;
+    assert_custom_error(result, MarketError::InvalidQuoteAccount, "Expected InvalidQuoteAccount for a foreign treasury account");
+
+    // An account of another mint as the buyer's quote account
+    let other_mint = create_quote_mint(&mut context).await?;
+    let other_quote = mint_quote_to(&mut context, &other_mint, &buyer.pubkey(), 50_000_000).await?;
+
+    let result = try_purchase_tokens(&mut context, &buyer, 1_000_000_000, u64::MAX, Some((other_quote, treasury_quote))).await;
+    assert_custom_error(result, MarketError::InvalidQuoteAccount, "Expected InvalidQuoteAccount for a wrong-mint buyer account");
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_purchase_tokens_when_disabled_should_fail() -> Result<(), TransportError> {
+    let (mut context, admin) = setup_test_env().await;
+
+    try_configure_pricing(
+        &mut context,
+        &admin,
+        vec!["buy_price=1000000".to_string(), "enabled=false".to_string()],
+    ).await?;
+
+    let buyer = Keypair::new();
+    fund_lamports(&mut context, &buyer, 1_000_000_000).await?;
+    create_user_ata(&mut context, &buyer).await?;
+
+    let result = try_purchase_tokens(&mut context, &buyer, 1_000_000_000, u64::MAX, None).await;
+
+    assert_custom_error(result, MarketError::PricingDisabled, "Expected PricingDisabled when priced purchases are off");
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_update_price_config_without_approval_should_fail() -> Result<(), TransportError> {
+    let (mut context, admin) = setup_test_env().await;
+
+    let result = try_update_price_config(&mut context, &admin, vec!["buy_price=1".to_string()], 1).await;
+
+    assert!(result.is_err(), "🚨 Expected failure due to lack of governance approval.");
+
+    Ok(())
+}
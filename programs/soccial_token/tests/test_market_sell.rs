@@ -0,0 +1,167 @@
+use soccial_token::market::MarketError;
+use solana_program_test::*;
+use solana_sdk::signature::Keypair;
+use solana_sdk::signer::Signer;
+use solana_sdk::transport::TransportError;
+
+mod testutils;
+mod trymethods;
+use crate::testutils::basics::assert_custom_error;
+use crate::testutils::environment::*;
+use crate::trymethods::trymarket::*;
+
+const ONE_SCTK: u64 = 1_000_000_000;
+
+/// Configures SOL pricing with the given buyback cap and buys 10 SCTK so the
+/// quote treasury holds SOL for payouts.
+async fn setup_buyback(
+    context: &mut EnvProgramTestContext,
+    admin: &Keypair,
+    sell_price: u64,
+    epoch_cap: u64,
+) -> Result<Keypair, TransportError> {
+    try_configure_pricing(
+        context,
+        admin,
+        vec![
+            "quote_mint=SOL".to_string(),
+            "buy_price=1000000".to_string(),
+            format!("sell_price={}", sell_price),
+            "buyback_epoch_duration=86400".to_string(),
+            format!("buyback_epoch_cap={}", epoch_cap),
+            "enabled=true".to_string(),
+        ],
+    ).await?;
+
+    let holder = Keypair::new();
+    fund_lamports(context, &holder, 1_000_000_000).await?;
+    create_user_ata(context, &holder).await?;
+
+    try_purchase_tokens(context, &holder, 10 * ONE_SCTK, u64::MAX, None).await?;
+    context.refresh().await;
+
+    Ok(holder)
+}
+
+#[tokio::test]
+async fn test_sell_tokens_with_sol_should_succeed() -> Result<(), TransportError> {
+    let (mut context, admin) = setup_test_env().await;
+
+    let sell_price = 800_000;
+    let holder = setup_buyback(&mut context, &admin, sell_price, 100 * ONE_SCTK).await?;
+
+    let amount = 5 * ONE_SCTK;
+    let expected_payout = 5 * sell_price;
+
+    let treasury = derive_quote_treasury_pda(&context.program_id);
+    let treasury_before = context.banks_client.get_balance(treasury).await?;
+    let holder_sol_before = context.banks_client.get_balance(holder.pubkey()).await?;
+    let holder_before = context.get_user_balance(&holder.pubkey()).await;
+    let liquidity_before = context.get_vault_balance("liquidity").await;
+
+    try_sell_tokens(&mut context, &holder, amount, None).await?;
+
+    context.refresh().await;
+
+    let treasury_after = context.banks_client.get_balance(treasury).await?;
+    let holder_sol_after = context.banks_client.get_balance(holder.pubkey()).await?;
+    let holder_after = context.get_user_balance(&holder.pubkey()).await;
+    let liquidity_after = context.get_vault_balance("liquidity").await;
+
+    assert_eq!(treasury_before - treasury_after, expected_payout, "❌ Quote treasury should pay out the sale");
+    assert_eq!(holder_sol_after - holder_sol_before, expected_payout, "❌ Seller should receive the SOL payout");
+    assert_eq!(holder_before - holder_after, amount, "❌ Seller balance should decrease by the sold amount");
+    assert_eq!(liquidity_after - liquidity_before, amount, "❌ Liquidity vault should receive the sold tokens");
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_sell_tokens_exceeding_epoch_cap_should_fail() -> Result<(), TransportError> {
+    let (mut context, admin) = setup_test_env().await;
+
+    let holder = setup_buyback(&mut context, &admin, 800_000, ONE_SCTK).await?;
+
+    let result = try_sell_tokens(&mut context, &holder, 2 * ONE_SCTK, None).await;
+
+    assert_custom_error(result, MarketError::BuybackCapExceeded, "Expected BuybackCapExceeded when selling above the epoch cap");
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_sell_tokens_without_sell_price_should_fail() -> Result<(), TransportError> {
+    let (mut context, admin) = setup_test_env().await;
+
+    let holder = setup_buyback(&mut context, &admin, 0, 100 * ONE_SCTK).await?;
+
+    let result = try_sell_tokens(&mut context, &holder, ONE_SCTK, None).await;
+
+    assert_custom_error(result, MarketError::PricingDisabled, "Expected PricingDisabled when no sell price is set");
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_sell_price_above_buy_price_should_fail() -> Result<(), TransportError> {
+    let (mut context, admin) = setup_test_env().await;
+
+    let result = try_configure_pricing(
+        &mut context,
+        &admin,
+        vec![
+            "quote_mint=SOL".to_string(),
+            "buy_price=1000000".to_string(),
+            "sell_price=1000001".to_string(),
+            "enabled=true".to_string(),
+        ],
+    ).await;
+
+    assert_custom_error(result, MarketError::InvalidPriceSpread, "Expected InvalidPriceSpread when sell price exceeds buy price");
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_sell_price_above_curve_base_price_should_fail() -> Result<(), TransportError> {
+    let (mut context, admin) = setup_test_env().await;
+
+    try_configure_pricing(
+        &mut context,
+        &admin,
+        vec![
+            "quote_mint=SOL".to_string(),
+            "buy_price=1000000".to_string(),
+            "sell_price=800000".to_string(),
+            "enabled=true".to_string(),
+        ],
+    ).await?;
+
+    // A curve starting below the buyback price is rejected
+    let result = try_configure_sale_curve(
+        &mut context,
+        &admin,
+        vec![
+            "curve_type=linear".to_string(),
+            "base_price=700000".to_string(),
+            "slope=1".to_string(),
+        ],
+    ).await;
+    assert_custom_error(result, MarketError::InvalidPriceSpread, "Expected InvalidPriceSpread when the curve starts below the sell price");
+
+    try_configure_sale_curve(
+        &mut context,
+        &admin,
+        vec![
+            "curve_type=linear".to_string(),
+            "base_price=900000".to_string(),
+            "slope=1".to_string(),
+        ],
+    ).await?;
+
+    // Raising the sell price above the curve's base is rejected too
+    let result = try_configure_pricing(&mut context, &admin, vec!["sell_price=900001".to_string()]).await;
+    assert_custom_error(result, MarketError::InvalidPriceSpread, "Expected InvalidPriceSpread when the sell price exceeds the curve base");
+
+    Ok(())
+}
@@ -26,11 +26,11 @@ async fn test_transfer_tokens_success() -> Result<(), TransportError> {
 
     let initial_deposit = 20_000_000_000;
     let transfer_amount = 15_000_000_000;
-    let fee_bps = 20u64; // 0.2%
 
     context.mint_tokens_to_user(&sender.pubkey(), initial_deposit).await;
     
     let state = context.load_token_state().await;
+    let fee_bps = state.fee_schedule.transfer_fee_bps as u64;
     let rewards_fee_bps = state.fee.rewards_fee_bps as u64;
     let airdrop_fee_bps = state.fee.airdrop_fee_bps as u64;
 
@@ -52,7 +52,6 @@ async fn test_transfer_tokens_success() -> Result<(), TransportError> {
         &sender,
         recipient_ata,
         transfer_amount,
-        fee_bps as u16,
     )
     .await?;
 
@@ -119,7 +118,6 @@ async fn test_transfer_tokens_insufficient_balance_should_fail() -> Result<(), T
     create_user_ata(&mut context, &recipient).await?;
 
     let excessive_amount = 100_000_000_000;
-    let fee_bps = 20;
 
     let result = try_transfer_tokens(
         &mut context,
@@ -127,7 +125,6 @@ async fn test_transfer_tokens_insufficient_balance_should_fail() -> Result<(), T
         &sender,
         derive_user_ata(&program_id, &recipient.pubkey()),
         excessive_amount,
-        fee_bps,
     )
     .await;
 
@@ -154,7 +151,6 @@ async fn test_transfer_tokens_without_permissions_should_fail() -> Result<(), Tr
     create_user_ata(&mut context, &intruder).await?;
 
     let excessive_amount = 100_000_000_000;
-    let fee_bps = 200;
 
     let result = try_transfer_tokens(
         &mut context,
@@ -162,7 +158,6 @@ async fn test_transfer_tokens_without_permissions_should_fail() -> Result<(), Tr
         &sender,
         derive_user_ata(&program_id, &recipient.pubkey()),
         excessive_amount,
-        fee_bps,
     )
     .await;
 
@@ -0,0 +1,131 @@
+use soccial_token::market::{MarketError, DEFAULT_ADDRESS_COOLDOWN_SECS};
+use solana_program_test::*;
+use solana_sdk::signature::Keypair;
+use solana_sdk::signer::Signer;
+use solana_sdk::transport::TransportError;
+
+mod testutils;
+mod trymethods;
+use crate::testutils::basics::assert_custom_error;
+use crate::testutils::environment::*;
+use crate::trymethods::trygovernance::try_approve_proposal_flow;
+use crate::trymethods::trymarket::*;
+use crate::trymethods::trytoken::try_update_withdrawal_settings;
+
+const AMOUNT: u64 = 5_000_000_000;
+
+/// Creates a funded user with an SCTK ATA and a funded off-chain reserve.
+async fn setup_user(context: &mut EnvProgramTestContext) -> Result<(Keypair, solana_sdk::pubkey::Pubkey), TransportError> {
+    let user = Keypair::new();
+    let user_ata = create_user_ata(context, &user).await?;
+    fund_lamports(context, &user, 1_000_000_000).await?;
+    context.mint_tokens_to_vault("offchain_reserve", 100_000_000_000).await?;
+
+    Ok((user, user_ata))
+}
+
+#[tokio::test]
+async fn test_deposit_to_unregistered_destination_should_fail() -> Result<(), TransportError> {
+    let (mut context, admin) = setup_test_env().await;
+    let (user, _) = setup_user(&mut context).await?;
+
+    let result = try_deposit_tokens(&mut context, &admin, &user.pubkey(), AMOUNT, "req-no-book").await;
+    assert_custom_error(result, MarketError::AddressNotRegistered, "Expected AddressNotRegistered without a book");
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_deposit_during_cooldown_should_fail() -> Result<(), TransportError> {
+    let (mut context, admin) = setup_test_env().await;
+    let (user, user_ata) = setup_user(&mut context).await?;
+
+    try_add_withdrawal_address(&mut context, &user, &user_ata).await?;
+
+    let result = try_deposit_tokens(&mut context, &admin, &user.pubkey(), AMOUNT, "req-cooldown").await;
+    assert_custom_error(result, MarketError::AddressInCooldown, "Expected AddressInCooldown right after registering");
+
+    context.warp_forward_seconds(DEFAULT_ADDRESS_COOLDOWN_SECS as u64 + 1).await;
+    context.refresh().await;
+
+    let before = context.get_user_balance(&user.pubkey()).await;
+    try_deposit_tokens(&mut context, &admin, &user.pubkey(), AMOUNT, "req-active").await?;
+    assert!(context.get_user_balance(&user.pubkey()).await > before, "❌ Active destination should be paid");
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_removed_destination_should_fail() -> Result<(), TransportError> {
+    let (mut context, admin) = setup_test_env().await;
+    let (user, user_ata) = setup_user(&mut context).await?;
+
+    try_activate_withdrawal_address(&mut context, &user, &user_ata).await?;
+
+    let result = try_add_withdrawal_address(&mut context, &user, &user_ata).await;
+    assert_custom_error(result, MarketError::AddressAlreadyRegistered, "Expected AddressAlreadyRegistered");
+
+    try_remove_withdrawal_address(&mut context, &user, &user_ata).await?;
+
+    let result = try_deposit_tokens(&mut context, &admin, &user.pubkey(), AMOUNT, "req-removed").await;
+    assert_custom_error(result, MarketError::AddressNotRegistered, "Expected AddressNotRegistered after removal");
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_update_address_cooldown_via_governance() -> Result<(), TransportError> {
+    let (mut context, admin) = setup_test_env().await;
+
+    let proposal_id = try_approve_proposal_flow(
+        &mut context,
+        &admin,
+        "address cooldown".to_string(),
+        vec!["UpdateGovernance".to_string()],
+    ).await?;
+
+    let result = try_update_withdrawal_settings(&mut context, &admin, vec!["address_cooldown=60".to_string()], proposal_id).await;
+    assert_custom_error(result, MarketError::InvalidCooldown, "Expected InvalidCooldown below the minimum");
+
+    try_update_withdrawal_settings(&mut context, &admin, vec!["address_cooldown=3600".to_string()], proposal_id).await?;
+
+    let state = context.load_token_state().await;
+    assert_eq!(state.withdrawal.address_cooldown_secs, 3_600);
+
+    // New entries now activate after one hour
+    let (user, user_ata) = setup_user(&mut context).await?;
+    try_add_withdrawal_address(&mut context, &user, &user_ata).await?;
+    context.warp_forward_seconds(3_601).await;
+    context.refresh().await;
+
+    try_deposit_tokens(&mut context, &admin, &user.pubkey(), AMOUNT, "req-short-cooldown").await?;
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_deposit_with_another_users_address_book_should_fail() -> Result<(), TransportError> {
+    let (mut context, admin) = setup_test_env().await;
+    let (user, user_ata) = setup_user(&mut context).await?;
+    let (attacker, _) = setup_user(&mut context).await?;
+
+    // The attacker lists the user's account in their own book
+    try_activate_withdrawal_address(&mut context, &attacker, &user_ata).await?;
+
+    let attacker_book = derive_withdrawal_address_book_pda(&context.program_id, &attacker.pubkey());
+    let result = try_deposit_tokens_with_address_book(
+        &mut context,
+        &admin,
+        &user.pubkey(),
+        AMOUNT,
+        "req-foreign-book",
+        Some(attacker_book),
+    ).await;
+    assert_custom_error(
+        result,
+        anchor_lang::error::ErrorCode::ConstraintSeeds as u32,
+        "Expected ConstraintSeeds for a book not owned by the destination authority",
+    );
+
+    Ok(())
+}
@@ -0,0 +1,103 @@
+use soccial_token::oracle::OracleError;
+use soccial_token::utils::error::ErrorCode;
+use solana_program_test::*;
+use solana_sdk::signature::Keypair;
+use solana_sdk::signer::Signer;
+use solana_sdk::transport::TransportError;
+
+mod testutils;
+mod trymethods;
+use crate::testutils::basics::{assert_custom_error, derive_seeds, get_current_timestamp};
+use crate::testutils::environment::*;
+use crate::trymethods::tryoracle::*;
+use crate::trymethods::tryuser::try_assign_permission;
+
+#[tokio::test]
+async fn test_set_and_get_price_should_succeed() -> Result<(), TransportError> {
+    let (mut context, admin) = setup_test_env().await;
+    let reader = Keypair::new();
+
+    try_set_price(&mut context, &admin, 10_000, 50).await?;
+    context.refresh().await;
+
+    let now = get_current_timestamp(&mut context.banks_client).await;
+    let feed = derive_manual_price_feed_pda(&context.program_id);
+    let reading = try_get_price(&mut context, &reader, feed, vec![]).await?;
+
+    assert_eq!(reading.price, 10_000);
+    assert_eq!(reading.confidence, 50);
+    assert!(reading.publish_time <= now, "❌ Publish time should come from the clock");
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_set_price_requires_permission() -> Result<(), TransportError> {
+    let (mut context, admin) = setup_test_env().await;
+    let publisher = Keypair::new();
+    fund_lamports(&mut context, &publisher, 1_000_000_000).await?;
+
+    let result = try_set_price(&mut context, &publisher, 10_000, 50).await;
+    assert_custom_error(result, ErrorCode::Unauthorized, "Expected Unauthorized without set_price");
+
+    try_assign_permission(&mut context, &admin, &publisher.pubkey(), vec!["set_price".to_string()]).await?;
+    context.refresh().await;
+
+    try_set_price(&mut context, &publisher, 10_000, 50).await?;
+
+    let result = try_set_price(&mut context, &publisher, 0, 0).await;
+    assert_custom_error(result, OracleError::InvalidPrice, "Expected InvalidPrice for a zero price");
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_get_stale_price_should_fail() -> Result<(), TransportError> {
+    let (mut context, admin) = setup_test_env().await;
+    let reader = Keypair::new();
+    let feed = derive_manual_price_feed_pda(&context.program_id);
+
+    try_set_price(&mut context, &admin, 10_000, 50).await?;
+    context.warp_forward_seconds(301).await;
+    context.refresh().await;
+
+    let result = try_get_price(&mut context, &reader, feed, vec![]).await;
+    assert_custom_error(result.map(|_| ()), OracleError::StalePrice, "Expected StalePrice after the default max age");
+
+    // A looser bound accepts the same reading
+    let reading = try_get_price(&mut context, &reader, feed, vec!["3600".to_string()]).await?;
+    assert_eq!(reading.price, 10_000);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_get_price_with_wide_confidence_should_fail() -> Result<(), TransportError> {
+    let (mut context, admin) = setup_test_env().await;
+    let reader = Keypair::new();
+    let feed = derive_manual_price_feed_pda(&context.program_id);
+
+    // 5% confidence is above the default 2% bound
+    try_set_price(&mut context, &admin, 10_000, 500).await?;
+    context.refresh().await;
+
+    let result = try_get_price(&mut context, &reader, feed, vec![]).await;
+    assert_custom_error(result.map(|_| ()), OracleError::ConfidenceTooWide, "Expected ConfidenceTooWide above 2%");
+
+    let reading = try_get_price(&mut context, &reader, feed, vec!["300".to_string(), "500".to_string()]).await?;
+    assert_eq!(reading.confidence, 500);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_get_price_from_foreign_account_should_fail() -> Result<(), TransportError> {
+    let (mut context, _admin) = setup_test_env().await;
+    let reader = Keypair::new();
+    let token_state = derive_seeds(&context.program_id, &reader.pubkey()).token_state;
+
+    let result = try_get_price(&mut context, &reader, token_state, vec![]).await;
+    assert_custom_error(result.map(|_| ()), OracleError::InvalidPriceFeed, "Expected InvalidPriceFeed for a non-feed account");
+
+    Ok(())
+}
@@ -0,0 +1,144 @@
+use soccial_token::oracle::OracleError;
+use solana_program_test::*;
+use solana_sdk::pubkey::Pubkey;
+use solana_sdk::signature::Keypair;
+use solana_sdk::signer::Signer;
+use solana_sdk::transport::TransportError;
+
+mod testutils;
+mod trymethods;
+use crate::testutils::basics::assert_custom_error;
+use crate::testutils::environment::*;
+use crate::trymethods::tryamm::*;
+use crate::trymethods::trymarket::*;
+use crate::trymethods::tryoracle::*;
+
+/// Enables SOL pricing on the linear curve and returns a funded buyer.
+async fn setup_sol_market(
+    context: &mut EnvProgramTestContext,
+    admin: &Keypair,
+) -> Result<Keypair, TransportError> {
+    try_configure_pricing(
+        context,
+        admin,
+        vec![
+            "quote_mint=SOL".to_string(),
+            "buy_price=1".to_string(),
+            "enabled=true".to_string(),
+        ],
+    ).await?;
+    configure_linear_curve(context, admin).await?;
+
+    let buyer = Keypair::new();
+    fund_lamports(context, &buyer, 1_000_000_000).await?;
+    create_user_ata(context, &buyer).await?;
+
+    Ok(buyer)
+}
+
+/// Seeds an AMM pool and returns its quote mint and a funded trader.
+async fn setup_pool(
+    context: &mut EnvProgramTestContext,
+    admin: &Keypair,
+) -> Result<(Pubkey, Keypair), TransportError> {
+    let quote_mint = create_quote_mint(context).await?;
+    mint_quote_to(context, &quote_mint, &admin.pubkey(), 1_000_000_000).await?;
+    try_initialize_amm_pool(context, admin, &quote_mint, 1_000_000_000_000, 1_000_000_000, 100).await?;
+
+    let trader = Keypair::new();
+    fund_lamports(context, &trader, 1_000_000_000).await?;
+    create_user_ata(context, &trader).await?;
+    mint_quote_to(context, &quote_mint, &trader.pubkey(), 100_000_000).await?;
+
+    context.refresh().await;
+
+    Ok((quote_mint, trader))
+}
+
+#[tokio::test]
+async fn test_twap_follows_buy_prices() -> Result<(), TransportError> {
+    let (mut context, admin) = setup_test_env().await;
+    let buyer = setup_sol_market(&mut context, &admin).await?;
+    let reader = Keypair::new();
+    let sol = Pubkey::default();
+
+    // First trade, then hold its price for 10 minutes
+    try_purchase_tokens(&mut context, &buyer, 10_000_000_000, u64::MAX, None).await?;
+    context.warp_forward_seconds(600).await;
+    context.refresh().await;
+
+    let first = try_get_twap(&mut context, &reader, &sol, 300).await?;
+    assert_eq!(first.twap, first.last_price, "❌ TWAP should equal the only traded price");
+
+    // Second trade moves the curve up, then hold for another 10 minutes
+    try_purchase_tokens(&mut context, &buyer, 10_000_000_000, u64::MAX, None).await?;
+    context.warp_forward_seconds(600).await;
+    context.refresh().await;
+
+    let second = try_get_twap(&mut context, &reader, &sol, 300).await?;
+    assert!(second.last_price > first.last_price, "❌ Curve price should increase");
+    assert_eq!(second.twap, second.last_price, "❌ Recent window should only see the latest price");
+
+    // A window spanning both trades averages them
+    let blended = try_get_twap(&mut context, &reader, &sol, 900).await?;
+    assert!(
+        blended.twap > first.last_price && blended.twap < second.last_price,
+        "❌ Blended TWAP {} should lie between {} and {}",
+        blended.twap,
+        first.last_price,
+        second.last_price,
+    );
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_twap_rejects_uncovered_windows() -> Result<(), TransportError> {
+    let (mut context, admin) = setup_test_env().await;
+    let (quote_mint, trader) = setup_pool(&mut context, &admin).await?;
+    let reader = Keypair::new();
+
+    // The pool creates its accumulator, but nothing has traded yet
+    let result = try_get_twap(&mut context, &reader, &quote_mint, 300).await;
+    assert_custom_error(result.map(|_| ()), OracleError::NoPriceObservations, "Expected NoPriceObservations before any trade");
+
+    try_amm_swap(&mut context, &trader, &quote_mint, "buy", 10_000_000, 0).await?;
+    context.warp_forward_seconds(600).await;
+    context.refresh().await;
+
+    let result = try_get_twap(&mut context, &reader, &quote_mint, 0).await;
+    assert_custom_error(result.map(|_| ()), OracleError::InvalidWindow, "Expected InvalidWindow for a zero window");
+
+    let result = try_get_twap(&mut context, &reader, &quote_mint, 86_400).await;
+    assert_custom_error(result.map(|_| ()), OracleError::InsufficientHistory, "Expected InsufficientHistory beyond the first trade");
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_twap_is_kept_per_quote_mint() -> Result<(), TransportError> {
+    let (mut context, admin) = setup_test_env().await;
+    let buyer = setup_sol_market(&mut context, &admin).await?;
+    let (quote_mint, trader) = setup_pool(&mut context, &admin).await?;
+    let reader = Keypair::new();
+    let sol = Pubkey::default();
+
+    try_purchase_tokens(&mut context, &buyer, 10_000_000_000, u64::MAX, None).await?;
+    context.warp_forward_seconds(600).await;
+    context.refresh().await;
+    let sol_before = try_get_twap(&mut context, &reader, &sol, 300).await?;
+
+    // An AMM swap only moves the pool quote mint's TWAP
+    try_amm_swap(&mut context, &trader, &quote_mint, "buy", 10_000_000, 0).await?;
+    context.warp_forward_seconds(600).await;
+    context.refresh().await;
+
+    let sol_after = try_get_twap(&mut context, &reader, &sol, 300).await?;
+    let pool = try_get_twap(&mut context, &reader, &quote_mint, 300).await?;
+
+    assert_eq!(sol_after.last_price, sol_before.last_price, "❌ SOL TWAP should ignore AMM swaps");
+    assert_eq!(sol_after.last_timestamp, sol_before.last_timestamp, "❌ SOL TWAP should ignore AMM swaps");
+    assert_ne!(pool.last_price, sol_after.last_price, "❌ Pool TWAP should follow its own spot price");
+
+    Ok(())
+}
@@ -0,0 +1,127 @@
+use anchor_lang::AccountDeserialize;
+use soccial_token::payments::{Invoice, InvoiceStatus, PaymentsError};
+use solana_program_test::*;
+use solana_sdk::signature::Keypair;
+use solana_sdk::signer::Signer;
+use solana_sdk::transport::TransportError;
+
+mod testutils;
+mod trymethods;
+use crate::testutils::basics::{assert_custom_error, get_current_timestamp};
+use crate::testutils::environment::*;
+use crate::trymethods::trypayments::*;
+
+const ONE_SCTK: u64 = 1_000_000_000;
+
+async fn load_invoice(
+    context: &mut EnvProgramTestContext,
+    merchant: &Keypair,
+    order_id: &str,
+) -> Result<Invoice, TransportError> {
+    let account = context.banks_client
+        .get_account(derive_invoice_pda(&context.program_id, &merchant.pubkey(), order_id))
+        .await?
+        .expect("Invoice should exist");
+
+    Ok(Invoice::try_deserialize(&mut &account.data[..]).expect("Failed to deserialize"))
+}
+
+#[tokio::test]
+async fn test_pay_invoice_should_split_fee_and_mark_paid() -> Result<(), TransportError> {
+    let (mut context, _admin) = setup_test_env().await;
+    let merchant = setup_wallet(&mut context, 0).await?;
+    let payer = setup_wallet(&mut context, 10 * ONE_SCTK).await?;
+
+    let amount = 5 * ONE_SCTK;
+    try_create_invoice(&mut context, &merchant, "order-1001", amount, 0).await?;
+    context.refresh().await;
+
+    let state = context.load_token_state().await;
+    let fee = amount * state.fee_schedule.transfer_fee_bps as u64 / 10_000;
+    let to_rewards = fee * state.fee.rewards_fee_bps as u64 / 10_000;
+    let to_airdrop = fee * state.fee.airdrop_fee_bps as u64 / 10_000;
+    let to_revenue = fee - to_rewards - to_airdrop;
+
+    let payer_before = context.get_user_balance(&payer.pubkey()).await;
+    let rewards_before = context.get_vault_balance("rewards").await;
+    let airdrop_before = context.get_vault_balance("airdrop").await;
+    let revenue_before = context.get_vault_balance("revenue").await;
+
+    try_pay_invoice(&mut context, &payer, &merchant.pubkey(), "order-1001", amount).await?;
+    context.refresh().await;
+
+    assert_eq!(payer_before - context.get_user_balance(&payer.pubkey()).await, amount, "❌ Payer should pay the invoice amount");
+    assert_eq!(context.get_user_balance(&merchant.pubkey()).await, amount - fee, "❌ Merchant should receive the net amount");
+    assert_eq!(context.get_vault_balance("rewards").await - rewards_before, to_rewards);
+    assert_eq!(context.get_vault_balance("airdrop").await - airdrop_before, to_airdrop);
+    assert_eq!(context.get_vault_balance("revenue").await - revenue_before, to_revenue);
+
+    let invoice = load_invoice(&mut context, &merchant, "order-1001").await?;
+    assert_eq!(invoice.status, InvoiceStatus::Paid);
+    assert_eq!(invoice.payer, payer.pubkey());
+    assert_eq!(invoice.fee_charged, fee);
+
+    // A paid invoice cannot be settled twice
+    let result = try_pay_invoice(&mut context, &payer, &merchant.pubkey(), "order-1001", amount).await;
+    assert_custom_error(result, PaymentsError::InvoiceNotOpen, "Expected InvoiceNotOpen on second payment");
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_pay_invoice_with_wrong_amount_should_fail() -> Result<(), TransportError> {
+    let (mut context, _admin) = setup_test_env().await;
+    let merchant = setup_wallet(&mut context, 0).await?;
+    let payer = setup_wallet(&mut context, 10 * ONE_SCTK).await?;
+
+    try_create_invoice(&mut context, &merchant, "order-1002", 2 * ONE_SCTK, 0).await?;
+    context.refresh().await;
+
+    let result = try_pay_invoice(&mut context, &payer, &merchant.pubkey(), "order-1002", ONE_SCTK).await;
+    assert_custom_error(result, PaymentsError::AmountMismatch, "Expected AmountMismatch for underpayment");
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_pay_expired_invoice_should_fail() -> Result<(), TransportError> {
+    let (mut context, _admin) = setup_test_env().await;
+    let merchant = setup_wallet(&mut context, 0).await?;
+    let payer = setup_wallet(&mut context, 10 * ONE_SCTK).await?;
+
+    let now = get_current_timestamp(&mut context.banks_client).await;
+    try_create_invoice(&mut context, &merchant, "order-1003", ONE_SCTK, now + 60).await?;
+
+    context.warp_forward_seconds(120).await;
+    context.refresh().await;
+
+    let result = try_pay_invoice(&mut context, &payer, &merchant.pubkey(), "order-1003", ONE_SCTK).await;
+    assert_custom_error(result, PaymentsError::InvoiceExpired, "Expected InvoiceExpired after expiry");
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_cancel_invoice_should_block_payment() -> Result<(), TransportError> {
+    let (mut context, _admin) = setup_test_env().await;
+    let merchant = setup_wallet(&mut context, 0).await?;
+    let payer = setup_wallet(&mut context, 10 * ONE_SCTK).await?;
+
+    try_create_invoice(&mut context, &merchant, "order-1004", ONE_SCTK, 0).await?;
+    context.refresh().await;
+
+    // Only the merchant can cancel
+    let result = try_cancel_invoice(&mut context, &payer, &merchant.pubkey(), "order-1004").await;
+    assert_custom_error(result, PaymentsError::NotInvoiceMerchant, "Expected NotInvoiceMerchant for a stranger");
+
+    try_cancel_invoice(&mut context, &merchant, &merchant.pubkey(), "order-1004").await?;
+    context.refresh().await;
+
+    let invoice = load_invoice(&mut context, &merchant, "order-1004").await?;
+    assert_eq!(invoice.status, InvoiceStatus::Cancelled);
+
+    let result = try_pay_invoice(&mut context, &payer, &merchant.pubkey(), "order-1004", ONE_SCTK).await;
+    assert_custom_error(result, PaymentsError::InvoiceNotOpen, "Expected InvoiceNotOpen on cancelled invoice");
+
+    Ok(())
+}
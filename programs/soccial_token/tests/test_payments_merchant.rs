@@ -0,0 +1,137 @@
+use anchor_lang::AccountDeserialize;
+use soccial_token::payments::{Merchant, PaymentsError};
+use soccial_token::utils::error::ErrorCode;
+use solana_program_test::*;
+use solana_sdk::signature::Keypair;
+use solana_sdk::signer::Signer;
+use solana_sdk::transport::TransportError;
+
+mod testutils;
+mod trymethods;
+use crate::testutils::basics::{assert_custom_error, derive_user_ata};
+use crate::testutils::environment::*;
+use crate::trymethods::trypayments::*;
+
+const ONE_SCTK: u64 = 1_000_000_000;
+
+async fn load_merchant(
+    context: &mut EnvProgramTestContext,
+    merchant: &Keypair,
+) -> Result<Merchant, TransportError> {
+    let account = context.banks_client
+        .get_account(derive_merchant_pda(&context.program_id, &merchant.pubkey()))
+        .await?
+        .expect("Merchant should exist");
+
+    Ok(Merchant::try_deserialize(&mut &account.data[..]).expect("Failed to deserialize"))
+}
+
+#[tokio::test]
+async fn test_merchant_payment_should_route_fee_and_referral() -> Result<(), TransportError> {
+    let (mut context, admin) = setup_test_env().await;
+    let program_id = context.program_id;
+    let merchant = setup_wallet(&mut context, 0).await?;
+    let referrer = setup_wallet(&mut context, 0).await?;
+    let payer = setup_wallet(&mut context, 10 * ONE_SCTK).await?;
+
+    let settlement = derive_user_ata(&program_id, &merchant.pubkey());
+    let referrer_ata = derive_user_ata(&program_id, &referrer.pubkey());
+    let referrer_arg = format!("referrer={}", referrer_ata);
+
+    try_manage_merchant(
+        &mut context,
+        &admin,
+        &merchant.pubkey(),
+        &settlement,
+        vec!["fee_bps=200", referrer_arg.as_str(), "referral_share_bps=2500"],
+    ).await?;
+    context.refresh().await;
+
+    let amount = 4 * ONE_SCTK;
+    let fee = amount * 200 / 10_000;
+    let to_referrer = fee * 2_500 / 10_000;
+
+    let revenue_before = context.get_vault_balance("revenue").await;
+    let rewards_before = context.get_vault_balance("rewards").await;
+    let airdrop_before = context.get_vault_balance("airdrop").await;
+
+    try_merchant_payment(&mut context, &payer, &merchant.pubkey(), &settlement, Some(referrer_ata), amount).await?;
+    context.refresh().await;
+
+    assert_eq!(context.get_user_balance(&merchant.pubkey()).await, amount - fee, "❌ Merchant should receive the net amount");
+    assert_eq!(context.get_user_balance(&referrer.pubkey()).await, to_referrer, "❌ Referrer should receive its share of the fee");
+
+    let to_vaults = (context.get_vault_balance("revenue").await - revenue_before)
+        + (context.get_vault_balance("rewards").await - rewards_before)
+        + (context.get_vault_balance("airdrop").await - airdrop_before);
+    assert_eq!(to_vaults, fee - to_referrer, "❌ Remaining fee should follow the standard split");
+
+    let entry = load_merchant(&mut context, &merchant).await?;
+    assert_eq!(entry.total_volume, amount);
+    assert_eq!(entry.total_fees, fee);
+    assert_eq!(entry.total_referral_paid, to_referrer);
+    assert_eq!(entry.payment_count, 1);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_merchant_payment_without_referrer_account_should_fail() -> Result<(), TransportError> {
+    let (mut context, admin) = setup_test_env().await;
+    let program_id = context.program_id;
+    let merchant = setup_wallet(&mut context, 0).await?;
+    let referrer = setup_wallet(&mut context, 0).await?;
+    let payer = setup_wallet(&mut context, 10 * ONE_SCTK).await?;
+
+    let settlement = derive_user_ata(&program_id, &merchant.pubkey());
+    let referrer_arg = format!("referrer={}", derive_user_ata(&program_id, &referrer.pubkey()));
+
+    try_manage_merchant(
+        &mut context,
+        &admin,
+        &merchant.pubkey(),
+        &settlement,
+        vec![referrer_arg.as_str(), "referral_share_bps=5000"],
+    ).await?;
+    context.refresh().await;
+
+    let result = try_merchant_payment(&mut context, &payer, &merchant.pubkey(), &settlement, None, ONE_SCTK).await;
+    assert_custom_error(result, PaymentsError::InvalidReferrerAccount, "Expected InvalidReferrerAccount without referrer account");
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_merchant_payment_to_inactive_merchant_should_fail() -> Result<(), TransportError> {
+    let (mut context, admin) = setup_test_env().await;
+    let program_id = context.program_id;
+    let merchant = setup_wallet(&mut context, 0).await?;
+    let payer = setup_wallet(&mut context, 10 * ONE_SCTK).await?;
+
+    let settlement = derive_user_ata(&program_id, &merchant.pubkey());
+
+    try_manage_merchant(&mut context, &admin, &merchant.pubkey(), &settlement, vec![]).await?;
+    context.refresh().await;
+    try_manage_merchant(&mut context, &admin, &merchant.pubkey(), &settlement, vec!["active=false"]).await?;
+    context.refresh().await;
+
+    let result = try_merchant_payment(&mut context, &payer, &merchant.pubkey(), &settlement, None, ONE_SCTK).await;
+    assert_custom_error(result, PaymentsError::MerchantInactive, "Expected MerchantInactive for deactivated merchant");
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_manage_merchant_without_permission_should_fail() -> Result<(), TransportError> {
+    let (mut context, _admin) = setup_test_env().await;
+    let program_id = context.program_id;
+    let merchant = setup_wallet(&mut context, 0).await?;
+    let intruder = setup_wallet(&mut context, 0).await?;
+
+    let settlement = derive_user_ata(&program_id, &merchant.pubkey());
+
+    let result = try_manage_merchant(&mut context, &intruder, &merchant.pubkey(), &settlement, vec!["fee_bps=0"]).await;
+    assert_custom_error(result, ErrorCode::Unauthorized, "Expected Unauthorized when registering a merchant");
+
+    Ok(())
+}
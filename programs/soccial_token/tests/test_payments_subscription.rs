@@ -0,0 +1,190 @@
+use anchor_lang::AccountDeserialize;
+use soccial_token::payments::{PaymentsError, Subscription};
+use solana_program_test::*;
+use solana_sdk::program_pack::Pack;
+use solana_sdk::signature::Keypair;
+use solana_sdk::signer::Signer;
+use solana_sdk::transport::TransportError;
+
+mod testutils;
+mod trymethods;
+use crate::testutils::basics::{assert_custom_error, derive_user_ata};
+use crate::testutils::environment::*;
+use crate::trymethods::trypayments::*;
+
+const ONE_SCTK: u64 = 1_000_000_000;
+const PERIOD: i64 = 3_600;
+
+async fn load_subscription(
+    context: &mut EnvProgramTestContext,
+    payer: &Keypair,
+    merchant: &Keypair,
+) -> Result<Subscription, TransportError> {
+    let account = context.banks_client
+        .get_account(derive_subscription_pda(&context.program_id, &payer.pubkey(), &merchant.pubkey()))
+        .await?
+        .expect("Subscription should exist");
+
+    Ok(Subscription::try_deserialize(&mut &account.data[..]).expect("Failed to deserialize"))
+}
+
+async fn load_payer_ata(
+    context: &mut EnvProgramTestContext,
+    payer: &Keypair,
+) -> Result<spl_token::state::Account, TransportError> {
+    let account = context.banks_client
+        .get_account(derive_user_ata(&context.program_id, &payer.pubkey()))
+        .await?
+        .expect("Payer ATA should exist");
+
+    Ok(spl_token::state::Account::unpack(&account.data).expect("Failed to unpack"))
+}
+
+#[tokio::test]
+async fn test_charge_subscription_should_pull_one_period() -> Result<(), TransportError> {
+    let (mut context, _admin) = setup_test_env().await;
+    let merchant = setup_wallet(&mut context, 0).await?;
+    let payer = setup_wallet(&mut context, 10 * ONE_SCTK).await?;
+    let cranker = setup_wallet(&mut context, 0).await?;
+
+    let amount = 2 * ONE_SCTK;
+    try_create_subscription(&mut context, &payer, &merchant.pubkey(), amount, PERIOD, 3).await?;
+    context.refresh().await;
+
+    let state = context.load_token_state().await;
+    let fee = amount * state.fee_schedule.transfer_fee_bps as u64 / 10_000;
+    let payer_before = context.get_user_balance(&payer.pubkey()).await;
+
+    try_charge_subscription(&mut context, &cranker, &payer.pubkey(), &merchant.pubkey()).await?;
+    context.refresh().await;
+
+    assert_eq!(payer_before - context.get_user_balance(&payer.pubkey()).await, amount, "❌ Payer should be charged one period");
+    assert_eq!(context.get_user_balance(&merchant.pubkey()).await, amount - fee, "❌ Merchant should receive the net charge");
+
+    let subscription = load_subscription(&mut context, &payer, &merchant).await?;
+    assert_eq!(subscription.charge_count, 1);
+    assert_eq!(subscription.remaining_allowance, 2 * amount);
+    assert_eq!(subscription.next_charge_at, subscription.created_at + PERIOD);
+
+    // The next period is not due yet
+    let result = try_charge_subscription(&mut context, &cranker, &payer.pubkey(), &merchant.pubkey()).await;
+    assert_custom_error(result, PaymentsError::SubscriptionNotDue, "Expected SubscriptionNotDue before the next period");
+
+    context.warp_forward_seconds(PERIOD as u64 + 1).await;
+    context.refresh().await;
+
+    try_charge_subscription(&mut context, &cranker, &payer.pubkey(), &merchant.pubkey()).await?;
+    context.refresh().await;
+
+    let subscription = load_subscription(&mut context, &payer, &merchant).await?;
+    assert_eq!(subscription.charge_count, 2);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_charge_subscription_with_insufficient_funds_should_record_failure() -> Result<(), TransportError> {
+    let (mut context, _admin) = setup_test_env().await;
+    let merchant = setup_wallet(&mut context, 0).await?;
+    let payer = setup_wallet(&mut context, ONE_SCTK).await?;
+    let cranker = setup_wallet(&mut context, 0).await?;
+
+    try_create_subscription(&mut context, &payer, &merchant.pubkey(), 2 * ONE_SCTK, PERIOD, 1).await?;
+    context.refresh().await;
+
+    try_charge_subscription(&mut context, &cranker, &payer.pubkey(), &merchant.pubkey()).await?;
+    context.refresh().await;
+
+    assert_eq!(context.get_user_balance(&payer.pubkey()).await, ONE_SCTK, "❌ A failed charge should not move tokens");
+
+    let subscription = load_subscription(&mut context, &payer, &merchant).await?;
+    assert_eq!(subscription.charge_count, 0);
+    assert_eq!(subscription.failed_charges, 1);
+    assert_eq!(subscription.next_charge_at, subscription.created_at, "❌ The period should stay due");
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_charge_subscription_beyond_allowance_should_record_failure() -> Result<(), TransportError> {
+    let (mut context, _admin) = setup_test_env().await;
+    let merchant = setup_wallet(&mut context, 0).await?;
+    let payer = setup_wallet(&mut context, 10 * ONE_SCTK).await?;
+    let cranker = setup_wallet(&mut context, 0).await?;
+
+    try_create_subscription(&mut context, &payer, &merchant.pubkey(), ONE_SCTK, PERIOD, 1).await?;
+    context.refresh().await;
+
+    try_charge_subscription(&mut context, &cranker, &payer.pubkey(), &merchant.pubkey()).await?;
+    context.warp_forward_seconds(PERIOD as u64 + 1).await;
+    context.refresh().await;
+
+    let payer_before = context.get_user_balance(&payer.pubkey()).await;
+    try_charge_subscription(&mut context, &cranker, &payer.pubkey(), &merchant.pubkey()).await?;
+    context.refresh().await;
+
+    assert_eq!(context.get_user_balance(&payer.pubkey()).await, payer_before, "❌ Exhausted allowance should not be charged");
+
+    let subscription = load_subscription(&mut context, &payer, &merchant).await?;
+    assert_eq!(subscription.charge_count, 1);
+    assert_eq!(subscription.failed_charges, 1);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_cancel_subscription_by_parties_only() -> Result<(), TransportError> {
+    let (mut context, _admin) = setup_test_env().await;
+    let merchant = setup_wallet(&mut context, 0).await?;
+    let payer = setup_wallet(&mut context, 10 * ONE_SCTK).await?;
+    let stranger = setup_wallet(&mut context, 0).await?;
+
+    try_create_subscription(&mut context, &payer, &merchant.pubkey(), ONE_SCTK, PERIOD, 12).await?;
+    context.refresh().await;
+
+    let result = try_cancel_subscription(&mut context, &stranger, &payer.pubkey(), &merchant.pubkey()).await;
+    assert_custom_error(result, PaymentsError::NotSubscriptionParty, "Expected NotSubscriptionParty for a stranger");
+
+    try_cancel_subscription(&mut context, &merchant, &payer.pubkey(), &merchant.pubkey()).await?;
+    context.refresh().await;
+
+    let account = context.banks_client
+        .get_account(derive_subscription_pda(&context.program_id, &payer.pubkey(), &merchant.pubkey()))
+        .await?;
+    assert!(account.is_none(), "❌ Cancelled subscription should be closed");
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_cancel_subscription_by_payer_should_release_allowance() -> Result<(), TransportError> {
+    let (mut context, _admin) = setup_test_env().await;
+    let first_merchant = setup_wallet(&mut context, 0).await?;
+    let second_merchant = setup_wallet(&mut context, 0).await?;
+    let payer = setup_wallet(&mut context, 10 * ONE_SCTK).await?;
+
+    try_create_subscription(&mut context, &payer, &first_merchant.pubkey(), ONE_SCTK, PERIOD, 12).await?;
+    try_create_subscription(&mut context, &payer, &second_merchant.pubkey(), 2 * ONE_SCTK, PERIOD, 3).await?;
+    context.refresh().await;
+
+    let ata = load_payer_ata(&mut context, &payer).await?;
+    assert_eq!(ata.delegated_amount, 18 * ONE_SCTK, "❌ Approvals should stack");
+
+    // Only the cancelled subscription's allowance is released
+    try_cancel_subscription(&mut context, &payer, &payer.pubkey(), &first_merchant.pubkey()).await?;
+    context.refresh().await;
+
+    let ata = load_payer_ata(&mut context, &payer).await?;
+    assert_eq!(ata.delegate, Some(derive_subscription_delegate_pda(&context.program_id)).into());
+    assert_eq!(ata.delegated_amount, 6 * ONE_SCTK, "❌ The other subscription should keep its allowance");
+
+    // Cancelling the last subscription revokes the delegate
+    try_cancel_subscription(&mut context, &payer, &payer.pubkey(), &second_merchant.pubkey()).await?;
+    context.refresh().await;
+
+    let ata = load_payer_ata(&mut context, &payer).await?;
+    assert!(ata.delegate.is_none(), "❌ Delegate should be revoked once no allowance is left");
+    assert_eq!(ata.delegated_amount, 0);
+
+    Ok(())
+}
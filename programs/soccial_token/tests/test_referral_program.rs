@@ -0,0 +1,272 @@
+use soccial_token::economics::EconomicsErrorCode;
+use soccial_token::referral::ReferralError;
+use solana_program_test::*;
+use solana_sdk::signature::Keypair;
+use solana_sdk::signer::Signer;
+use solana_sdk::transport::TransportError;
+
+mod testutils;
+mod trymethods;
+use crate::testutils::basics::{assert_custom_error, derive_user_ata};
+use crate::testutils::environment::*;
+use crate::trymethods::trygovernance::try_approve_proposal_flow;
+use crate::trymethods::trymarket::*;
+use crate::trymethods::tryreferral::*;
+use crate::trymethods::trytoken::try_update_referral_settings;
+
+const ONE_SCTK: u64 = 1_000_000_000;
+
+async fn configure_referral(
+    context: &mut EnvProgramTestContext,
+    admin: &Keypair,
+    args: Vec<String>,
+) -> Result<(), TransportError> {
+    let proposal_id = try_approve_proposal_flow(
+        context,
+        admin,
+        "referral share".to_string(),
+        vec!["AdjustTaxRate".to_string()],
+    ).await?;
+
+    try_update_referral_settings(context, admin, args, proposal_id).await
+}
+
+#[tokio::test]
+async fn test_transfer_should_credit_referrer_and_claim_from_revenue() -> Result<(), TransportError> {
+    let (mut context, admin) = setup_test_env().await;
+    let program_id = context.program_id;
+    let referrer = setup_wallet(&mut context, 0).await?;
+    let referee = setup_wallet(&mut context, 100 * ONE_SCTK).await?;
+    let recipient = setup_wallet(&mut context, 0).await?;
+
+    try_register_referral(&mut context, &referee, &referrer.pubkey()).await?;
+    context.refresh().await;
+
+    let stats = load_referrer_account(&mut context, &referrer.pubkey()).await;
+    assert_eq!(stats.referral_count, 1);
+    assert_eq!(stats.claimable, 0);
+
+    let amount = 50 * ONE_SCTK;
+    let state = context.load_token_state().await;
+    let fee = amount * state.fee_schedule.transfer_fee_bps as u64 / 10_000;
+    let share = fee * state.referral.share_bps as u64 / 10_000;
+    assert!(share > 0, "❌ Default referral share should credit a non-zero amount");
+
+    let revenue_before = context.get_vault_balance("revenue").await;
+
+    try_transfer_tokens(
+        &mut context,
+        &admin,
+        &referee,
+        derive_user_ata(&program_id, &recipient.pubkey()),
+        amount,
+    ).await?;
+    context.refresh().await;
+
+    // The fee is still fully distributed; the credit is bookkeeping only
+    let revenue_after_transfer = context.get_vault_balance("revenue").await;
+    assert!(revenue_after_transfer - revenue_before >= share, "❌ Revenue vault should cover the credited share");
+    assert_eq!(context.get_user_balance(&recipient.pubkey()).await, amount - fee);
+
+    let stats = load_referrer_account(&mut context, &referrer.pubkey()).await;
+    assert_eq!(stats.claimable, share, "❌ Referrer should be credited its share of the fee");
+    assert_eq!(stats.total_earned, share);
+
+    try_claim_referral_rewards(&mut context, &referrer).await?;
+    context.refresh().await;
+
+    assert_eq!(context.get_user_balance(&referrer.pubkey()).await, share, "❌ Referrer should receive the claimable balance");
+    assert_eq!(context.get_vault_balance("revenue").await, revenue_after_transfer - share, "❌ Claim should be paid from the revenue vault");
+
+    let stats = load_referrer_account(&mut context, &referrer.pubkey()).await;
+    assert_eq!(stats.claimable, 0);
+    assert_eq!(stats.total_claimed, share);
+
+    // Nothing left to claim
+    let result = try_claim_referral_rewards(&mut context, &referrer).await;
+    assert_custom_error(result, ReferralError::NothingToClaim, "Expected NothingToClaim on a second claim");
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_transfer_with_tampered_referral_accounts_should_fail() -> Result<(), TransportError> {
+    let (mut context, admin) = setup_test_env().await;
+    let program_id = context.program_id;
+    let referrer = setup_wallet(&mut context, 0).await?;
+    let referee = setup_wallet(&mut context, 100 * ONE_SCTK).await?;
+    let recipient = setup_wallet(&mut context, 0).await?;
+
+    try_register_referral(&mut context, &referee, &referrer.pubkey()).await?;
+    context.refresh().await;
+
+    let recipient_ata = derive_user_ata(&program_id, &recipient.pubkey());
+    let referral = derive_referral_pda(&program_id, &referee.pubkey());
+
+    // The relayer cannot swap in another user's empty referral PDA
+    let result = try_transfer_tokens_with_referral(
+        &mut context,
+        &admin,
+        &referee,
+        recipient_ata,
+        ONE_SCTK,
+        derive_referral_pda(&program_id, &recipient.pubkey()),
+        derive_referrer_account_pda(&program_id, &referrer.pubkey()),
+    ).await;
+    assert_custom_error(result, anchor_lang::error::ErrorCode::ConstraintSeeds as u32, "Expected ConstraintSeeds for a foreign referral PDA");
+
+    // Nor point the credit at a referrer account that is not the referral's
+    let result = try_transfer_tokens_with_referral(
+        &mut context,
+        &admin,
+        &referee,
+        recipient_ata,
+        ONE_SCTK,
+        referral,
+        derive_referrer_account_pda(&program_id, &recipient.pubkey()),
+    ).await;
+    assert_custom_error(result, ReferralError::InvalidReferrerAccount, "Expected InvalidReferrerAccount for a foreign referrer account");
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_buy_should_credit_referrer() -> Result<(), TransportError> {
+    let (mut context, admin) = setup_test_env().await;
+    try_configure_sale_curve(
+        &mut context,
+        &admin,
+        vec!["curve_type=linear".to_string(), "base_price=10000".to_string(), "slope=1".to_string()],
+    ).await?;
+    let referrer = setup_wallet(&mut context, 0).await?;
+    let buyer = setup_wallet(&mut context, 0).await?;
+
+    try_register_referral(&mut context, &buyer, &referrer.pubkey()).await?;
+    context.refresh().await;
+
+    let amount = 10 * ONE_SCTK;
+    let state = context.load_token_state().await;
+    let fee = amount * state.fee_schedule.buy_fee_bps as u64 / 10_000;
+    let share = fee * state.referral.share_bps as u64 / 10_000;
+
+    try_buy_tokens(&mut context, &admin, &buyer, amount, u64::MAX).await?;
+    context.refresh().await;
+
+    let stats = load_referrer_account(&mut context, &referrer.pubkey()).await;
+    assert_eq!(stats.claimable, share, "❌ Referrer should be credited its share of the buy fee");
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_batch_transfer_should_credit_referrer() -> Result<(), TransportError> {
+    let (mut context, admin) = setup_test_env().await;
+    let program_id = context.program_id;
+    let referrer = setup_wallet(&mut context, 0).await?;
+    let sender = setup_wallet(&mut context, 100 * ONE_SCTK).await?;
+    let first = setup_wallet(&mut context, 0).await?;
+    let second = setup_wallet(&mut context, 0).await?;
+
+    try_register_referral(&mut context, &sender, &referrer.pubkey()).await?;
+    context.refresh().await;
+
+    let amounts = [30 * ONE_SCTK, 20 * ONE_SCTK];
+    let state = context.load_token_state().await;
+    let fee: u64 = amounts.iter().map(|amount| amount * state.fee_schedule.transfer_fee_bps as u64 / 10_000).sum();
+    let share = fee * state.referral.share_bps as u64 / 10_000;
+    assert!(share > 0, "❌ Default referral share should credit a non-zero amount");
+
+    try_batch_transfer_tokens(
+        &mut context,
+        &admin,
+        &sender,
+        "per_leg",
+        &[
+            derive_user_ata(&program_id, &first.pubkey()),
+            derive_user_ata(&program_id, &second.pubkey()),
+        ],
+        &amounts,
+    ).await?;
+    context.refresh().await;
+
+    let stats = load_referrer_account(&mut context, &referrer.pubkey()).await;
+    assert_eq!(stats.claimable, share, "❌ Referrer should be credited its share of the batch fee");
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_purchase_should_credit_referrer() -> Result<(), TransportError> {
+    let (mut context, admin) = setup_test_env().await;
+    try_configure_pricing(
+        &mut context,
+        &admin,
+        vec![
+            "quote_mint=SOL".to_string(),
+            "buy_price=1000000".to_string(),
+            "enabled=true".to_string(),
+        ],
+    ).await?;
+    let referrer = setup_wallet(&mut context, 0).await?;
+    let buyer = setup_wallet(&mut context, 0).await?;
+
+    try_register_referral(&mut context, &buyer, &referrer.pubkey()).await?;
+    context.refresh().await;
+
+    let amount = 10 * ONE_SCTK;
+    let state = context.load_token_state().await;
+    let fee = amount * state.fee_schedule.buy_fee_bps as u64 / 10_000;
+    let share = fee * state.referral.share_bps as u64 / 10_000;
+    assert!(share > 0, "❌ Default referral share should credit a non-zero amount");
+
+    try_purchase_tokens(&mut context, &buyer, amount, u64::MAX, None).await?;
+    context.refresh().await;
+
+    let stats = load_referrer_account(&mut context, &referrer.pubkey()).await;
+    assert_eq!(stats.claimable, share, "❌ Referrer should be credited its share of the purchase fee");
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_register_referral_twice_should_fail() -> Result<(), TransportError> {
+    let (mut context, _admin) = setup_test_env().await;
+    let referrer = setup_wallet(&mut context, 0).await?;
+    let other = setup_wallet(&mut context, 0).await?;
+    let referee = setup_wallet(&mut context, 0).await?;
+
+    try_register_referral(&mut context, &referee, &referrer.pubkey()).await?;
+    context.refresh().await;
+
+    let result = try_register_referral(&mut context, &referee, &other.pubkey()).await;
+    assert!(result.is_err(), "❌ A user should only be bound to a referrer once");
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_self_referral_should_fail() -> Result<(), TransportError> {
+    let (mut context, _admin) = setup_test_env().await;
+    let user = setup_wallet(&mut context, 0).await?;
+
+    let result = try_register_referral(&mut context, &user, &user.pubkey()).await;
+    assert_custom_error(result, ReferralError::SelfReferral, "Expected SelfReferral when referring oneself");
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_update_referral_share_above_max_should_fail() -> Result<(), TransportError> {
+    let (mut context, admin) = setup_test_env().await;
+
+    let result = configure_referral(&mut context, &admin, vec!["share=5001".to_string()]).await;
+    assert_custom_error(result, EconomicsErrorCode::InvalidFeeValue, "Expected InvalidFeeValue above the max referral share");
+
+    configure_referral(&mut context, &admin, vec!["share=2500".to_string()]).await?;
+    context.refresh().await;
+
+    let state = context.load_token_state().await;
+    assert_eq!(state.referral.share_bps, 2_500);
+
+    Ok(())
+}
@@ -0,0 +1,111 @@
+// ======================================================================
+/// Soccial Token – Integration Tests: TokenState Migration
+///
+/// These tests validate `migrate_token_state`, which grows a `TokenState`
+/// created by an older program version to the current layout.
+///
+/// Covered scenarios:
+/// - ✅ A legacy account is reallocated, keeping existing settings and
+///   filling the missing sections with defaults
+/// - ❌ An account already on the current layout is rejected
+///
+/// Author: Paulo Rodrigues
+/// Project: Soccial Token
+/// Website: https://www.soccial.com/thetoken
+/// ======================================================================
+
+use soccial_token::economics::state::{FeeDiscounts, ReferralSettings};
+use soccial_token::economy::fee::DEFAULT_REFERRAL_SHARE_BPS;
+use soccial_token::market::state::{WithdrawalSettings, DEFAULT_ADDRESS_COOLDOWN_SECS};
+use soccial_token::token::{TokenError, TokenState};
+use solana_program_test::*;
+use solana_sdk::account::AccountSharedData;
+use solana_sdk::signer::Signer;
+use solana_sdk::transport::TransportError;
+
+mod testutils;
+mod trymethods;
+use crate::testutils::basics::*;
+use crate::testutils::environment::*;
+use crate::testutils::environment::setup_test_env;
+use crate::trymethods::trygovernance::try_approve_proposal_flow;
+use crate::trymethods::trytoken::*;
+
+/// Size of `TokenState` before `fee_discounts`, `referral` and `withdrawal`
+/// were appended.
+const LEGACY_LEN: usize =
+    TokenState::LEN - FeeDiscounts::LEN - ReferralSettings::LEN - WithdrawalSettings::LEN;
+
+/// Truncates the on-chain `TokenState` to `len` bytes, as left by an older program.
+async fn truncate_token_state(context: &mut EnvProgramTestContext, len: usize) -> Result<(), TransportError> {
+    let seeds = derive_seeds(&context.program_id, &context.payer.pubkey());
+
+    let mut account = context
+        .banks_client
+        .get_account(seeds.token_state)
+        .await?
+        .expect("Token state account not found");
+    account.data.truncate(len);
+
+    context
+        .original_context
+        .set_account(&seeds.token_state, &AccountSharedData::from(account));
+
+    Ok(())
+}
+
+// ======================================================================
+// TESTS
+// ======================================================================
+
+#[tokio::test]
+async fn test_migrate_legacy_token_state_should_succeed() -> Result<(), TransportError> {
+    let (mut context, admin) = setup_test_env().await;
+
+    let proposal_id = try_approve_proposal_flow(
+        &mut context,
+        &admin,
+        "custom buy fee".to_string(),
+        vec!["AdjustTaxRate".to_string()],
+    ).await?;
+    try_update_fee_schedule(&mut context, &admin, vec!["buy=123".to_string()], proposal_id).await?;
+
+    truncate_token_state(&mut context, LEGACY_LEN).await?;
+
+    let proposal_id = try_approve_proposal_flow(
+        &mut context,
+        &admin,
+        "migrate token state".to_string(),
+        vec!["UpdateGovernance".to_string()],
+    ).await?;
+    try_migrate_token_state(&mut context, &admin, proposal_id).await?;
+
+    let state = context.load_token_state().await;
+    assert_eq!(state.fee_schedule.buy_fee_bps, 123, "❌ Existing settings should be kept");
+    assert_eq!(state.fee_discounts.vip_bps, 0, "❌ Fee discounts should default to none");
+    assert_eq!(state.referral.share_bps, DEFAULT_REFERRAL_SHARE_BPS, "❌ Referral share should get its default");
+    assert_eq!(
+        state.withdrawal.address_cooldown_secs,
+        DEFAULT_ADDRESS_COOLDOWN_SECS,
+        "❌ Address cooldown should get its default"
+    );
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_migrate_current_token_state_should_fail() -> Result<(), TransportError> {
+    let (mut context, admin) = setup_test_env().await;
+
+    let proposal_id = try_approve_proposal_flow(
+        &mut context,
+        &admin,
+        "migrate token state".to_string(),
+        vec!["UpdateGovernance".to_string()],
+    ).await?;
+
+    let result = try_migrate_token_state(&mut context, &admin, proposal_id).await;
+    assert_custom_error(result, TokenError::StateAlreadyCurrent, "Expected StateAlreadyCurrent");
+
+    Ok(())
+}
@@ -0,0 +1,81 @@
+use soccial_token::economics::EconomicsErrorCode;
+use solana_program_test::*;
+use solana_sdk::transport::TransportError;
+
+mod testutils;
+mod trymethods;
+
+use crate::testutils::environment::setup_test_env;
+use crate::trymethods::trygovernance::try_approve_proposal_flow;
+use crate::trymethods::trytoken::*;
+use crate::testutils::basics::*;
+use soccial_token::economy::fee::MAX_FEE_BPS;
+
+#[tokio::test]
+async fn test_update_fee_schedule_success() -> Result<(), TransportError> {
+    let (mut context, admin) = setup_test_env().await;
+
+    let proposal_types = vec!["AdjustTaxRate".to_string()];
+    let proposal_id = try_approve_proposal_flow(
+        &mut context,
+        &admin,
+        "testing proposal".to_string(),
+        proposal_types
+    ).await?;
+
+    let before = context.load_token_state().await;
+
+    try_update_fee_schedule(
+        &mut context,
+        &admin,
+        vec!["buy=250".to_string(), "transfer=10".to_string()],
+        proposal_id,
+    ).await.unwrap();
+
+    let state = context.load_token_state().await;
+    assert_eq!(state.fee_schedule.buy_fee_bps, 250, "Buy fee should be updated");
+    assert_eq!(state.fee_schedule.transfer_fee_bps, 10, "Transfer fee should be updated");
+    assert_eq!(
+        state.fee_schedule.withdrawal_fee_bps,
+        before.fee_schedule.withdrawal_fee_bps,
+        "Withdrawal fee should be unchanged"
+    );
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_update_fee_schedule_without_approval_should_fail() -> Result<(), TransportError> {
+    let (mut context, admin) = setup_test_env().await;
+
+    let proposal_id = 1;
+
+    let result = try_update_fee_schedule(&mut context, &admin, vec!["buy=250".to_string()], proposal_id).await;
+
+    assert!(
+        result.is_err(),
+        "🚨 Expected failure due to lack of governance approval."
+    );
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_update_fee_schedule_exceeds_max_should_fail() -> Result<(), TransportError> {
+    let (mut context, admin) = setup_test_env().await;
+
+    let proposal_types = vec!["AdjustTaxRate".to_string()];
+    let proposal_id = try_approve_proposal_flow(
+        &mut context,
+        &admin,
+        "testing proposal".to_string(),
+        proposal_types
+    ).await?;
+
+    let invalid_fee = MAX_FEE_BPS + 1;
+    let result = try_update_fee_schedule(&mut context, &admin, vec![format!("withdrawal={}", invalid_fee)], proposal_id).await;
+
+    assert_custom_error(result, EconomicsErrorCode::InvalidFeeValue, "Fee exceeding max should fail");
+
+    Ok(())
+}
@@ -0,0 +1,250 @@
+// ======================================================================
+/// Soccial Token – Integration Tests: Approval Council
+///
+/// These tests validate the M-of-N council guarding treasury and
+/// insurance outflows.
+///
+/// Covered scenarios:
+/// - ❌ Invalid council configurations are rejected
+/// - ❌ Single-key treasury withdrawals are rejected once configured
+/// - ✅ Withdrawals and transfers execute after M approvals
+/// - ✅ Transfers requiring a proposal accept one at proposal and execution
+/// - ❌ Transfers forbidden after the proposal cannot be executed
+/// - ❌ Outsiders, duplicate approvals and stale operations are rejected
+///
+/// Author: Paulo Rodrigues  
+/// Project: Soccial Token  
+/// Website: https://www.soccial.com/thetoken  
+/// ======================================================================
+
+use soccial_token::vaults::VaultError;
+use solana_program_test::*;
+use solana_sdk::pubkey::Pubkey;
+use solana_sdk::signature::Keypair;
+use solana_sdk::signer::Signer;
+use solana_sdk::transport::TransportError;
+
+mod testutils;
+mod trymethods;
+use crate::testutils::basics::*;
+use crate::testutils::environment::*;
+use crate::testutils::environment::setup_test_env;
+use crate::trymethods::trygovernance::try_approve_proposal_flow;
+use crate::trymethods::tryvaults::*;
+
+const AMOUNT: u64 = 5_000_000_000;
+
+/// Configures a 2-of-3 council and funds the treasury. Returns the members.
+async fn setup_council(
+    context: &mut EnvProgramTestContext,
+    admin: &Keypair,
+) -> Result<[Keypair; 3], TransportError> {
+    let members = [Keypair::new(), Keypair::new(), Keypair::new()];
+    for member in &members {
+        fund_lamports(context, member, 1_000_000_000).await?;
+    }
+
+    let proposal_id = try_approve_proposal_flow(
+        context,
+        admin,
+        "approval council".to_string(),
+        vec!["UpdateGovernance".to_string()],
+    ).await?;
+
+    let mut args = vec!["2".to_string()];
+    args.extend(members.iter().map(|m| m.pubkey().to_string()));
+    try_update_approval_council(context, admin, args, proposal_id).await?;
+
+    context.mint_tokens_to_vault("treasury", 100_000_000_000).await?;
+
+    Ok(members)
+}
+
+/// Approves an `UpdateGovernance` proposal and applies `rule` to `source → destination`.
+async fn set_rule(
+    context: &mut EnvProgramTestContext,
+    admin: &Keypair,
+    source: &str,
+    destination: &str,
+    rule: &str,
+) -> Result<(), TransportError> {
+    let proposal_id = try_approve_proposal_flow(
+        context,
+        admin,
+        format!("{} to {}", source, destination),
+        vec!["UpdateGovernance".to_string()],
+    ).await?;
+
+    try_set_vault_transfer_rule(context, admin, source, destination, rule, proposal_id).await
+}
+
+/// Creates a participant ATA that receives council withdrawals.
+async fn setup_recipient(context: &mut EnvProgramTestContext) -> Result<(Keypair, Pubkey), TransportError> {
+    let participant = Keypair::new();
+    let ata = create_user_ata(context, &participant).await?;
+    fund_lamports(context, &participant, 5_000_000).await?;
+
+    Ok((participant, ata))
+}
+
+// ======================================================================
+// TESTS
+// ======================================================================
+
+#[tokio::test]
+async fn test_invalid_council_config_should_fail() -> Result<(), TransportError> {
+    let (mut context, admin) = setup_test_env().await;
+    let member = Keypair::new();
+
+    let proposal_id = try_approve_proposal_flow(
+        &mut context,
+        &admin,
+        "approval council".to_string(),
+        vec!["UpdateGovernance".to_string()],
+    ).await?;
+
+    let args = vec!["2".to_string(), member.pubkey().to_string()];
+    let result = try_update_approval_council(&mut context, &admin, args, proposal_id).await;
+    assert_custom_error(result, VaultError::InvalidCouncilConfig, "Expected InvalidCouncilConfig for 2-of-1");
+
+    let args = vec!["1".to_string(), member.pubkey().to_string(), member.pubkey().to_string()];
+    let result = try_update_approval_council(&mut context, &admin, args, proposal_id).await;
+    assert_custom_error(result, VaultError::InvalidCouncilConfig, "Expected InvalidCouncilConfig for duplicates");
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_direct_treasury_withdrawal_requires_council() -> Result<(), TransportError> {
+    let (mut context, admin) = setup_test_env().await;
+    setup_council(&mut context, &admin).await?;
+    let (participant, _) = setup_recipient(&mut context).await?;
+
+    let result = try_withdraw_treasury_vault(&mut context, &admin, &participant, 2_000).await;
+    assert_custom_error(result, VaultError::CouncilApprovalRequired, "Expected CouncilApprovalRequired");
+
+    // Vaults outside the council keep the single-key path
+    context.mint_tokens_to_vault("rewards", 100_000_000_000).await?;
+    try_withdraw_rewards_vault(&mut context, &admin, &participant, 2_000).await?;
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_council_withdrawal_executes_after_threshold() -> Result<(), TransportError> {
+    let (mut context, admin) = setup_test_env().await;
+    let [alice, bob, _carol] = setup_council(&mut context, &admin).await?;
+    let (participant, participant_ata) = setup_recipient(&mut context).await?;
+
+    try_propose_council_operation(&mut context, &alice, 1, "withdraw", "treasury", participant_ata, AMOUNT, None).await?;
+
+    let result = try_execute_council_operation(&mut context, &alice, 1, "treasury", participant_ata, None).await;
+    assert_custom_error(result, VaultError::OperationThresholdNotMet, "Expected OperationThresholdNotMet at 1/2");
+
+    let result = try_approve_council_operation(&mut context, &alice, 1).await;
+    assert_custom_error(result, VaultError::OperationAlreadyApproved, "Expected OperationAlreadyApproved");
+
+    let outsider = Keypair::new();
+    fund_lamports(&mut context, &outsider, 1_000_000_000).await?;
+    let result = try_approve_council_operation(&mut context, &outsider, 1).await;
+    assert_custom_error(result, VaultError::NotCouncilMember, "Expected NotCouncilMember");
+
+    try_approve_council_operation(&mut context, &bob, 1).await?;
+
+    let treasury_before = context.get_vault_balance("treasury").await;
+    let participant_before = context.get_user_balance(&participant.pubkey()).await;
+
+    try_execute_council_operation(&mut context, &bob, 1, "treasury", participant_ata, None).await?;
+
+    assert_eq!(treasury_before - context.get_vault_balance("treasury").await, AMOUNT);
+    assert_eq!(context.get_user_balance(&participant.pubkey()).await - participant_before, AMOUNT);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_council_transfer_to_rewards_vault() -> Result<(), TransportError> {
+    let (mut context, admin) = setup_test_env().await;
+    let [alice, _bob, carol] = setup_council(&mut context, &admin).await?;
+    let seeds = derive_seeds(&context.program_id, &admin.pubkey());
+
+    try_propose_council_operation(&mut context, &carol, 2, "transfer", "treasury", seeds.rewards_vault_token_account, AMOUNT, None).await?;
+    try_approve_council_operation(&mut context, &alice, 2).await?;
+
+    let rewards_before = context.get_vault_balance("rewards").await;
+    try_execute_council_operation(&mut context, &carol, 2, "treasury", seeds.rewards_vault_token_account, None).await?;
+    assert_eq!(context.get_vault_balance("rewards").await - rewards_before, AMOUNT);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_council_transfer_requiring_proposal() -> Result<(), TransportError> {
+    let (mut context, admin) = setup_test_env().await;
+    let [alice, _bob, carol] = setup_council(&mut context, &admin).await?;
+    let seeds = derive_seeds(&context.program_id, &admin.pubkey());
+    set_rule(&mut context, &admin, "treasury_vault", "rewards_vault", "RewardsAllocation").await?;
+
+    let result = try_propose_council_operation(&mut context, &carol, 4, "transfer", "treasury", seeds.rewards_vault_token_account, AMOUNT, None).await;
+    assert_custom_error(result, VaultError::MissingProposalApproval, "Expected MissingProposalApproval without a proposal");
+
+    let proposal_id = try_approve_proposal_flow(
+        &mut context,
+        &admin,
+        "fund rewards".to_string(),
+        vec!["RewardsAllocation".to_string()],
+    ).await?;
+
+    try_propose_council_operation(&mut context, &carol, 4, "transfer", "treasury", seeds.rewards_vault_token_account, AMOUNT, Some(proposal_id)).await?;
+    try_approve_council_operation(&mut context, &alice, 4).await?;
+
+    let result = try_execute_council_operation(&mut context, &carol, 4, "treasury", seeds.rewards_vault_token_account, None).await;
+    assert_custom_error(result, VaultError::MissingProposalApproval, "Expected MissingProposalApproval at execution");
+
+    let rewards_before = context.get_vault_balance("rewards").await;
+    try_execute_council_operation(&mut context, &carol, 4, "treasury", seeds.rewards_vault_token_account, Some(proposal_id)).await?;
+    assert_eq!(context.get_vault_balance("rewards").await - rewards_before, AMOUNT);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_council_transfer_forbidden_after_proposal_should_fail() -> Result<(), TransportError> {
+    let (mut context, admin) = setup_test_env().await;
+    let [alice, _bob, carol] = setup_council(&mut context, &admin).await?;
+    let seeds = derive_seeds(&context.program_id, &admin.pubkey());
+
+    try_propose_council_operation(&mut context, &carol, 5, "transfer", "treasury", seeds.rewards_vault_token_account, AMOUNT, None).await?;
+    try_approve_council_operation(&mut context, &alice, 5).await?;
+
+    // Governance forbids the pair while the operation is pending
+    set_rule(&mut context, &admin, "treasury_vault", "rewards_vault", "Forbidden").await?;
+
+    let result = try_execute_council_operation(&mut context, &carol, 5, "treasury", seeds.rewards_vault_token_account, None).await;
+    assert_custom_error(result, VaultError::UnauthorizedVaultTransfer, "Expected UnauthorizedVaultTransfer once forbidden");
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_operation_is_stale_after_council_change() -> Result<(), TransportError> {
+    let (mut context, admin) = setup_test_env().await;
+    let [alice, bob, carol] = setup_council(&mut context, &admin).await?;
+    let (_, participant_ata) = setup_recipient(&mut context).await?;
+
+    try_propose_council_operation(&mut context, &alice, 3, "withdraw", "treasury", participant_ata, AMOUNT, None).await?;
+
+    let proposal_id = try_approve_proposal_flow(
+        &mut context,
+        &admin,
+        "rotate council".to_string(),
+        vec!["UpdateGovernance".to_string()],
+    ).await?;
+    let args = vec!["2".to_string(), bob.pubkey().to_string(), carol.pubkey().to_string()];
+    try_update_approval_council(&mut context, &admin, args, proposal_id).await?;
+
+    let result = try_approve_council_operation(&mut context, &bob, 3).await;
+    assert_custom_error(result, VaultError::StaleCouncilOperation, "Expected StaleCouncilOperation");
+
+    Ok(())
+}
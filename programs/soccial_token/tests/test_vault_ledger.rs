@@ -0,0 +1,107 @@
+// ======================================================================
+/// Soccial Token – Integration Tests: Vault Ledger
+///
+/// These tests validate the per-vault ledger opened through
+/// `open_vault_ledger` and read through `get_vault_ledger`.
+///
+/// Covered scenarios:
+/// - ✅ Opening a ledger captures the current vault balance
+/// - ✅ Manual deposits and withdrawals are posted and reconcile
+/// - ✅ Vault-to-vault transfers post to both ledgers
+/// - ❌ A ledger cannot be opened twice
+///
+/// Author: Paulo Rodrigues
+/// Project: Soccial Token
+/// Website: https://www.soccial.com/thetoken
+/// ======================================================================
+
+use soccial_token::vaults::{LedgerCategory, VaultType};
+use solana_program_test::*;
+use solana_sdk::signature::Keypair;
+use solana_sdk::transport::TransportError;
+
+mod testutils;
+mod trymethods;
+use crate::testutils::environment::*;
+use crate::testutils::environment::setup_test_env;
+use crate::trymethods::tryvaults::*;
+
+const MANUAL: usize = LedgerCategory::Manual as usize;
+
+// ======================================================================
+// TESTS
+// ======================================================================
+
+#[tokio::test]
+async fn test_open_vault_ledger_should_capture_balance() -> Result<(), TransportError> {
+    let (mut context, admin) = setup_test_env().await;
+
+    context.mint_tokens_to_vault("treasury", 50_000).await?;
+    try_open_vault_ledger(&mut context, &admin, "treasury").await?;
+
+    let snapshot = try_get_vault_ledger(&mut context, &admin, "treasury").await?;
+    assert_eq!(snapshot.vault_type, VaultType::Treasury);
+    assert_eq!(snapshot.opening_balance, 50_000);
+    assert_eq!(snapshot.total_inflow, 0);
+    assert_eq!(snapshot.total_outflow, 0);
+    assert!(snapshot.reconciled, "🚨 Fresh ledger should reconcile");
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_manual_flows_should_post_and_reconcile() -> Result<(), TransportError> {
+    let (mut context, admin) = setup_test_env().await;
+
+    let participant = Keypair::new();
+    create_user_ata(&mut context, &participant).await?;
+    fund_lamports(&mut context, &participant, 5_000_000).await?;
+    context.mint_tokens(&participant, 10_000).await;
+
+    try_open_vault_ledger(&mut context, &admin, "rewards").await?;
+
+    try_deposit_rewards_vault(&mut context, &admin, &participant, 4_000).await?;
+    try_withdraw_rewards_vault(&mut context, &admin, &participant, 1_500).await?;
+
+    let snapshot = try_get_vault_ledger(&mut context, &admin, "rewards").await?;
+    assert_eq!(snapshot.inflows[MANUAL], 4_000);
+    assert_eq!(snapshot.outflows[MANUAL], 1_500);
+    assert_eq!(snapshot.expected_balance, snapshot.opening_balance as i128 + 2_500);
+    assert!(snapshot.reconciled, "🚨 Ledger should match the vault balance");
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_vault_transfer_should_post_to_both_ledgers() -> Result<(), TransportError> {
+    let (mut context, admin) = setup_test_env().await;
+
+    context.mint_tokens_to_vault("revenue", 10_000).await?;
+    try_open_vault_ledger(&mut context, &admin, "revenue").await?;
+    try_open_vault_ledger(&mut context, &admin, "rewards").await?;
+
+    try_transfer_between_vaults(&mut context, &admin, "revenue_vault", "rewards_vault", 3_000, None).await?;
+
+    let source = try_get_vault_ledger(&mut context, &admin, "revenue").await?;
+    assert_eq!(source.outflows[MANUAL], 3_000);
+    assert!(source.reconciled, "🚨 Source ledger should reconcile");
+
+    let destination = try_get_vault_ledger(&mut context, &admin, "rewards").await?;
+    assert_eq!(destination.inflows[MANUAL], 3_000);
+    assert!(destination.reconciled, "🚨 Destination ledger should reconcile");
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_open_vault_ledger_twice_should_fail() -> Result<(), TransportError> {
+    let (mut context, admin) = setup_test_env().await;
+
+    try_open_vault_ledger(&mut context, &admin, "airdrop").await?;
+
+    context.refresh().await;
+    let result = try_open_vault_ledger(&mut context, &admin, "airdrop").await;
+    assert!(result.is_err(), "🚨 Re-opening a ledger should fail");
+
+    Ok(())
+}
@@ -0,0 +1,176 @@
+// ======================================================================
+/// Soccial Token – Integration Tests: Vault Outflow Limits
+///
+/// These tests validate the per-epoch outflow cap and the circuit
+/// breaker configured through `set_vault_limits`.
+///
+/// Covered scenarios:
+/// - ❌ Invalid limit configurations are rejected
+/// - ❌ Outflows beyond the epoch cap are rejected
+/// - ✅ Crossing the pause threshold pauses the contract
+/// - ✅ A new epoch resets the outflow counter
+/// - ❌ Staking reserves drawn from a capped liquidity vault are rejected
+///
+/// Author: Paulo Rodrigues
+/// Project: Soccial Token
+/// Website: https://www.soccial.com/thetoken
+/// ======================================================================
+
+use soccial_token::vaults::VaultError;
+use solana_program_test::*;
+use solana_sdk::signature::{Keypair, Signer};
+use solana_sdk::transport::TransportError;
+
+mod testutils;
+mod trymethods;
+use crate::testutils::basics::*;
+use crate::testutils::environment::*;
+use crate::testutils::environment::setup_test_env;
+use crate::trymethods::trygovernance::try_approve_proposal_flow;
+use crate::trymethods::trystaking::try_buy_and_stake_tokens;
+use crate::trymethods::tryvaults::*;
+
+const EPOCH_SECS: u64 = 86_400;
+const MAX_OUTFLOW: u64 = 10_000;
+const PAUSE_THRESHOLD: u64 = 8_000;
+
+/// Sets limits on the rewards vault and funds it. Returns the participant.
+async fn setup_limits(
+    context: &mut EnvProgramTestContext,
+    admin: &Keypair,
+    pause_threshold: u64,
+) -> Result<Keypair, TransportError> {
+    let proposal_id = try_approve_proposal_flow(
+        context,
+        admin,
+        "rewards limits".to_string(),
+        vec!["UpdateGovernance".to_string()],
+    ).await?;
+
+    try_set_vault_limits(
+        context,
+        admin,
+        "rewards",
+        vec![EPOCH_SECS.to_string(), MAX_OUTFLOW.to_string(), pause_threshold.to_string()],
+        proposal_id,
+    ).await?;
+
+    let participant = Keypair::new();
+    create_user_ata(context, &participant).await?;
+    fund_lamports(context, &participant, 5_000_000).await?;
+
+    context.mint_tokens_to_vault("rewards", 100_000).await?;
+
+    Ok(participant)
+}
+
+// ======================================================================
+// TESTS
+// ======================================================================
+
+#[tokio::test]
+async fn test_invalid_vault_limits_should_fail() -> Result<(), TransportError> {
+    let (mut context, admin) = setup_test_env().await;
+
+    let proposal_id = try_approve_proposal_flow(
+        &mut context,
+        &admin,
+        "bad limits".to_string(),
+        vec!["UpdateGovernance".to_string()],
+    ).await?;
+
+    // Epoch shorter than the minimum
+    let args = vec!["60".to_string(), MAX_OUTFLOW.to_string(), "0".to_string()];
+    let result = try_set_vault_limits(&mut context, &admin, "rewards", args, proposal_id).await;
+    assert_custom_error(result, VaultError::InvalidVaultLimits, "Expected InvalidVaultLimits for short epoch");
+
+    // Pause threshold above the cap
+    let args = vec![EPOCH_SECS.to_string(), MAX_OUTFLOW.to_string(), (MAX_OUTFLOW + 1).to_string()];
+    let result = try_set_vault_limits(&mut context, &admin, "rewards", args, proposal_id).await;
+    assert_custom_error(result, VaultError::InvalidVaultLimits, "Expected InvalidVaultLimits for threshold > cap");
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_outflow_above_epoch_cap_should_fail() -> Result<(), TransportError> {
+    let (mut context, admin) = setup_test_env().await;
+    let participant = setup_limits(&mut context, &admin, 0).await?;
+
+    try_withdraw_rewards_vault(&mut context, &admin, &participant, 6_000).await?;
+
+    let result = try_withdraw_rewards_vault(&mut context, &admin, &participant, 4_001).await;
+    assert_custom_error(result, VaultError::EpochOutflowExceeded, "Expected EpochOutflowExceeded");
+
+    // Exactly up to the cap is still allowed
+    try_withdraw_rewards_vault(&mut context, &admin, &participant, 4_000).await?;
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_crossing_pause_threshold_should_pause_contract() -> Result<(), TransportError> {
+    let (mut context, admin) = setup_test_env().await;
+    let participant = setup_limits(&mut context, &admin, PAUSE_THRESHOLD).await?;
+
+    try_withdraw_rewards_vault(&mut context, &admin, &participant, PAUSE_THRESHOLD - 1).await?;
+    assert!(!context.load_token_state().await.core.paused, "🚨 Contract should not be paused yet");
+
+    // The crossing outflow succeeds but trips the circuit breaker
+    try_withdraw_rewards_vault(&mut context, &admin, &participant, 1).await?;
+    assert!(context.load_token_state().await.core.paused, "🚨 Contract should be paused by the circuit breaker");
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_new_epoch_should_reset_outflow() -> Result<(), TransportError> {
+    let (mut context, admin) = setup_test_env().await;
+    let participant = setup_limits(&mut context, &admin, 0).await?;
+
+    try_withdraw_rewards_vault(&mut context, &admin, &participant, MAX_OUTFLOW).await?;
+
+    let result = try_withdraw_rewards_vault(&mut context, &admin, &participant, 1).await;
+    assert_custom_error(result, VaultError::EpochOutflowExceeded, "Expected EpochOutflowExceeded");
+
+    context.warp_forward_seconds(EPOCH_SECS).await;
+    context.refresh().await;
+
+    try_withdraw_rewards_vault(&mut context, &admin, &participant, MAX_OUTFLOW).await?;
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_buy_and_stake_above_epoch_cap_should_fail() -> Result<(), TransportError> {
+    let (mut context, admin) = setup_test_env().await;
+
+    let proposal_id = try_approve_proposal_flow(
+        &mut context,
+        &admin,
+        "liquidity limits".to_string(),
+        vec!["UpdateGovernance".to_string()],
+    ).await?;
+
+    try_set_vault_limits(
+        &mut context,
+        &admin,
+        "liquidity",
+        vec![EPOCH_SECS.to_string(), MAX_OUTFLOW.to_string(), "0".to_string()],
+        proposal_id,
+    ).await?;
+
+    let participant = Keypair::new();
+    create_user_ata(&mut context, &participant).await?;
+    fund_lamports(&mut context, &participant, 5_000_000).await?;
+
+    context.mint_tokens_to_vault("liquidity", 100_000).await?;
+
+    // Stake plus reward reserve stays under the cap
+    try_buy_and_stake_tokens(&mut context, &admin, &participant.pubkey(), 9_000, 1).await?;
+
+    let result = try_buy_and_stake_tokens(&mut context, &admin, &participant.pubkey(), 1_000, 1).await;
+    assert_custom_error(result, VaultError::EpochOutflowExceeded, "Expected EpochOutflowExceeded");
+
+    Ok(())
+}
@@ -0,0 +1,120 @@
+// ======================================================================
+/// Soccial Token – Integration Tests: Vault Transfer Policy
+///
+/// These tests validate the governance-editable transfer matrix set
+/// through `set_vault_transfer_rule` and evaluated by
+/// `transfer_between_vaults`.
+///
+/// Covered scenarios:
+/// - ✅ A pair forbidden by default can be allowed through governance
+/// - ❌ A pair allowed by default can be forbidden through governance
+/// - ❌ A pair can be made to require a proposal of a given type
+/// - ❌ Invalid rules and same-vault rules are rejected
+///
+/// Author: Paulo Rodrigues
+/// Project: Soccial Token
+/// Website: https://www.soccial.com/thetoken
+/// ======================================================================
+
+use soccial_token::vaults::VaultError;
+use solana_program_test::*;
+use solana_sdk::signature::Keypair;
+use solana_sdk::transport::TransportError;
+
+mod testutils;
+mod trymethods;
+use crate::testutils::basics::*;
+use crate::testutils::environment::*;
+use crate::testutils::environment::setup_test_env;
+use crate::trymethods::trygovernance::try_approve_proposal_flow;
+use crate::trymethods::tryvaults::*;
+
+/// Approves an `UpdateGovernance` proposal and applies `rule` to `source → destination`.
+async fn set_rule(
+    context: &mut EnvProgramTestContext,
+    admin: &Keypair,
+    source: &str,
+    destination: &str,
+    rule: &str,
+) -> Result<(), TransportError> {
+    let proposal_id = try_approve_proposal_flow(
+        context,
+        admin,
+        format!("{} to {}", source, destination),
+        vec!["UpdateGovernance".to_string()],
+    ).await?;
+
+    try_set_vault_transfer_rule(context, admin, source, destination, rule, proposal_id).await
+}
+
+// ======================================================================
+// TESTS
+// ======================================================================
+
+#[tokio::test]
+async fn test_forbidden_pair_can_be_allowed() -> Result<(), TransportError> {
+    let (mut context, admin) = setup_test_env().await;
+    context.mint_tokens_to_vault("staking", 10_000).await?;
+
+    let result = try_transfer_between_vaults(&mut context, &admin, "staking_vault", "treasury_vault", 1_000, None).await;
+    assert_custom_error(result, VaultError::UnauthorizedVaultTransfer, "Expected UnauthorizedVaultTransfer by default");
+
+    set_rule(&mut context, &admin, "staking_vault", "treasury_vault", "Allowed").await?;
+
+    try_transfer_between_vaults(&mut context, &admin, "staking_vault", "treasury_vault", 1_000, None).await?;
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_allowed_pair_can_be_forbidden() -> Result<(), TransportError> {
+    let (mut context, admin) = setup_test_env().await;
+    context.mint_tokens_to_vault("revenue", 10_000).await?;
+
+    try_transfer_between_vaults(&mut context, &admin, "revenue_vault", "rewards_vault", 1_000, None).await?;
+
+    set_rule(&mut context, &admin, "revenue_vault", "rewards_vault", "Forbidden").await?;
+
+    let result = try_transfer_between_vaults(&mut context, &admin, "revenue_vault", "rewards_vault", 1_000, None).await;
+    assert_custom_error(result, VaultError::UnauthorizedVaultTransfer, "Expected UnauthorizedVaultTransfer once forbidden");
+
+    // Other pairs keep their built-in rules
+    try_transfer_between_vaults(&mut context, &admin, "revenue_vault", "airdrop_vault", 1_000, None).await?;
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_pair_can_require_proposal() -> Result<(), TransportError> {
+    let (mut context, admin) = setup_test_env().await;
+    context.mint_tokens_to_vault("revenue", 10_000).await?;
+
+    set_rule(&mut context, &admin, "revenue_vault", "treasury_vault", "TreasuryAllocation").await?;
+
+    let result = try_transfer_between_vaults(&mut context, &admin, "revenue_vault", "treasury_vault", 1_000, None).await;
+    assert_custom_error(result, VaultError::MissingProposalApproval, "Expected MissingProposalApproval");
+
+    let proposal_id = try_approve_proposal_flow(
+        &mut context,
+        &admin,
+        "fund treasury".to_string(),
+        vec!["TreasuryAllocation".to_string()],
+    ).await?;
+
+    try_transfer_between_vaults(&mut context, &admin, "revenue_vault", "treasury_vault", 1_000, Some(proposal_id)).await?;
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_invalid_transfer_rule_should_fail() -> Result<(), TransportError> {
+    let (mut context, admin) = setup_test_env().await;
+
+    let result = set_rule(&mut context, &admin, "revenue_vault", "rewards_vault", "Sometimes").await;
+    assert_custom_error(result, VaultError::InvalidTransferRule, "Expected InvalidTransferRule");
+
+    let result = set_rule(&mut context, &admin, "revenue_vault", "revenue_vault", "Allowed").await;
+    assert_custom_error(result, VaultError::InvalidItselfVaultTransfer, "Expected InvalidItselfVaultTransfer");
+
+    Ok(())
+}
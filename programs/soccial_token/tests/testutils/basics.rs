@@ -66,6 +66,7 @@ pub struct AccountSeeds {
     pub staking_account: Pubkey,
     pub staking_state: Pubkey,
     pub governance_state: Pubkey,
+    pub sale_state: Pubkey,
     
     pub token_state: Pubkey,
     pub token_mint: Pubkey,
@@ -173,6 +174,7 @@ pub fn derive_seeds(program_id: &Pubkey, caller: &Pubkey) -> AccountSeeds {
     let (staking_account, _staking_account_bump) = derive(b"staking_account", Some(caller), program_id);
     let (staking_state, _staking_state_bump) = derive(b"staking_state", None, program_id);
     let (governance_state, _governance_state_bump) = derive(b"governance_state", None, program_id);
+    let (sale_state, _sale_state_bump) = derive(b"sale_state", None, program_id);
 
     let (token_mint, _token_mint_bump) = derive(b"token_mint", None, program_id);
     let (mint_authority, mint_authority_bump) = derive(b"mint_authority", None, program_id);
@@ -244,6 +246,7 @@ pub fn derive_seeds(program_id: &Pubkey, caller: &Pubkey) -> AccountSeeds {
         staking_account,
         staking_state,
         governance_state,
+        sale_state,
 
         token_mint,
         mint_authority,
@@ -617,6 +620,7 @@ pub fn build_initialize_token_accounts(
         governance_state: seeds.governance_state,
         staking_state: seeds.staking_state,
         vesting_state: seeds.vesting_state,
+        sale_state: seeds.sale_state,
         system_program: system_program::ID,
         rent: sysvar::rent::ID,
         token_program: spl_token::ID,
@@ -819,6 +819,31 @@ pub async fn create_user_ata(
     Ok(user_ata)
 }
 
+/// Creates a funded wallet with an ATA holding `balance` SCTK units.
+///
+/// # Arguments
+/// * `context` - The current test environment.
+/// * `balance` - SCTK units minted to the wallet's ATA (none when zero).
+///
+/// # Returns
+/// * `Ok(Keypair)` - The new wallet, with 1 SOL for fees and rent.
+/// * `Err(TransportError)` - If funding or ATA creation fails.
+#[allow(dead_code)]
+pub async fn setup_wallet(
+    context: &mut EnvProgramTestContext,
+    balance: u64,
+) -> Result<Keypair, TransportError> {
+    let wallet = Keypair::new();
+    fund_lamports(context, &wallet, 1_000_000_000).await?;
+    create_user_ata(context, &wallet).await?;
+    if balance > 0 {
+        context.mint_tokens_to_user(&wallet.pubkey(), balance).await;
+    }
+    context.refresh().await;
+
+    Ok(wallet)
+}
+
 /// ======================================================================
 /// Manually creates a Token Account with a custom owner.
 /// Unlike create_user_ata (which uses the Associated Token Program),
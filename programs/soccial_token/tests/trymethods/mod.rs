@@ -1,6 +1,11 @@
 pub mod tryairdrop;
+pub mod tryamm;
+pub mod tryescrow;
 pub mod trygovernance;
 pub mod trymarket;
+pub mod tryoracle;
+pub mod trypayments;
+pub mod tryreferral;
 pub mod trysystem;
 pub mod trystaking;
 pub mod trytoken;
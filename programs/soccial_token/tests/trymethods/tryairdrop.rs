@@ -16,6 +16,16 @@
 // - Designed for integration testing of custom instructions
 //
 // ----------------------------------------------------------------------------
+// Key Functions:
+// - `try_airdrop`: Transfers tokens from the airdrop vault to a recipient
+// - `voucher_keypair`: Derives the voucher key a gift code seeds
+// - `build_voucher_tree`: Builds the merkle root and proofs for gift codes
+// - `derive_voucher_batch_pda` / `derive_voucher_bitmap_pda`: Voucher PDAs
+// - `try_create_voucher_batch`: Registers a voucher batch
+// - `try_redeem_voucher`: Redeems a gift code into the caller's ATA
+// - `try_redeem_voucher_with_key`: Redeems with an explicit voucher key
+//
+// ----------------------------------------------------------------------------
 // Naming Convention:
 // All functions use the `try_*` prefix (e.g., `try_airdrop`, `try_stake`) to
 // indicate they return a `Result<(), TransportError>` and are used in tests.
@@ -32,10 +42,12 @@
 // ============================================================================
 
 use crate::testutils::environment::EnvProgramTestContext;
-use solana_sdk::{pubkey::Pubkey, signature::Keypair, signer::Signer};
+use solana_sdk::{pubkey::Pubkey, signature::Keypair, signer::{keypair::keypair_from_seed, Signer}, system_program};
 use solana_sdk::transport::TransportError;
 use soccial_token::{instruction as soccial_instruction, accounts as soccial_accounts};
+use soccial_token::airdrop::{voucher_key_seed, voucher_leaf, voucher_node};
 use crate::testutils::basics::*;
+use crate::trymethods::tryvaults::{derive_vault_ledger_pda, derive_vault_limits_pda};
 
 // ============================================================================
 /// Attempts to execute an airdrop transfer from the airdrop vault to a recipient.
@@ -82,7 +94,9 @@ pub async fn try_airdrop(
             caller: caller.pubkey(),
             token_state: seeds.token_state,
             airdrop_vault: seeds.airdrop_vault,
+            vault_limits: derive_vault_limits_pda(&context.program_id, &seeds.airdrop_vault),
             airdrop_vault_token_account: seeds.airdrop_vault_token_account,
+            airdrop_ledger: derive_vault_ledger_pda(&context.program_id, &seeds.airdrop_vault),
             mint: seeds.token_mint,
             recipient_token_account: seeds.user_token_ata,
             user_access: None,
@@ -101,3 +115,190 @@ pub async fn try_airdrop(
 
     Ok(())
 }
+
+/// Derives the ed25519 voucher key seeded by `code`.
+#[allow(dead_code)]
+pub fn voucher_keypair(code: &str) -> Keypair {
+    keypair_from_seed(&voucher_key_seed(code)).expect("32-byte seed")
+}
+
+// ============================================================================
+/// Builds the voucher merkle tree for `codes` (index = position).
+///
+/// Odd nodes are promoted to the next level unchanged, so their proofs
+/// simply skip that level.
+///
+/// # Returns:
+/// - `(root, proofs)` – hex-encoded root and one hex proof per code
+// ============================================================================
+#[allow(dead_code)]
+pub fn build_voucher_tree(codes: &[&str]) -> (String, Vec<Vec<String>>) {
+    let mut level: Vec<[u8; 32]> = codes
+        .iter()
+        .enumerate()
+        .map(|(index, code)| voucher_leaf(index as u32, &voucher_keypair(code).pubkey()))
+        .collect();
+    let mut positions: Vec<usize> = (0..codes.len()).collect();
+    let mut proofs: Vec<Vec<String>> = vec![vec![]; codes.len()];
+
+    while level.len() > 1 {
+        for (proof, position) in proofs.iter_mut().zip(positions.iter_mut()) {
+            let sibling = *position ^ 1;
+            if sibling < level.len() {
+                proof.push(to_hex(&level[sibling]));
+            }
+            *position /= 2;
+        }
+
+        level = level
+            .chunks(2)
+            .map(|pair| match pair {
+                [left, right] => voucher_node(left, right),
+                [single] => *single,
+                _ => unreachable!(),
+            })
+            .collect();
+    }
+
+    (to_hex(&level[0]), proofs)
+}
+
+fn to_hex(bytes: &[u8; 32]) -> String {
+    bytes.iter().map(|byte| format!("{:02x}", byte)).collect()
+}
+
+/// Derives the `VoucherBatch` PDA for `batch_id`.
+#[allow(dead_code)]
+pub fn derive_voucher_batch_pda(program_id: &Pubkey, batch_id: u64) -> Pubkey {
+    Pubkey::find_program_address(&[b"voucher_batch", &batch_id.to_le_bytes()], program_id).0
+}
+
+/// Derives the `VoucherBitmap` PDA of a batch.
+#[allow(dead_code)]
+pub fn derive_voucher_bitmap_pda(program_id: &Pubkey, batch: &Pubkey) -> Pubkey {
+    Pubkey::find_program_address(&[b"voucher_bitmap", batch.as_ref()], program_id).0
+}
+
+// ============================================================================
+/// Creates a voucher batch.
+///
+/// `args` follows the instruction: `[batch_id, root, code_count, amount,
+/// budget, expires_at]`.
+///
+/// # Example:
+/// ```
+/// try_create_voucher_batch(&mut context, &owner, 1, vec![...]).await?;
+/// ```
+// ============================================================================
+#[allow(dead_code)]
+pub async fn try_create_voucher_batch(
+    context: &mut EnvProgramTestContext,
+    caller: &Keypair,
+    batch_id: u64,
+    args: Vec<String>,
+) -> Result<(), TransportError> {
+    let seeds = derive_seeds(&context.program_id, &caller.pubkey());
+    let voucher_batch = derive_voucher_batch_pda(&context.program_id, batch_id);
+
+    let ix = anchor_ix(
+        context.program_id,
+        soccial_accounts::CreateVoucherBatchContext {
+            caller: caller.pubkey(),
+            user_access: None,
+            voucher_batch,
+            voucher_bitmap: derive_voucher_bitmap_pda(&context.program_id, &voucher_batch),
+            token_state: seeds.token_state,
+            mint: seeds.token_mint,
+            airdrop_vault: seeds.airdrop_vault,
+            airdrop_vault_token_account: seeds.airdrop_vault_token_account,
+            system_program: system_program::ID,
+        },
+        soccial_instruction::CreateVoucherBatch { args },
+    );
+
+    send_ix(
+        &mut context.banks_client,
+        &context.payer,
+        &[&context.payer, caller],
+        ix,
+        context.recent_blockhash,
+    ).await?;
+
+    Ok(())
+}
+
+// ============================================================================
+/// Redeems `code` at `index` of batch `batch_id` into the caller's ATA,
+/// co-signing with the voucher key the code seeds.
+///
+/// # Example:
+/// ```
+/// try_redeem_voucher(&mut context, &user, 1, 0, "WELCOME-01", proof).await?;
+/// ```
+// ============================================================================
+#[allow(dead_code)]
+pub async fn try_redeem_voucher(
+    context: &mut EnvProgramTestContext,
+    caller: &Keypair,
+    batch_id: u64,
+    index: u32,
+    code: &str,
+    proof: Vec<String>,
+) -> Result<(), TransportError> {
+    try_redeem_voucher_with_key(context, caller, batch_id, index, &voucher_keypair(code), proof).await
+}
+
+// ============================================================================
+/// Redeems the voucher at `index` of batch `batch_id` into the caller's ATA,
+/// co-signing with an explicit `voucher_key`.
+///
+/// # Example:
+/// ```
+/// try_redeem_voucher_with_key(&mut context, &user, 1, 0, &key, proof).await?;
+/// ```
+// ============================================================================
+#[allow(dead_code)]
+pub async fn try_redeem_voucher_with_key(
+    context: &mut EnvProgramTestContext,
+    caller: &Keypair,
+    batch_id: u64,
+    index: u32,
+    voucher_key: &Keypair,
+    proof: Vec<String>,
+) -> Result<(), TransportError> {
+    let seeds = derive_seeds(&context.program_id, &caller.pubkey());
+    let voucher_batch = derive_voucher_batch_pda(&context.program_id, batch_id);
+
+    let mut args = vec![batch_id.to_string(), index.to_string()];
+    args.extend(proof);
+
+    let ix = anchor_ix(
+        context.program_id,
+        soccial_accounts::RedeemVoucherContext {
+            caller: caller.pubkey(),
+            voucher_key: voucher_key.pubkey(),
+            user_access: seeds.user_access,
+            voucher_batch,
+            voucher_bitmap: derive_voucher_bitmap_pda(&context.program_id, &voucher_batch),
+            token_state: seeds.token_state,
+            mint: seeds.token_mint,
+            airdrop_vault: seeds.airdrop_vault,
+            vault_limits: derive_vault_limits_pda(&context.program_id, &seeds.airdrop_vault),
+            airdrop_vault_token_account: seeds.airdrop_vault_token_account,
+            airdrop_ledger: derive_vault_ledger_pda(&context.program_id, &seeds.airdrop_vault),
+            redeemer_token_account: seeds.user_token_ata,
+            token_program: spl_token::ID,
+        },
+        soccial_instruction::RedeemVoucher { args },
+    );
+
+    send_ix(
+        &mut context.banks_client,
+        &context.payer,
+        &[&context.payer, caller, voucher_key],
+        ix,
+        context.recent_blockhash,
+    ).await?;
+
+    Ok(())
+}
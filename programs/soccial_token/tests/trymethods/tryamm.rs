@@ -0,0 +1,284 @@
+// ============================================================================
+// Soccial Token – AMM Pool Test Helpers
+// ----------------------------------------------------------------------------
+//
+// This module provides helper functions to exercise the constant-product
+// pool: creating a quote mint, seeding the pool from the liquidity vault,
+// swapping in both directions and removing treasury-owned liquidity.
+//
+// ----------------------------------------------------------------------------
+// Key Functions:
+// - `create_quote_mint`: Creates a test SPL mint used as the quote currency
+// - `mint_quote_to`: Creates an ATA for the owner and mints quote tokens
+// - `try_initialize_amm_pool`: Seeds the pool from the liquidity vault
+// - `try_amm_swap`: Swaps an exact input amount with a `min_out` bound
+// - `try_remove_amm_liquidity`: Burns treasury LP shares
+//
+// ----------------------------------------------------------------------------
+// Author: Paulo Rodrigues
+// Project: Soccial Token
+// Website: https://www.soccial.com/thetoken
+// License: MIT
+// ============================================================================
+
+use anchor_spl::associated_token::spl_associated_token_account;
+use solana_sdk::{
+    program_pack::Pack, pubkey::Pubkey, signature::Keypair, signer::Signer, system_instruction,
+    system_program, transaction::Transaction, transport::TransportError,
+};
+use spl_associated_token_account::{get_associated_token_address, instruction::create_associated_token_account};
+use crate::testutils::basics::*;
+use crate::testutils::environment::EnvProgramTestContext;
+use crate::trymethods::tryoracle::derive_price_accumulator_pda;
+use crate::trymethods::tryvaults::{derive_vault_ledger_pda, derive_vault_limits_pda};
+use soccial_token::{self, instruction as soccial_instruction};
+
+#[allow(dead_code)]
+pub const QUOTE_DECIMALS: u8 = 6;
+
+#[allow(dead_code)]
+pub fn derive_amm_pool_pda(program_id: &Pubkey) -> Pubkey {
+    Pubkey::find_program_address(&[b"amm_pool"], program_id).0
+}
+
+// ============================================================================
+/// Creates a test SPL mint (6 decimals) with the test payer as mint authority.
+// ============================================================================
+#[allow(dead_code)]
+pub async fn create_quote_mint(
+    context: &mut EnvProgramTestContext,
+) -> Result<Pubkey, TransportError> {
+    let mint = Keypair::new();
+    let rent = context.banks_client.get_rent().await?;
+
+    let create_ix = system_instruction::create_account(
+        &context.payer.pubkey(),
+        &mint.pubkey(),
+        rent.minimum_balance(spl_token::state::Mint::LEN),
+        spl_token::state::Mint::LEN as u64,
+        &spl_token::ID,
+    );
+    let init_ix = spl_token::instruction::initialize_mint2(
+        &spl_token::ID,
+        &mint.pubkey(),
+        &context.payer.pubkey(),
+        None,
+        QUOTE_DECIMALS,
+    ).unwrap();
+
+    let tx = Transaction::new_signed_with_payer(
+        &[create_ix, init_ix],
+        Some(&context.payer.pubkey()),
+        &[&context.payer, &mint],
+        context.recent_blockhash,
+    );
+    context.banks_client.process_transaction(tx).await?;
+
+    Ok(mint.pubkey())
+}
+
+// ============================================================================
+/// Creates the owner's ATA for `mint` (if missing) and mints `amount` to it.
+// ============================================================================
+#[allow(dead_code)]
+pub async fn mint_quote_to(
+    context: &mut EnvProgramTestContext,
+    mint: &Pubkey,
+    owner: &Pubkey,
+    amount: u64,
+) -> Result<Pubkey, TransportError> {
+    let ata = get_associated_token_address(owner, mint);
+
+    let mut instructions = vec![];
+    if context.banks_client.get_account(ata).await?.is_none() {
+        instructions.push(create_associated_token_account(
+            &context.payer.pubkey(),
+            owner,
+            mint,
+            &spl_token::ID,
+        ));
+    }
+    instructions.push(spl_token::instruction::mint_to(
+        &spl_token::ID,
+        mint,
+        &ata,
+        &context.payer.pubkey(),
+        &[],
+        amount,
+    ).unwrap());
+
+    let tx = Transaction::new_signed_with_payer(
+        &instructions,
+        Some(&context.payer.pubkey()),
+        &[&context.payer],
+        context.recent_blockhash,
+    );
+    context.banks_client.process_transaction(tx).await?;
+
+    Ok(ata)
+}
+
+// ============================================================================
+/// Seeds the AMM pool with SCTK from the liquidity vault and quote tokens
+/// from the caller's quote ATA.
+///
+/// # Example:
+/// ```
+/// try_initialize_amm_pool(&mut context, &admin, &quote_mint, 1_000_000_000_000, 1_000_000_000, 30).await?;
+/// ```
+// ============================================================================
+#[allow(dead_code)]
+pub async fn try_initialize_amm_pool(
+    context: &mut EnvProgramTestContext,
+    caller: &Keypair,
+    quote_mint: &Pubkey,
+    sctk_amount: u64,
+    quote_amount: u64,
+    swap_fee_bps: u16,
+) -> Result<(), TransportError> {
+    let seeds = derive_seeds(&context.program_id, &caller.pubkey());
+    let amm_pool = derive_amm_pool_pda(&context.program_id);
+    let args = vec![sctk_amount.to_string(), quote_amount.to_string(), swap_fee_bps.to_string()];
+
+    let ix = anchor_ix(
+        context.program_id,
+        soccial_token::accounts::InitializeAmmPool {
+            caller: caller.pubkey(),
+            caller_quote_account: get_associated_token_address(&caller.pubkey(), quote_mint),
+            amm_pool,
+            price_accumulator: derive_price_accumulator_pda(&context.program_id, quote_mint),
+            quote_mint: *quote_mint,
+            pool_token_account: get_associated_token_address(&amm_pool, &seeds.token_mint),
+            pool_quote_account: get_associated_token_address(&amm_pool, quote_mint),
+            liquidity_vault: seeds.liquidity_vault,
+            liquidity_vault_token_account: seeds.liquidity_vault_token_account,
+            vault_limits: derive_vault_limits_pda(&context.program_id, &seeds.liquidity_vault),
+            liquidity_ledger: derive_vault_ledger_pda(&context.program_id, &seeds.liquidity_vault),
+            treasury_vault: seeds.treasury_vault,
+            token_mint: seeds.token_mint,
+            user_access: None,
+            token_state: seeds.token_state,
+            token_program: spl_token::ID,
+            associated_token_program: spl_associated_token_account::ID,
+            system_program: system_program::ID,
+        },
+        soccial_instruction::InitializeAmmPool { args },
+    );
+
+    send_ix(
+        &mut context.banks_client,
+        &context.payer,
+        &[&context.payer, caller],
+        ix,
+        context.recent_blockhash,
+    ).await?;
+
+    Ok(())
+}
+
+// ============================================================================
+/// Swaps `amount_in` against the pool.
+///
+/// # Parameters:
+/// - `side`: `"buy"` (quote → SCTK) or `"sell"` (SCTK → quote)
+/// - `min_out`: Minimum accepted output
+// ============================================================================
+#[allow(dead_code)]
+pub async fn try_amm_swap(
+    context: &mut EnvProgramTestContext,
+    trader: &Keypair,
+    quote_mint: &Pubkey,
+    side: &str,
+    amount_in: u64,
+    min_out: u64,
+) -> Result<(), TransportError> {
+    let seeds = derive_seeds(&context.program_id, &trader.pubkey());
+    let amm_pool = derive_amm_pool_pda(&context.program_id);
+    let args = vec![side.to_string(), amount_in.to_string(), min_out.to_string()];
+
+    let ix = anchor_ix(
+        context.program_id,
+        soccial_token::accounts::AmmSwapContext {
+            caller: trader.pubkey(),
+            user_token_account: seeds.user_token_ata,
+            user_quote_account: get_associated_token_address(&trader.pubkey(), quote_mint),
+            amm_pool,
+            price_accumulator: derive_price_accumulator_pda(&context.program_id, quote_mint),
+            quote_mint: *quote_mint,
+            pool_token_account: get_associated_token_address(&amm_pool, &seeds.token_mint),
+            pool_quote_account: get_associated_token_address(&amm_pool, quote_mint),
+            rewards_vault: seeds.rewards_vault,
+            rewards_vault_token_account: seeds.rewards_vault_token_account,
+            revenue_vault: seeds.revenue_vault,
+            revenue_vault_token_account: seeds.revenue_vault_token_account,
+            airdrop_vault: seeds.airdrop_vault,
+            airdrop_vault_token_account: seeds.airdrop_vault_token_account,
+            rewards_ledger: derive_vault_ledger_pda(&context.program_id, &seeds.rewards_vault),
+            revenue_ledger: derive_vault_ledger_pda(&context.program_id, &seeds.revenue_vault),
+            airdrop_ledger: derive_vault_ledger_pda(&context.program_id, &seeds.airdrop_vault),
+            token_mint: seeds.token_mint,
+            user_access: None,
+            token_state: seeds.token_state,
+            token_program: spl_token::ID,
+        },
+        soccial_instruction::AmmSwap { args },
+    );
+
+    send_ix(
+        &mut context.banks_client,
+        &context.payer,
+        &[&context.payer, trader],
+        ix,
+        context.recent_blockhash,
+    ).await?;
+
+    Ok(())
+}
+
+// ============================================================================
+/// Burns `shares` treasury LP shares, returning SCTK to the liquidity vault
+/// and quote tokens to the treasury vault's quote ATA.
+// ============================================================================
+#[allow(dead_code)]
+pub async fn try_remove_amm_liquidity(
+    context: &mut EnvProgramTestContext,
+    caller: &Keypair,
+    quote_mint: &Pubkey,
+    shares: u64,
+) -> Result<(), TransportError> {
+    let seeds = derive_seeds(&context.program_id, &caller.pubkey());
+    let amm_pool = derive_amm_pool_pda(&context.program_id);
+
+    let ix = anchor_ix(
+        context.program_id,
+        soccial_token::accounts::RemoveAmmLiquidity {
+            caller: caller.pubkey(),
+            amm_pool,
+            quote_mint: *quote_mint,
+            pool_token_account: get_associated_token_address(&amm_pool, &seeds.token_mint),
+            pool_quote_account: get_associated_token_address(&amm_pool, quote_mint),
+            liquidity_vault: seeds.liquidity_vault,
+            liquidity_vault_token_account: seeds.liquidity_vault_token_account,
+            liquidity_ledger: derive_vault_ledger_pda(&context.program_id, &seeds.liquidity_vault),
+            treasury_vault: seeds.treasury_vault,
+            treasury_quote_account: get_associated_token_address(&seeds.treasury_vault, quote_mint),
+            token_mint: seeds.token_mint,
+            user_access: None,
+            token_state: seeds.token_state,
+            token_program: spl_token::ID,
+            associated_token_program: spl_associated_token_account::ID,
+            system_program: system_program::ID,
+        },
+        soccial_instruction::RemoveAmmLiquidity { args: vec![shares.to_string()] },
+    );
+
+    send_ix(
+        &mut context.banks_client,
+        &context.payer,
+        &[&context.payer, caller],
+        ix,
+        context.recent_blockhash,
+    ).await?;
+
+    Ok(())
+}
@@ -0,0 +1,385 @@
+// ============================================================================
+// Soccial Token – Escrow Test Helpers
+// ----------------------------------------------------------------------------
+//
+// This module provides helper functions to simulate escrowed peer-to-peer
+// trades (e.g. Collaboration Cards) held by the program until settlement,
+// and milestone-based collaboration escrows.
+//
+// ----------------------------------------------------------------------------
+// Key Functions:
+// - `derive_escrow_pda`: Derives the escrow PDA for an escrow ID
+// - `try_open_escrow`: Funds a new escrow as the maker
+// - `try_release_escrow`: Pays the taker
+// - `try_refund_escrow`: Returns the funds to the maker
+// - `try_arbitrate_escrow`: Settles a dispute as the arbiter
+// - `derive_milestone_escrow_pda`: Derives the milestone escrow PDA for an ID
+// - `try_open_milestone_escrow`: Deposits a collaboration total in milestones
+// - `try_release_milestone`: Pays out one milestone
+// - `try_refund_milestone_escrow`: Refunds the remainder after the deadline
+//
+// ----------------------------------------------------------------------------
+// Author: Paulo Rodrigues
+// Project: Soccial Token
+// Website: https://www.soccial.com/thetoken
+// License: MIT
+// ============================================================================
+
+use solana_sdk::{pubkey::Pubkey, signature::Keypair, signer::Signer, system_program, transport::TransportError};
+use crate::testutils::basics::*;
+use crate::testutils::environment::EnvProgramTestContext;
+use crate::trymethods::trymarket::caller_user_access;
+use crate::trymethods::tryvaults::derive_vault_ledger_pda;
+use soccial_token::{self, instruction as soccial_instruction};
+
+/// Derives the global `EscrowState` PDA.
+#[allow(dead_code)]
+pub fn derive_escrow_state_pda(program_id: &Pubkey) -> Pubkey {
+    Pubkey::find_program_address(&[b"escrow_state"], program_id).0
+}
+
+/// Derives the `Escrow` PDA for an escrow ID.
+#[allow(dead_code)]
+pub fn derive_escrow_pda(program_id: &Pubkey, id: u64) -> Pubkey {
+    Pubkey::find_program_address(&[b"escrow", &id.to_le_bytes()], program_id).0
+}
+
+// ============================================================================
+/// Opens escrow `id` from `maker` to `taker`.
+///
+/// `item_hash` is 64 hex characters; `arbiter` is optional.
+///
+/// # Example:
+/// ```
+/// try_open_escrow(&mut context, &maker, 0, &taker.pubkey(), 1_000, &"ab".repeat(32), deadline, None).await?;
+/// ```
+// ============================================================================
+#[allow(dead_code)]
+pub async fn try_open_escrow(
+    context: &mut EnvProgramTestContext,
+    maker: &Keypair,
+    id: u64,
+    taker: &Pubkey,
+    amount: u64,
+    item_hash: &str,
+    deadline: i64,
+    arbiter: Option<Pubkey>,
+) -> Result<(), TransportError> {
+    let seeds = derive_seeds(&context.program_id, &maker.pubkey());
+    let escrow = derive_escrow_pda(&context.program_id, id);
+    let args = vec![
+        taker.to_string(),
+        amount.to_string(),
+        item_hash.to_string(),
+        deadline.to_string(),
+        arbiter.map(|a| a.to_string()).unwrap_or_else(|| "none".to_string()),
+    ];
+
+    let ix = anchor_ix(
+        context.program_id,
+        soccial_token::accounts::OpenEscrowContext {
+            caller: maker.pubkey(),
+            maker_token_account: seeds.user_token_ata,
+            escrow_state: derive_escrow_state_pda(&context.program_id),
+            escrow,
+            escrow_token_account: derive_user_ata(&context.program_id, &escrow),
+            token_mint: seeds.token_mint,
+            user_access: None,
+            token_state: seeds.token_state,
+            token_program: spl_token::ID,
+            associated_token_program: spl_associated_token_account::ID,
+            system_program: system_program::ID,
+        },
+        soccial_instruction::OpenEscrow { args },
+    );
+
+    send_ix(
+        &mut context.banks_client,
+        &context.payer,
+        &[&context.payer, maker],
+        ix,
+        context.recent_blockhash,
+    ).await?;
+
+    Ok(())
+}
+
+/// Builds the shared settlement accounts for escrow `id`.
+fn settle_accounts(
+    program_id: &Pubkey,
+    caller: &Pubkey,
+    id: u64,
+    maker: &Pubkey,
+    taker: &Pubkey,
+) -> soccial_token::accounts::SettleEscrowContext {
+    let seeds = derive_seeds(program_id, caller);
+    let escrow = derive_escrow_pda(program_id, id);
+
+    soccial_token::accounts::SettleEscrowContext {
+        caller: *caller,
+        escrow,
+        escrow_token_account: derive_user_ata(program_id, &escrow),
+        maker: *maker,
+        maker_token_account: derive_user_ata(program_id, maker),
+        taker_token_account: derive_user_ata(program_id, taker),
+
+        rewards_vault: seeds.rewards_vault,
+        rewards_vault_token_account: seeds.rewards_vault_token_account,
+        revenue_vault: seeds.revenue_vault,
+        revenue_vault_token_account: seeds.revenue_vault_token_account,
+        airdrop_vault: seeds.airdrop_vault,
+        airdrop_vault_token_account: seeds.airdrop_vault_token_account,
+        rewards_ledger: derive_vault_ledger_pda(program_id, &seeds.rewards_vault),
+        revenue_ledger: derive_vault_ledger_pda(program_id, &seeds.revenue_vault),
+        airdrop_ledger: derive_vault_ledger_pda(program_id, &seeds.airdrop_vault),
+
+        token_mint: seeds.token_mint,
+        user_access: None,
+        maker_access: None,
+        token_state: seeds.token_state,
+        token_program: spl_token::ID,
+    }
+}
+
+// ============================================================================
+/// Releases escrow `id` to the taker, signed by `caller`.
+// ============================================================================
+#[allow(dead_code)]
+pub async fn try_release_escrow(
+    context: &mut EnvProgramTestContext,
+    caller: &Keypair,
+    id: u64,
+    maker: &Pubkey,
+    taker: &Pubkey,
+) -> Result<(), TransportError> {
+    let ix = anchor_ix(
+        context.program_id,
+        settle_accounts(&context.program_id, &caller.pubkey(), id, maker, taker),
+        soccial_instruction::ReleaseEscrow {},
+    );
+
+    send_ix(
+        &mut context.banks_client,
+        &context.payer,
+        &[&context.payer, caller],
+        ix,
+        context.recent_blockhash,
+    ).await?;
+
+    Ok(())
+}
+
+// ============================================================================
+/// Refunds escrow `id` to the maker, signed by `caller`.
+// ============================================================================
+#[allow(dead_code)]
+pub async fn try_refund_escrow(
+    context: &mut EnvProgramTestContext,
+    caller: &Keypair,
+    id: u64,
+    maker: &Pubkey,
+    taker: &Pubkey,
+) -> Result<(), TransportError> {
+    let ix = anchor_ix(
+        context.program_id,
+        settle_accounts(&context.program_id, &caller.pubkey(), id, maker, taker),
+        soccial_instruction::RefundEscrow {},
+    );
+
+    send_ix(
+        &mut context.banks_client,
+        &context.payer,
+        &[&context.payer, caller],
+        ix,
+        context.recent_blockhash,
+    ).await?;
+
+    Ok(())
+}
+
+// ============================================================================
+/// Settles escrow `id` with `decision` (`"release"` or `"refund"`).
+// ============================================================================
+#[allow(dead_code)]
+pub async fn try_arbitrate_escrow(
+    context: &mut EnvProgramTestContext,
+    arbiter: &Keypair,
+    id: u64,
+    maker: &Pubkey,
+    taker: &Pubkey,
+    decision: &str,
+) -> Result<(), TransportError> {
+    let ix = anchor_ix(
+        context.program_id,
+        settle_accounts(&context.program_id, &arbiter.pubkey(), id, maker, taker),
+        soccial_instruction::ArbitrateEscrow { args: vec![decision.to_string()] },
+    );
+
+    send_ix(
+        &mut context.banks_client,
+        &context.payer,
+        &[&context.payer, arbiter],
+        ix,
+        context.recent_blockhash,
+    ).await?;
+
+    Ok(())
+}
+
+/// Derives the `MilestoneEscrow` PDA for an escrow ID.
+#[allow(dead_code)]
+pub fn derive_milestone_escrow_pda(program_id: &Pubkey, id: u64) -> Pubkey {
+    Pubkey::find_program_address(&[b"milestone_escrow", &id.to_le_bytes()], program_id).0
+}
+
+// ============================================================================
+/// Opens milestone escrow `id` from `funder` to `recipient`.
+///
+/// # Example:
+/// ```
+/// try_open_milestone_escrow(&mut context, &funder, 0, &recipient.pubkey(), deadline, &[100, 200]).await?;
+/// ```
+// ============================================================================
+#[allow(dead_code)]
+pub async fn try_open_milestone_escrow(
+    context: &mut EnvProgramTestContext,
+    funder: &Keypair,
+    id: u64,
+    recipient: &Pubkey,
+    deadline: i64,
+    milestones: &[u64],
+) -> Result<(), TransportError> {
+    let seeds = derive_seeds(&context.program_id, &funder.pubkey());
+    let milestone_escrow = derive_milestone_escrow_pda(&context.program_id, id);
+
+    let mut args = vec![recipient.to_string(), deadline.to_string()];
+    args.extend(milestones.iter().map(|m| m.to_string()));
+
+    let ix = anchor_ix(
+        context.program_id,
+        soccial_token::accounts::OpenMilestoneEscrowContext {
+            caller: funder.pubkey(),
+            funder_token_account: seeds.user_token_ata,
+            escrow_state: derive_escrow_state_pda(&context.program_id),
+            milestone_escrow,
+            escrow_token_account: derive_user_ata(&context.program_id, &milestone_escrow),
+            token_mint: seeds.token_mint,
+            user_access: None,
+            token_state: seeds.token_state,
+            token_program: spl_token::ID,
+            associated_token_program: spl_associated_token_account::ID,
+            system_program: system_program::ID,
+        },
+        soccial_instruction::OpenMilestoneEscrow { args },
+    );
+
+    send_ix(
+        &mut context.banks_client,
+        &context.payer,
+        &[&context.payer, funder],
+        ix,
+        context.recent_blockhash,
+    ).await?;
+
+    Ok(())
+}
+
+/// Builds the shared settlement accounts for milestone escrow `id`.
+async fn settle_milestone_accounts(
+    context: &mut EnvProgramTestContext,
+    caller: &Pubkey,
+    id: u64,
+    funder: &Pubkey,
+    recipient: &Pubkey,
+) -> soccial_token::accounts::SettleMilestoneContext {
+    let program_id = context.program_id;
+    let seeds = derive_seeds(&program_id, caller);
+    let milestone_escrow = derive_milestone_escrow_pda(&program_id, id);
+    let user_access = caller_user_access(context, caller).await;
+
+    soccial_token::accounts::SettleMilestoneContext {
+        caller: *caller,
+        milestone_escrow,
+        escrow_token_account: derive_user_ata(&program_id, &milestone_escrow),
+        funder: *funder,
+        funder_token_account: derive_user_ata(&program_id, funder),
+        recipient_token_account: derive_user_ata(&program_id, recipient),
+
+        rewards_vault: seeds.rewards_vault,
+        rewards_vault_token_account: seeds.rewards_vault_token_account,
+        revenue_vault: seeds.revenue_vault,
+        revenue_vault_token_account: seeds.revenue_vault_token_account,
+        airdrop_vault: seeds.airdrop_vault,
+        airdrop_vault_token_account: seeds.airdrop_vault_token_account,
+        rewards_ledger: derive_vault_ledger_pda(&program_id, &seeds.rewards_vault),
+        revenue_ledger: derive_vault_ledger_pda(&program_id, &seeds.revenue_vault),
+        airdrop_ledger: derive_vault_ledger_pda(&program_id, &seeds.airdrop_vault),
+
+        token_mint: seeds.token_mint,
+        user_access,
+        funder_access: None,
+        token_state: seeds.token_state,
+        token_program: spl_token::ID,
+    }
+}
+
+// ============================================================================
+/// Releases milestone `index` of escrow `id`, signed by `caller`.
+// ============================================================================
+#[allow(dead_code)]
+pub async fn try_release_milestone(
+    context: &mut EnvProgramTestContext,
+    caller: &Keypair,
+    id: u64,
+    funder: &Pubkey,
+    recipient: &Pubkey,
+    index: u8,
+) -> Result<(), TransportError> {
+    let accounts = settle_milestone_accounts(context, &caller.pubkey(), id, funder, recipient).await;
+
+    let ix = anchor_ix(
+        context.program_id,
+        accounts,
+        soccial_instruction::ReleaseMilestone { args: vec![index.to_string()] },
+    );
+
+    send_ix(
+        &mut context.banks_client,
+        &context.payer,
+        &[&context.payer, caller],
+        ix,
+        context.recent_blockhash,
+    ).await?;
+
+    Ok(())
+}
+
+// ============================================================================
+/// Refunds the remainder of milestone escrow `id`, signed by `caller`.
+// ============================================================================
+#[allow(dead_code)]
+pub async fn try_refund_milestone_escrow(
+    context: &mut EnvProgramTestContext,
+    caller: &Keypair,
+    id: u64,
+    funder: &Pubkey,
+    recipient: &Pubkey,
+) -> Result<(), TransportError> {
+    let accounts = settle_milestone_accounts(context, &caller.pubkey(), id, funder, recipient).await;
+
+    let ix = anchor_ix(
+        context.program_id,
+        accounts,
+        soccial_instruction::RefundMilestoneEscrow {},
+    );
+
+    send_ix(
+        &mut context.banks_client,
+        &context.payer,
+        &[&context.payer, caller],
+        ix,
+        context.recent_blockhash,
+    ).await?;
+
+    Ok(())
+}
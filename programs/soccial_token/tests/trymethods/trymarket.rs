@@ -19,8 +19,20 @@
 // ----------------------------------------------------------------------------
 // Key Functions:
 // - `try_buy_tokens`: Buys tokens from the liquidity vault  
+// - `try_buy_tokens_with_quote`: Self-submits a purchase signed by the API authority  
 // - `try_deposit_tokens`: Deposits tokens into the off-chain reserve  
+// - `try_deposit_tokens_with_address_book`: Deposits with an explicit withdrawal address book  
 // - `try_transfer_tokens`: Transfers tokens between users with fee logic  
+// - `caller_user_access`: Resolves the caller's `UserAccessAccount`, if created  
+// - `try_batch_transfer_tokens`: Pays many recipients in one instruction  
+// - `try_configure_sale_curve`: Sets the primary-sale bonding curve via governance  
+// - `try_update_price_config`: Updates pricing through an approved proposal  
+// - `try_purchase_tokens`: Buys tokens paying on-chain in the quote currency  
+// - `try_sell_tokens`: Sells tokens back to the protocol for the quote currency  
+// - `try_credit_offchain_wallet`: Moves SPL tokens into the Soccial Wallet  
+// - `try_add_withdrawal_address`: Registers a destination in the caller's address book  
+// - `try_remove_withdrawal_address`: Removes a destination from the address book  
+// - `try_activate_withdrawal_address`: Registers a destination and waits out the cooldown  
 //
 // ----------------------------------------------------------------------------
 // Author: Paulo Rodrigues  
@@ -29,13 +41,40 @@
 // License: MIT  
 // ============================================================================
 
-use solana_sdk::{pubkey::Pubkey, signature::Keypair, signer::Signer, transport::TransportError};
+use anchor_lang::AccountDeserialize;
+use solana_sdk::{
+    ed25519_program, instruction::{AccountMeta, Instruction}, pubkey::Pubkey, signature::Keypair, signer::Signer,
+    system_program, transaction::Transaction, transport::TransportError,
+};
 use crate::testutils::{basics::*};
 use crate::testutils::environment::EnvProgramTestContext;
+use crate::trymethods::trygovernance::try_approve_proposal_flow;
+use crate::trymethods::tryreferral::referral_accounts;
+use crate::trymethods::tryoracle::derive_price_accumulator_pda;
+use crate::trymethods::tryvaults::{derive_vault_ledger_pda, derive_vault_limits_pda};
+use soccial_token::market::{request_id_seed, PriceConfig, DEFAULT_ADDRESS_COOLDOWN_SECS};
 use soccial_token::{self, instruction as soccial_instruction};
 
 // ============================================================================
-/// Buys tokens from the liquidity vault, applying fee-based distribution.
+/// Returns the caller's `UserAccessAccount` PDA if it exists on-chain.
+///
+/// Market helpers pass it along so flag-based fee discounts (VIP, Staff, ...)
+/// apply; callers without an access account are sent as `None`.
+// ============================================================================
+#[allow(dead_code)]
+pub async fn caller_user_access(
+    context: &mut EnvProgramTestContext,
+    caller: &Pubkey,
+) -> Option<Pubkey> {
+    let user_access = derive_seeds(&context.program_id, caller).user_access;
+    match context.banks_client.get_account(user_access).await {
+        Ok(Some(_)) => Some(user_access),
+        _ => None,
+    }
+}
+
+// ============================================================================
+/// Buys tokens from the liquidity vault, applying the scheduled buy fee.
 ///
 /// This simulates a user buying SCTK tokens, with fees allocated across
 /// the rewards, revenue, and airdrop vaults.
@@ -45,14 +84,14 @@ use soccial_token::{self, instruction as soccial_instruction};
 /// - `caller`: Authorized contract signer
 /// - `buyer`: Buyer of the tokens
 /// - `amount`: Amount of tokens to purchase
-/// - `fee_bps`: Fee in basis points (1 BPS = 0.01%)
+/// - `max_price`: Maximum accepted bonding-curve price
 ///
 /// # Returns:
 /// `Ok(())` if transaction succeeded, or `TransportError` on failure
 ///
 /// # Example:
 /// ```
-/// try_buy_tokens(&mut context, &admin, &buyer, 1_000_000, 500).await?;
+/// try_buy_tokens(&mut context, &admin, &buyer, 1_000_000, u64::MAX).await?;
 /// ```
 // ============================================================================
 #[allow(dead_code)]
@@ -61,20 +100,25 @@ pub async fn try_buy_tokens(
     caller: &Keypair,
     buyer: &Keypair,
     amount: u64,
-    fee_bps: u16,
+    max_price: u64,
 ) -> Result<(), TransportError> {
     let seeds = derive_seeds(&context.program_id, &buyer.pubkey());
+    let user_access = caller_user_access(context, &caller.pubkey()).await;
+    let (referral, referrer_account) = referral_accounts(context, &buyer.pubkey()).await;
     let args = vec![
         amount.to_string(),
-        fee_bps.to_string(),
+        max_price.to_string(),
     ];
 
     let ix = anchor_ix(
         context.program_id,
         soccial_token::accounts::BuyTokensContext {
             caller: caller.pubkey(),
+            sale_state: derive_sale_state_pda(&context.program_id),
             liquidity_vault: seeds.liquidity_vault,
+            vault_limits: derive_vault_limits_pda(&context.program_id, &seeds.liquidity_vault),
             liquidity_vault_token_account: seeds.liquidity_vault_token_account,
+            liquidity_ledger: derive_vault_ledger_pda(&context.program_id, &seeds.liquidity_vault),
             buyer_token_account: seeds.user_token_ata,
 
             rewards_vault: seeds.rewards_vault,
@@ -83,9 +127,15 @@ pub async fn try_buy_tokens(
             revenue_vault_token_account: seeds.revenue_vault_token_account,
             airdrop_vault: seeds.airdrop_vault,
             airdrop_vault_token_account: seeds.airdrop_vault_token_account,
+            rewards_ledger: derive_vault_ledger_pda(&context.program_id, &seeds.rewards_vault),
+            revenue_ledger: derive_vault_ledger_pda(&context.program_id, &seeds.revenue_vault),
+            airdrop_ledger: derive_vault_ledger_pda(&context.program_id, &seeds.airdrop_vault),
 
             token_mint: seeds.token_mint,
-            user_access: None,
+            referral,
+            referrer_account,
+            user_access,
+            fee_payer_access: seeds.user_access,
             token_state: seeds.token_state,
             token_program: spl_token::ID,
            
@@ -104,6 +154,142 @@ pub async fn try_buy_tokens(
     Ok(())
 }
 
+/// Derives the `QuoteNonce` PDA for a buyer and quote nonce.
+#[allow(dead_code)]
+pub fn derive_quote_nonce_pda(program_id: &Pubkey, buyer: &Pubkey, nonce: u64) -> Pubkey {
+    Pubkey::find_program_address(&[b"quote_nonce", buyer.as_ref(), &nonce.to_le_bytes()], program_id).0
+}
+
+// ============================================================================
+/// Builds a native ed25519 instruction verifying `message` signed by `signer`,
+/// with the key, signature and message stored inside the instruction.
+// ============================================================================
+#[allow(dead_code)]
+pub fn build_ed25519_ix(signer: &Keypair, message: &[u8]) -> Instruction {
+    let signature = signer.sign_message(message);
+    let pubkey_offset: u16 = 16;
+    let signature_offset: u16 = pubkey_offset + 32;
+    let message_offset: u16 = signature_offset + 64;
+
+    let mut data = vec![1u8, 0u8];
+    for value in [
+        signature_offset,
+        u16::MAX,
+        pubkey_offset,
+        u16::MAX,
+        message_offset,
+        message.len() as u16,
+        u16::MAX,
+    ] {
+        data.extend_from_slice(&value.to_le_bytes());
+    }
+    data.extend_from_slice(signer.pubkey().as_ref());
+    data.extend_from_slice(signature.as_ref());
+    data.extend_from_slice(message);
+
+    Instruction {
+        program_id: ed25519_program::ID,
+        accounts: vec![],
+        data,
+    }
+}
+
+// ============================================================================
+/// Buys tokens with a quote signed by `quote_signer` (normally the API
+/// authority), submitted by the buyer together with the ed25519 verification.
+/// The executed price is paid in SOL, so pricing must use `quote_mint=SOL`.
+///
+/// # Parameters:
+/// - `context`: Test context
+/// - `buyer`: Buyer submitting and signing the purchase
+/// - `quote_signer`: Key signing the quote
+/// - `nonce`: Single-use quote nonce
+/// - `amount`: Amount of tokens to purchase
+/// - `price`: Quoted price per 1 SCTK
+/// - `expires_at`: Quote expiry (unix timestamp)
+///
+/// # Example:
+/// ```
+/// try_buy_tokens_with_quote(&mut context, &buyer, &api, 1, 1_000_000, 10_005, expires_at).await?;
+/// ```
+// ============================================================================
+#[allow(dead_code)]
+pub async fn try_buy_tokens_with_quote(
+    context: &mut EnvProgramTestContext,
+    buyer: &Keypair,
+    quote_signer: &Keypair,
+    nonce: u64,
+    amount: u64,
+    price: u64,
+    expires_at: i64,
+) -> Result<(), TransportError> {
+    let seeds = derive_seeds(&context.program_id, &buyer.pubkey());
+    let user_access = caller_user_access(context, &buyer.pubkey()).await;
+    let (referral, referrer_account) = referral_accounts(context, &buyer.pubkey()).await;
+    let quote = soccial_token::market::PriceQuote {
+        price,
+        amount,
+        buyer: buyer.pubkey(),
+        expires_at,
+        nonce,
+    };
+    let args = vec![
+        nonce.to_string(),
+        amount.to_string(),
+        price.to_string(),
+        expires_at.to_string(),
+    ];
+
+    let quote_mint = pricing_quote_mint(context).await;
+    let ix = anchor_ix(
+        context.program_id,
+        soccial_token::accounts::BuyTokensWithQuoteContext {
+            caller: buyer.pubkey(),
+            sale_state: derive_sale_state_pda(&context.program_id),
+            price_accumulator: derive_price_accumulator_pda(&context.program_id, &quote_mint),
+            price_config: derive_price_config_pda(&context.program_id),
+            quote_treasury: derive_quote_treasury_pda(&context.program_id),
+            buyer_quote_account: None,
+            quote_treasury_token_account: None,
+            liquidity_vault: seeds.liquidity_vault,
+            vault_limits: derive_vault_limits_pda(&context.program_id, &seeds.liquidity_vault),
+            liquidity_vault_token_account: seeds.liquidity_vault_token_account,
+            liquidity_ledger: derive_vault_ledger_pda(&context.program_id, &seeds.liquidity_vault),
+            buyer_token_account: seeds.user_token_ata,
+
+            rewards_vault: seeds.rewards_vault,
+            rewards_vault_token_account: seeds.rewards_vault_token_account,
+            revenue_vault: seeds.revenue_vault,
+            revenue_vault_token_account: seeds.revenue_vault_token_account,
+            airdrop_vault: seeds.airdrop_vault,
+            airdrop_vault_token_account: seeds.airdrop_vault_token_account,
+            rewards_ledger: derive_vault_ledger_pda(&context.program_id, &seeds.rewards_vault),
+            revenue_ledger: derive_vault_ledger_pda(&context.program_id, &seeds.revenue_vault),
+            airdrop_ledger: derive_vault_ledger_pda(&context.program_id, &seeds.airdrop_vault),
+
+            token_mint: seeds.token_mint,
+            referral,
+            referrer_account,
+            user_access,
+            token_state: seeds.token_state,
+            token_program: spl_token::ID,
+            quote_nonce: derive_quote_nonce_pda(&context.program_id, &buyer.pubkey(), nonce),
+            instructions_sysvar: solana_sdk::sysvar::instructions::ID,
+            system_program: system_program::ID,
+        },
+        soccial_instruction::BuyTokensWithQuote { args },
+    );
+
+    let mut tx = Transaction::new_with_payer(
+        &[build_ed25519_ix(quote_signer, &quote.message()), ix],
+        Some(&context.payer.pubkey()),
+    );
+    tx.sign(&[&context.payer, buyer], context.recent_blockhash);
+    context.banks_client.process_transaction(tx).await?;
+
+    Ok(())
+}
+
 // ============================================================================
 /// Deposits tokens into the off-chain reserve (e.g., for CEX custody).
 ///
@@ -115,14 +301,14 @@ pub async fn try_buy_tokens(
 /// - `caller`: Authorized signer
 /// - `recipient`: User depositing the tokens
 /// - `amount`: Amount to deposit (raw units)
-/// - `fee_bps`: Fee in basis points
+/// - `request_id`: Backend idempotency key (seeds the `WithdrawalReceipt`)
 ///
 /// # Returns:
 /// `Ok(())` if transaction succeeded, or `TransportError` on failure
 ///
 /// # Example:
 /// ```
-/// try_deposit_tokens(&mut context, &admin, &user.pubkey(), 5_000_000, 300).await?;
+/// try_deposit_tokens(&mut context, &admin, &user.pubkey(), 5_000_000, "req-1").await?;
 /// ```
 // ============================================================================
 #[allow(dead_code)]
@@ -131,22 +317,51 @@ pub async fn try_deposit_tokens(
     caller: &Keypair,
     recipient: &Pubkey,
     amount: u64,
-    fee_bps: u16,
+    request_id: &str,
+) -> Result<(), TransportError> {
+    let withdrawal_address_book = existing_account(
+        context,
+        derive_withdrawal_address_book_pda(&context.program_id, recipient),
+    ).await;
+
+    try_deposit_tokens_with_address_book(context, caller, recipient, amount, request_id, withdrawal_address_book).await
+}
+
+// ============================================================================
+/// Same as `try_deposit_tokens`, but passes `withdrawal_address_book` as-is
+/// instead of resolving the recipient's book.
+///
+/// # Example:
+/// ```
+/// try_deposit_tokens_with_address_book(&mut context, &admin, &user.pubkey(), 5_000_000, "req-1", Some(book)).await?;
+/// ```
+// ============================================================================
+#[allow(dead_code)]
+pub async fn try_deposit_tokens_with_address_book(
+    context: &mut EnvProgramTestContext,
+    caller: &Keypair,
+    recipient: &Pubkey,
+    amount: u64,
+    request_id: &str,
+    withdrawal_address_book: Option<Pubkey>,
 ) -> Result<(), TransportError> {
     let seeds = derive_seeds(&context.program_id, recipient);
     let args = vec![
         amount.to_string(),
-        fee_bps.to_string(),
+        request_id.to_string(),
     ];
-    
+
     let ix = anchor_ix(
         context.program_id,
         soccial_token::accounts::DepositTokensContext {
             caller: caller.pubkey(),
             offchain_reserve_vault: seeds.offchain_reserve_vault,
+            vault_limits: derive_vault_limits_pda(&context.program_id, &seeds.offchain_reserve_vault),
             offchain_reserve_vault_token_account: seeds.offchain_reserve_vault_token_account,
+            offchain_reserve_ledger: derive_vault_ledger_pda(&context.program_id, &seeds.offchain_reserve_vault),
             destination_authority: *recipient,
             destination_token_account: seeds.user_token_ata,
+            withdrawal_address_book,
 
             rewards_vault: seeds.rewards_vault,
             rewards_vault_token_account: seeds.rewards_vault_token_account,
@@ -154,11 +369,17 @@ pub async fn try_deposit_tokens(
             revenue_vault_token_account: seeds.revenue_vault_token_account,
             airdrop_vault: seeds.airdrop_vault,
             airdrop_vault_token_account: seeds.airdrop_vault_token_account,
+            rewards_ledger: derive_vault_ledger_pda(&context.program_id, &seeds.rewards_vault),
+            revenue_ledger: derive_vault_ledger_pda(&context.program_id, &seeds.revenue_vault),
+            airdrop_ledger: derive_vault_ledger_pda(&context.program_id, &seeds.airdrop_vault),
 
             token_mint: seeds.token_mint,
             user_access: None,
+            fee_payer_access: seeds.user_access,
             token_state: seeds.token_state,
+            withdrawal_receipt: derive_withdrawal_receipt_pda(&context.program_id, request_id),
             token_program: spl_token::ID,
+            system_program: system_program::ID,
         },
         soccial_instruction::DepositTokens { args },
     );
@@ -187,14 +408,13 @@ pub async fn try_deposit_tokens(
 /// - `sender`: Token sender account
 /// - `recipient_ata`: Associated token account of the recipient
 /// - `amount`: Token amount to send
-/// - `fee_bps`: Fee in basis points to apply
 ///
 /// # Returns:
 /// `Ok(())` if transfer succeeded, or error on failure
 ///
 /// # Example:
 /// ```
-/// try_transfer_tokens(&mut context, &admin, &user, recipient_ata, 10_000_000, 250).await?;
+/// try_transfer_tokens(&mut context, &admin, &user, recipient_ata, 10_000_000).await?;
 /// ```
 // ============================================================================
 
@@ -205,12 +425,35 @@ pub async fn try_transfer_tokens(
     sender: &Keypair,
     recipient_ata: Pubkey,
     amount: u64,
-    fee_bps: u16,
+) -> Result<(), TransportError> {
+    let (referral, referrer_account) = referral_accounts(context, &sender.pubkey()).await;
+
+    try_transfer_tokens_with_referral(context, caller, sender, recipient_ata, amount, referral, referrer_account).await
+}
+
+// ============================================================================
+/// Same as `try_transfer_tokens`, with explicit referral accounts, to
+/// simulate a relayer tampering with them.
+///
+/// # Example:
+/// ```
+/// try_transfer_tokens_with_referral(&mut context, &admin, &user, recipient_ata, 10_000_000, referral, other_referrer).await?;
+/// ```
+// ============================================================================
+#[allow(dead_code)]
+pub async fn try_transfer_tokens_with_referral(
+    context: &mut EnvProgramTestContext,
+    caller: &Keypair,
+    sender: &Keypair,
+    recipient_ata: Pubkey,
+    amount: u64,
+    referral: Pubkey,
+    referrer_account: Pubkey,
 ) -> Result<(), TransportError> {
     let seeds = derive_seeds(&context.program_id, &sender.pubkey());
+    let user_access = caller_user_access(context, &caller.pubkey()).await;
     let args = vec![
         amount.to_string(),
-        fee_bps.to_string(),
     ];
 
     let ix = anchor_ix(
@@ -220,6 +463,8 @@ pub async fn try_transfer_tokens(
             sender: sender.pubkey(),
             sender_token_account: seeds.user_token_ata,
             recipient_token_account: recipient_ata,
+            referral,
+            referrer_account,
 
             rewards_vault: seeds.rewards_vault,
             rewards_vault_token_account: seeds.rewards_vault_token_account,
@@ -227,9 +472,13 @@ pub async fn try_transfer_tokens(
             revenue_vault_token_account: seeds.revenue_vault_token_account,
             airdrop_vault: seeds.airdrop_vault,
             airdrop_vault_token_account: seeds.airdrop_vault_token_account,
+            rewards_ledger: derive_vault_ledger_pda(&context.program_id, &seeds.rewards_vault),
+            revenue_ledger: derive_vault_ledger_pda(&context.program_id, &seeds.revenue_vault),
+            airdrop_ledger: derive_vault_ledger_pda(&context.program_id, &seeds.airdrop_vault),
 
             token_mint: seeds.token_mint,
-            user_access: None,
+            user_access,
+            fee_payer_access: seeds.user_access,
             token_state: seeds.token_state,
             token_program: spl_token::ID,
         },
@@ -246,3 +495,580 @@ pub async fn try_transfer_tokens(
     
     Ok(())
 }
+
+// ============================================================================
+/// Transfers tokens from `sender` to several recipients in one instruction.
+///
+/// # Parameters:
+/// - `context`: Test context
+/// - `caller`: Signer triggering the batch
+/// - `sender`: Token sender account
+/// - `fee_mode`: `"per_leg"` or `"aggregate"`
+/// - `recipients`: Recipient ATAs, passed as `remaining_accounts`
+/// - `amounts`: Amount per recipient, in the same order
+///
+/// # Example:
+/// ```
+/// try_batch_transfer_tokens(&mut context, &admin, &sender, "per_leg", &[ata_a, ata_b], &[100, 200]).await?;
+/// ```
+// ============================================================================
+#[allow(dead_code)]
+pub async fn try_batch_transfer_tokens(
+    context: &mut EnvProgramTestContext,
+    caller: &Keypair,
+    sender: &Keypair,
+    fee_mode: &str,
+    recipients: &[Pubkey],
+    amounts: &[u64],
+) -> Result<(), TransportError> {
+    let seeds = derive_seeds(&context.program_id, &sender.pubkey());
+    let user_access = caller_user_access(context, &caller.pubkey()).await;
+    let (referral, referrer_account) = referral_accounts(context, &sender.pubkey()).await;
+
+    let mut args = vec![fee_mode.to_string()];
+    args.extend(amounts.iter().map(|amount| amount.to_string()));
+
+    let mut ix = anchor_ix(
+        context.program_id,
+        soccial_token::accounts::BatchTransferTokensContext {
+            caller: caller.pubkey(),
+            sender: sender.pubkey(),
+            sender_token_account: seeds.user_token_ata,
+
+            rewards_vault: seeds.rewards_vault,
+            rewards_vault_token_account: seeds.rewards_vault_token_account,
+            revenue_vault: seeds.revenue_vault,
+            revenue_vault_token_account: seeds.revenue_vault_token_account,
+            airdrop_vault: seeds.airdrop_vault,
+            airdrop_vault_token_account: seeds.airdrop_vault_token_account,
+            rewards_ledger: derive_vault_ledger_pda(&context.program_id, &seeds.rewards_vault),
+            revenue_ledger: derive_vault_ledger_pda(&context.program_id, &seeds.revenue_vault),
+            airdrop_ledger: derive_vault_ledger_pda(&context.program_id, &seeds.airdrop_vault),
+
+            token_mint: seeds.token_mint,
+            referral,
+            referrer_account,
+            user_access,
+            fee_payer_access: seeds.user_access,
+            token_state: seeds.token_state,
+            token_program: spl_token::ID,
+        },
+        soccial_instruction::BatchTransferTokens { args },
+    );
+    ix.accounts.extend(recipients.iter().map(|recipient| AccountMeta::new(*recipient, false)));
+
+    send_ix(
+        &mut context.banks_client,
+        &context.payer,
+        &[&context.payer, caller, sender],
+        ix,
+        context.recent_blockhash,
+    ).await?;
+
+    Ok(())
+}
+
+/// Derives the `WithdrawalReceipt` PDA for a backend request ID.
+#[allow(dead_code)]
+pub fn derive_withdrawal_receipt_pda(program_id: &Pubkey, request_id: &str) -> Pubkey {
+    Pubkey::find_program_address(&[b"withdrawal_receipt", &request_id_seed(request_id)], program_id).0
+}
+
+/// Derives the global `PriceConfig` PDA.
+#[allow(dead_code)]
+pub fn derive_price_config_pda(program_id: &Pubkey) -> Pubkey {
+    Pubkey::find_program_address(&[b"price_config"], program_id).0
+}
+
+/// Derives the `quote_treasury` PDA that receives purchase proceeds.
+#[allow(dead_code)]
+pub fn derive_quote_treasury_pda(program_id: &Pubkey) -> Pubkey {
+    Pubkey::find_program_address(&[b"quote_treasury"], program_id).0
+}
+
+// ============================================================================
+/// Updates the price configuration using an approved `UpdatePricing` proposal.
+///
+/// # Parameters:
+/// - `context`: Test context
+/// - `caller`: Signer with `manage_economy` permission
+/// - `args`: key=value updates (e.g. `vec!["buy_price=1000000"]`)
+/// - `proposal_id`: Approved proposal ID
+///
+/// # Returns:
+/// `Ok(())` if updated, or `TransportError` on failure
+// ============================================================================
+#[allow(dead_code)]
+pub async fn try_update_price_config(
+    context: &mut EnvProgramTestContext,
+    caller: &Keypair,
+    args: Vec<String>,
+    proposal_id: u64,
+) -> Result<(), TransportError> {
+    let seeds = derive_seeds(&context.program_id, &caller.pubkey());
+    let (proposal, _) = derive_proposal_account(&context.program_id, proposal_id);
+
+    let ix = anchor_ix(
+        context.program_id,
+        soccial_token::accounts::ManagePriceConfig {
+            caller: caller.pubkey(),
+            price_config: derive_price_config_pda(&context.program_id),
+            sale_state: derive_sale_state_pda(&context.program_id),
+            token_state: seeds.token_state,
+            proposal,
+            governance_state: seeds.governance_state,
+            user_access: None,
+            system_program: system_program::ID,
+        },
+        soccial_instruction::UpdatePriceConfig { args },
+    );
+
+    send_ix(
+        &mut context.banks_client,
+        &context.payer,
+        &[&context.payer, caller],
+        ix,
+        context.recent_blockhash,
+    ).await?;
+
+    Ok(())
+}
+
+// ============================================================================
+/// Approves an `UpdatePricing` proposal and applies the given price updates.
+///
+/// # Parameters:
+/// - `context`: Test context
+/// - `admin`: Owner or authority keypair
+/// - `args`: key=value updates (e.g. `vec!["buy_price=1000000"]`)
+///
+/// # Returns:
+/// `Ok(())` if updated, or `TransportError` on failure
+// ============================================================================
+#[allow(dead_code)]
+pub async fn try_configure_pricing(
+    context: &mut EnvProgramTestContext,
+    admin: &Keypair,
+    args: Vec<String>,
+) -> Result<(), TransportError> {
+    let proposal_id = try_approve_proposal_flow(
+        context,
+        admin,
+        "pricing proposal".to_string(),
+        vec!["UpdatePricing".to_string()],
+    ).await?;
+
+    try_update_price_config(context, admin, args, proposal_id).await
+}
+
+// ============================================================================
+/// Buys tokens paying on-chain with the configured quote currency.
+///
+/// # Parameters:
+/// - `context`: Test context
+/// - `buyer`: Buyer and payer (signer)
+/// - `amount`: Amount of SCTK units to purchase
+/// - `max_price`: Maximum accepted price per 1 SCTK
+/// - `quote_accounts`: `(buyer_quote_account, quote_treasury_token_account)`
+///   when paying with an SPL mint, `None` for SOL
+///
+/// # Returns:
+/// `Ok(())` if transaction succeeded, or `TransportError` on failure
+// ============================================================================
+#[allow(dead_code)]
+pub async fn try_purchase_tokens(
+    context: &mut EnvProgramTestContext,
+    buyer: &Keypair,
+    amount: u64,
+    max_price: u64,
+    quote_accounts: Option<(Pubkey, Pubkey)>,
+) -> Result<(), TransportError> {
+    let seeds = derive_seeds(&context.program_id, &buyer.pubkey());
+    let args = vec![amount.to_string(), max_price.to_string()];
+    let user_access = caller_user_access(context, &buyer.pubkey()).await;
+    let (referral, referrer_account) = referral_accounts(context, &buyer.pubkey()).await;
+
+    let quote_mint = pricing_quote_mint(context).await;
+    let ix = anchor_ix(
+        context.program_id,
+        soccial_token::accounts::PurchaseTokensContext {
+            caller: buyer.pubkey(),
+            buyer_token_account: seeds.user_token_ata,
+            price_config: derive_price_config_pda(&context.program_id),
+            sale_state: derive_sale_state_pda(&context.program_id),
+            price_accumulator: derive_price_accumulator_pda(&context.program_id, &quote_mint),
+            quote_treasury: derive_quote_treasury_pda(&context.program_id),
+            buyer_quote_account: quote_accounts.map(|(buyer_quote, _)| buyer_quote),
+            quote_treasury_token_account: quote_accounts.map(|(_, treasury_quote)| treasury_quote),
+
+            liquidity_vault: seeds.liquidity_vault,
+            vault_limits: derive_vault_limits_pda(&context.program_id, &seeds.liquidity_vault),
+            liquidity_vault_token_account: seeds.liquidity_vault_token_account,
+            liquidity_ledger: derive_vault_ledger_pda(&context.program_id, &seeds.liquidity_vault),
+            rewards_vault: seeds.rewards_vault,
+            rewards_vault_token_account: seeds.rewards_vault_token_account,
+            revenue_vault: seeds.revenue_vault,
+            revenue_vault_token_account: seeds.revenue_vault_token_account,
+            airdrop_vault: seeds.airdrop_vault,
+            airdrop_vault_token_account: seeds.airdrop_vault_token_account,
+            rewards_ledger: derive_vault_ledger_pda(&context.program_id, &seeds.rewards_vault),
+            revenue_ledger: derive_vault_ledger_pda(&context.program_id, &seeds.revenue_vault),
+            airdrop_ledger: derive_vault_ledger_pda(&context.program_id, &seeds.airdrop_vault),
+
+            token_mint: seeds.token_mint,
+            referral,
+            referrer_account,
+            user_access,
+            token_state: seeds.token_state,
+            token_program: spl_token::ID,
+            system_program: system_program::ID,
+        },
+        soccial_instruction::PurchaseTokens { args },
+    );
+
+    send_ix(
+        &mut context.banks_client,
+        &context.payer,
+        &[&context.payer, buyer],
+        ix,
+        context.recent_blockhash,
+    ).await?;
+
+    Ok(())
+}
+
+// ============================================================================
+/// Sells tokens back to the protocol for the configured quote currency.
+///
+/// # Parameters:
+/// - `context`: Test context
+/// - `seller`: Token holder (signer)
+/// - `amount`: Amount of SCTK units to sell
+/// - `quote_accounts`: `(seller_quote_account, quote_treasury_token_account)`
+///   when paid in an SPL mint, `None` for SOL
+///
+/// # Returns:
+/// `Ok(())` if transaction succeeded, or `TransportError` on failure
+// ============================================================================
+#[allow(dead_code)]
+pub async fn try_sell_tokens(
+    context: &mut EnvProgramTestContext,
+    seller: &Keypair,
+    amount: u64,
+    quote_accounts: Option<(Pubkey, Pubkey)>,
+) -> Result<(), TransportError> {
+    let seeds = derive_seeds(&context.program_id, &seller.pubkey());
+    let args = vec![amount.to_string()];
+
+    let quote_mint = pricing_quote_mint(context).await;
+    let ix = anchor_ix(
+        context.program_id,
+        soccial_token::accounts::SellTokensContext {
+            caller: seller.pubkey(),
+            seller_token_account: seeds.user_token_ata,
+            price_config: derive_price_config_pda(&context.program_id),
+            price_accumulator: derive_price_accumulator_pda(&context.program_id, &quote_mint),
+            quote_treasury: derive_quote_treasury_pda(&context.program_id),
+            seller_quote_account: quote_accounts.map(|(seller_quote, _)| seller_quote),
+            quote_treasury_token_account: quote_accounts.map(|(_, treasury_quote)| treasury_quote),
+
+            liquidity_vault: seeds.liquidity_vault,
+            liquidity_vault_token_account: seeds.liquidity_vault_token_account,
+            liquidity_ledger: derive_vault_ledger_pda(&context.program_id, &seeds.liquidity_vault),
+            rewards_vault: seeds.rewards_vault,
+            rewards_vault_token_account: seeds.rewards_vault_token_account,
+            revenue_vault: seeds.revenue_vault,
+            revenue_vault_token_account: seeds.revenue_vault_token_account,
+            airdrop_vault: seeds.airdrop_vault,
+            airdrop_vault_token_account: seeds.airdrop_vault_token_account,
+            rewards_ledger: derive_vault_ledger_pda(&context.program_id, &seeds.rewards_vault),
+            revenue_ledger: derive_vault_ledger_pda(&context.program_id, &seeds.revenue_vault),
+            airdrop_ledger: derive_vault_ledger_pda(&context.program_id, &seeds.airdrop_vault),
+
+            token_mint: seeds.token_mint,
+            user_access: None,
+            token_state: seeds.token_state,
+            token_program: spl_token::ID,
+            system_program: system_program::ID,
+        },
+        soccial_instruction::SellTokens { args },
+    );
+
+    send_ix(
+        &mut context.banks_client,
+        &context.payer,
+        &[&context.payer, seller],
+        ix,
+        context.recent_blockhash,
+    ).await?;
+
+    Ok(())
+}
+
+/// Derives the global `SaleState` PDA.
+#[allow(dead_code)]
+pub fn derive_sale_state_pda(program_id: &Pubkey) -> Pubkey {
+    Pubkey::find_program_address(&[b"sale_state"], program_id).0
+}
+
+// ============================================================================
+/// Approves an `UpdatePricing` proposal and applies the given bonding-curve
+/// settings (e.g. `["curve_type=linear", "base_price=10000", "slope=1"]`).
+// ============================================================================
+#[allow(dead_code)]
+pub async fn try_configure_sale_curve(
+    context: &mut EnvProgramTestContext,
+    admin: &Keypair,
+    args: Vec<String>,
+) -> Result<(), TransportError> {
+    let proposal_id = try_approve_proposal_flow(
+        context,
+        admin,
+        "sale curve proposal".to_string(),
+        vec!["UpdatePricing".to_string()],
+    ).await?;
+
+    let seeds = derive_seeds(&context.program_id, &admin.pubkey());
+    let (proposal, _) = derive_proposal_account(&context.program_id, proposal_id);
+
+    let ix = anchor_ix(
+        context.program_id,
+        soccial_token::accounts::ManageSaleState {
+            caller: admin.pubkey(),
+            sale_state: derive_sale_state_pda(&context.program_id),
+            price_config: derive_price_config_pda(&context.program_id),
+            token_state: seeds.token_state,
+            proposal,
+            governance_state: seeds.governance_state,
+            user_access: None,
+            system_program: system_program::ID,
+        },
+        soccial_instruction::UpdateSaleCurve { args },
+    );
+
+    send_ix(
+        &mut context.banks_client,
+        &context.payer,
+        &[&context.payer, admin],
+        ix,
+        context.recent_blockhash,
+    ).await?;
+
+    Ok(())
+}
+
+// ============================================================================
+/// Configures a linear curve: 0.01 price units per SCTK, +1 per SCTK sold.
+// ============================================================================
+#[allow(dead_code)]
+pub async fn configure_linear_curve(
+    context: &mut EnvProgramTestContext,
+    admin: &Keypair,
+) -> Result<(), TransportError> {
+    try_configure_sale_curve(
+        context,
+        admin,
+        vec![
+            "curve_type=linear".to_string(),
+            "base_price=10000".to_string(),
+            "slope=1".to_string(),
+        ],
+    ).await
+}
+
+/// Derives a holder's `CreditLedger` PDA.
+#[allow(dead_code)]
+pub fn derive_credit_ledger_pda(program_id: &Pubkey, owner: &Pubkey) -> Pubkey {
+    Pubkey::find_program_address(&[b"credit_ledger", owner.as_ref()], program_id).0
+}
+
+/// Derives a holder's `CreditReceipt` PDA for a sequence number.
+#[allow(dead_code)]
+pub fn derive_credit_receipt_pda(program_id: &Pubkey, owner: &Pubkey, sequence: u64) -> Pubkey {
+    Pubkey::find_program_address(
+        &[b"credit_receipt", owner.as_ref(), &sequence.to_le_bytes()],
+        program_id,
+    ).0
+}
+
+// ============================================================================
+/// Moves SCTK from the holder's SPL wallet into the off-chain reserve,
+/// crediting the given Soccial Wallet reference.
+///
+/// # Parameters:
+/// - `context`: Test context
+/// - `holder`: Token holder (signer)
+/// - `amount`: Amount of SCTK units to credit
+/// - `user_reference`: Soccial user reference
+/// - `sequence`: Holder's expected next `CreditLedger` sequence (seeds the receipt)
+// ============================================================================
+#[allow(dead_code)]
+pub async fn try_credit_offchain_wallet(
+    context: &mut EnvProgramTestContext,
+    holder: &Keypair,
+    amount: u64,
+    user_reference: &str,
+    sequence: u64,
+) -> Result<(), TransportError> {
+    let seeds = derive_seeds(&context.program_id, &holder.pubkey());
+    let args = vec![amount.to_string(), user_reference.to_string()];
+
+    let ix = anchor_ix(
+        context.program_id,
+        soccial_token::accounts::CreditOffchainWalletContext {
+            caller: holder.pubkey(),
+            source_token_account: seeds.user_token_ata,
+            offchain_reserve_vault: seeds.offchain_reserve_vault,
+            offchain_reserve_vault_token_account: seeds.offchain_reserve_vault_token_account,
+            offchain_reserve_ledger: derive_vault_ledger_pda(&context.program_id, &seeds.offchain_reserve_vault),
+            credit_ledger: derive_credit_ledger_pda(&context.program_id, &holder.pubkey()),
+            credit_receipt: derive_credit_receipt_pda(&context.program_id, &holder.pubkey(), sequence),
+            token_mint: seeds.token_mint,
+            user_access: None,
+            token_state: seeds.token_state,
+            token_program: spl_token::ID,
+            system_program: system_program::ID,
+        },
+        soccial_instruction::CreditOffchainWallet { args },
+    );
+
+    send_ix(
+        &mut context.banks_client,
+        &context.payer,
+        &[&context.payer, holder],
+        ix,
+        context.recent_blockhash,
+    ).await?;
+
+    Ok(())
+}
+
+/// Derives a user's `WithdrawalAddressBook` PDA.
+#[allow(dead_code)]
+pub fn derive_withdrawal_address_book_pda(program_id: &Pubkey, owner: &Pubkey) -> Pubkey {
+    Pubkey::find_program_address(&[b"withdrawal_address_book", owner.as_ref()], program_id).0
+}
+
+/// Returns the `PriceConfig` quote mint (the default key for SOL or when unset).
+async fn pricing_quote_mint(context: &mut EnvProgramTestContext) -> Pubkey {
+    let address = derive_price_config_pda(&context.program_id);
+    match context.banks_client.get_account(address).await {
+        Ok(Some(account)) => PriceConfig::try_deserialize(&mut &account.data[..])
+            .map(|config| config.quote_mint)
+            .unwrap_or_default(),
+        _ => Pubkey::default(),
+    }
+}
+
+/// Returns `Some(address)` if the account exists on-chain.
+async fn existing_account(context: &mut EnvProgramTestContext, address: Pubkey) -> Option<Pubkey> {
+    match context.banks_client.get_account(address).await {
+        Ok(Some(_)) => Some(address),
+        _ => None,
+    }
+}
+
+// ============================================================================
+/// Registers `token_account` in the caller's withdrawal address book.
+///
+/// # Example:
+/// ```
+/// try_add_withdrawal_address(&mut context, &user, &user_ata).await?;
+/// ```
+// ============================================================================
+#[allow(dead_code)]
+pub async fn try_add_withdrawal_address(
+    context: &mut EnvProgramTestContext,
+    caller: &Keypair,
+    token_account: &Pubkey,
+) -> Result<(), TransportError> {
+    let seeds = derive_seeds(&context.program_id, &caller.pubkey());
+    let user_access = caller_user_access(context, &caller.pubkey()).await;
+
+    let ix = anchor_ix(
+        context.program_id,
+        soccial_token::accounts::AddWithdrawalAddressContext {
+            caller: caller.pubkey(),
+            withdrawal_address_book: derive_withdrawal_address_book_pda(&context.program_id, &caller.pubkey()),
+            destination_token_account: *token_account,
+            token_mint: seeds.token_mint,
+            user_access,
+            token_state: seeds.token_state,
+            system_program: system_program::ID,
+        },
+        soccial_instruction::AddWithdrawalAddress {},
+    );
+
+    send_ix(
+        &mut context.banks_client,
+        &context.payer,
+        &[&context.payer, caller],
+        ix,
+        context.recent_blockhash,
+    ).await?;
+
+    Ok(())
+}
+
+// ============================================================================
+/// Removes `token_account` from the caller's withdrawal address book.
+///
+/// # Example:
+/// ```
+/// try_remove_withdrawal_address(&mut context, &user, &user_ata).await?;
+/// ```
+// ============================================================================
+#[allow(dead_code)]
+pub async fn try_remove_withdrawal_address(
+    context: &mut EnvProgramTestContext,
+    caller: &Keypair,
+    token_account: &Pubkey,
+) -> Result<(), TransportError> {
+    let seeds = derive_seeds(&context.program_id, &caller.pubkey());
+    let user_access = caller_user_access(context, &caller.pubkey()).await;
+
+    let ix = anchor_ix(
+        context.program_id,
+        soccial_token::accounts::RemoveWithdrawalAddressContext {
+            caller: caller.pubkey(),
+            withdrawal_address_book: derive_withdrawal_address_book_pda(&context.program_id, &caller.pubkey()),
+            user_access,
+            token_state: seeds.token_state,
+        },
+        soccial_instruction::RemoveWithdrawalAddress {
+            args: vec![token_account.to_string()],
+        },
+    );
+
+    send_ix(
+        &mut context.banks_client,
+        &context.payer,
+        &[&context.payer, caller],
+        ix,
+        context.recent_blockhash,
+    ).await?;
+
+    Ok(())
+}
+
+// ============================================================================
+/// Registers `token_account` in the caller's address book and warps past the
+/// default cooldown, so `deposit_tokens` can pay into it.
+///
+/// # Example:
+/// ```
+/// try_activate_withdrawal_address(&mut context, &user, &user_ata).await?;
+/// ```
+// ============================================================================
+#[allow(dead_code)]
+pub async fn try_activate_withdrawal_address(
+    context: &mut EnvProgramTestContext,
+    caller: &Keypair,
+    token_account: &Pubkey,
+) -> Result<(), TransportError> {
+    try_add_withdrawal_address(context, caller, token_account).await?;
+    context.warp_forward_seconds(DEFAULT_ADDRESS_COOLDOWN_SECS as u64 + 1).await;
+    context.refresh().await;
+
+    Ok(())
+}
@@ -0,0 +1,171 @@
+// ============================================================================
+// Soccial Token – Oracle Test Helpers
+// ----------------------------------------------------------------------------
+//
+// This module provides helper functions to drive the program-owned
+// `ManualPriceFeed`, read validated prices back as return data and read
+// TWAPs from the `PriceAccumulator`.
+//
+// ----------------------------------------------------------------------------
+// Key Functions:
+// - `derive_manual_price_feed_pda`: Derives the `ManualPriceFeed` PDA
+// - `try_set_price`: Pushes a price and confidence into the feed
+// - `try_get_price`: Reads a validated `PriceReading` from a feed
+// - `derive_price_accumulator_pda`: Derives a quote mint's TWAP `PriceAccumulator` PDA
+// - `try_get_twap`: Reads a `TwapReading` over a window
+//
+// ----------------------------------------------------------------------------
+// Author: Paulo Rodrigues
+// Project: Soccial Token
+// Website: https://www.soccial.com/thetoken
+// License: MIT
+// ============================================================================
+
+use anchor_lang::AnchorDeserialize;
+use solana_sdk::{pubkey::Pubkey, signature::Keypair, signer::Signer, system_program, transaction::Transaction, transport::TransportError};
+use crate::testutils::basics::*;
+use crate::testutils::environment::EnvProgramTestContext;
+use crate::trymethods::trymarket::caller_user_access;
+use soccial_token::{self, instruction as soccial_instruction, oracle::{PriceReading, TwapReading}};
+
+/// Derives the program-owned `ManualPriceFeed` PDA.
+#[allow(dead_code)]
+pub fn derive_manual_price_feed_pda(program_id: &Pubkey) -> Pubkey {
+    Pubkey::find_program_address(&[b"manual_price_feed"], program_id).0
+}
+
+/// Derives the `PriceAccumulator` PDA of a quote mint (the default key for SOL).
+#[allow(dead_code)]
+pub fn derive_price_accumulator_pda(program_id: &Pubkey, quote_mint: &Pubkey) -> Pubkey {
+    Pubkey::find_program_address(&[b"price_accumulator", quote_mint.as_ref()], program_id).0
+}
+
+// ============================================================================
+/// Pushes `price` ± `confidence` into the `ManualPriceFeed`.
+///
+/// # Example:
+/// ```
+/// try_set_price(&mut context, &admin, 10_000, 50).await?;
+/// ```
+// ============================================================================
+#[allow(dead_code)]
+pub async fn try_set_price(
+    context: &mut EnvProgramTestContext,
+    caller: &Keypair,
+    price: u64,
+    confidence: u64,
+) -> Result<(), TransportError> {
+    let seeds = derive_seeds(&context.program_id, &caller.pubkey());
+    let user_access = caller_user_access(context, &caller.pubkey()).await;
+
+    let ix = anchor_ix(
+        context.program_id,
+        soccial_token::accounts::SetPriceContext {
+            caller: caller.pubkey(),
+            price_feed: derive_manual_price_feed_pda(&context.program_id),
+            user_access,
+            token_state: seeds.token_state,
+            system_program: system_program::ID,
+        },
+        soccial_instruction::SetPrice {
+            args: vec![price.to_string(), confidence.to_string()],
+        },
+    );
+
+    send_ix(
+        &mut context.banks_client,
+        &context.payer,
+        &[&context.payer, caller],
+        ix,
+        context.recent_blockhash,
+    ).await?;
+
+    Ok(())
+}
+
+// ============================================================================
+/// Reads a validated reading from `price_feed` via `get_price`.
+///
+/// `args` carries the optional bounds (`[max_age, max_confidence_bps]`).
+///
+/// # Example:
+/// ```
+/// let reading = try_get_price(&mut context, &user, feed, vec![]).await?;
+/// ```
+// ============================================================================
+#[allow(dead_code)]
+pub async fn try_get_price(
+    context: &mut EnvProgramTestContext,
+    caller: &Keypair,
+    price_feed: Pubkey,
+    args: Vec<String>,
+) -> Result<PriceReading, TransportError> {
+    let seeds = derive_seeds(&context.program_id, &caller.pubkey());
+
+    let ix = anchor_ix(
+        context.program_id,
+        soccial_token::accounts::ReadPriceFeedContext {
+            caller: caller.pubkey(),
+            price_feed,
+            token_state: seeds.token_state,
+        },
+        soccial_instruction::GetPrice { args },
+    );
+
+    let mut tx = Transaction::new_with_payer(&[ix], Some(&context.payer.pubkey()));
+    tx.sign(&[&context.payer, caller], context.recent_blockhash);
+
+    let outcome = context.banks_client.process_transaction_with_metadata(tx).await?;
+    outcome.result.map_err(TransportError::TransactionError)?;
+
+    let return_data = outcome
+        .metadata
+        .and_then(|metadata| metadata.return_data)
+        .expect("get_price should set return data");
+
+    Ok(PriceReading::try_from_slice(&return_data.data).expect("Failed to deserialize"))
+}
+
+// ============================================================================
+/// Reads the SCTK TWAP in `quote_mint` over the last `window` seconds via `get_twap`.
+///
+/// # Example:
+/// ```
+/// let reading = try_get_twap(&mut context, &user, &Pubkey::default(), 600).await?;
+/// ```
+// ============================================================================
+#[allow(dead_code)]
+pub async fn try_get_twap(
+    context: &mut EnvProgramTestContext,
+    caller: &Keypair,
+    quote_mint: &Pubkey,
+    window: i64,
+) -> Result<TwapReading, TransportError> {
+    let seeds = derive_seeds(&context.program_id, &caller.pubkey());
+
+    let ix = anchor_ix(
+        context.program_id,
+        soccial_token::accounts::ReadTwapContext {
+            caller: caller.pubkey(),
+            quote_mint: *quote_mint,
+            price_accumulator: derive_price_accumulator_pda(&context.program_id, quote_mint),
+            token_state: seeds.token_state,
+        },
+        soccial_instruction::GetTwap {
+            args: vec![window.to_string()],
+        },
+    );
+
+    let mut tx = Transaction::new_with_payer(&[ix], Some(&context.payer.pubkey()));
+    tx.sign(&[&context.payer, caller], context.recent_blockhash);
+
+    let outcome = context.banks_client.process_transaction_with_metadata(tx).await?;
+    outcome.result.map_err(TransportError::TransactionError)?;
+
+    let return_data = outcome
+        .metadata
+        .and_then(|metadata| metadata.return_data)
+        .expect("get_twap should set return data");
+
+    Ok(TwapReading::try_from_slice(&return_data.data).expect("Failed to deserialize"))
+}
@@ -0,0 +1,454 @@
+// ============================================================================
+// Soccial Token – Payments (Invoices, Merchants & Subscriptions) Test Helpers
+// ----------------------------------------------------------------------------
+//
+// This module provides helper functions to simulate paying Soccial services
+// and third-party merchants in SCTK, including recurring subscriptions.
+//
+// ----------------------------------------------------------------------------
+// Key Functions:
+// - `derive_invoice_pda`: Derives the invoice PDA for a merchant and order ID
+// - `try_create_invoice`: Opens a payment request as the merchant
+// - `try_pay_invoice`: Pays an invoice from the payer's wallet
+// - `try_cancel_invoice`: Cancels an open invoice
+// - `derive_merchant_pda`: Derives the merchant registry PDA
+// - `try_manage_merchant`: Registers or updates a merchant
+// - `try_merchant_payment`: Pays a registered merchant
+// - `derive_subscription_pda`: Derives the subscription PDA for a payer and merchant
+// - `try_create_subscription`: Subscribes and approves the program delegate
+// - `try_charge_subscription`: Cranks one due subscription period
+// - `try_cancel_subscription`: Cancels a subscription as payer or merchant
+//
+// ----------------------------------------------------------------------------
+// Author: Paulo Rodrigues
+// Project: Soccial Token
+// Website: https://www.soccial.com/thetoken
+// License: MIT
+// ============================================================================
+
+use solana_sdk::{pubkey::Pubkey, signature::Keypair, signer::Signer, system_program, transport::TransportError};
+use crate::testutils::basics::*;
+use crate::testutils::environment::EnvProgramTestContext;
+use crate::trymethods::trymarket::caller_user_access;
+use crate::trymethods::tryvaults::derive_vault_ledger_pda;
+use soccial_token::{self, instruction as soccial_instruction};
+
+/// Derives the `Invoice` PDA for a merchant and external order ID.
+#[allow(dead_code)]
+pub fn derive_invoice_pda(program_id: &Pubkey, merchant: &Pubkey, order_id: &str) -> Pubkey {
+    Pubkey::find_program_address(&[b"invoice", merchant.as_ref(), order_id.as_bytes()], program_id).0
+}
+
+// ============================================================================
+/// Creates an invoice for `order_id`, signed and paid for by `merchant`.
+///
+/// # Example:
+/// ```
+/// try_create_invoice(&mut context, &merchant, "order-1", 1_000, 0).await?;
+/// ```
+// ============================================================================
+#[allow(dead_code)]
+pub async fn try_create_invoice(
+    context: &mut EnvProgramTestContext,
+    merchant: &Keypair,
+    order_id: &str,
+    amount: u64,
+    expires_at: i64,
+) -> Result<(), TransportError> {
+    let seeds = derive_seeds(&context.program_id, &merchant.pubkey());
+    let args = vec![order_id.to_string(), amount.to_string(), expires_at.to_string()];
+
+    let ix = anchor_ix(
+        context.program_id,
+        soccial_token::accounts::CreateInvoiceContext {
+            caller: merchant.pubkey(),
+            invoice: derive_invoice_pda(&context.program_id, &merchant.pubkey(), order_id),
+            user_access: None,
+            token_state: seeds.token_state,
+            system_program: system_program::ID,
+        },
+        soccial_instruction::CreateInvoice { args },
+    );
+
+    send_ix(
+        &mut context.banks_client,
+        &context.payer,
+        &[&context.payer, merchant],
+        ix,
+        context.recent_blockhash,
+    ).await?;
+
+    Ok(())
+}
+
+// ============================================================================
+/// Pays the invoice `order_id` of `merchant` from `payer`'s wallet.
+///
+/// # Example:
+/// ```
+/// try_pay_invoice(&mut context, &payer, &merchant.pubkey(), "order-1", 1_000).await?;
+/// ```
+// ============================================================================
+#[allow(dead_code)]
+pub async fn try_pay_invoice(
+    context: &mut EnvProgramTestContext,
+    payer: &Keypair,
+    merchant: &Pubkey,
+    order_id: &str,
+    amount: u64,
+) -> Result<(), TransportError> {
+    let seeds = derive_seeds(&context.program_id, &payer.pubkey());
+    let merchant_seeds = derive_seeds(&context.program_id, merchant);
+    let user_access = caller_user_access(context, &payer.pubkey()).await;
+    let args = vec![amount.to_string()];
+
+    let ix = anchor_ix(
+        context.program_id,
+        soccial_token::accounts::PayInvoiceContext {
+            caller: payer.pubkey(),
+            invoice: derive_invoice_pda(&context.program_id, merchant, order_id),
+            payer_token_account: seeds.user_token_ata,
+            merchant_token_account: merchant_seeds.user_token_ata,
+
+            rewards_vault: seeds.rewards_vault,
+            rewards_vault_token_account: seeds.rewards_vault_token_account,
+            revenue_vault: seeds.revenue_vault,
+            revenue_vault_token_account: seeds.revenue_vault_token_account,
+            airdrop_vault: seeds.airdrop_vault,
+            airdrop_vault_token_account: seeds.airdrop_vault_token_account,
+            rewards_ledger: derive_vault_ledger_pda(&context.program_id, &seeds.rewards_vault),
+            revenue_ledger: derive_vault_ledger_pda(&context.program_id, &seeds.revenue_vault),
+            airdrop_ledger: derive_vault_ledger_pda(&context.program_id, &seeds.airdrop_vault),
+
+            user_access,
+            token_mint: seeds.token_mint,
+            token_state: seeds.token_state,
+            token_program: spl_token::ID,
+        },
+        soccial_instruction::PayInvoice { args },
+    );
+
+    send_ix(
+        &mut context.banks_client,
+        &context.payer,
+        &[&context.payer, payer],
+        ix,
+        context.recent_blockhash,
+    ).await?;
+
+    Ok(())
+}
+
+// ============================================================================
+/// Cancels the invoice `order_id` of `merchant`, signed by `caller`.
+///
+/// # Example:
+/// ```
+/// try_cancel_invoice(&mut context, &merchant, &merchant.pubkey(), "order-1").await?;
+/// ```
+// ============================================================================
+#[allow(dead_code)]
+pub async fn try_cancel_invoice(
+    context: &mut EnvProgramTestContext,
+    caller: &Keypair,
+    merchant: &Pubkey,
+    order_id: &str,
+) -> Result<(), TransportError> {
+    let seeds = derive_seeds(&context.program_id, &caller.pubkey());
+
+    let ix = anchor_ix(
+        context.program_id,
+        soccial_token::accounts::CancelInvoiceContext {
+            caller: caller.pubkey(),
+            invoice: derive_invoice_pda(&context.program_id, merchant, order_id),
+            user_access: None,
+            token_state: seeds.token_state,
+        },
+        soccial_instruction::CancelInvoice {},
+    );
+
+    send_ix(
+        &mut context.banks_client,
+        &context.payer,
+        &[&context.payer, caller],
+        ix,
+        context.recent_blockhash,
+    ).await?;
+
+    Ok(())
+}
+
+/// Derives the `Merchant` registry PDA for a merchant wallet.
+#[allow(dead_code)]
+pub fn derive_merchant_pda(program_id: &Pubkey, merchant_authority: &Pubkey) -> Pubkey {
+    Pubkey::find_program_address(&[b"merchant", merchant_authority.as_ref()], program_id).0
+}
+
+// ============================================================================
+/// Registers or updates the merchant `merchant_authority`, settling to
+/// `settlement_token_account`.
+///
+/// # Example:
+/// ```
+/// try_manage_merchant(&mut context, &admin, &merchant.pubkey(), &merchant_ata, vec!["fee_bps=150"]).await?;
+/// ```
+// ============================================================================
+#[allow(dead_code)]
+pub async fn try_manage_merchant(
+    context: &mut EnvProgramTestContext,
+    caller: &Keypair,
+    merchant_authority: &Pubkey,
+    settlement_token_account: &Pubkey,
+    args: Vec<&str>,
+) -> Result<(), TransportError> {
+    let seeds = derive_seeds(&context.program_id, &caller.pubkey());
+    let user_access = caller_user_access(context, &caller.pubkey()).await;
+
+    let ix = anchor_ix(
+        context.program_id,
+        soccial_token::accounts::ManageMerchantContext {
+            caller: caller.pubkey(),
+            merchant_authority: *merchant_authority,
+            merchant: derive_merchant_pda(&context.program_id, merchant_authority),
+            settlement_token_account: *settlement_token_account,
+            token_mint: seeds.token_mint,
+            user_access,
+            token_state: seeds.token_state,
+            system_program: system_program::ID,
+        },
+        soccial_instruction::ManageMerchant {
+            args: args.into_iter().map(String::from).collect(),
+        },
+    );
+
+    send_ix(
+        &mut context.banks_client,
+        &context.payer,
+        &[&context.payer, caller],
+        ix,
+        context.recent_blockhash,
+    ).await?;
+
+    Ok(())
+}
+
+// ============================================================================
+/// Pays `amount` to the merchant `merchant_authority` from `payer`'s wallet.
+///
+/// # Example:
+/// ```
+/// try_merchant_payment(&mut context, &payer, &merchant.pubkey(), &merchant_ata, None, 1_000).await?;
+/// ```
+// ============================================================================
+#[allow(dead_code)]
+pub async fn try_merchant_payment(
+    context: &mut EnvProgramTestContext,
+    payer: &Keypair,
+    merchant_authority: &Pubkey,
+    settlement_token_account: &Pubkey,
+    referrer_token_account: Option<Pubkey>,
+    amount: u64,
+) -> Result<(), TransportError> {
+    let seeds = derive_seeds(&context.program_id, &payer.pubkey());
+    let user_access = caller_user_access(context, &payer.pubkey()).await;
+
+    let ix = anchor_ix(
+        context.program_id,
+        soccial_token::accounts::MerchantPaymentContext {
+            caller: payer.pubkey(),
+            merchant: derive_merchant_pda(&context.program_id, merchant_authority),
+            settlement_token_account: *settlement_token_account,
+            payer_token_account: seeds.user_token_ata,
+            referrer_token_account,
+
+            rewards_vault: seeds.rewards_vault,
+            rewards_vault_token_account: seeds.rewards_vault_token_account,
+            revenue_vault: seeds.revenue_vault,
+            revenue_vault_token_account: seeds.revenue_vault_token_account,
+            airdrop_vault: seeds.airdrop_vault,
+            airdrop_vault_token_account: seeds.airdrop_vault_token_account,
+            rewards_ledger: derive_vault_ledger_pda(&context.program_id, &seeds.rewards_vault),
+            revenue_ledger: derive_vault_ledger_pda(&context.program_id, &seeds.revenue_vault),
+            airdrop_ledger: derive_vault_ledger_pda(&context.program_id, &seeds.airdrop_vault),
+
+            user_access,
+            token_mint: seeds.token_mint,
+            token_state: seeds.token_state,
+            token_program: spl_token::ID,
+        },
+        soccial_instruction::MerchantPayment {
+            args: vec![amount.to_string()],
+        },
+    );
+
+    send_ix(
+        &mut context.banks_client,
+        &context.payer,
+        &[&context.payer, payer],
+        ix,
+        context.recent_blockhash,
+    ).await?;
+
+    Ok(())
+}
+
+/// Derives the `Subscription` PDA for a payer and merchant.
+#[allow(dead_code)]
+pub fn derive_subscription_pda(program_id: &Pubkey, payer: &Pubkey, merchant: &Pubkey) -> Pubkey {
+    Pubkey::find_program_address(&[b"subscription", payer.as_ref(), merchant.as_ref()], program_id).0
+}
+
+/// Derives the `subscription_delegate` PDA approved on payer ATAs.
+#[allow(dead_code)]
+pub fn derive_subscription_delegate_pda(program_id: &Pubkey) -> Pubkey {
+    Pubkey::find_program_address(&[b"subscription_delegate"], program_id).0
+}
+
+// ============================================================================
+/// Subscribes `payer` to `merchant`, approving `amount × approved_periods`.
+///
+/// # Example:
+/// ```
+/// try_create_subscription(&mut context, &payer, &merchant.pubkey(), 1_000, 86_400, 12).await?;
+/// ```
+// ============================================================================
+#[allow(dead_code)]
+pub async fn try_create_subscription(
+    context: &mut EnvProgramTestContext,
+    payer: &Keypair,
+    merchant: &Pubkey,
+    amount: u64,
+    period: i64,
+    approved_periods: u64,
+) -> Result<(), TransportError> {
+    let seeds = derive_seeds(&context.program_id, &payer.pubkey());
+    let args = vec![amount.to_string(), period.to_string(), approved_periods.to_string()];
+
+    let ix = anchor_ix(
+        context.program_id,
+        soccial_token::accounts::CreateSubscriptionContext {
+            caller: payer.pubkey(),
+            merchant: *merchant,
+            subscription: derive_subscription_pda(&context.program_id, &payer.pubkey(), merchant),
+            payer_token_account: seeds.user_token_ata,
+            subscription_delegate: derive_subscription_delegate_pda(&context.program_id),
+            token_mint: seeds.token_mint,
+            user_access: None,
+            token_state: seeds.token_state,
+            token_program: spl_token::ID,
+            system_program: system_program::ID,
+        },
+        soccial_instruction::CreateSubscription { args },
+    );
+
+    send_ix(
+        &mut context.banks_client,
+        &context.payer,
+        &[&context.payer, payer],
+        ix,
+        context.recent_blockhash,
+    ).await?;
+
+    Ok(())
+}
+
+// ============================================================================
+/// Cranks the subscription of `payer` to `merchant`, signed by `cranker`.
+///
+/// # Example:
+/// ```
+/// try_charge_subscription(&mut context, &cranker, &payer.pubkey(), &merchant.pubkey()).await?;
+/// ```
+// ============================================================================
+#[allow(dead_code)]
+pub async fn try_charge_subscription(
+    context: &mut EnvProgramTestContext,
+    cranker: &Keypair,
+    payer: &Pubkey,
+    merchant: &Pubkey,
+) -> Result<(), TransportError> {
+    let seeds = derive_seeds(&context.program_id, payer);
+    let merchant_seeds = derive_seeds(&context.program_id, merchant);
+    let payer_access = caller_user_access(context, payer).await;
+
+    let ix = anchor_ix(
+        context.program_id,
+        soccial_token::accounts::ChargeSubscriptionContext {
+            caller: cranker.pubkey(),
+            subscription: derive_subscription_pda(&context.program_id, payer, merchant),
+            payer_token_account: seeds.user_token_ata,
+            merchant_token_account: merchant_seeds.user_token_ata,
+            subscription_delegate: derive_subscription_delegate_pda(&context.program_id),
+
+            rewards_vault: seeds.rewards_vault,
+            rewards_vault_token_account: seeds.rewards_vault_token_account,
+            revenue_vault: seeds.revenue_vault,
+            revenue_vault_token_account: seeds.revenue_vault_token_account,
+            airdrop_vault: seeds.airdrop_vault,
+            airdrop_vault_token_account: seeds.airdrop_vault_token_account,
+            rewards_ledger: derive_vault_ledger_pda(&context.program_id, &seeds.rewards_vault),
+            revenue_ledger: derive_vault_ledger_pda(&context.program_id, &seeds.revenue_vault),
+            airdrop_ledger: derive_vault_ledger_pda(&context.program_id, &seeds.airdrop_vault),
+
+            payer_access,
+            token_mint: seeds.token_mint,
+            token_state: seeds.token_state,
+            token_program: spl_token::ID,
+        },
+        soccial_instruction::ChargeSubscription {},
+    );
+
+    send_ix(
+        &mut context.banks_client,
+        &context.payer,
+        &[&context.payer, cranker],
+        ix,
+        context.recent_blockhash,
+    ).await?;
+
+    Ok(())
+}
+
+// ============================================================================
+/// Cancels the subscription of `payer` to `merchant`, signed by `caller`.
+///
+/// # Example:
+/// ```
+/// try_cancel_subscription(&mut context, &merchant, &payer.pubkey(), &merchant.pubkey()).await?;
+/// ```
+// ============================================================================
+#[allow(dead_code)]
+pub async fn try_cancel_subscription(
+    context: &mut EnvProgramTestContext,
+    caller: &Keypair,
+    payer: &Pubkey,
+    merchant: &Pubkey,
+) -> Result<(), TransportError> {
+    let seeds = derive_seeds(&context.program_id, &caller.pubkey());
+
+    let ix = anchor_ix(
+        context.program_id,
+        soccial_token::accounts::CancelSubscriptionContext {
+            caller: caller.pubkey(),
+            subscription: derive_subscription_pda(&context.program_id, payer, merchant),
+            payer: *payer,
+            payer_token_account: derive_user_ata(&context.program_id, payer),
+            subscription_delegate: derive_subscription_delegate_pda(&context.program_id),
+            token_mint: seeds.token_mint,
+            user_access: None,
+            token_state: seeds.token_state,
+            token_program: spl_token::ID,
+        },
+        soccial_instruction::CancelSubscription {},
+    );
+
+    send_ix(
+        &mut context.banks_client,
+        &context.payer,
+        &[&context.payer, caller],
+        ix,
+        context.recent_blockhash,
+    ).await?;
+
+    Ok(())
+}
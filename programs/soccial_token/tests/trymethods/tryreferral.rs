@@ -0,0 +1,172 @@
+// ============================================================================
+// Soccial Token – Referral Test Helpers
+// ----------------------------------------------------------------------------
+//
+// This module provides helper functions to simulate the referral program:
+// binding a user to a referrer and claiming the credited fee share.
+//
+// ----------------------------------------------------------------------------
+// Key Functions:
+// - `derive_referral_pda`: Derives the `Referral` PDA of a referee
+// - `derive_referrer_account_pda`: Derives the `ReferrerAccount` PDA of a referrer
+// - `referral_accounts`: Resolves the referral accounts market calls expect
+// - `load_referrer_account`: Reads a referrer's stats and claimable balance
+// - `try_register_referral`: Binds a referee to a referrer
+// - `try_claim_referral_rewards`: Pays a referrer's claimable balance
+//
+// ----------------------------------------------------------------------------
+// Author: Paulo Rodrigues
+// Project: Soccial Token
+// Website: https://www.soccial.com/thetoken
+// License: MIT
+// ============================================================================
+
+use anchor_lang::AccountDeserialize;
+use solana_sdk::{pubkey::Pubkey, signature::Keypair, signer::Signer, system_program, transport::TransportError};
+use crate::testutils::basics::*;
+use crate::testutils::environment::EnvProgramTestContext;
+use crate::trymethods::trymarket::caller_user_access;
+use crate::trymethods::tryvaults::{derive_vault_ledger_pda, derive_vault_limits_pda};
+use soccial_token::{
+    self,
+    instruction as soccial_instruction,
+    referral::state::{Referral, ReferrerAccount},
+};
+
+/// Derives the `Referral` PDA of a referee.
+#[allow(dead_code)]
+pub fn derive_referral_pda(program_id: &Pubkey, referee: &Pubkey) -> Pubkey {
+    Pubkey::find_program_address(&[b"referral", referee.as_ref()], program_id).0
+}
+
+/// Derives the `ReferrerAccount` PDA of a referrer.
+#[allow(dead_code)]
+pub fn derive_referrer_account_pda(program_id: &Pubkey, referrer: &Pubkey) -> Pubkey {
+    Pubkey::find_program_address(&[b"referrer", referrer.as_ref()], program_id).0
+}
+
+// ============================================================================
+/// Returns the `Referral` and `ReferrerAccount` PDAs market calls expect
+/// for `user`.
+///
+/// Users without a referral send their empty `Referral` PDA together with
+/// their own `referrer` PDA, which the program ignores.
+// ============================================================================
+#[allow(dead_code)]
+pub async fn referral_accounts(
+    context: &mut EnvProgramTestContext,
+    user: &Pubkey,
+) -> (Pubkey, Pubkey) {
+    let referral = derive_referral_pda(&context.program_id, user);
+    let referrer = match context.banks_client.get_account(referral).await {
+        Ok(Some(account)) => {
+            Referral::try_deserialize(&mut &account.data[..]).expect("Failed to deserialize").referrer
+        }
+        _ => *user,
+    };
+
+    (referral, derive_referrer_account_pda(&context.program_id, &referrer))
+}
+
+/// Reads a referrer's `ReferrerAccount`.
+#[allow(dead_code)]
+pub async fn load_referrer_account(
+    context: &mut EnvProgramTestContext,
+    referrer: &Pubkey,
+) -> ReferrerAccount {
+    let pda = derive_referrer_account_pda(&context.program_id, referrer);
+    let account = context
+        .banks_client
+        .get_account(pda)
+        .await
+        .expect("Failed to fetch referrer account")
+        .expect("Referrer account not found");
+    ReferrerAccount::try_deserialize(&mut &account.data[..]).expect("Failed to deserialize")
+}
+
+// ============================================================================
+/// Binds `referee` to `referrer`.
+///
+/// # Example:
+/// ```
+/// try_register_referral(&mut context, &referee, &referrer.pubkey()).await?;
+/// ```
+// ============================================================================
+#[allow(dead_code)]
+pub async fn try_register_referral(
+    context: &mut EnvProgramTestContext,
+    referee: &Keypair,
+    referrer: &Pubkey,
+) -> Result<(), TransportError> {
+    let seeds = derive_seeds(&context.program_id, &referee.pubkey());
+    let user_access = caller_user_access(context, &referee.pubkey()).await;
+
+    let ix = anchor_ix(
+        context.program_id,
+        soccial_token::accounts::RegisterReferralContext {
+            caller: referee.pubkey(),
+            referrer: *referrer,
+            referral: derive_referral_pda(&context.program_id, &referee.pubkey()),
+            referrer_account: derive_referrer_account_pda(&context.program_id, referrer),
+            user_access,
+            token_state: seeds.token_state,
+            system_program: system_program::ID,
+        },
+        soccial_instruction::RegisterReferral {},
+    );
+
+    send_ix(
+        &mut context.banks_client,
+        &context.payer,
+        &[&context.payer, referee],
+        ix,
+        context.recent_blockhash,
+    ).await?;
+
+    Ok(())
+}
+
+// ============================================================================
+/// Pays `referrer`'s claimable balance from the Revenue Vault.
+///
+/// # Example:
+/// ```
+/// try_claim_referral_rewards(&mut context, &referrer).await?;
+/// ```
+// ============================================================================
+#[allow(dead_code)]
+pub async fn try_claim_referral_rewards(
+    context: &mut EnvProgramTestContext,
+    referrer: &Keypair,
+) -> Result<(), TransportError> {
+    let seeds = derive_seeds(&context.program_id, &referrer.pubkey());
+    let user_access = caller_user_access(context, &referrer.pubkey()).await;
+
+    let ix = anchor_ix(
+        context.program_id,
+        soccial_token::accounts::ClaimReferralRewardsContext {
+            caller: referrer.pubkey(),
+            referrer_account: derive_referrer_account_pda(&context.program_id, &referrer.pubkey()),
+            referrer_token_account: seeds.user_token_ata,
+            revenue_vault: seeds.revenue_vault,
+            revenue_vault_token_account: seeds.revenue_vault_token_account,
+            vault_limits: derive_vault_limits_pda(&context.program_id, &seeds.revenue_vault),
+            revenue_ledger: derive_vault_ledger_pda(&context.program_id, &seeds.revenue_vault),
+            token_mint: seeds.token_mint,
+            user_access,
+            token_state: seeds.token_state,
+            token_program: spl_token::ID,
+        },
+        soccial_instruction::ClaimReferralRewards {},
+    );
+
+    send_ix(
+        &mut context.banks_client,
+        &context.payer,
+        &[&context.payer, referrer],
+        ix,
+        context.recent_blockhash,
+    ).await?;
+
+    Ok(())
+}
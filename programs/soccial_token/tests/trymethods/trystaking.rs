@@ -32,6 +32,7 @@ use anchor_lang::{solana_program::{self, example_mocks::solana_sdk::system_progr
 use solana_sdk::{pubkey::Pubkey, signature::Keypair, signer::Signer, transport::TransportError};
 use crate::testutils::environment::EnvProgramTestContext;
 use crate::testutils::basics::*;
+use crate::trymethods::tryvaults::{derive_vault_ledger_pda, derive_vault_limits_pda};
 use soccial_token::{accounts as soccial_accounts, instruction as soccial_instruction, staking::StakingState, token::TokenState};
 use anchor_lang::{InstructionData, ToAccountMetas};
 use solana_program::sysvar::clock;
@@ -110,7 +111,10 @@ pub async fn try_buy_and_stake_tokens(
         token_mint: seeds.token_mint,
         liquidity_vault: seeds.liquidity_vault,
         liquidity_vault_token_account: seeds.liquidity_vault_token_account,
+        vault_limits: derive_vault_limits_pda(&context.program_id, &seeds.liquidity_vault),
+        liquidity_ledger: derive_vault_ledger_pda(&context.program_id, &seeds.liquidity_vault),
         staking_vault_token_account: seeds.staking_vault_token_account,
+        staking_ledger: derive_vault_ledger_pda(&context.program_id, &seeds.staking_vault),
         staking_vault: seeds.staking_vault,
         caller: caller.pubkey(),
         user_access: None,
@@ -205,7 +209,10 @@ pub async fn try_stake_tokens(
         participant_token_account: seeds.user_token_ata,
         liquidity_vault: seeds.liquidity_vault,
         liquidity_vault_token_account: seeds.liquidity_vault_token_account,
+        vault_limits: derive_vault_limits_pda(&context.program_id, &seeds.liquidity_vault),
+        liquidity_ledger: derive_vault_ledger_pda(&context.program_id, &seeds.liquidity_vault),
         staking_vault_token_account: seeds.staking_vault_token_account,
+        staking_ledger: derive_vault_ledger_pda(&context.program_id, &seeds.staking_vault),
         staking_vault: seeds.staking_vault,
         staking_account: staking_account_pda,
         staking_state: seeds.staking_state,
@@ -286,9 +293,12 @@ pub async fn try_add_to_stake(
         staking_state: seeds.staking_state,
         staking_account: staking_account_pda,
         staking_vault_token_account: seeds.staking_vault_token_account,
+        staking_ledger: derive_vault_ledger_pda(&context.program_id, &seeds.staking_vault),
         staking_vault: seeds.staking_vault,
         liquidity_vault: seeds.liquidity_vault,
         liquidity_vault_token_account: seeds.liquidity_vault_token_account,
+        vault_limits: derive_vault_limits_pda(&context.program_id, &seeds.liquidity_vault),
+        liquidity_ledger: derive_vault_ledger_pda(&context.program_id, &seeds.liquidity_vault),
         token_mint: seeds.token_mint,
         destination_token_account: seeds.user_token_ata,
         mint_authority: seeds.mint_authority,
@@ -373,6 +383,8 @@ pub async fn try_withdraw_staked_tokens(
         mint_authority: seeds.mint_authority,
         mint: seeds.token_mint,
         staking_vault_token_account: seeds.staking_vault_token_account,
+        vault_limits: derive_vault_limits_pda(&context.program_id, &seeds.staking_vault),
+        staking_ledger: derive_vault_ledger_pda(&context.program_id, &seeds.staking_vault),
         staking_vault: seeds.staking_vault,
         destination_token_account: seeds.user_token_ata,
         token_program: spl_token::ID,
@@ -434,6 +446,8 @@ pub async fn try_claim_staking_rewards(
         mint_authority: seeds.mint_authority,
         mint: seeds.token_mint,
         staking_vault_token_account: seeds.staking_vault_token_account,
+        vault_limits: derive_vault_limits_pda(&context.program_id, &seeds.staking_vault),
+        staking_ledger: derive_vault_ledger_pda(&context.program_id, &seeds.staking_vault),
         staking_vault: seeds.staking_vault,
         destination_token_account: seeds.user_token_ata,
         token_program: spl_token::ID,
@@ -19,6 +19,11 @@
 // - `try_set_api_authority`: Assigns new API signer  
 // - `try_pause_contract` / `try_resume_contract`: Toggle pause state  
 // - `try_update_rewards_fee` / `try_update_airdrop_fee`: Modify system fees  
+// - `try_update_fee_schedule`: Modify buy / withdrawal / transfer fees  
+// - `try_update_fee_discounts`: Modify flag-based fee discounts  
+// - `try_update_referral_settings`: Modify the referrer share of referee fees  
+// - `try_update_withdrawal_settings`: Modify the withdrawal address book cooldown  
+// - `try_migrate_token_state`: Grow a legacy `TokenState` to the current layout  
 //
 // ----------------------------------------------------------------------------
 // Author: Paulo Rodrigues  
@@ -294,3 +299,288 @@ pub async fn try_update_airdrop_fee(
 
     Ok(())
 }
+
+// ============================================================================
+/// Attempts to update the per-operation fee schedule.
+///
+/// # Parameters:
+/// - `context`: Test environment
+/// - `caller`: Authorized signer (must have economy permissions)
+/// - `args`: Key-value pairs (e.g., `vec!["buy=250", "transfer=10"]`)
+/// - `proposal_id`: Approved `AdjustTaxRate` proposal
+///
+/// # Returns:
+/// `Ok(())` if the schedule is updated, or `TransportError` otherwise
+///
+/// # Example:
+/// ```
+/// try_update_fee_schedule(&mut context, &admin, vec!["buy=250".to_string()], 1).await?;
+/// ```
+// ============================================================================
+#[allow(dead_code)]
+pub async fn try_update_fee_schedule(
+    context: &mut EnvProgramTestContext,
+    caller: &Keypair,
+    args: Vec<String>,
+    proposal_id: u64,
+) -> Result<(), TransportError> {
+    let seeds = derive_seeds(&context.program_id, &caller.pubkey());
+
+    let (proposal, _) = Pubkey::find_program_address(
+        &[b"proposal", &proposal_id.to_le_bytes()],
+        &context.program_id,
+    );
+
+    let ix = anchor_ix(
+        context.program_id,
+        soccial_accounts::ManageContractGovernance {
+            caller: caller.pubkey(),
+            token_state: seeds.token_state,
+            governance_state: seeds.governance_state,
+            proposal,
+            user_access: None,
+            system_program: system_program::ID,
+        },
+        soccial_instruction::UpdateFeeSchedule {
+            args,
+        },
+    );
+
+    send_ix(
+        &mut context.banks_client,
+        &context.payer,
+        &[&context.payer, caller],
+        ix,
+        context.recent_blockhash,
+    )
+    .await?;
+
+    Ok(())
+}
+
+// ============================================================================
+/// Attempts to update the flag-based fee discounts.
+///
+/// # Parameters:
+/// - `context`: Test environment
+/// - `caller`: Authorized signer (must have economy permissions)
+/// - `args`: Flag-value pairs (e.g., `vec!["vip=5000", "staff=10000"]`)
+/// - `proposal_id`: Approved `AdjustTaxRate` proposal
+///
+/// # Returns:
+/// `Ok(())` if the discounts are updated, or `TransportError` otherwise
+///
+/// # Example:
+/// ```
+/// try_update_fee_discounts(&mut context, &admin, vec!["vip=5000".to_string()], 1).await?;
+/// ```
+// ============================================================================
+#[allow(dead_code)]
+pub async fn try_update_fee_discounts(
+    context: &mut EnvProgramTestContext,
+    caller: &Keypair,
+    args: Vec<String>,
+    proposal_id: u64,
+) -> Result<(), TransportError> {
+    let seeds = derive_seeds(&context.program_id, &caller.pubkey());
+
+    let (proposal, _) = Pubkey::find_program_address(
+        &[b"proposal", &proposal_id.to_le_bytes()],
+        &context.program_id,
+    );
+
+    let ix = anchor_ix(
+        context.program_id,
+        soccial_accounts::ManageContractGovernance {
+            caller: caller.pubkey(),
+            token_state: seeds.token_state,
+            governance_state: seeds.governance_state,
+            proposal,
+            user_access: None,
+            system_program: system_program::ID,
+        },
+        soccial_instruction::UpdateFeeDiscounts {
+            args,
+        },
+    );
+
+    send_ix(
+        &mut context.banks_client,
+        &context.payer,
+        &[&context.payer, caller],
+        ix,
+        context.recent_blockhash,
+    )
+    .await?;
+
+    Ok(())
+}
+
+// ============================================================================
+/// Attempts to update the referrer share of referee fees.
+///
+/// # Parameters:
+/// - `context`: Test environment
+/// - `caller`: Authorized signer (must have economy permissions)
+/// - `args`: Key-value pairs (e.g., `vec!["share=2000"]`)
+/// - `proposal_id`: Approved `AdjustTaxRate` proposal
+///
+/// # Returns:
+/// `Ok(())` if the settings are updated, or `TransportError` otherwise
+///
+/// # Example:
+/// ```
+/// try_update_referral_settings(&mut context, &admin, vec!["share=2000".to_string()], 1).await?;
+/// ```
+// ============================================================================
+#[allow(dead_code)]
+pub async fn try_update_referral_settings(
+    context: &mut EnvProgramTestContext,
+    caller: &Keypair,
+    args: Vec<String>,
+    proposal_id: u64,
+) -> Result<(), TransportError> {
+    let seeds = derive_seeds(&context.program_id, &caller.pubkey());
+
+    let (proposal, _) = Pubkey::find_program_address(
+        &[b"proposal", &proposal_id.to_le_bytes()],
+        &context.program_id,
+    );
+
+    let ix = anchor_ix(
+        context.program_id,
+        soccial_accounts::ManageContractGovernance {
+            caller: caller.pubkey(),
+            token_state: seeds.token_state,
+            governance_state: seeds.governance_state,
+            proposal,
+            user_access: None,
+            system_program: system_program::ID,
+        },
+        soccial_instruction::UpdateReferralSettings {
+            args,
+        },
+    );
+
+    send_ix(
+        &mut context.banks_client,
+        &context.payer,
+        &[&context.payer, caller],
+        ix,
+        context.recent_blockhash,
+    )
+    .await?;
+
+    Ok(())
+}
+
+// ============================================================================
+/// Attempts to update the withdrawal address book cooldown.
+///
+/// # Parameters:
+/// - `context`: Test environment
+/// - `caller`: Authorized signer (must have `manage_contract`)
+/// - `args`: Key-value pairs (e.g., `vec!["address_cooldown=3600"]`)
+/// - `proposal_id`: Approved `UpdateGovernance` proposal
+///
+/// # Returns:
+/// `Ok(())` if the settings are updated, or `TransportError` otherwise
+///
+/// # Example:
+/// ```
+/// try_update_withdrawal_settings(&mut context, &admin, vec!["address_cooldown=3600".to_string()], 1).await?;
+/// ```
+// ============================================================================
+#[allow(dead_code)]
+pub async fn try_update_withdrawal_settings(
+    context: &mut EnvProgramTestContext,
+    caller: &Keypair,
+    args: Vec<String>,
+    proposal_id: u64,
+) -> Result<(), TransportError> {
+    let seeds = derive_seeds(&context.program_id, &caller.pubkey());
+
+    let (proposal, _) = Pubkey::find_program_address(
+        &[b"proposal", &proposal_id.to_le_bytes()],
+        &context.program_id,
+    );
+
+    let ix = anchor_ix(
+        context.program_id,
+        soccial_accounts::ManageContractGovernance {
+            caller: caller.pubkey(),
+            token_state: seeds.token_state,
+            governance_state: seeds.governance_state,
+            proposal,
+            user_access: None,
+            system_program: system_program::ID,
+        },
+        soccial_instruction::UpdateWithdrawalSettings {
+            args,
+        },
+    );
+
+    send_ix(
+        &mut context.banks_client,
+        &context.payer,
+        &[&context.payer, caller],
+        ix,
+        context.recent_blockhash,
+    )
+    .await?;
+
+    Ok(())
+}
+
+// ============================================================================
+/// Attempts to migrate `TokenState` to the current layout.
+///
+/// # Parameters:
+/// - `context`: Test environment
+/// - `caller`: Contract owner (pays the rent top-up)
+/// - `proposal_id`: Approved `UpdateGovernance` proposal
+///
+/// # Returns:
+/// `Ok(())` if the account is migrated, or `TransportError` otherwise
+///
+/// # Example:
+/// ```
+/// try_migrate_token_state(&mut context, &admin, 1).await?;
+/// ```
+// ============================================================================
+#[allow(dead_code)]
+pub async fn try_migrate_token_state(
+    context: &mut EnvProgramTestContext,
+    caller: &Keypair,
+    proposal_id: u64,
+) -> Result<(), TransportError> {
+    let seeds = derive_seeds(&context.program_id, &caller.pubkey());
+
+    let (proposal, _) = Pubkey::find_program_address(
+        &[b"proposal", &proposal_id.to_le_bytes()],
+        &context.program_id,
+    );
+
+    let ix = anchor_ix(
+        context.program_id,
+        soccial_accounts::MigrateTokenStateContext {
+            caller: caller.pubkey(),
+            token_state: seeds.token_state,
+            proposal,
+            governance_state: seeds.governance_state,
+            system_program: system_program::ID,
+        },
+        soccial_instruction::MigrateTokenState {},
+    );
+
+    send_ix(
+        &mut context.banks_client,
+        &context.payer,
+        &[&context.payer, caller],
+        ix,
+        context.recent_blockhash,
+    )
+    .await?;
+
+    Ok(())
+}
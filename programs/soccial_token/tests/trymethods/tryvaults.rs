@@ -15,6 +15,8 @@
 // ✔ Transfer tokens between vaults using seed-resolved addresses  
 // ✔ Specialized wrappers for each vault type (airdrop, rewards, revenue, etc.)  
 // ✔ Internal contract-to-vault transfers (using PDA authority)  
+// ✔ Timelocked withdrawals: queue, execute and cancel  
+// ✔ Approval council: configure, propose, approve and execute operations  
 // ✔ Test functions to assert success or expected failure of vault interactions  
 //
 // ----------------------------------------------------------------------------
@@ -38,12 +40,14 @@
 // ============================================================================
 
 
-use soccial_token::vaults::VaultError;
-use solana_sdk::{msg, pubkey::Pubkey, signature::Keypair, signer::Signer, transport::TransportError};
+use anchor_lang::AnchorDeserialize;
+use soccial_token::vaults::{VaultError, VaultLedgerSnapshot};
+use solana_sdk::{msg, pubkey::Pubkey, signature::Keypair, signer::Signer, system_program, transaction::Transaction, transport::TransportError};
 use crate::testutils::{basics::*, environment::log_all_balances};
-use crate::testutils::environment::EnvProgramTestContext;
+use crate::testutils::environment::{get_vault_accounts_by_name, EnvProgramTestContext};
 use crate::trymethods::trygovernance::try_approve_proposal_flow;
 use soccial_token::{self, instruction as soccial_instruction};
+use solana_sdk::program_pack::Pack;
 
 // ============================================================================
 /// Attempts to deposit tokens from a participant into a specified vault.
@@ -94,6 +98,7 @@ pub async fn try_vault_deposit(
             vault_token_account,
             vault,
             vault_authority,
+            vault_ledger: derive_vault_ledger_pda(&context.program_id, &vault),
             user_access: None,
             token_state: seeds.token_state,
             token_program: spl_token::ID,
@@ -167,9 +172,14 @@ pub async fn try_vault_withdraw(
             user_token_account: seeds.user_token_ata,
             vault,
             vault_authority,
+            approval_council: derive_approval_council_pda(&context.program_id),
+            vault_limits: derive_vault_limits_pda(&context.program_id, &vault),
+            vault_ledger: derive_vault_ledger_pda(&context.program_id, &vault),
+            direct_outflow: derive_direct_outflow_pda(&context.program_id, &vault),
             user_access: None,
             token_state: seeds.token_state,
             token_program: spl_token::ID,
+            system_program: system_program::ID,
         },
         soccial_instruction::VaultWithdraw { args },
     );
@@ -185,6 +195,164 @@ pub async fn try_vault_withdraw(
     Ok(())
 }
 
+/// Derives the `DirectOutflowWindow` PDA of a vault.
+#[allow(dead_code)]
+pub fn derive_direct_outflow_pda(program_id: &Pubkey, vault: &Pubkey) -> Pubkey {
+    Pubkey::find_program_address(&[b"direct_outflow", vault.as_ref()], program_id).0
+}
+
+/// Derives the `PendingWithdrawal` PDA for `id`.
+#[allow(dead_code)]
+pub fn derive_pending_withdrawal_pda(program_id: &Pubkey, id: u64) -> Pubkey {
+    Pubkey::find_program_address(&[b"pending_withdrawal", &id.to_le_bytes()], program_id).0
+}
+
+// ============================================================================
+/// Queues a timelocked withdrawal of `amount` from `vault_name` (e.g.
+/// `"treasury"`) to the destination's ATA.
+///
+/// # Example:
+/// ```
+/// try_queue_vault_withdrawal(&mut context, &admin, "treasury", &user, 1, 5_000, Some("grant")).await?;
+/// ```
+// ============================================================================
+#[allow(dead_code)]
+pub async fn try_queue_vault_withdrawal(
+    context: &mut EnvProgramTestContext,
+    caller: &Keypair,
+    vault_name: &str,
+    destination: &Keypair,
+    id: u64,
+    amount: u64,
+    reason: Option<&str>,
+) -> Result<(), TransportError> {
+    let seeds = derive_seeds(&context.program_id, &destination.pubkey());
+    let (vault, vault_token_account) = get_vault_accounts_by_name(vault_name, &seeds);
+
+    let mut args = vec![id.to_string(), amount.to_string()];
+    if let Some(r) = reason {
+        args.push(r.to_string());
+    }
+
+    let ix = anchor_ix(
+        context.program_id,
+        soccial_token::accounts::QueueVaultWithdrawalContext {
+            caller: caller.pubkey(),
+            user_access: None,
+            pending_withdrawal: derive_pending_withdrawal_pda(&context.program_id, id),
+            vault_token_account,
+            recipient_token_account: seeds.user_token_ata,
+            vault,
+            approval_council: derive_approval_council_pda(&context.program_id),
+            token_state: seeds.token_state,
+            system_program: solana_sdk::system_program::ID,
+        },
+        soccial_instruction::QueueVaultWithdrawal { args },
+    );
+
+    send_ix(
+        &mut context.banks_client,
+        &context.payer,
+        &[&context.payer, caller],
+        ix,
+        context.recent_blockhash,
+    ).await?;
+
+    Ok(())
+}
+
+// ============================================================================
+/// Executes queued withdrawal `id` from `vault_name` to the destination's ATA.
+///
+/// # Example:
+/// ```
+/// try_execute_vault_withdrawal(&mut context, &admin, "treasury", &user, 1).await?;
+/// ```
+// ============================================================================
+#[allow(dead_code)]
+pub async fn try_execute_vault_withdrawal(
+    context: &mut EnvProgramTestContext,
+    caller: &Keypair,
+    vault_name: &str,
+    destination: &Keypair,
+    id: u64,
+) -> Result<(), TransportError> {
+    let seeds = derive_seeds(&context.program_id, &destination.pubkey());
+    let (vault, vault_token_account) = get_vault_accounts_by_name(vault_name, &seeds);
+
+    let ix = anchor_ix(
+        context.program_id,
+        soccial_token::accounts::ExecuteVaultWithdrawalContext {
+            caller: caller.pubkey(),
+            user_access: None,
+            pending_withdrawal: derive_pending_withdrawal_pda(&context.program_id, id),
+            vault_token_account,
+            recipient_token_account: seeds.user_token_ata,
+            vault,
+            approval_council: derive_approval_council_pda(&context.program_id),
+            vault_limits: derive_vault_limits_pda(&context.program_id, &vault),
+            vault_ledger: derive_vault_ledger_pda(&context.program_id, &vault),
+            token_state: seeds.token_state,
+            token_program: spl_token::ID,
+        },
+        soccial_instruction::ExecuteVaultWithdrawal { args: vec![id.to_string()] },
+    );
+
+    send_ix(
+        &mut context.banks_client,
+        &context.payer,
+        &[&context.payer, caller],
+        ix,
+        context.recent_blockhash,
+    ).await?;
+
+    Ok(())
+}
+
+// ============================================================================
+/// Cancels queued withdrawal `id`. The caller must be an admin or hold
+/// `guard_vaults`; the `user_access` PDA is passed when it exists.
+///
+/// # Example:
+/// ```
+/// try_cancel_vault_withdrawal(&mut context, &guardian, 1).await?;
+/// ```
+// ============================================================================
+#[allow(dead_code)]
+pub async fn try_cancel_vault_withdrawal(
+    context: &mut EnvProgramTestContext,
+    caller: &Keypair,
+    id: u64,
+) -> Result<(), TransportError> {
+    let seeds = derive_seeds(&context.program_id, &caller.pubkey());
+    let user_access = context
+        .banks_client
+        .get_account(seeds.user_access)
+        .await?
+        .map(|_| seeds.user_access);
+
+    let ix = anchor_ix(
+        context.program_id,
+        soccial_token::accounts::CancelVaultWithdrawalContext {
+            caller: caller.pubkey(),
+            user_access,
+            pending_withdrawal: derive_pending_withdrawal_pda(&context.program_id, id),
+            token_state: seeds.token_state,
+        },
+        soccial_instruction::CancelVaultWithdrawal { args: vec![id.to_string()] },
+    );
+
+    send_ix(
+        &mut context.banks_client,
+        &context.payer,
+        &[&context.payer, caller],
+        ix,
+        context.recent_blockhash,
+    ).await?;
+
+    Ok(())
+}
+
 
 // ============================================================================
 /// Transfers tokens between two vaults (source → destination).
@@ -234,6 +402,8 @@ pub async fn try_vault_transfer(
     let proposal = proposal_id.map(|id| {
         derive_proposal_account(&context.program_id, id).0
     });
+
+    let destination_vault = token_account_owner(context, destination_vault_token_account).await?;
     
     let ix = anchor_ix(
         context.program_id,
@@ -242,12 +412,19 @@ pub async fn try_vault_transfer(
             source_vault_token_account,
             destination_vault_token_account,
             source_vault_authority,
+            vault_policy: derive_vault_policy_pda(&context.program_id),
+            approval_council: derive_approval_council_pda(&context.program_id),
+            vault_limits: derive_vault_limits_pda(&context.program_id, &source_vault),
+            source_vault_ledger: derive_vault_ledger_pda(&context.program_id, &source_vault),
+            direct_outflow: derive_direct_outflow_pda(&context.program_id, &source_vault),
+            destination_vault_ledger: derive_vault_ledger_pda(&context.program_id, &destination_vault),
             caller: caller.pubkey(),
             user_access: None,
             token_state: seeds.token_state,
             governance_state: seeds.governance_state,
             proposal,
             token_program: spl_token::ID,
+            system_program: system_program::ID,
         },
         soccial_token::instruction::TransferBetweenVaults { args },
     );
@@ -476,6 +653,7 @@ pub async fn try_transfer_contract_to_vault(
             source_token_account: contract_token_account,
             destination_vault,
             destination_vault_token_account,
+            destination_vault_ledger: derive_vault_ledger_pda(&context.program_id, &destination_vault),
             caller: context.caller.pubkey(),
             user_access: None,
             token_state: seeds.token_state,
@@ -933,3 +1111,417 @@ pub async fn try_transfer_between_vaults_test_vault_fail(
 }
 
 
+/// Derives the `ApprovalCouncil` PDA.
+#[allow(dead_code)]
+pub fn derive_approval_council_pda(program_id: &Pubkey) -> Pubkey {
+    Pubkey::find_program_address(&[b"approval_council"], program_id).0
+}
+
+/// Derives the `VaultLimits` PDA of `vault`.
+#[allow(dead_code)]
+pub fn derive_vault_limits_pda(program_id: &Pubkey, vault: &Pubkey) -> Pubkey {
+    Pubkey::find_program_address(&[b"vault_limits", vault.as_ref()], program_id).0
+}
+
+/// Derives the `VaultLedger` PDA of `vault`.
+#[allow(dead_code)]
+pub fn derive_vault_ledger_pda(program_id: &Pubkey, vault: &Pubkey) -> Pubkey {
+    Pubkey::find_program_address(&[b"vault_ledger", vault.as_ref()], program_id).0
+}
+
+/// Derives the global `VaultPolicy` PDA.
+#[allow(dead_code)]
+pub fn derive_vault_policy_pda(program_id: &Pubkey) -> Pubkey {
+    Pubkey::find_program_address(&[b"vault_policy"], program_id).0
+}
+
+/// Reads the owner of an SPL token account, as used for ledger PDAs.
+#[allow(dead_code)]
+pub async fn token_account_owner(
+    context: &mut EnvProgramTestContext,
+    token_account: Pubkey,
+) -> Result<Pubkey, TransportError> {
+    let account = context.banks_client.get_account(token_account).await?
+        .expect("token account should exist");
+    Ok(spl_token::state::Account::unpack(&account.data).unwrap().owner)
+}
+
+/// Derives the `CouncilOperation` PDA for `id`.
+#[allow(dead_code)]
+pub fn derive_council_operation_pda(program_id: &Pubkey, id: u64) -> Pubkey {
+    Pubkey::find_program_address(&[b"council_operation", &id.to_le_bytes()], program_id).0
+}
+
+// ============================================================================
+/// Replaces the approval council through an approved `UpdateGovernance`
+/// proposal. `args` is `[threshold, member...]`.
+///
+/// # Example:
+/// ```
+/// try_update_approval_council(&mut context, &admin, vec!["2".into(), a, b, c], proposal_id).await?;
+/// ```
+// ============================================================================
+#[allow(dead_code)]
+pub async fn try_update_approval_council(
+    context: &mut EnvProgramTestContext,
+    caller: &Keypair,
+    args: Vec<String>,
+    proposal_id: u64,
+) -> Result<(), TransportError> {
+    let seeds = derive_seeds(&context.program_id, &caller.pubkey());
+
+    let ix = anchor_ix(
+        context.program_id,
+        soccial_token::accounts::UpdateApprovalCouncilContext {
+            caller: caller.pubkey(),
+            user_access: None,
+            approval_council: derive_approval_council_pda(&context.program_id),
+            proposal: derive_proposal_account(&context.program_id, proposal_id).0,
+            governance_state: seeds.governance_state,
+            token_state: seeds.token_state,
+            system_program: solana_sdk::system_program::ID,
+        },
+        soccial_instruction::UpdateApprovalCouncil { args },
+    );
+
+    send_ix(
+        &mut context.banks_client,
+        &context.payer,
+        &[&context.payer, caller],
+        ix,
+        context.recent_blockhash,
+    ).await?;
+
+    Ok(())
+}
+
+// ============================================================================
+/// Proposes a council operation of `kind` (`"withdraw"` or `"transfer"`)
+/// moving `amount` from `vault_name` to `destination` (a token account).
+/// `proposal_id` is required for transfers whose policy rule needs one.
+///
+/// # Example:
+/// ```
+/// try_propose_council_operation(&mut context, &member, 1, "withdraw", "treasury", user_ata, 5_000, None).await?;
+/// ```
+// ============================================================================
+#[allow(dead_code)]
+pub async fn try_propose_council_operation(
+    context: &mut EnvProgramTestContext,
+    caller: &Keypair,
+    id: u64,
+    kind: &str,
+    vault_name: &str,
+    destination: Pubkey,
+    amount: u64,
+    proposal_id: Option<u64>,
+) -> Result<(), TransportError> {
+    let seeds = derive_seeds(&context.program_id, &caller.pubkey());
+    let (source_vault, source_vault_token_account) = get_vault_accounts_by_name(vault_name, &seeds);
+    let proposal = proposal_id.map(|id| derive_proposal_account(&context.program_id, id).0);
+
+    let ix = anchor_ix(
+        context.program_id,
+        soccial_token::accounts::ProposeCouncilOperationContext {
+            caller: caller.pubkey(),
+            user_access: None,
+            approval_council: derive_approval_council_pda(&context.program_id),
+            council_operation: derive_council_operation_pda(&context.program_id, id),
+            source_vault,
+            source_vault_token_account,
+            destination_token_account: destination,
+            vault_policy: derive_vault_policy_pda(&context.program_id),
+            proposal,
+            governance_state: seeds.governance_state,
+            token_state: seeds.token_state,
+            system_program: solana_sdk::system_program::ID,
+        },
+        soccial_instruction::ProposeCouncilOperation {
+            args: vec![id.to_string(), kind.to_string(), amount.to_string()],
+        },
+    );
+
+    send_ix(
+        &mut context.banks_client,
+        &context.payer,
+        &[&context.payer, caller],
+        ix,
+        context.recent_blockhash,
+    ).await?;
+
+    Ok(())
+}
+
+// ============================================================================
+/// Approves council operation `id` as `caller`.
+///
+/// # Example:
+/// ```
+/// try_approve_council_operation(&mut context, &member, 1).await?;
+/// ```
+// ============================================================================
+#[allow(dead_code)]
+pub async fn try_approve_council_operation(
+    context: &mut EnvProgramTestContext,
+    caller: &Keypair,
+    id: u64,
+) -> Result<(), TransportError> {
+    let seeds = derive_seeds(&context.program_id, &caller.pubkey());
+
+    let ix = anchor_ix(
+        context.program_id,
+        soccial_token::accounts::ApproveCouncilOperationContext {
+            caller: caller.pubkey(),
+            user_access: None,
+            approval_council: derive_approval_council_pda(&context.program_id),
+            council_operation: derive_council_operation_pda(&context.program_id, id),
+            token_state: seeds.token_state,
+        },
+        soccial_instruction::ApproveCouncilOperation { args: vec![id.to_string()] },
+    );
+
+    send_ix(
+        &mut context.banks_client,
+        &context.payer,
+        &[&context.payer, caller],
+        ix,
+        context.recent_blockhash,
+    ).await?;
+
+    Ok(())
+}
+
+// ============================================================================
+/// Executes council operation `id` from `vault_name` to `destination`.
+/// `proposal_id` is required for transfers whose policy rule needs one.
+///
+/// # Example:
+/// ```
+/// try_execute_council_operation(&mut context, &member, 1, "treasury", user_ata, None).await?;
+/// ```
+// ============================================================================
+#[allow(dead_code)]
+pub async fn try_execute_council_operation(
+    context: &mut EnvProgramTestContext,
+    caller: &Keypair,
+    id: u64,
+    vault_name: &str,
+    destination: Pubkey,
+    proposal_id: Option<u64>,
+) -> Result<(), TransportError> {
+    let seeds = derive_seeds(&context.program_id, &caller.pubkey());
+    let (source_vault, source_vault_token_account) = get_vault_accounts_by_name(vault_name, &seeds);
+    let destination_owner = token_account_owner(context, destination).await?;
+    let proposal = proposal_id.map(|id| derive_proposal_account(&context.program_id, id).0);
+
+    let ix = anchor_ix(
+        context.program_id,
+        soccial_token::accounts::ExecuteCouncilOperationContext {
+            caller: caller.pubkey(),
+            user_access: None,
+            approval_council: derive_approval_council_pda(&context.program_id),
+            vault_limits: derive_vault_limits_pda(&context.program_id, &source_vault),
+            council_operation: derive_council_operation_pda(&context.program_id, id),
+            source_vault,
+            source_vault_token_account,
+            source_vault_ledger: derive_vault_ledger_pda(&context.program_id, &source_vault),
+            destination_token_account: destination,
+            destination_ledger: derive_vault_ledger_pda(&context.program_id, &destination_owner),
+            vault_policy: derive_vault_policy_pda(&context.program_id),
+            proposal,
+            governance_state: seeds.governance_state,
+            token_state: seeds.token_state,
+            token_program: spl_token::ID,
+        },
+        soccial_instruction::ExecuteCouncilOperation { args: vec![id.to_string()] },
+    );
+
+    send_ix(
+        &mut context.banks_client,
+        &context.payer,
+        &[&context.payer, caller],
+        ix,
+        context.recent_blockhash,
+    ).await?;
+
+    Ok(())
+}
+
+// ============================================================================
+/// Sets the outflow limits of `vault_name` through an approved
+/// `UpdateGovernance` proposal. `args` is `[epoch_secs, max_outflow, pause_threshold]`.
+///
+/// # Example:
+/// ```
+/// try_set_vault_limits(&mut context, &admin, "treasury", vec!["86400".into(), "1000".into(), "800".into()], proposal_id).await?;
+/// ```
+// ============================================================================
+#[allow(dead_code)]
+pub async fn try_set_vault_limits(
+    context: &mut EnvProgramTestContext,
+    caller: &Keypair,
+    vault_name: &str,
+    args: Vec<String>,
+    proposal_id: u64,
+) -> Result<(), TransportError> {
+    let seeds = derive_seeds(&context.program_id, &caller.pubkey());
+    let (vault, _) = get_vault_accounts_by_name(vault_name, &seeds);
+
+    let ix = anchor_ix(
+        context.program_id,
+        soccial_token::accounts::SetVaultLimitsContext {
+            caller: caller.pubkey(),
+            user_access: None,
+            vault,
+            vault_limits: derive_vault_limits_pda(&context.program_id, &vault),
+            proposal: derive_proposal_account(&context.program_id, proposal_id).0,
+            governance_state: seeds.governance_state,
+            token_state: seeds.token_state,
+            system_program: solana_sdk::system_program::ID,
+        },
+        soccial_instruction::SetVaultLimits { args },
+    );
+
+    send_ix(
+        &mut context.banks_client,
+        &context.payer,
+        &[&context.payer, caller],
+        ix,
+        context.recent_blockhash,
+    ).await?;
+
+    Ok(())
+}
+
+// ============================================================================
+/// Opens the on-chain ledger of `vault_name`.
+///
+/// # Example:
+/// ```
+/// try_open_vault_ledger(&mut context, &admin, "treasury").await?;
+/// ```
+// ============================================================================
+#[allow(dead_code)]
+pub async fn try_open_vault_ledger(
+    context: &mut EnvProgramTestContext,
+    caller: &Keypair,
+    vault_name: &str,
+) -> Result<(), TransportError> {
+    let seeds = derive_seeds(&context.program_id, &caller.pubkey());
+    let (vault, vault_token_account) = get_vault_accounts_by_name(vault_name, &seeds);
+
+    let ix = anchor_ix(
+        context.program_id,
+        soccial_token::accounts::OpenVaultLedgerContext {
+            caller: caller.pubkey(),
+            user_access: None,
+            vault,
+            vault_token_account,
+            vault_ledger: derive_vault_ledger_pda(&context.program_id, &vault),
+            token_state: seeds.token_state,
+            system_program: solana_sdk::system_program::ID,
+        },
+        soccial_instruction::OpenVaultLedger {},
+    );
+
+    send_ix(
+        &mut context.banks_client,
+        &context.payer,
+        &[&context.payer, caller],
+        ix,
+        context.recent_blockhash,
+    ).await?;
+
+    Ok(())
+}
+
+// ============================================================================
+/// Reads the ledger of `vault_name` via `get_vault_ledger`.
+///
+/// # Example:
+/// ```
+/// let snapshot = try_get_vault_ledger(&mut context, &admin, "treasury").await?;
+/// assert!(snapshot.reconciled);
+/// ```
+// ============================================================================
+#[allow(dead_code)]
+pub async fn try_get_vault_ledger(
+    context: &mut EnvProgramTestContext,
+    caller: &Keypair,
+    vault_name: &str,
+) -> Result<VaultLedgerSnapshot, TransportError> {
+    let seeds = derive_seeds(&context.program_id, &caller.pubkey());
+    let (vault, vault_token_account) = get_vault_accounts_by_name(vault_name, &seeds);
+
+    let ix = anchor_ix(
+        context.program_id,
+        soccial_token::accounts::ReadVaultLedgerContext {
+            caller: caller.pubkey(),
+            vault_ledger: derive_vault_ledger_pda(&context.program_id, &vault),
+            vault_token_account,
+            token_state: seeds.token_state,
+        },
+        soccial_instruction::GetVaultLedger {},
+    );
+
+    let mut tx = Transaction::new_with_payer(&[ix], Some(&context.payer.pubkey()));
+    tx.sign(&[&context.payer, caller], context.recent_blockhash);
+
+    let outcome = context.banks_client.process_transaction_with_metadata(tx).await?;
+    outcome.result.map_err(TransportError::TransactionError)?;
+
+    let return_data = outcome
+        .metadata
+        .and_then(|metadata| metadata.return_data)
+        .expect("get_vault_ledger should set return data");
+
+    Ok(VaultLedgerSnapshot::try_from_slice(&return_data.data).expect("Failed to deserialize"))
+}
+
+// ============================================================================
+/// Sets the transfer rule of `source → destination` (vault seeds) through an
+/// approved `UpdateGovernance` proposal. `rule` is `Allowed`, `Forbidden` or
+/// the proposal type the transfer requires.
+///
+/// # Example:
+/// ```
+/// try_set_vault_transfer_rule(&mut context, &admin, "revenue_vault", "rewards_vault", "Forbidden", proposal_id).await?;
+/// ```
+// ============================================================================
+#[allow(dead_code)]
+pub async fn try_set_vault_transfer_rule(
+    context: &mut EnvProgramTestContext,
+    caller: &Keypair,
+    source: &str,
+    destination: &str,
+    rule: &str,
+    proposal_id: u64,
+) -> Result<(), TransportError> {
+    let seeds = derive_seeds(&context.program_id, &caller.pubkey());
+
+    let ix = anchor_ix(
+        context.program_id,
+        soccial_token::accounts::SetVaultTransferRuleContext {
+            caller: caller.pubkey(),
+            user_access: None,
+            vault_policy: derive_vault_policy_pda(&context.program_id),
+            proposal: derive_proposal_account(&context.program_id, proposal_id).0,
+            governance_state: seeds.governance_state,
+            token_state: seeds.token_state,
+            system_program: solana_sdk::system_program::ID,
+        },
+        soccial_instruction::SetVaultTransferRule {
+            args: vec![source.to_string(), destination.to_string(), rule.to_string()],
+        },
+    );
+
+    send_ix(
+        &mut context.banks_client,
+        &context.payer,
+        &[&context.payer, caller],
+        ix,
+        context.recent_blockhash,
+    ).await?;
+
+    Ok(())
+}
@@ -35,6 +35,7 @@
 
 use crate::testutils::environment::EnvProgramTestContext;
 use crate::testutils::basics::*;
+use crate::trymethods::tryvaults::{derive_vault_ledger_pda, derive_vault_limits_pda};
 use solana_sdk::{pubkey::Pubkey, signature::Keypair, signer::Signer, system_program, transport::TransportError};
 use spl_associated_token_account::{get_associated_token_address, ID as ASSOCIATED_TOKEN_PROGRAM_ID};
 use spl_token::ID as TOKEN_PROGRAM_ID;
@@ -140,8 +141,10 @@ pub async fn try_create_vesting_schedule(
         destination_token_account: seeds.user_token_ata,
         liquidity_vault: seeds.liquidity_vault,
         liquidity_vault_token_account: seeds.liquidity_vault_token_account,
+        liquidity_ledger: derive_vault_ledger_pda(&context.program_id, &seeds.liquidity_vault),
         vesting_vault: seeds.vesting_vault,
         vesting_vault_token_account: seeds.vesting_vault_token_account,
+        vesting_ledger: derive_vault_ledger_pda(&context.program_id, &seeds.vesting_vault),
         associated_token_program: ASSOCIATED_TOKEN_PROGRAM_ID,
         token_program: TOKEN_PROGRAM_ID,
         system_program: system_program::ID,
@@ -209,8 +212,10 @@ pub async fn try_cancel_vesting_schedule(
         vesting_state: seeds.vesting_state,
         liquidity_vault: seeds.liquidity_vault,
         liquidity_vault_token_account: seeds.liquidity_vault_token_account,
+        liquidity_ledger: derive_vault_ledger_pda(&context.program_id, &seeds.liquidity_vault),
         vesting_vault: seeds.vesting_vault,
         vesting_vault_token_account: seeds.vesting_vault_token_account,
+        vesting_ledger: derive_vault_ledger_pda(&context.program_id, &seeds.vesting_vault),
         mint_authority: seeds.mint_authority,
         mint: seeds.token_mint,
         destination_token_account: seeds.user_token_ata,
@@ -300,8 +305,10 @@ pub async fn try_update_vesting_schedule(
         vesting_state: seeds.vesting_state,
         liquidity_vault: seeds.liquidity_vault,
         liquidity_vault_token_account: seeds.liquidity_vault_token_account,
+        liquidity_ledger: derive_vault_ledger_pda(&context.program_id, &seeds.liquidity_vault),
         vesting_vault: seeds.vesting_vault,
         vesting_vault_token_account: seeds.vesting_vault_token_account,
+        vesting_ledger: derive_vault_ledger_pda(&context.program_id, &seeds.vesting_vault),
         mint_authority: seeds.mint_authority,
         mint: seeds.token_mint,
         destination_token_account: seeds.user_token_ata,
@@ -451,6 +458,8 @@ pub async fn try_claim_vested_tokens(
         mint: seeds.token_mint,
         vesting_vault,
         vesting_vault_token_account,
+        vault_limits: derive_vault_limits_pda(&context.program_id, &vesting_vault),
+        vesting_ledger: derive_vault_ledger_pda(&context.program_id, &vesting_vault),
         destination_token_account: seeds.user_token_ata,
         token_program: TOKEN_PROGRAM_ID,
         system_program: system_program::ID,